@@ -0,0 +1,452 @@
+//! Compile-time check of `@[story:…]` embeds.
+//!
+//! Embeds are resolved when the storybook runs, and listed in the registry
+//! summary's broken links when they don't. The `#[storybook]` and
+//! `storydoc!` macros catch the typos earlier with this crate: they read the
+//! components and story titles declared in the crate's sources into a
+//! [`Catalog`] and warn about the embeds naming a story that doesn't exist.
+//!
+//! Only what can be read from the sources is checked. Embeds of components
+//! from other crates, and of components whose story titles are computed
+//! (not string literals, or generated with `auto_variants`), are left to the
+//! runtime check.
+
+use proc_macro2::TokenStream;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Attribute, Expr, Lit, Meta, Token, Type};
+
+/// Story titles of a component, `None` when they can't all be read from the
+/// sources.
+type StoryTitles = Option<Vec<String>>;
+
+/// An embed naming a story that isn't declared, see [`Catalog::unresolved`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnresolvedEmbed {
+    /// 0-based line of the embed in the Markdown text
+    pub line: usize,
+    /// The embed, e.g. `@[story:Forms/Button/Primry]`
+    pub embed: String,
+    pub reason: String,
+}
+
+/// The story titles of the components declared in a crate's sources.
+#[derive(Debug, Default)]
+pub struct Catalog {
+    components: HashMap<String, StoryTitles>,
+}
+
+impl Catalog {
+    /// Read the components and stories of `files`. A file that doesn't parse
+    /// is skipped: the compiler reports it.
+    pub fn read(files: &[PathBuf]) -> io::Result<Self> {
+        let mut catalog = Catalog::default();
+        for file in files {
+            let _ = catalog.add_source(&fs::read_to_string(file)?);
+        }
+        Ok(catalog)
+    }
+
+    /// Add the components and stories of a Rust source file.
+    pub fn add_source(&mut self, source: &str) -> syn::Result<()> {
+        let file = syn::parse_file(source)?;
+        self.visit_file(&file);
+        Ok(())
+    }
+
+    /// The story titles of `component`, `None` when it's unknown or its
+    /// titles are computed.
+    pub fn titles(&self, component: &str) -> Option<&[String]> {
+        self.components.get(component)?.as_deref()
+    }
+
+    /// The `@[story:…]` embeds of `markdown` naming a story missing from a
+    /// known component.
+    pub fn unresolved(&self, markdown: &str) -> Vec<UnresolvedEmbed> {
+        story_embeds(markdown)
+            .filter_map(|(line, story_path)| {
+                let reason = match story_path.rsplit_once('/') {
+                    None => "expected `Category/Component/Story`".to_string(),
+                    Some((component_path, story)) => {
+                        let component = component_path.rsplit('/').next().unwrap_or_default();
+                        let titles = self.titles(component)?;
+                        if titles.iter().any(|title| title == story) {
+                            return None;
+                        }
+                        format!(
+                            "{component} has no story \"{story}\" ({})",
+                            titles.join(", ")
+                        )
+                    }
+                };
+                Some(UnresolvedEmbed {
+                    line,
+                    embed: format!("@[story:{story_path}]"),
+                    reason,
+                })
+            })
+            .collect()
+    }
+
+    fn add_titles(&mut self, component: String, titles: StoryTitles) {
+        let entry = self.components.entry(component).or_insert(Some(Vec::new()));
+        match (entry.as_mut(), titles) {
+            (Some(known), Some(titles)) => known.extend(titles),
+            _ => *entry = None,
+        }
+    }
+
+    /// Record the stories implied by the `#[storybook]` attribute of the
+    /// `component` item, also under `cfg_attr`.
+    fn add_storybook_item(&mut self, component: String, attrs: &[Attribute]) {
+        let args: Vec<Meta> = attrs.iter().flat_map(storybook_args).collect();
+        // `auto_story` implements `Stories` with a "Default" story
+        if args.iter().any(|arg| arg.path().is_ident("auto_story")) {
+            self.add_titles(component.clone(), Some(vec!["Default".to_string()]));
+        }
+        // `auto_variants` adds stories at runtime
+        if args.iter().any(|arg| arg.path().is_ident("auto_variants")) {
+            self.add_titles(component, None);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Catalog {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        self.add_storybook_item(item.sig.ident.to_string(), &item.attrs);
+        visit::visit_item_fn(self, item);
+    }
+
+    fn visit_item_struct(&mut self, item: &'ast syn::ItemStruct) {
+        if let Some(component) = item.ident.to_string().strip_suffix("Props") {
+            self.add_storybook_item(component.to_string(), &item.attrs);
+        }
+        visit::visit_item_struct(self, item);
+    }
+
+    fn visit_item_impl(&mut self, item: &'ast syn::ItemImpl) {
+        let implements_stories = item
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Stories");
+        if let Some(component) = props_component(&item.self_ty).filter(|_| implements_stories) {
+            let mut titles = TitleCollector {
+                titles: Some(Vec::new()),
+            };
+            titles.visit_item_impl(item);
+            self.add_titles(component, titles.titles.filter(|titles| !titles.is_empty()));
+        }
+        visit::visit_item_impl(self, item);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "stories")
+        {
+            if let Ok(StoriesMacro(impls)) = mac.parse_body() {
+                for (component, titles) in impls {
+                    self.add_titles(component, titles);
+                }
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+}
+
+/// Collects the literal titles of the `Story::new` and
+/// `Story::with_description` calls of a `Stories` impl.
+struct TitleCollector {
+    titles: StoryTitles,
+}
+
+impl<'ast> Visit<'ast> for TitleCollector {
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if is_story_constructor(&call.func) {
+            match call.args.first().and_then(string_literal) {
+                Some(title) => self
+                    .titles
+                    .iter_mut()
+                    .for_each(|titles| titles.push(title.clone())),
+                None => self.titles = None,
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    // Stories built by loops or iterators
+    fn visit_expr_for_loop(&mut self, expr: &'ast syn::ExprForLoop) {
+        self.titles = None;
+        visit::visit_expr_for_loop(self, expr);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if ["map", "flat_map", "extend", "chain", "push"]
+            .iter()
+            .any(|method| call.method == method)
+        {
+            self.titles = None;
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+
+    // `vec![…]` and other macros taking expressions. The stories of other
+    // macros can't be read.
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        match mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            Ok(exprs) => exprs.iter().for_each(|expr| self.visit_expr(expr)),
+            Err(_) if mac.tokens.to_string().contains("Story") => self.titles = None,
+            Err(_) => {}
+        }
+    }
+}
+
+/// The titles of a `stories!` invocation, per component.
+struct StoriesMacro(Vec<(String, StoryTitles)>);
+
+impl Parse for StoriesMacro {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut impls = Vec::new();
+        while !input.is_empty() {
+            let props: Type = input.parse()?;
+            let content;
+            syn::braced!(content in input);
+            let mut titles = Some(Vec::new());
+            while !content.is_empty() {
+                match content.parse::<Lit>()? {
+                    Lit::Str(title) => titles
+                        .iter_mut()
+                        .for_each(|titles| titles.push(title.value())),
+                    _ => titles = None,
+                }
+                if content.peek(syn::Ident) {
+                    let _desc: syn::Ident = content.parse()?;
+                    let _description: Lit = content.parse()?;
+                }
+                content.parse::<Token![=>]>()?;
+                let fields;
+                syn::braced!(fields in content);
+                fields.parse::<TokenStream>()?;
+                // Builder calls, e.g. `.with_tags([…])`
+                while content.peek(Token![.]) {
+                    content.parse::<Token![.]>()?;
+                    let _method: syn::Ident = content.parse()?;
+                    let args;
+                    syn::parenthesized!(args in content);
+                    args.parse::<TokenStream>()?;
+                }
+                if !content.is_empty() {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+            if let Some(component) = props_component(&props) {
+                impls.push((component, titles));
+            }
+        }
+        Ok(StoriesMacro(impls))
+    }
+}
+
+/// The `.rs` files under `dir`, recursively.
+pub fn source_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    collect_rs_files(dir, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_rs_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_rs_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The 0-based line and story path of the `@[story:…]` embeds of
+/// `markdown`. As in the rendered docs, an embed stands on its own line,
+/// out of fenced code blocks.
+fn story_embeds(markdown: &str) -> impl Iterator<Item = (usize, String)> + '_ {
+    let mut fence: Option<&str> = None;
+    markdown
+        .lines()
+        .enumerate()
+        .filter_map(move |(line, text)| {
+            let text = text.trim();
+            if let Some(marker) = fence {
+                if text.starts_with(marker) {
+                    fence = None;
+                }
+                return None;
+            }
+            if let Some(marker) = ["```", "~~~"].into_iter().find(|m| text.starts_with(m)) {
+                fence = Some(marker);
+                return None;
+            }
+            let rest = text.strip_prefix("@[story:")?;
+            Some((line, rest[..rest.find(']')?].trim().to_string()))
+        })
+}
+
+/// The arguments of a `#[storybook(…)]` or
+/// `#[cfg_attr(…, storybook(…))]` attribute.
+fn storybook_args(attr: &Attribute) -> Vec<Meta> {
+    let metas = if attr.path().is_ident("cfg_attr") {
+        attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .map(|metas| metas.into_iter().skip(1).collect())
+            .unwrap_or_default()
+    } else {
+        vec![attr.meta.clone()]
+    };
+    metas
+        .into_iter()
+        .filter(|meta| {
+            meta.path()
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "storybook")
+        })
+        .flat_map(|meta| match meta {
+            Meta::List(list) => list
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .map(|args| args.into_iter().collect())
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
+
+/// The component of a `…Props` type.
+fn props_component(ty: &Type) -> Option<String> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let name = path.path.segments.last()?.ident.to_string();
+    name.strip_suffix("Props").map(str::to_string)
+}
+
+/// Whether `func` is `Story::new` or `Story::with_description`.
+fn is_story_constructor(func: &Expr) -> bool {
+    let Expr::Path(path) = func else {
+        return false;
+    };
+    let mut segments = path.path.segments.iter().rev();
+    matches!(
+        (segments.next(), segments.next()),
+        (Some(constructor), Some(story))
+            if story.ident == "Story"
+                && (constructor.ident == "new" || constructor.ident == "with_description")
+    )
+}
+
+fn string_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.value()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPONENTS: &str = include_str!("../tests/fixtures/components.rs");
+
+    fn catalog() -> Catalog {
+        let mut catalog = Catalog::default();
+        catalog.add_source(COMPONENTS).unwrap();
+        catalog
+    }
+
+    #[test]
+    fn reads_literal_story_titles() {
+        // Raw strings, and brackets in strings, chars and comments
+        assert_eq!(
+            catalog().titles("Button"),
+            Some(&["Primary".to_string(), "Secondary".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn skips_commented_out_code() {
+        assert!(!catalog().components.contains_key("Tag"));
+    }
+
+    #[test]
+    fn reads_stories_macro_titles() {
+        assert_eq!(
+            catalog().titles("Tooltip"),
+            Some(&["Short".to_string(), "Long".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn reads_auto_story() {
+        assert_eq!(
+            catalog().titles("Divider"),
+            Some(&["Default".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn skips_computed_story_titles() {
+        let catalog = catalog();
+        assert_eq!(catalog.titles("Badge"), None);
+        assert_eq!(catalog.titles("Alert"), None);
+
+        let mut catalog = Catalog::default();
+        let source = r#"impl Stories for TagProps {
+            fn stories() -> Vec<Story<Self>> {
+                vec![Story::new(TITLE, Self::default())]
+            }
+        }"#;
+        catalog.add_source(source).unwrap();
+        assert!(catalog.components.contains_key("Tag"));
+        assert_eq!(catalog.titles("Tag"), None);
+    }
+
+    #[test]
+    fn reports_mistyped_stories_only() {
+        let markdown = "A button.\n\
+            \n\
+            @[story:Forms/Button/Primary]\n\
+            @[story:Forms/Button/Primry]\n\
+            @[story:Display/Badge/Size 3]\n\
+            @[story:Other/Unknown/Story]\n\
+            ```text\n\
+            @[story:Forms/Button/Ignored in code blocks]\n\
+            ```";
+        assert_eq!(
+            catalog().unresolved(markdown),
+            [UnresolvedEmbed {
+                line: 3,
+                embed: "@[story:Forms/Button/Primry]".to_string(),
+                reason: "Button has no story \"Primry\" (Primary, Secondary)".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn reads_source_files() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let catalog = Catalog::read(&source_files(&dir).unwrap()).unwrap();
+        assert_eq!(
+            catalog.titles("Divider"),
+            Some(&["Default".to_string()][..])
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+/// A button.
+///
+/// @[story:Forms/Button/Primary]
+#[storybook(tag = "Forms")]
+#[component]
+pub fn Button(label: String) -> Element {
+    rsx! { button { "{label}" } }
+}
+
+impl Stories for ButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        // Story::new("Commented out", Self { label: "Hidden".to_string() }),
+        vec![
+            Story::new("Primary", Self { label: "Save".to_string() }),
+            Story::with_description(
+                r#"Secondary"#,
+                "A \"quiet\" button: }",
+                Self { label: '}'.to_string() },
+            ),
+        ]
+    }
+}
+
+/* impl Stories for TagProps { fn stories() -> Vec<Story<Self>> { vec![] } } */
+
+/// A badge per size.
+#[storybook(tag = "Display")]
+#[component]
+pub fn Badge(size: u8) -> Element {
+    rsx! { span { "{size}" } }
+}
+
+impl Stories for BadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        (1..=4)
+            .map(|size| Story::new(format!("Size {size}"), Self { size }))
+            .collect()
+    }
+}
+
+/// A divider.
+#[cfg_attr(feature = "stories", storybook(tag = "Layout", auto_story))]
+#[component]
+pub fn Divider(#[props(default)] vertical: bool) -> Element {
+    rsx! { hr {} }
+}
+
+/// An alert per variant.
+#[storybook(tag = "Feedback", auto_variants = ["variant"])]
+#[component]
+pub fn Alert(variant: String) -> Element {
+    rsx! { div { "{variant}" } }
+}
+
+impl Stories for AlertProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new("Info", Self { variant: "info".to_string() })]
+    }
+}
+
+#[storybook(tag = "Overlays")]
+#[derive(Props, Clone, PartialEq)]
+pub struct TooltipProps {
+    text: String,
+}
+
+#[component]
+pub fn Tooltip(props: TooltipProps) -> Element {
+    rsx! { span { "{props.text}" } }
+}
+
+storybook::stories! {
+    TooltipProps {
+        "Short" => { text: "Hi".to_string() },
+        "Long" desc "Wraps over several lines" => {
+            text: "A much longer tooltip".to_string(),
+        }.with_tags(["layout"]),
+    }
+}
@@ -168,6 +168,11 @@ impl Stories for ExampleCardProps {
             Story::new("Default", Self {
                 title: "Card Title".to_string(),
                 content: "This is the card content.".to_string(),
+            })
+            .with_play(|ctx| async move {
+                ctx.assert_exists("h3").await;
+                ctx.assert_text("Card Title").await;
+                ctx.assert_text("This is the card content.").await;
             }),
             Story::with_description(
                 "Long Content",
@@ -192,7 +197,8 @@ impl Stories for ExampleCardProps {
                     title: "Hi".to_string(),
                     content: "Short.".to_string(),
                 },
-            ),
+            )
+            .with_tags(["wip"]),
         ]
     }
 }
@@ -451,5 +457,8 @@ impl Stories for ExampleAlertProps {
 storybook::storydoc!("Examples", "assets/getting-started.md");
 
 fn main() {
+    if storybook::scaffold::run_cli() {
+        return;
+    }
     storybook::launch(StorybookConfig::default().with_title("Example Storybook"));
 }
@@ -0,0 +1,311 @@
+//! `config!()`: build a `StorybookConfig` from `storybook.toml` or
+//! `[package.metadata.storybook]`.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Name of the standalone configuration file, next to `Cargo.toml`.
+const CONFIG_FILE: &str = "storybook.toml";
+
+/// Expand `config!()` for the crate being compiled.
+pub(crate) fn expand() -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let (source, path, table) = read_config(Path::new(&manifest_dir))?;
+
+    let mut calls = Vec::new();
+    for (key, value) in &table {
+        let error = |expected: &str| {
+            syn::Error::new(
+                Span::call_site(),
+                format!("{source}: `{key}` must be {expected}"),
+            )
+        };
+        let string = || value.as_str().ok_or_else(|| error("a string"));
+        let strings = || {
+            value
+                .as_array()
+                .and_then(|items| items.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+                .ok_or_else(|| error("an array of strings"))
+        };
+
+        match key.as_str() {
+            "title" => {
+                let title = string()?;
+                calls.push(quote! { .with_title(#title) });
+            }
+            "css" => {
+                for path in strings()? {
+                    calls.push(quote! { .with_css(storybook::dioxus::prelude::asset!(#path)) });
+                }
+            }
+            "dark_css" => {
+                for path in strings()? {
+                    calls
+                        .push(quote! { .with_dark_css(storybook::dioxus::prelude::asset!(#path)) });
+                }
+            }
+            "tailwind" => {
+                let path = string()?;
+                calls.push(quote! { .with_tailwind(storybook::dioxus::prelude::asset!(#path)) });
+            }
+            "css_reset" => {
+                let reset = match string()? {
+                    "normalize" => quote! { storybook::Reset::Normalize },
+                    path => quote! {
+                        storybook::Reset::Custom(storybook::dioxus::prelude::asset!(#path))
+                    },
+                };
+                calls.push(quote! { .with_css_reset(#reset) });
+            }
+            "fonts" => {
+                for url in strings()? {
+                    calls.push(quote! { .with_font(#url) });
+                }
+            }
+            "head_html" => {
+                for html in strings()? {
+                    calls.push(quote! { .with_head_html(#html) });
+                }
+            }
+            "category_order" => {
+                let order = strings()?;
+                calls.push(quote! { .with_category_order(&[#(#order),*]) });
+            }
+            "include_tags" => {
+                let patterns = strings()?;
+                calls.push(quote! { .include_tags([#(#patterns),*]) });
+            }
+            "exclude_tags" => {
+                let patterns = strings()?;
+                calls.push(quote! { .exclude_tags([#(#patterns),*]) });
+            }
+            "preview_isolation" => {
+                let isolation = match string()? {
+                    "iframe" => quote! { Iframe },
+                    "shadow-dom" => quote! { ShadowDom },
+                    "inline" => quote! { Inline },
+                    _ => return Err(error(r#""iframe", "shadow-dom" or "inline""#)),
+                };
+                calls.push(
+                    quote! { .with_preview_isolation(storybook::PreviewIsolation::#isolation) },
+                );
+            }
+            "presentation" => {
+                let entries = strings()?;
+                calls.push(quote! { .with_presentation([#(#entries),*]) });
+            }
+            "presentation_interval" => {
+                let seconds = value
+                    .as_integer()
+                    .and_then(|seconds| u32::try_from(seconds).ok())
+                    .ok_or_else(|| error("a positive integer"))?;
+                calls.push(quote! { .with_presentation_interval(#seconds) });
+            }
+            "category_overview" => {
+                if value.as_bool().ok_or_else(|| error("a boolean"))? {
+                    calls.push(quote! { .with_category_overview() });
+                }
+            }
+            "session_activity" => {
+                if value.as_bool().ok_or_else(|| error("a boolean"))? {
+                    calls.push(quote! { .with_session_activity() });
+                }
+            }
+            "playlists" => {
+                let playlists = value
+                    .as_table()
+                    .and_then(|table| {
+                        table
+                            .iter()
+                            .map(|(name, entries)| {
+                                let entries = entries
+                                    .as_array()?
+                                    .iter()
+                                    .map(Value::as_str)
+                                    .collect::<Option<Vec<_>>>()?;
+                                Some(quote! { .with_playlist(#name, [#(#entries),*]) })
+                            })
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .ok_or_else(|| error("a table of arrays of strings"))?;
+                calls.extend(playlists);
+            }
+            "snapshot_concurrency" => {
+                let stories = value
+                    .as_integer()
+                    .and_then(|stories| usize::try_from(stories).ok())
+                    .ok_or_else(|| error("a positive integer"))?;
+                calls.push(quote! { .with_snapshot_concurrency(#stories) });
+            }
+            "doc_staleness_days" => {
+                let days = value
+                    .as_integer()
+                    .and_then(|days| u32::try_from(days).ok())
+                    .ok_or_else(|| error("a positive integer"))?;
+                calls.push(quote! { .with_doc_staleness(#days) });
+            }
+            "globals" => {
+                let globals = value
+                    .as_table()
+                    .and_then(|table| {
+                        table
+                            .iter()
+                            .map(|(name, values)| {
+                                let values = values
+                                    .as_array()?
+                                    .iter()
+                                    .map(Value::as_str)
+                                    .collect::<Option<Vec<_>>>()?;
+                                Some(quote! { .with_global(#name, [#(#values),*]) })
+                            })
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .ok_or_else(|| error("a table of arrays of strings"))?;
+                calls.extend(globals);
+            }
+            "theme" => {
+                let table = value
+                    .as_table()
+                    .ok_or_else(|| error("a table of theme settings"))?;
+                let mut theme = vec![quote! { storybook::StorybookTheme::default() }];
+                for (field, setting) in table {
+                    let setting_error = |expected: &str| {
+                        syn::Error::new(
+                            Span::call_site(),
+                            format!("{source}: `theme.{field}` must be {expected}"),
+                        )
+                    };
+                    match field.as_str() {
+                        "brand_color" | "font_family" => {
+                            let text = setting.as_str().ok_or_else(|| setting_error("a string"))?;
+                            let method = format_ident!("with_{field}");
+                            theme.push(quote! { .#method(#text) });
+                        }
+                        "logo" => {
+                            let path = setting.as_str().ok_or_else(|| setting_error("a string"))?;
+                            theme.push(
+                                quote! { .with_logo(storybook::dioxus::prelude::asset!(#path)) },
+                            );
+                        }
+                        "sidebar_width" | "radius" => {
+                            let pixels = setting
+                                .as_integer()
+                                .and_then(|pixels| u32::try_from(pixels).ok())
+                                .ok_or_else(|| setting_error("a positive integer"))?;
+                            let method = format_ident!("with_{field}");
+                            theme.push(quote! { .#method(#pixels) });
+                        }
+                        _ => {
+                            return Err(syn::Error::new(
+                                Span::call_site(),
+                                format!("{source}: unknown theme setting `{field}`"),
+                            ));
+                        }
+                    }
+                }
+                calls.push(quote! { .with_theme(#(#theme)*) });
+            }
+            "zoom" => {
+                let table = value
+                    .as_table()
+                    .filter(|table| {
+                        table.iter().all(|(key, value)| {
+                            ["min", "max", "step"].contains(&key.as_str())
+                                && value.as_integer().is_some_and(|v| i32::try_from(v).is_ok())
+                        })
+                    })
+                    .ok_or_else(|| error("a table of integer `min`, `max` and `step`"))?;
+                // Missing keys keep their default value
+                let [min, max, step] = ["min", "max", "step"].map(|key| {
+                    let field = format_ident!("{key}");
+                    match table.get(key).and_then(Value::as_integer) {
+                        Some(v) => {
+                            let v = v as i32;
+                            quote! { #v }
+                        }
+                        None => quote! { storybook::ZoomConfig::default().#field },
+                    }
+                });
+                calls.push(quote! { .with_zoom(#min, #max, #step) });
+            }
+            "story_migrations" => {
+                let migrations = value
+                    .as_table()
+                    .and_then(|table| {
+                        table
+                            .iter()
+                            .map(|(old, new)| Some((old.as_str(), new.as_str()?)))
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .ok_or_else(|| error("a table of strings"))?;
+                let (old, new): (Vec<_>, Vec<_>) = migrations.into_iter().unzip();
+                calls.push(quote! { .with_story_migrations([#((#old, #new)),*]) });
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    format!("{source}: unknown setting `{key}`"),
+                ));
+            }
+        }
+    }
+
+    // Including the file makes cargo rebuild the crate when it changes.
+    let tracked = path.map(|path| {
+        let path = path.display().to_string();
+        quote! { const _: &str = include_str!(#path); }
+    });
+
+    Ok(quote! {
+        {
+            #tracked
+            storybook::StorybookConfig::default() #(#calls)*
+        }
+    })
+}
+
+/// Read the settings table: `storybook.toml` if present, otherwise the
+/// `[package.metadata.storybook]` table of `Cargo.toml` (or no settings).
+///
+/// Returns the name of the source, for error messages, the file read, and
+/// the table.
+fn read_config(manifest_dir: &Path) -> syn::Result<(&'static str, Option<PathBuf>, Table)> {
+    let parse = |path: &Path| -> syn::Result<Option<Table>> {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return Ok(None);
+        };
+        content.parse::<Table>().map(Some).map_err(|e| {
+            syn::Error::new(
+                Span::call_site(),
+                format!("Failed to parse '{}': {e}", path.display()),
+            )
+        })
+    };
+
+    let config_path = manifest_dir.join(CONFIG_FILE);
+    if let Some(table) = parse(&config_path)? {
+        return Ok((CONFIG_FILE, Some(config_path), table));
+    }
+
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    let metadata = parse(&manifest_path)?
+        .and_then(|manifest| {
+            manifest
+                .get("package")?
+                .get("metadata")?
+                .get("storybook")
+                .cloned()
+        })
+        .and_then(|storybook| match storybook {
+            Value::Table(table) => Some(table),
+            _ => None,
+        })
+        .unwrap_or_default();
+    Ok((
+        "[package.metadata.storybook]",
+        Some(manifest_path),
+        metadata,
+    ))
+}
@@ -0,0 +1,87 @@
+//! Warnings for the `@[story:…]` embeds of doc comments and `storydoc!`
+//! files naming a story that isn't declared in the crate's sources.
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote_spanned;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use storybook_embeds::{Catalog, UnresolvedEmbed};
+
+/// The `.rs` files the catalog was read from, with their modification time.
+type Fingerprint = Vec<(PathBuf, Option<SystemTime>)>;
+
+/// Catalog of the crate's `src` directory, kept between the expansions of
+/// the crate and read again when a file changes (e.g. in rust-analyzer).
+static CATALOG: Mutex<Option<(Fingerprint, Catalog)>> = Mutex::new(None);
+
+/// A warning for each `@[story:…]` embed of `markdown` naming a story
+/// missing from a component of the crate. `span_of` maps a 0-based line of
+/// `markdown` to the span the warning is reported at, and `file` names the
+/// Markdown file, if any.
+pub(crate) fn warnings(
+    markdown: &str,
+    span_of: impl Fn(usize) -> Span,
+    file: Option<&str>,
+) -> TokenStream2 {
+    if !markdown.contains("@[story:") {
+        return TokenStream2::new();
+    }
+    unresolved(markdown)
+        .into_iter()
+        .map(|embed| {
+            let location = file
+                .map(|file| format!("{file}:{}: ", embed.line + 1))
+                .unwrap_or_default();
+            let message = format!(
+                "{location}broken story embed {}: {}",
+                embed.embed, embed.reason
+            );
+            warning(span_of(embed.line), &message)
+        })
+        .collect()
+}
+
+fn unresolved(markdown: &str) -> Vec<UnresolvedEmbed> {
+    let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+        return Vec::new();
+    };
+    let Ok(files) = storybook_embeds::source_files(&Path::new(&manifest_dir).join("src")) else {
+        return Vec::new();
+    };
+    let fingerprint: Fingerprint = files
+        .into_iter()
+        .map(|file| {
+            let modified = fs::metadata(&file).and_then(|meta| meta.modified()).ok();
+            (file, modified)
+        })
+        .collect();
+
+    let Ok(mut cached) = CATALOG.lock() else {
+        return Vec::new();
+    };
+    if cached.as_ref().is_none_or(|(read, _)| *read != fingerprint) {
+        let files: Vec<PathBuf> = fingerprint.iter().map(|(file, _)| file.clone()).collect();
+        let Ok(catalog) = Catalog::read(&files) else {
+            return Vec::new();
+        };
+        *cached = Some((fingerprint, catalog));
+    }
+    cached
+        .as_ref()
+        .map(|(_, catalog)| catalog.unresolved(markdown))
+        .unwrap_or_default()
+}
+
+/// A compiler warning at `span`. Proc macros can't emit warnings on stable,
+/// so this uses an item marked `#[deprecated]`.
+fn warning(span: Span, message: &str) -> TokenStream2 {
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            struct BrokenStoryEmbed;
+            let _ = BrokenStoryEmbed;
+        };
+    }
+}
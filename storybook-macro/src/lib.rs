@@ -1,15 +1,132 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use pulldown_cmark::{Options, Parser, html};
-use quote::{format_ident, quote};
+use proc_macro2::{Group, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+use storybook_markdown::{asset_references, markdown_to_html, parse_glossary};
+use syn::spanned::Spanned;
 use syn::{Fields, FnArg, Ident, ItemFn, ItemStruct, Pat, Type, parse_macro_input};
 
+mod config_file;
+mod embeds;
+mod source_date;
+
 /// Common field information used by both struct and function storybook processing
 struct FieldInfo {
     name: Ident,
     ty: Type,
     /// Doc comments for this field (to be preserved in generated StoryProps)
     doc_attrs: Vec<syn::Attribute>,
+    /// Default from `#[props(default)]` or `#[props(default = …)]`
+    default: Option<PropDefault>,
+    /// Whether the component can be rendered without this prop
+    optional: bool,
+    /// Left out of the StoryProps by `#[storybook(skip)]`: the prop keeps the
+    /// value of the default story and isn't shown in the editor
+    skip: bool,
+    /// Label shown instead of the prop name, from `#[storybook(rename = …)]`
+    label: Option<String>,
+    /// Description overriding the doc comment, from
+    /// `#[storybook(description = …)]`
+    description: Option<String>,
+}
+
+/// Options of a field's `#[storybook(…)]` attribute
+#[derive(Default)]
+struct FieldOptions {
+    skip: bool,
+    label: Option<String>,
+    description: Option<String>,
+}
+
+/// The default value of a component prop, as declared on its field
+enum PropDefault {
+    /// `#[props(default)]`: the type's `Default` value
+    Type,
+    /// `#[props(default = expr)]`
+    Expr(Box<syn::Expr>),
+}
+
+/// Read the default of a field from its `#[props(…)]` attributes. Options
+/// other than `default` are skipped.
+fn extract_prop_default(attrs: &[syn::Attribute]) -> Option<PropDefault> {
+    let mut default = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("props")) {
+        // Unknown option syntaxes (e.g. `!optional`) stop the parsing, which
+        // is fine: only `default` matters here
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.input.peek(syn::Token![=]) {
+                let value: syn::Expr = meta.value()?.parse()?;
+                if meta.path.is_ident("default") {
+                    default = Some(PropDefault::Expr(Box::new(value)));
+                }
+            } else if meta.path.is_ident("default") {
+                default = Some(PropDefault::Type);
+            }
+            Ok(())
+        });
+    }
+    default
+}
+
+/// Read the `#[storybook(skip, rename = "…", description = "…")]` options
+/// of a field. The attribute is removed from `attrs`, as nothing else reads
+/// it.
+fn take_field_options(attrs: &mut Vec<syn::Attribute>) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("storybook"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("rename") {
+                options.label = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            } else if meta.path.is_ident("description") {
+                options.description = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            } else {
+                return Err(meta.error(
+                    "unknown storybook prop option, expected `skip`, `rename` or `description`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+    attrs.retain(|attr| !attr.path().is_ident("storybook"));
+    Ok(options)
+}
+
+/// Whether a prop is optional on the component: it has a default, or it is
+/// an `Option` (possibly in a signal) not marked `#[props(!optional)]`.
+fn is_optional_prop(attrs: &[syn::Attribute], ty: &Type, default: &Option<PropDefault>) -> bool {
+    if default.is_some() {
+        return true;
+    }
+    let required_option = attrs.iter().any(|attr| {
+        attr.path().is_ident("props")
+            && quote! { #attr }
+                .to_string()
+                .replace(' ', "")
+                .contains("!optional")
+    });
+    let ty_str = quote! { #ty }.to_string().replace(' ', "");
+    !required_option
+        && (ty_str.starts_with("Option<")
+            || extract_signal_inner_type_str(ty).is_some_and(|inner| inner.starts_with("Option<")))
+}
+
+/// Render an expression the way it is usually written, e.g.
+/// `"Click me".to_string()` rather than `"Click me" . to_string ()`.
+fn expr_to_string(expr: &syn::Expr) -> String {
+    quote! { #expr }
+        .to_string()
+        .replace(" . ", ".")
+        .replace(" :: ", "::")
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",")
+        .replace(" !", "!")
 }
 
 /// Metadata about the component being processed
@@ -18,9 +135,130 @@ struct ComponentMeta {
     component_name_str: String,
     props_struct_name: Ident,
     story_props_name: Ident,
-    tag: String,
+    /// Sidebar placements, at least one
+    tags: Vec<String>,
+    /// Sort weight within the sidebar category
+    weight: i32,
+    /// `ComponentStatus` variant name, e.g. `Deprecated`
+    status: Option<Ident>,
+    /// When the component's crate sources last changed (Unix seconds)
+    source_updated: Option<u64>,
     /// HTML description extracted from doc comments
     description_html: String,
+    /// Props whose value combinations get a story each
+    auto_variants: Vec<syn::LitStr>,
+    /// Generic arguments of the instantiation registered for a generic
+    /// component, from `concrete = "List::<String>"`
+    generic_args: Option<Vec<syn::GenericArgument>>,
+    /// Prefix of the module the generated code is emitted in, from
+    /// `module = "__stories"`
+    module: Option<Ident>,
+    /// The `auto_story` keyword, when a "Default" story is derived from
+    /// `Default`
+    auto_story: Option<Ident>,
+}
+
+/// The instantiation of a generic component registered in the storybook
+struct ConcreteInstance {
+    /// Generic arguments, e.g. `String` for `List::<String>`
+    args: Vec<syn::GenericArgument>,
+    /// The type each type parameter stands for
+    substitutions: Vec<(Ident, Type)>,
+}
+
+/// Resolve `concrete = "List::<String>"` for a component with `generics`.
+/// `None` for non-generic components.
+fn resolve_concrete(
+    concrete: Option<&syn::LitStr>,
+    component_name: &str,
+    generics: &syn::Generics,
+) -> syn::Result<Option<ConcreteInstance>> {
+    let params: Vec<&Ident> = generics.type_params().map(|param| &param.ident).collect();
+    let Some(concrete) = concrete else {
+        if params.is_empty() {
+            return Ok(None);
+        }
+        return Err(syn::Error::new_spanned(
+            generics,
+            format!(
+                "generic components need a concrete instantiation to register, e.g. `#[storybook(concrete = \"{component_name}::<String>\")]`"
+            ),
+        ));
+    };
+
+    let path: syn::Path = concrete.parse()?;
+    let segment = path
+        .segments
+        .last()
+        .filter(|segment| segment.ident == component_name)
+        .ok_or_else(|| {
+            syn::Error::new(
+                concrete.span(),
+                format!("`concrete` must instantiate {component_name}, e.g. `{component_name}::<String>`"),
+            )
+        })?;
+    let args: Vec<syn::GenericArgument> = match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().cloned().collect(),
+        _ => Vec::new(),
+    };
+    let types: Vec<&Type> = args
+        .iter()
+        .filter_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .collect();
+    if types.len() != params.len() {
+        return Err(syn::Error::new(
+            concrete.span(),
+            format!(
+                "`concrete` gives {} type argument(s), {component_name} has {}",
+                types.len(),
+                params.len()
+            ),
+        ));
+    }
+    let substitutions = params
+        .into_iter()
+        .cloned()
+        .zip(types.into_iter().cloned())
+        .collect();
+    Ok(Some(ConcreteInstance {
+        args,
+        substitutions,
+    }))
+}
+
+/// Replace the type parameters of a generic component in `ty` with the
+/// types of its concrete instantiation.
+fn substitute_type_params(ty: &Type, substitutions: &[(Ident, Type)]) -> Type {
+    if substitutions.is_empty() {
+        return ty.clone();
+    }
+    syn::parse2(substitute_tokens(quote! { #ty }, substitutions)).unwrap_or_else(|_| ty.clone())
+}
+
+fn substitute_tokens(tokens: TokenStream2, substitutions: &[(Ident, Type)]) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Ident(ident) => {
+                match substitutions.iter().find(|(param, _)| *param == ident) {
+                    Some((_, ty)) => quote! { #ty },
+                    None => TokenTree::Ident(ident).into(),
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut substituted = Group::new(
+                    group.delimiter(),
+                    substitute_tokens(group.stream(), substitutions),
+                );
+                substituted.set_span(group.span());
+                TokenTree::Group(substituted).into()
+            }
+            other => other.into(),
+        })
+        .collect()
 }
 
 /// Extract doc comments from a list of attributes and return them as a single string
@@ -43,53 +281,35 @@ fn extract_doc_comments(attrs: &[syn::Attribute]) -> String {
         .join("\n")
 }
 
-/// Convert Markdown text to HTML using pulldown-cmark.
-///
-/// When `process_story_embeds` is `true`, the raw markdown is pre-processed
-/// to replace `@[story:Category/Component/Story Name]` lines with
-/// `<div class="storybook-embed" …></div>` HTML blocks before parsing.
-/// This avoids issues with pulldown-cmark splitting `@[story:…]` across
-/// multiple text events.
-fn markdown_to_html(markdown: &str, process_story_embeds: bool) -> String {
-    let source = if process_story_embeds {
-        preprocess_story_embeds(markdown)
-    } else {
-        markdown.to_string()
-    };
-
-    let options = Options::all();
-    let parser = Parser::new_ext(&source, options);
-
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-    html_output
-}
-
-/// Pre-process raw markdown to replace `@[story:…]` lines with HTML embed
-/// markers before feeding the text to pulldown-cmark.
-///
-/// pulldown-cmark treats `[…]` as potential link references and splits the
-/// surrounding text across multiple `Text` events, making it impossible to
-/// detect `@[story:…]` reliably in the event stream. By replacing matching
-/// lines in the source markdown with `<div>` blocks, pulldown-cmark passes
-/// them through as native HTML blocks.
-fn preprocess_story_embeds(markdown: &str) -> String {
-    let mut result = String::with_capacity(markdown.len());
-    for line in markdown.lines() {
-        let trimmed = line.trim();
-        if trimmed.starts_with("@[story:") && trimmed.ends_with(']') {
-            let full_path = &trimmed[8..trimmed.len() - 1];
-            let story_name = full_path.rsplit('/').next().unwrap_or(full_path);
-            result.push_str(&format!(
-                "<div class=\"storybook-embed\" data-story-path=\"{}\" data-story-name=\"{}\"></div>\n",
-                full_path, story_name
-            ));
-        } else {
-            result.push_str(line);
-            result.push('\n');
-        }
-    }
-    result
+/// Warnings for the broken `@[story:…]` embeds of the doc comments in
+/// `attrs`, each reported at its doc comment line.
+fn doc_embed_warnings(attrs: &[syn::Attribute]) -> TokenStream2 {
+    let line_spans: Vec<proc_macro2::Span> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }),
+                ..
+            }) => Some((attr.span(), lit_str.value())),
+            _ => None,
+        })
+        .flat_map(|(span, doc)| std::iter::repeat_n(span, doc.split('\n').count()))
+        .collect();
+    embeds::warnings(
+        &extract_doc_comments(attrs),
+        |line| {
+            line_spans
+                .get(line)
+                .copied()
+                .unwrap_or_else(proc_macro2::Span::call_site)
+        },
+        None,
+    )
 }
 
 impl ComponentMeta {
@@ -113,6 +333,13 @@ impl ComponentMeta {
             self.component_name_str.to_lowercase()
         )
     }
+
+    fn check_props_fn_name(&self) -> Ident {
+        format_ident!(
+            "__storybook_check_props_{}",
+            self.component_name_str.to_lowercase()
+        )
+    }
 }
 
 /// Marks a component for inclusion in the storybook.
@@ -120,6 +347,33 @@ impl ComponentMeta {
 /// The component's Props struct must implement the `Stories` trait
 /// to provide story configurations for the storybook UI.
 ///
+/// Arguments:
+///
+/// - `tag = "Category/Folder"` — sidebar placement, same as `tags` with a
+///   single path,
+/// - `tags = ["Forms/Inputs", "Deprecated"]` — several sidebar placements;
+///   the component is listed under each of them,
+/// - `weight = 10` — optional sort weight among the components of the same
+///   folder; lower weights come first, ties are sorted by name (default `0`).
+/// - `status = "deprecated"` — optional maturity badge shown in the sidebar
+///   and on the story page: `stable`, `beta`, `experimental` or
+///   `deprecated`.
+/// - `auto_variants = ["variant", "size"]` — generate a story for each
+///   combination of the values of these enum or `bool` props, based on the
+///   first story and listed under a "Variants" group.
+/// - `concrete = "List::<String>"` — the instantiation registered for a
+///   generic component; its props and stories use these type arguments
+///   (`impl Stories for ListProps<String>`).
+/// - `module = "__stories"` — emit the generated code (story props,
+///   helper functions, registration) in a nested `#[doc(hidden)]` module
+///   rather than next to the component. The module is named after the
+///   component (`__stories_button` for `Button`), so the components of a
+///   parent module can share the option.
+/// - `auto_story` — implement `Stories` with a single "Default" story built
+///   from the Props' `Default` impl, for components that need no other
+///   story. The Props must implement `Default`, and must not implement
+///   `Stories` themselves.
+///
 /// # Example
 /// ```ignore
 /// #[storybook(tag = "Thumbnails")]
@@ -156,7 +410,15 @@ impl ComponentMeta {
 /// ```
 #[proc_macro_attribute]
 pub fn storybook(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let attr_args = parse_macro_input!(attr as StorybookArgs);
+    let attr_args = match syn::parse::<StorybookArgs>(attr) {
+        Ok(attr_args) => attr_args,
+        Err(err) => {
+            // Keep the component so that its uses don't add errors of their own
+            let mut output = TokenStream::from(err.to_compile_error());
+            output.extend(item);
+            return output;
+        }
+    };
 
     // Try to parse as a function first, then as a struct
     let item_clone = item.clone();
@@ -217,32 +479,295 @@ fn is_non_serializable_type(ty: &Type) -> bool {
         || ty_str.starts_with("Vec<Attribute>")
 }
 
+/// A prop borrowing its value (`&'static str`, `&'static [T]`), which the
+/// StoryProps own instead so they can be deserialized
+struct BorrowedProp {
+    /// The slice's element type, `None` for a `str`
+    element: Option<Type>,
+    /// Whether the reference is wrapped in an `Option`
+    optional: bool,
+}
+
+impl BorrowedProp {
+    /// Recognize `&str`, `&[T]` and their `Option`s
+    fn of(ty: &Type) -> Option<Self> {
+        let (reference, optional) = match ty {
+            Type::Reference(reference) => (reference, false),
+            Type::Path(path) => {
+                let segment = path.path.segments.last()?;
+                let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                    return None;
+                };
+                match args.args.first()? {
+                    syn::GenericArgument::Type(Type::Reference(reference))
+                        if segment.ident == "Option" && args.args.len() == 1 =>
+                    {
+                        (reference, true)
+                    }
+                    _ => return None,
+                }
+            }
+            _ => return None,
+        };
+        if reference.mutability.is_some() {
+            return None;
+        }
+        let element = match reference.elem.as_ref() {
+            Type::Path(path) if path.path.is_ident("str") => None,
+            Type::Slice(slice) => Some(slice.elem.as_ref().clone()),
+            _ => return None,
+        };
+        Some(Self { element, optional })
+    }
+
+    /// The owned type stored in the StoryProps
+    fn owned_type(&self) -> TokenStream2 {
+        let owned = match &self.element {
+            Some(element) => quote! { Vec<#element> },
+            None => quote! { String },
+        };
+        if self.optional {
+            quote! { Option<#owned> }
+        } else {
+            owned
+        }
+    }
+
+    /// Convert the borrowed `value` to the owned type
+    fn owned_value(&self, value: TokenStream2) -> TokenStream2 {
+        let convert = match &self.element {
+            Some(_) => quote! { <[_]>::to_vec },
+            None => quote! { str::to_string },
+        };
+        if self.optional {
+            quote! { #value.map(#convert) }
+        } else {
+            quote! { #convert(#value) }
+        }
+    }
+
+    /// Convert the owned `value` back to a `'static` reference, or to
+    /// `fallback` once the storybook stopped keeping borrowed values
+    fn borrowed_value(&self, value: TokenStream2, fallback: TokenStream2) -> TokenStream2 {
+        let convert = match &self.element {
+            Some(_) => quote! { storybook::static_slice },
+            None => quote! { storybook::static_str },
+        };
+        if self.optional {
+            quote! {
+                match #value.as_deref() {
+                    Some(value) => #convert(value).map(Some).unwrap_or(#fallback),
+                    None => None,
+                }
+            }
+        } else {
+            quote! { #convert(&#value).unwrap_or(#fallback) }
+        }
+    }
+}
+
+/// Name of the StoryProps function returning the `#[props(default = …)]`
+/// value of `field`
+fn default_fn_name(field: &Ident) -> Ident {
+    format_ident!("__storybook_default_{}", field)
+}
+
 /// Generate StoryProps field definitions from field info
-fn generate_story_props_fields(fields: &[FieldInfo]) -> Vec<TokenStream2> {
+///
+/// Prop defaults become serde defaults, which schemars writes to the
+/// schema's `default` keyword; the expression of `#[props(default = …)]` is
+/// also kept as is under `x-default-expr`.
+fn generate_story_props_fields(
+    fields: &[FieldInfo],
+    story_props_name: &Ident,
+) -> Vec<TokenStream2> {
     fields
         .iter()
+        .filter(|field| !field.skip)
         .map(|field| {
             let name = &field.name;
             let ty = &field.ty;
-            let doc_attrs = &field.doc_attrs;
-            if is_non_serializable_type(ty) {
-                quote! {
+            let doc_attrs = field.doc_attrs.iter().map(|attr| quote! { #attr });
+            let editable_ty = editable_type(ty);
+            let mut default_attrs = match &field.default {
+                Some(PropDefault::Type) => vec![quote! { #[serde(default)] }],
+                Some(PropDefault::Expr(expr)) => {
+                    let expr_str = expr_to_string(expr);
+                    let mut attrs =
+                        vec![quote! { #[schemars(extend("x-default-expr" = #expr_str))] }];
+                    if editable_ty.is_some() && !is_editable_signal_type(ty) {
+                        let path = format!("{}::{}", story_props_name, default_fn_name(name));
+                        attrs.push(quote! { #[serde(default = #path)] });
+                    }
+                    attrs
+                }
+                None => Vec::new(),
+            };
+            if let Some(editable_ty) = &editable_ty {
+                let (serialize_fn, deserialize_fn, schema_fn) = editable_fn_names(name);
+                let serialize_path = format!("{}::{}", story_props_name, serialize_fn);
+                let deserialize_path = format!("{}::{}", story_props_name, deserialize_fn);
+                let schema_path = format!("{}::{}", story_props_name, schema_fn);
+                default_attrs.push(quote! {
+                    #[serde(serialize_with = #serialize_path, deserialize_with = #deserialize_path)]
+                });
+                default_attrs.push(quote! { #[schemars(schema_with = #schema_path)] });
+                // The schema function writes the default itself: schemars
+                // would serialize it through the field's type (and a `serde`
+                // path that isn't in scope here). `deserialize_with` also
+                // loses serde's implicit `None` for a missing `Option`.
+                let is_option = editable_ty
+                    .to_string()
+                    .replace(' ', "")
+                    .starts_with("Option<");
+                if editable_default(field).is_some() {
+                    default_attrs.push(quote! { #[schemars(!default)] });
+                } else if is_option {
+                    default_attrs.push(quote! { #[serde(default)] #[schemars(!default)] });
+                }
+            }
+            let label_attr = field
+                .label
+                .as_ref()
+                .map(|label| quote! { #[schemars(title = #label)] });
+            let description_attr = field
+                .description
+                .as_ref()
+                .map(|description| quote! { #[schemars(description = #description)] });
+            let doc_attrs = doc_attrs
+                .chain(default_attrs)
+                .chain(label_attr)
+                .chain(description_attr)
+                .collect::<Vec<_>>();
+            match editable_ty {
+                Some(editable_ty) => quote! {
+                    #(#doc_attrs)*
+                    pub #name: #editable_ty
+                },
+                None => quote! {
                     #(#doc_attrs)*
                     pub #name: ()
+                },
+            }
+        })
+        .collect()
+}
+
+/// Generate the StoryProps functions evaluating `#[props(default = …)]`
+/// expressions, used as serde defaults
+fn generate_default_fns(fields: &[FieldInfo]) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .filter(|field| !field.skip)
+        .filter(|field| !is_non_serializable_type(&field.ty) && !is_editable_signal_type(&field.ty))
+        .filter_map(|field| match &field.default {
+            Some(PropDefault::Expr(expr)) => {
+                let fn_name = default_fn_name(&field.name);
+                let ty = &field.ty;
+                Some(match BorrowedProp::of(ty) {
+                    Some(borrowed) => {
+                        let owned_ty = borrowed.owned_type();
+                        let value = borrowed.owned_value(quote! { value });
+                        quote! {
+                            fn #fn_name() -> #owned_ty {
+                                let value: #ty = #expr;
+                                #value
+                            }
+                        }
+                    }
+                    None => quote! {
+                        fn #fn_name() -> #ty {
+                            #expr
+                        }
+                    },
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Names of the StoryProps functions serializing, deserializing and
+/// describing the editable `field`
+fn editable_fn_names(field: &Ident) -> (Ident, Ident, Ident) {
+    (
+        format_ident!("__storybook_serialize_{}", field),
+        format_ident!("__storybook_deserialize_{}", field),
+        format_ident!("__storybook_schema_{}", field),
+    )
+}
+
+/// The type a prop is edited as in the StoryProps, `None` for the
+/// non-serializable props mapped to `()`
+fn editable_type(ty: &Type) -> Option<TokenStream2> {
+    if is_non_serializable_type(ty) {
+        None
+    } else if let Some(inner_ty_str) = extract_signal_inner_type_str(ty) {
+        let inner_ty: Type = syn::parse_str(&inner_ty_str).expect("Failed to parse inner type");
+        Some(quote! { #inner_ty })
+    } else if let Some(borrowed) = BorrowedProp::of(ty) {
+        Some(borrowed.owned_type())
+    } else {
+        Some(quote! { #ty })
+    }
+}
+
+/// The value an editable field defaults to when missing from the props
+/// JSON, `None` for fields without a serde default
+fn editable_default(field: &FieldInfo) -> Option<TokenStream2> {
+    let ty = editable_type(&field.ty)?;
+    match &field.default {
+        Some(PropDefault::Type) => Some(quote! { <#ty as ::core::default::Default>::default() }),
+        Some(PropDefault::Expr(_)) if !is_editable_signal_type(&field.ty) => {
+            let default_fn = default_fn_name(&field.name);
+            Some(quote! { Self::#default_fn() })
+        }
+        _ => None,
+    }
+}
+
+/// Generate the StoryProps functions the serde and schemars attributes of
+/// each editable field point to.
+///
+/// They go through `storybook::EditableProp`, spanned on the field's type,
+/// so that an unsupported type is reported once, on its field, rather than
+/// by each of the derives of the StoryProps.
+fn generate_editable_prop_fns(fields: &[FieldInfo]) -> Vec<TokenStream2> {
+    fields
+        .iter()
+        .filter(|field| !field.skip)
+        .filter_map(|field| {
+            let ty = editable_type(&field.ty)?;
+            let (serialize_fn, deserialize_fn, schema_fn) = editable_fn_names(&field.name);
+            let editable = quote_spanned! {field.ty.span()=>
+                <#ty as storybook::EditableProp>
+            };
+            let schema = match editable_default(field) {
+                Some(default) => {
+                    quote! { #editable::prop_schema_with_default(generator, &#default) }
                 }
-            } else if let Some(inner_ty_str) = extract_signal_inner_type_str(ty) {
-                let inner_ty: Type =
-                    syn::parse_str(&inner_ty_str).expect("Failed to parse inner type");
-                quote! {
-                    #(#doc_attrs)*
-                    pub #name: #inner_ty
+                None => quote! { #editable::prop_schema(generator) },
+            };
+            Some(quote! {
+                fn #serialize_fn<S: storybook::serde::Serializer>(
+                    value: &#ty,
+                    serializer: S,
+                ) -> ::core::result::Result<S::Ok, S::Error> {
+                    #editable::serialize_prop(value, serializer)
                 }
-            } else {
-                quote! {
-                    #(#doc_attrs)*
-                    pub #name: #ty
+
+                fn #deserialize_fn<'de, D: storybook::serde::Deserializer<'de>>(
+                    deserializer: D,
+                ) -> ::core::result::Result<#ty, D::Error> {
+                    #editable::deserialize_prop(deserializer)
                 }
-            }
+
+                fn #schema_fn(
+                    generator: &mut storybook::schemars::SchemaGenerator,
+                ) -> storybook::schemars::Schema {
+                    #schema
+                }
+            })
         })
         .collect()
 }
@@ -251,6 +776,7 @@ fn generate_story_props_fields(fields: &[FieldInfo]) -> Vec<TokenStream2> {
 fn generate_props_to_story_fields(fields: &[FieldInfo]) -> Vec<TokenStream2> {
     fields
         .iter()
+        .filter(|field| !field.skip)
         .map(|field| {
             let name = &field.name;
             let ty = &field.ty;
@@ -258,6 +784,9 @@ fn generate_props_to_story_fields(fields: &[FieldInfo]) -> Vec<TokenStream2> {
                 quote! { #name: () }
             } else if is_editable_signal_type(ty) {
                 quote! { #name: props.#name.read().clone() }
+            } else if let Some(borrowed) = BorrowedProp::of(ty) {
+                let value = borrowed.owned_value(quote! { props.#name });
+                quote! { #name: #value }
             } else {
                 quote! { #name: props.#name.clone() }
             }
@@ -272,10 +801,14 @@ fn generate_story_to_props_fields(fields: &[FieldInfo]) -> Vec<TokenStream2> {
         .map(|field| {
             let name = &field.name;
             let ty = &field.ty;
-            if is_non_serializable_type(ty) {
+            if field.skip || is_non_serializable_type(ty) {
                 quote! { #name: default_props.#name.clone() }
             } else if is_editable_signal_type(ty) {
                 quote! { #name: dioxus::prelude::Signal::new(story_props.#name.clone()).into() }
+            } else if let Some(borrowed) = BorrowedProp::of(ty) {
+                let value = borrowed
+                    .borrowed_value(quote! { story_props.#name }, quote! { default_props.#name });
+                quote! { #name: #value }
             } else {
                 quote! { #name: story_props.#name.clone() }
             }
@@ -294,23 +827,113 @@ fn generate_storybook_code(
         component_name_str,
         props_struct_name,
         story_props_name,
-        tag,
+        tags,
+        weight,
+        status,
+        source_updated,
         description_html,
+        auto_variants,
+        generic_args,
+        module,
+        auto_story,
     } = meta;
+    // Tags are never empty, see `StorybookArgs::parse`
+    let primary_tag = &tags[0];
+
+    // A generic component is rendered and its props named with the
+    // arguments of its concrete instantiation; an alias stands for the
+    // instantiated props struct, so it can be used in struct expressions
+    let alias = format_ident!("__Storybook{}", props_struct_name);
+    let (component, props_alias, props_struct_name) = match generic_args {
+        Some(args) => (
+            quote! { #component_name::<#(#args),*> },
+            quote! {
+                #[doc(hidden)]
+                #[allow(non_camel_case_types)]
+                type #alias = #props_struct_name<#(#args),*>;
+            },
+            &alias,
+        ),
+        None => (quote! { #component_name }, quote! {}, props_struct_name),
+    };
+
+    if let Some(unknown) = auto_variants.iter().find(|prop| {
+        !fields
+            .iter()
+            .any(|field| field.name == prop.value() && !field.skip)
+    }) {
+        return syn::Error::new(
+            unknown.span(),
+            format!(
+                "`auto_variants`: `{}` is not a prop of {component_name_str}",
+                unknown.value()
+            ),
+        )
+        .to_compile_error();
+    }
+
+    let status = match status {
+        Some(variant) => quote! { Some(storybook::ComponentStatus::#variant) },
+        None => quote! { None },
+    };
+    let source_updated = match source_updated {
+        Some(seconds) => quote! { Some(#seconds) },
+        None => quote! { None },
+    };
 
     let render_fn_name = meta.render_fn_name();
     let get_stories_fn_name = meta.get_stories_fn_name();
     let get_prop_schema_fn_name = meta.get_prop_schema_fn_name();
+    let check_props_fn_name = meta.check_props_fn_name();
 
-    let story_props_fields = generate_story_props_fields(fields);
+    let story_props_fields = generate_story_props_fields(fields, story_props_name);
+    let default_fns = generate_default_fns(fields);
+    let auto_variant_stories = if auto_variants.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let mut stories = stories;
+            storybook::push_auto_variants(
+                &mut stories,
+                #component_name_str,
+                &#get_prop_schema_fn_name(),
+                &[#(#auto_variants),*],
+            );
+        }
+    };
+    let optional_fields = fields
+        .iter()
+        .filter(|field| field.optional && !field.skip)
+        .map(|field| field.name.to_string());
     let props_to_story_fields = generate_props_to_story_fields(fields);
     let story_to_props_fields = generate_story_to_props_fields(fields);
+    let editable_prop_fns = generate_editable_prop_fns(fields);
 
-    quote! {
-        #original_item
+    let auto_stories = match auto_story {
+        Some(keyword) => {
+            let default_props = quote_spanned! {keyword.span()=>
+                <#props_struct_name as ::core::default::Default>::default()
+            };
+            quote! {
+                impl storybook::Stories for #props_struct_name {
+                    fn stories() -> Vec<storybook::Story<Self>> {
+                        vec![storybook::Story::new("Default", #default_props)]
+                    }
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let generated = quote! {
+        #props_alias
+
+        #auto_stories
 
         /// Auto-generated story props struct for storybook UI editing.
-        /// Non-serializable fields (EventHandler, Callback, Element, etc.) are mapped to ().
+        /// Non-serializable fields (EventHandler, Callback, Element, etc.) are mapped to ();
+        /// fields marked `#[storybook(skip)]` are left out.
+        /// Borrowed fields (`&'static str`, `&'static [T]`) are owned (String, Vec<T>).
         #[derive(Clone, storybook::serde::Serialize, storybook::serde::Deserialize, storybook::schemars::JsonSchema)]
         #[serde(crate = "storybook::serde")]
         #[schemars(crate = "storybook::schemars")]
@@ -327,13 +950,17 @@ fn generate_storybook_code(
                 }
             }
 
-            /// Convert StoryProps back to Props, using defaults for non-serializable fields
+            /// Convert StoryProps back to Props, using defaults for non-serializable and skipped fields
             pub fn to_props(&self, default_props: &#props_struct_name) -> #props_struct_name {
                 let story_props = self;
                 #props_struct_name {
                     #(#story_to_props_fields),*
                 }
             }
+
+            #(#default_fns)*
+
+            #(#editable_prop_fns)*
         }
 
         #[doc(hidden)]
@@ -344,21 +971,28 @@ fn generate_storybook_code(
             let stories = <#props_struct_name as Stories>::stories();
             let default_props = stories.into_iter().next().expect("At least one story must be defined").props;
 
-            // Try to parse the JSON, fall back to defaults on error
+            // Try to parse the JSON, report the error and fall back to defaults on failure
             let props = match storybook::serde_json::from_str::<#story_props_name>(props_json) {
                 Ok(story_props) => story_props.to_props(&default_props),
-                Err(_) => default_props,
+                Err(err) => {
+                    storybook::watch::report(&storybook::props_deserialize_error(
+                        #component_name_str,
+                        props_json,
+                        &err,
+                    ));
+                    default_props
+                }
             };
 
             rsx! {
-                #component_name { ..props }
+                #component { ..props }
             }
         }
 
         #[doc(hidden)]
         fn #get_stories_fn_name() -> Vec<storybook::StoryInfo> {
             use storybook::Stories;
-            <#props_struct_name as Stories>::stories()
+            let stories = <#props_struct_name as Stories>::stories()
                 .into_iter()
                 .map(|story| {
                     let story_props = #story_props_name::from_props(&story.props);
@@ -367,30 +1001,92 @@ fn generate_storybook_code(
                         description: story.description.map(|d| d.to_string()),
                         props_json: storybook::serde_json::to_string_pretty(&story_props).unwrap_or_default(),
                         decorators: story.decorators,
+                        contexts: story.contexts,
+                        play: story.play,
+                        loader: story.loader.map(|loader| {
+                            loader.map(|props| {
+                                let story_props = #story_props_name::from_props(&props);
+                                storybook::serde_json::to_string_pretty(&story_props).unwrap_or_default()
+                            })
+                        }),
+                        tags: story.tags.iter().map(|t| t.to_string()).collect(),
+                        skipped: story.skipped,
+                        id: story
+                            .id
+                            .map(str::to_string)
+                            .unwrap_or_else(|| storybook::story_id(#component_name_str, story.title)),
+                        viewport: story.viewport,
+                        background: story.background.map(|b| b.to_string()),
+                        layout: story.layout,
+                        preview_isolation: story.preview_isolation,
+                        group: story.group.map(|g| g.to_string()),
+                        masks: story.masks.iter().map(|m| m.to_string()).collect(),
                     }
                 })
-                .collect()
+                .collect::<Vec<_>>();
+            #auto_variant_stories
+            stories
         }
 
         #[doc(hidden)]
         fn #get_prop_schema_fn_name() -> storybook::schemars::Schema {
-            storybook::schemars::schema_for!(#story_props_name)
+            let mut schema = storybook::schemars::schema_for!(#story_props_name);
+            storybook::mark_optional_props(&mut schema, &[#(#optional_fields),*]);
+            schema
+        }
+
+        #[doc(hidden)]
+        fn #check_props_fn_name(props_json: &str) -> Result<(), storybook::serde_json::Error> {
+            storybook::serde_json::from_str::<#story_props_name>(props_json).map(|_| ())
         }
 
         storybook::inventory::submit! {
             storybook::ComponentRegistration {
                 name: #component_name_str,
-                tag: #tag,
+                tags: &[#(#tags),*],
+                #[allow(deprecated)]
+                tag: #primary_tag,
+                weight: #weight,
+                status: #status,
+                source_updated: #source_updated,
                 description: #description_html,
                 render_with_props: storybook::RenderFn(#render_fn_name),
                 get_stories: #get_stories_fn_name,
                 get_prop_schema: #get_prop_schema_fn_name,
+                check_props: #check_props_fn_name,
             }
         }
+    };
+
+    match module {
+        Some(module) => {
+            // One module per component, so that components can share `module`
+            let module = format_ident!(
+                "{}_{}",
+                module,
+                component_name_str.to_lowercase(),
+                span = module.span()
+            );
+            quote! {
+                #original_item
+
+                #[doc(hidden)]
+                mod #module {
+                    use super::*;
+
+                    #generated
+                }
+            }
+        }
+        None => quote! {
+            #original_item
+
+            #generated
+        },
     }
 }
 
-fn storybook_for_struct(input: ItemStruct, attr_args: StorybookArgs) -> TokenStream {
+fn storybook_for_struct(mut input: ItemStruct, attr_args: StorybookArgs) -> TokenStream {
     let struct_name = &input.ident;
     let struct_name_str = struct_name.to_string();
 
@@ -403,6 +1099,17 @@ fn storybook_for_struct(input: ItemStruct, attr_args: StorybookArgs) -> TokenStr
     // process_story_embeds=true so @[story:...] lines become embed markers
     let doc_markdown = extract_doc_comments(&input.attrs);
     let description_html = markdown_to_html(&doc_markdown, true);
+    let embed_warnings = doc_embed_warnings(&input.attrs);
+
+    let options = match input
+        .fields
+        .iter_mut()
+        .map(|field| take_field_options(&mut field.attrs))
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(options) => options,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
 
     // Extract fields from the struct
     let syn_fields = match &input.fields {
@@ -418,10 +1125,21 @@ fn storybook_for_struct(input: ItemStruct, attr_args: StorybookArgs) -> TokenStr
         }
     };
 
+    let (generic_args, substitutions) = match resolve_concrete(
+        attr_args.concrete.as_ref(),
+        component_name_str,
+        &input.generics,
+    ) {
+        Ok(Some(instance)) => (Some(instance.args), instance.substitutions),
+        Ok(None) => (None, Vec::new()),
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
     // Convert to FieldInfo format, preserving doc comments
     let fields: Vec<FieldInfo> = syn_fields
         .iter()
-        .filter_map(|field| {
+        .zip(options)
+        .filter_map(|(field, options)| {
             field.ident.as_ref().map(|name| {
                 // Extract doc attributes from the field
                 let doc_attrs: Vec<syn::Attribute> = field
@@ -430,10 +1148,16 @@ fn storybook_for_struct(input: ItemStruct, attr_args: StorybookArgs) -> TokenStr
                     .filter(|attr| attr.path().is_ident("doc"))
                     .cloned()
                     .collect();
+                let default = extract_prop_default(&field.attrs);
                 FieldInfo {
                     name: name.clone(),
-                    ty: field.ty.clone(),
+                    ty: substitute_type_params(&field.ty, &substitutions),
                     doc_attrs,
+                    optional: is_optional_prop(&field.attrs, &field.ty, &default),
+                    default,
+                    skip: options.skip,
+                    label: options.label,
+                    description: options.description,
                 }
             })
         })
@@ -441,21 +1165,31 @@ fn storybook_for_struct(input: ItemStruct, attr_args: StorybookArgs) -> TokenStr
 
     // Build component metadata
     let meta = ComponentMeta {
-        component_name: format_ident!("{}", component_name_str),
+        component_name: format_ident!("{}", component_name_str, span = struct_name.span()),
         component_name_str: component_name_str.to_string(),
         props_struct_name: struct_name.clone(),
         story_props_name: format_ident!("{}StoryProps", component_name_str),
-        tag: attr_args.tag.clone(),
+        tags: attr_args.tags.clone(),
+        weight: attr_args.weight,
+        status: attr_args.status.clone(),
+        source_updated: source_date::crate_last_modified(),
         description_html,
+        auto_variants: attr_args.auto_variants.clone(),
+        generic_args,
+        module: attr_args.module.clone(),
+        auto_story: attr_args.auto_story.clone(),
     };
 
     let original_item = quote! { #input };
     let expanded = generate_storybook_code(&meta, &fields, original_item);
 
-    TokenStream::from(expanded)
+    TokenStream::from(quote! {
+        #expanded
+        #embed_warnings
+    })
 }
 
-fn storybook_for_function(input: ItemFn, attr_args: StorybookArgs) -> TokenStream {
+fn storybook_for_function(mut input: ItemFn, attr_args: StorybookArgs) -> TokenStream {
     let fn_name = &input.sig.ident;
     let fn_name_str = fn_name.to_string();
 
@@ -469,6 +1203,31 @@ fn storybook_for_function(input: ItemFn, attr_args: StorybookArgs) -> TokenStrea
     // process_story_embeds=true so @[story:...] lines become embed markers
     let doc_markdown = extract_doc_comments(&input.attrs);
     let description_html = markdown_to_html(&doc_markdown, true);
+    let embed_warnings = doc_embed_warnings(&input.attrs);
+
+    let (generic_args, substitutions) = match resolve_concrete(
+        attr_args.concrete.as_ref(),
+        &fn_name_str,
+        &input.sig.generics,
+    ) {
+        Ok(Some(instance)) => (Some(instance.args), instance.substitutions),
+        Ok(None) => (None, Vec::new()),
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
+    let options = match input
+        .sig
+        .inputs
+        .iter_mut()
+        .map(|arg| match arg {
+            FnArg::Typed(pat_type) => take_field_options(&mut pat_type.attrs),
+            FnArg::Receiver(_) => Ok(FieldOptions::default()),
+        })
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(options) => options,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
 
     // Extract function parameters as FieldInfo
     // Note: Function parameters don't have doc comments, so doc_attrs is empty
@@ -476,7 +1235,8 @@ fn storybook_for_function(input: ItemFn, attr_args: StorybookArgs) -> TokenStrea
         .sig
         .inputs
         .iter()
-        .filter_map(|arg| {
+        .zip(options)
+        .filter_map(|(arg, options)| {
             if let FnArg::Typed(pat_type) = arg
                 && let Pat::Ident(pat_ident) = &*pat_type.pat
             {
@@ -487,10 +1247,16 @@ fn storybook_for_function(input: ItemFn, attr_args: StorybookArgs) -> TokenStrea
                     .filter(|attr| attr.path().is_ident("doc"))
                     .cloned()
                     .collect();
+                let default = extract_prop_default(&pat_type.attrs);
                 return Some(FieldInfo {
                     name: pat_ident.ident.clone(),
-                    ty: (*pat_type.ty).clone(),
+                    ty: substitute_type_params(&pat_type.ty, &substitutions),
                     doc_attrs,
+                    optional: is_optional_prop(&pat_type.attrs, &pat_type.ty, &default),
+                    default,
+                    skip: options.skip,
+                    label: options.label,
+                    description: options.description,
                 });
             }
             None
@@ -503,14 +1269,24 @@ fn storybook_for_function(input: ItemFn, attr_args: StorybookArgs) -> TokenStrea
         component_name_str: fn_name_str.clone(),
         props_struct_name: format_ident!("{}Props", fn_name_str),
         story_props_name: format_ident!("{}StoryProps", fn_name_str),
-        tag: attr_args.tag,
+        tags: attr_args.tags,
+        weight: attr_args.weight,
+        status: attr_args.status,
+        source_updated: source_date::crate_last_modified(),
         description_html,
+        auto_variants: attr_args.auto_variants,
+        generic_args,
+        module: attr_args.module,
+        auto_story: attr_args.auto_story,
     };
 
     let original_item = quote! { #input };
     let expanded = generate_storybook_code(&meta, &fields, original_item);
 
-    TokenStream::from(expanded)
+    TokenStream::from(quote! {
+        #expanded
+        #embed_warnings
+    })
 }
 
 /// Check if the function uses a props struct pattern (single argument named "props" with a type ending in "Props")
@@ -530,27 +1306,147 @@ fn is_props_struct_pattern(input: &ItemFn) -> bool {
 }
 
 struct StorybookArgs {
-    tag: String,
+    tags: Vec<String>,
+    weight: i32,
+    status: Option<Ident>,
+    auto_variants: Vec<syn::LitStr>,
+    concrete: Option<syn::LitStr>,
+    module: Option<Ident>,
+    auto_story: Option<Ident>,
 }
 
 impl syn::parse::Parse for StorybookArgs {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let mut tag = String::new();
+        let mut tags = Vec::new();
+        let mut weight = 0;
+        let mut status = None;
+        let mut auto_variants = Vec::new();
+        let mut concrete = None;
+        let mut module = None;
+        let mut auto_story = None;
 
         while !input.is_empty() {
             let ident: Ident = input.parse()?;
             if ident == "tag" {
                 let _: syn::Token![=] = input.parse()?;
                 let lit: syn::LitStr = input.parse()?;
-                tag = lit.value();
+                tags.push(lit.value());
+            } else if ident == "tags" {
+                let _: syn::Token![=] = input.parse()?;
+                let content;
+                syn::bracketed!(content in input);
+                let lits = content
+                    .parse_terminated(|input| input.parse::<syn::LitStr>(), syn::Token![,])?;
+                tags.extend(lits.iter().map(syn::LitStr::value));
+            } else if ident == "weight" {
+                let _: syn::Token![=] = input.parse()?;
+                let negative = input.parse::<Option<syn::Token![-]>>()?.is_some();
+                let mut lit: syn::LitInt = input.parse()?;
+                // Parse the negated literal so that `-2147483648` fits
+                if negative {
+                    lit = syn::LitInt::new(&format!("-{}", lit.base10_digits()), lit.span());
+                }
+                weight = lit.base10_parse()?;
+            } else if ident == "status" {
+                let _: syn::Token![=] = input.parse()?;
+                let lit: syn::LitStr = input.parse()?;
+                let variant = match lit.value().as_str() {
+                    "stable" => "Stable",
+                    "beta" => "Beta",
+                    "experimental" => "Experimental",
+                    "deprecated" => "Deprecated",
+                    other => {
+                        return Err(syn::Error::new(
+                            lit.span(),
+                            format!(
+                                "unknown status `{other}`, expected `stable`, `beta`, `experimental` or `deprecated`"
+                            ),
+                        ));
+                    }
+                };
+                status = Some(format_ident!("{variant}"));
+            } else if ident == "auto_variants" {
+                let _: syn::Token![=] = input.parse()?;
+                let content;
+                syn::bracketed!(content in input);
+                let lits = content
+                    .parse_terminated(|input| input.parse::<syn::LitStr>(), syn::Token![,])?;
+                auto_variants.extend(lits);
+            } else if ident == "concrete" {
+                let _: syn::Token![=] = input.parse()?;
+                concrete = Some(input.parse::<syn::LitStr>()?);
+            } else if ident == "module" {
+                let _: syn::Token![=] = input.parse()?;
+                let lit: syn::LitStr = input.parse()?;
+                module = Some(lit.parse::<Ident>().map_err(|_| {
+                    syn::Error::new(
+                        lit.span(),
+                        format!("`module`: `{}` is not a module name", lit.value()),
+                    )
+                })?);
+            } else if ident == "auto_story" {
+                auto_story = Some(ident);
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "unknown `#[storybook]` argument `{ident}`, expected `tag`, `tags`, `weight`, `status`, `auto_variants`, `concrete`, `module` or `auto_story`"
+                    ),
+                ));
             }
             if input.peek(syn::Token![,]) {
                 let _: syn::Token![,] = input.parse()?;
             }
         }
 
-        Ok(StorybookArgs { tag })
+        // Untagged components are listed at the sidebar root
+        if tags.is_empty() {
+            tags.push(String::new());
+        }
+
+        Ok(StorybookArgs {
+            tags,
+            weight,
+            status,
+            auto_variants,
+            concrete,
+            module,
+            auto_story,
+        })
+    }
+}
+
+/// Builds a `StorybookConfig` from the crate's `storybook.toml`, or from the
+/// `[package.metadata.storybook]` table of its `Cargo.toml`.
+///
+/// The file is read at compile time, so the same settings can be shared with
+/// CI tooling without duplicating builder calls. The result is a regular
+/// `StorybookConfig`, which can be refined further with builder methods.
+///
+/// # Example
+/// ```toml
+/// title = "My Components"
+/// css = ["/assets/main.css"]
+/// category_order = ["Atoms", "Molecules"]
+/// exclude_tags = ["*/Experimental/**"]
+/// ```
+///
+/// ```ignore
+/// storybook::launch(storybook::config!());
+/// ```
+#[proc_macro]
+pub fn config(input: TokenStream) -> TokenStream {
+    if !input.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "config!() takes no arguments",
+        )
+        .to_compile_error()
+        .into();
     }
+    config_file::expand()
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// Registers a markdown documentation page for a category or folder in the storybook.
@@ -560,11 +1456,24 @@ impl syn::parse::Parse for StorybookArgs {
 /// storydoc!("Buttons/Primary", "docs/buttons_primary.md");
 /// ```
 ///
+/// A path naming a component under one of its categories (e.g.
+/// `"Examples/Buttons/ExampleButton"`) attaches the page to that component
+/// instead: it's shown as a tab of the component's docs.
+///
 /// The markdown file can embed live story previews using the `@[story:...]` syntax:
 /// ```markdown
 /// @[story:Category/Component/Story Name]
 /// ```
-/// This will render the story inline within the documentation.
+/// This will render the story inline within the documentation. A JSON object
+/// right after the directive (`@[story:…]{ "label": "Overridden" }`)
+/// overrides some of the story's props for that embed only.
+///
+/// `@[props:Component]` embeds the component's props table and
+/// `@[canvas:Component]` embeds all of its stories in a grid. Inline,
+/// `@[prop:Component.field]` links to the row of `field` in the props table,
+/// `@[link:Category/Component]` to a component and `@[doc:Path]` to a doc
+/// page. `@[video:path]` and `@[iframe:url]` embed a video player and a sandboxed
+/// iframe.
 #[proc_macro]
 pub fn storydoc(input: TokenStream) -> TokenStream {
     let parsed = syn::parse::<StorydocArgs2>(input);
@@ -573,6 +1482,7 @@ pub fn storydoc(input: TokenStream) -> TokenStream {
         Ok(args) => {
             let path = args.path;
             let md_file = args.markdown_file;
+            let md_span = args.markdown_span;
 
             // Read the markdown file at compile time
             let manifest_dir =
@@ -598,6 +1508,36 @@ pub fn storydoc(input: TokenStream) -> TokenStream {
 
             // Convert markdown to HTML, processing @[story:...] embeds
             let html_content = markdown_to_html(&markdown_content, true);
+            let embed_warnings = embeds::warnings(&markdown_content, |_| md_span, Some(&md_file));
+
+            let last_updated = match source_date::last_modified(&full_path) {
+                Some(seconds) => quote! { Some(#seconds) },
+                None => quote! { None },
+            };
+
+            // Files referenced by the page that don't exist, reported by the
+            // registry summary: paths starting with `/` are relative to the
+            // crate, the others to the markdown file.
+            let missing_assets: Vec<String> = asset_references(&html_content)
+                .into_iter()
+                .filter(|reference| {
+                    let file = reference.split(['?', '#']).next().unwrap_or_default();
+                    let resolved = match file.strip_prefix('/') {
+                        Some(file) => std::path::Path::new(&manifest_dir).join(file),
+                        None => full_path.parent().unwrap_or(&full_path).join(file),
+                    };
+                    !resolved.exists()
+                })
+                .collect();
+
+            // With `hot-docs`, the markdown file is also bundled as an asset so
+            // the runtime can re-fetch and re-render it when it changes.
+            let source_asset = if cfg!(feature = "hot-docs") {
+                let asset_path = format!("/{}", md_file.trim_start_matches("./"));
+                quote! { Some(storybook::dioxus::prelude::asset!(#asset_path)) }
+            } else {
+                quote! { None }
+            };
 
             // Generate the inventory submission
             let expanded = quote! {
@@ -605,8 +1545,14 @@ pub fn storydoc(input: TokenStream) -> TokenStream {
                     storybook::DocRegistration {
                         path: #path,
                         content_html: #html_content,
+                        source_path: #md_file,
+                        source_asset: #source_asset,
+                        last_updated: #last_updated,
+                        missing_assets: &[#(#missing_assets),*],
                     }
                 }
+
+                #embed_warnings
             };
 
             TokenStream::from(expanded)
@@ -618,6 +1564,7 @@ pub fn storydoc(input: TokenStream) -> TokenStream {
 struct StorydocArgs2 {
     path: String,
     markdown_file: String,
+    markdown_span: proc_macro2::Span,
 }
 
 impl syn::parse::Parse for StorydocArgs2 {
@@ -629,6 +1576,95 @@ impl syn::parse::Parse for StorydocArgs2 {
         Ok(StorydocArgs2 {
             path: path.value(),
             markdown_file: markdown_file.value(),
+            markdown_span: markdown_file.span(),
         })
     }
 }
+
+/// Registers a glossary of the design language.
+///
+/// Each `## Term` heading of the markdown file defines a term with the text
+/// up to the next heading. The glossary gets a "Glossary" page in the
+/// sidebar, and the first occurrence of each term in every doc page shows
+/// its definition in a popover.
+///
+/// # Example
+/// ```ignore
+/// storyglossary!("docs/glossary.md");
+/// ```
+///
+/// ```markdown
+/// ## Surface
+///
+/// A background layer holding content, such as a card or a dialog.
+/// ```
+#[proc_macro]
+pub fn storyglossary(input: TokenStream) -> TokenStream {
+    let md_file = parse_macro_input!(input as syn::LitStr).value();
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let full_path = std::path::Path::new(&manifest_dir).join(&md_file);
+
+    let markdown_content = match std::fs::read_to_string(&full_path) {
+        Ok(content) => content,
+        Err(e) => {
+            return TokenStream::from(
+                syn::Error::new(
+                    proc_macro2::Span::call_site(),
+                    format!(
+                        "Failed to read glossary file '{}': {}",
+                        full_path.display(),
+                        e
+                    ),
+                )
+                .to_compile_error(),
+            );
+        }
+    };
+
+    let terms = parse_glossary(&markdown_content);
+    if terms.is_empty() {
+        return TokenStream::from(
+            syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "Glossary file '{}' defines no term (expected `## Term` headings)",
+                    full_path.display()
+                ),
+            )
+            .to_compile_error(),
+        );
+    }
+    let terms = terms.iter().map(|(term, definition_html)| {
+        quote! {
+            storybook::GlossaryTerm {
+                term: #term,
+                definition_html: #definition_html,
+            }
+        }
+    });
+    let html_content = markdown_to_html(&markdown_content, true);
+    let last_updated = match source_date::last_modified(&full_path) {
+        Some(seconds) => quote! { Some(#seconds) },
+        None => quote! { None },
+    };
+
+    TokenStream::from(quote! {
+        storybook::inventory::submit! {
+            storybook::GlossaryRegistration {
+                source_path: #md_file,
+                terms: &[#(#terms),*],
+            }
+        }
+
+        storybook::inventory::submit! {
+            storybook::DocRegistration {
+                path: storybook::GLOSSARY_PATH,
+                content_html: #html_content,
+                source_path: #md_file,
+                source_asset: None,
+                last_updated: #last_updated,
+                missing_assets: &[],
+            }
+        }
+    })
+}
@@ -0,0 +1,34 @@
+//! Compile-time dates of source files, for the "Last updated" line of doc
+//! pages and their staleness warnings.
+
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+/// Date of the component sources, set by the crate's build script with
+/// `cargo:rustc-env=STORYBOOK_SOURCE_DATE=<seconds>`.
+const SOURCE_DATE_VAR: &str = "STORYBOOK_SOURCE_DATE";
+
+/// Date every file is pinned to in reproducible builds.
+const SOURCE_DATE_EPOCH_VAR: &str = "SOURCE_DATE_EPOCH";
+
+/// When `path` last changed, in seconds since the Unix epoch:
+/// `SOURCE_DATE_EPOCH` when set, its modification time otherwise.
+pub(crate) fn last_modified(path: &Path) -> Option<u64> {
+    env_date(SOURCE_DATE_EPOCH_VAR).or_else(|| modified_time(path))
+}
+
+/// When the sources of the crate being compiled last changed:
+/// `STORYBOOK_SOURCE_DATE` as set by its build script, or
+/// `SOURCE_DATE_EPOCH`. `None` when neither is set.
+pub(crate) fn crate_last_modified() -> Option<u64> {
+    env_date(SOURCE_DATE_VAR).or_else(|| env_date(SOURCE_DATE_EPOCH_VAR))
+}
+
+fn env_date(name: &str) -> Option<u64> {
+    std::env::var(name).ok()?.trim().parse().ok()
+}
+
+fn modified_time(path: &Path) -> Option<u64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_secs())
+}
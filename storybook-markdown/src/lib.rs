@@ -0,0 +1,460 @@
+//! Markdown pipeline shared by the `storybook` runtime and the
+//! `storybook-macro` proc macros.
+//!
+//! The proc macros convert doc comments and `storydoc!` files to HTML at
+//! compile time. With the `hot-docs` feature the storybook runtime re-renders
+//! the same Markdown in the browser, so both sides must go through this crate
+//! to produce identical output.
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd, html};
+use std::collections::HashMap;
+
+/// Convert Markdown text to HTML using pulldown-cmark.
+///
+/// When `process_story_embeds` is `true`, the raw markdown is pre-processed
+/// to replace directive lines (`@[story:…]`, `@[props:…]`, `@[canvas:…]`
+/// and custom ones, see [`preprocess_story_embeds`]) with
+/// `<div class="storybook-…" …></div>` HTML blocks, `@[video:…]` and
+/// `@[iframe:…]` with their players, and inline
+/// `@[prop:…]` references with prop chips, before parsing.
+/// This avoids issues with pulldown-cmark splitting `@[…]` across multiple
+/// text events.
+///
+/// Tables are wrapped in a `<div class="doc-table-wrapper">` so wide ones
+/// scroll horizontally, and task list markers become
+/// read-only `<input class="task-checkbox">` checkboxes. Headings get an id
+/// (see [`heading_slug`]) and a `<a class="heading-anchor">` link to it.
+pub fn markdown_to_html(markdown: &str, process_story_embeds: bool) -> String {
+    let source = if process_story_embeds {
+        preprocess_story_embeds(markdown)
+    } else {
+        markdown.to_string()
+    };
+
+    let options = Options::all();
+    let events: Vec<Event> = Parser::new_ext(&source, options)
+        .flat_map(map_gfm_event)
+        .collect();
+
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, add_heading_anchors(events).into_iter());
+    html_output
+}
+
+/// The URL fragment of a heading titled `text`, as GitHub generates them:
+/// lowercase, spaces replaced with `-`, punctuation removed (`"Props &
+/// Events!"` becomes `props--events`).
+pub fn heading_slug(text: &str) -> String {
+    text.trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() => Some(c.to_lowercase().next().unwrap_or(c)),
+            ' ' | '-' => Some('-'),
+            '_' => Some('_'),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Give every heading an id — its `{#custom-id}` or the [`heading_slug`] of
+/// its text, numbered (`-1`, `-2`…) when repeated — and end it with an
+/// anchor link to that id.
+fn add_heading_anchors(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut output = Vec::with_capacity(events.len());
+    let mut index = 0;
+    while index < events.len() {
+        let Event::Start(Tag::Heading {
+            level,
+            id,
+            classes,
+            attrs,
+        }) = &events[index]
+        else {
+            output.push(events[index].clone());
+            index += 1;
+            continue;
+        };
+        let end = events[index..]
+            .iter()
+            .position(|e| matches!(e, Event::End(TagEnd::Heading(_))))
+            .map_or(events.len(), |offset| index + offset);
+        let slug = match id {
+            Some(id) => id.to_string(),
+            None => {
+                let text: String = events[index + 1..end]
+                    .iter()
+                    .filter_map(|e| match e {
+                        Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                        _ => None,
+                    })
+                    .collect();
+                let base = heading_slug(&text);
+                let count = seen.entry(base.clone()).or_insert(0);
+                let slug = if *count == 0 {
+                    base
+                } else {
+                    format!("{base}-{count}")
+                };
+                *count += 1;
+                slug
+            }
+        };
+        output.push(Event::Start(Tag::Heading {
+            level: *level,
+            id: Some(CowStr::from(slug.clone())),
+            classes: classes.clone(),
+            attrs: attrs.clone(),
+        }));
+        output.extend(events[index + 1..end].iter().cloned());
+        output.push(Event::InlineHtml(CowStr::from(format!(
+            "<a class=\"heading-anchor\" href=\"#{slug}\" aria-label=\"Link to this section\">#</a>"
+        ))));
+        if let Some(end_event) = events.get(end) {
+            output.push(end_event.clone());
+        }
+        index = end + 1;
+    }
+    output
+}
+
+/// Rewrite the GitHub-flavored Markdown events the default HTML output
+/// leaves unstyled: tables get a scrollable wrapper and task list markers a
+/// classed, read-only checkbox.
+fn map_gfm_event(event: Event<'_>) -> Vec<Event<'_>> {
+    match event {
+        Event::Start(Tag::Table(alignments)) => vec![
+            Event::Html(CowStr::Borrowed("<div class=\"doc-table-wrapper\">\n")),
+            Event::Start(Tag::Table(alignments)),
+        ],
+        Event::End(TagEnd::Table) => vec![
+            Event::End(TagEnd::Table),
+            Event::Html(CowStr::Borrowed("</div>\n")),
+        ],
+        Event::TaskListMarker(checked) => vec![Event::InlineHtml(CowStr::Borrowed(if checked {
+            "<input class=\"task-checkbox\" type=\"checkbox\" checked disabled aria-label=\"Done\"/>"
+        } else {
+            "<input class=\"task-checkbox\" type=\"checkbox\" disabled aria-label=\"To do\"/>"
+        }))],
+        event => vec![event],
+    }
+}
+
+/// Pre-process raw markdown to replace directive lines with HTML embed
+/// markers before feeding the text to pulldown-cmark.
+///
+/// Supported directives, each on its own line:
+///
+/// - `@[story:Category/Component/Story Name]` — a live story preview,
+///   optionally followed by a JSON object overriding some of the story's
+///   props for this embed only
+///   (`@[story:Forms/Button/Primary]{ "label": "Overridden" }`),
+/// - `@[props:Component]` — the component's props table,
+/// - `@[canvas:Component]` — every story of the component in a grid,
+/// - `@[video:path or URL]` — a responsive video player,
+/// - `@[iframe:URL]` — a responsive, sandboxed iframe for external demos,
+/// - `@[kind:argument]` — any other directive, rendered by the storybook's
+///   handler for `kind` (`StorybookConfig::with_doc_directive`), as
+///   `<div class="storybook-directive" data-kind="…" data-argument="…">`.
+///
+/// `Component` may also be given as a full tree path
+/// (`Category/Folder/Component`); only the last segment is used.
+///
+/// Within a line, `@[prop:Component.field]` becomes a chip linking to the
+/// row of `field` in the component's props table, see
+/// [`prop_row_id`]. `@[link:Category/Component]` and `@[doc:Path]` become
+/// links to the component and to the doc page, which the storybook follows
+/// without reloading; both take an optional label after a `|`
+/// (`@[doc:Getting Started|the guide]`). Directives in fenced code blocks
+/// and references in code spans are left as is.
+///
+/// pulldown-cmark treats `[…]` as potential link references and splits the
+/// surrounding text across multiple `Text` events, making it impossible to
+/// detect `@[…]` reliably in the event stream. By replacing matching lines
+/// in the source markdown with `<div>` blocks, pulldown-cmark passes them
+/// through as native HTML blocks.
+pub fn preprocess_story_embeds(markdown: &str) -> String {
+    let mut result = String::with_capacity(markdown.len());
+    let mut fence: Option<&str> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            result.push_str(line);
+            result.push('\n');
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+            result.push_str(line);
+            result.push('\n');
+            continue;
+        }
+        let (directive, props) = split_story_props(trimmed);
+        match parse_directive(directive) {
+            Some(("story", full_path)) => {
+                let story_name = full_path.rsplit('/').next().unwrap_or(full_path);
+                let props = props
+                    .map(|props| format!(" data-props=\"{}\"", escape_attribute(props)))
+                    .unwrap_or_default();
+                result.push_str(&format!(
+                    "<div class=\"storybook-embed\" data-story-path=\"{}\" data-story-name=\"{}\"{}></div>\n",
+                    full_path, story_name, props
+                ));
+            }
+            Some((kind @ ("props" | "canvas"), component_path)) => {
+                let component_name = component_path.rsplit('/').next().unwrap_or(component_path);
+                result.push_str(&format!(
+                    "<div class=\"storybook-{}\" data-component=\"{}\"></div>\n",
+                    kind, component_name
+                ));
+            }
+            Some(("video", src)) => {
+                result.push_str(&format!(
+                    "<div class=\"doc-embed doc-embed-video\"><video src=\"{}\" controls preload=\"metadata\"></video></div>\n",
+                    escape_attribute(src.trim())
+                ));
+            }
+            Some(("iframe", src)) => {
+                result.push_str(&format!(
+                    "<div class=\"doc-embed doc-embed-iframe\"><iframe src=\"{}\" sandbox=\"{}\" loading=\"lazy\" allowfullscreen></iframe></div>\n",
+                    escape_attribute(src.trim()),
+                    IFRAME_SANDBOX
+                ));
+            }
+            Some((kind, argument)) if !INLINE_KINDS.contains(&kind) && is_directive_kind(kind) => {
+                result.push_str(&format!(
+                    "<div class=\"storybook-directive\" data-kind=\"{}\" data-argument=\"{}\"></div>\n",
+                    kind,
+                    escape_attribute(argument)
+                ));
+            }
+            _ => {
+                result.push_str(&link_inline_references(line));
+                result.push('\n');
+            }
+        }
+    }
+    result
+}
+
+/// DOM id of the row of `field` in the props table of `component_name`.
+pub fn prop_row_id(component_name: &str, field: &str) -> String {
+    format!("prop-{component_name}-{field}")
+}
+
+/// Permissions of `@[iframe:…]` embeds: enough for interactive demos, but
+/// no top-level navigation or access to the storybook.
+const IFRAME_SANDBOX: &str = "allow-scripts allow-same-origin allow-forms allow-popups";
+
+/// Kinds of the references written within a line rather than on a line of
+/// their own.
+const INLINE_KINDS: &[&str] = &["prop", "link", "doc"];
+
+/// Replace the `@[prop:…]`, `@[link:…]` and `@[doc:…]` references of a
+/// line, outside its code spans, with prop chips and storybook links.
+fn link_inline_references(line: &str) -> String {
+    if !line.contains("@[") {
+        return line.to_string();
+    }
+    let mut output = String::with_capacity(line.len());
+    let mut rest = line;
+    let mut in_code = false;
+    while !rest.is_empty() {
+        if rest.starts_with('`') {
+            let ticks = rest.len() - rest.trim_start_matches('`').len();
+            in_code = !in_code;
+            output.push_str(&rest[..ticks]);
+            rest = &rest[ticks..];
+        } else if let Some((html, after)) = split_inline_reference(rest).filter(|_| !in_code) {
+            output.push_str(&html);
+            rest = after;
+        } else {
+            let next = rest.chars().next().map_or(1, char::len_utf8);
+            output.push_str(&rest[..next]);
+            rest = &rest[next..];
+        }
+    }
+    output
+}
+
+/// Split an inline reference starting `text` into its HTML and the text
+/// after it.
+fn split_inline_reference(text: &str) -> Option<(String, &str)> {
+    let (kind, reference) = text.strip_prefix("@[")?.split_once(':')?;
+    if !INLINE_KINDS.contains(&kind) {
+        return None;
+    }
+    let end = reference.find(']')?;
+    let html = match kind {
+        "prop" => prop_chip(&reference[..end])?,
+        _ => storybook_link(kind, &reference[..end])?,
+    };
+    Some((html, &reference[end + 1..]))
+}
+
+/// The chip of a `Component.field` prop reference, linking to the
+/// component's docs page.
+fn prop_chip(reference: &str) -> Option<String> {
+    let (component_path, field) = reference.rsplit_once('.')?;
+    let component_name = component_path.rsplit('/').next().unwrap_or(component_path);
+    if component_name.is_empty() || field.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "<a class=\"prop-chip\" href=\"?doc=__component__/{component_name}#{id}\" data-prop-row=\"{id}\" title=\"{component_name}.{field}\"><code>{field}</code></a>",
+        id = prop_row_id(component_name, field),
+    ))
+}
+
+/// The link of a `@[link:Category/Component]` or `@[doc:Path]` reference,
+/// labelled with the text after its `|` or the last segment of its target.
+///
+/// The `href` opens the component's docs or the doc page when the link is
+/// opened in a new tab; `data-component` and `data-doc` let the storybook
+/// select it in place.
+fn storybook_link(kind: &str, reference: &str) -> Option<String> {
+    let (target, label) = match reference.split_once('|') {
+        Some((target, label)) => (target.trim(), Some(label.trim())),
+        None => (reference.trim(), None),
+    };
+    let last_segment = target.rsplit('/').next().unwrap_or(target);
+    let label = label.unwrap_or(last_segment);
+    if label.is_empty() {
+        return None;
+    }
+    let (path, attribute, value) = match kind {
+        "link" if !last_segment.is_empty() => (
+            format!("__component__/{last_segment}"),
+            "data-component",
+            last_segment,
+        ),
+        "doc" => (target.to_string(), "data-doc", target),
+        _ => return None,
+    };
+    Some(format!(
+        "<a class=\"storybook-link\" href=\"?doc={}\" {}=\"{}\">{}</a>",
+        encode_query_value(&path),
+        attribute,
+        escape_attribute(value),
+        escape_attribute(label)
+    ))
+}
+
+/// Percent-encode the characters of `value` that would end or break a query
+/// parameter value.
+fn encode_query_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            ' ' => encoded.push_str("%20"),
+            '%' | '&' | '#' | '+' | '"' | '<' | '>' => {
+                encoded.push_str(&format!("%{:02X}", c as u32));
+            }
+            c => encoded.push(c),
+        }
+    }
+    encoded
+}
+
+/// Split a `@[kind:argument]` directive line into `(kind, argument)`.
+fn parse_directive(line: &str) -> Option<(&str, &str)> {
+    let inner = line.strip_prefix("@[")?.strip_suffix(']')?;
+    let (kind, argument) = inner.split_once(':')?;
+    Some((kind, argument))
+}
+
+/// Split the props override of a `@[story:…]{ … }` line from the
+/// directive; other lines are returned whole.
+fn split_story_props(line: &str) -> (&str, Option<&str>) {
+    if line.starts_with("@[story:")
+        && line.ends_with('}')
+        && let Some(end) = line.find("]{")
+    {
+        (&line[..=end], Some(&line[end + 1..]))
+    } else {
+        (line, None)
+    }
+}
+
+/// Whether `kind` can name a custom directive: ASCII letters, digits, `-`
+/// and `_`, so that prose like `@[see above: …]` is left alone.
+fn is_directive_kind(kind: &str) -> bool {
+    !kind.is_empty()
+        && kind
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Escape `value` for a double-quoted HTML attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The file paths referenced by the `src` and `href` attributes of `html`
+/// (images, downloads…), leaving out URLs, page links (`?…`, `#…`) and
+/// absolute paths with a scheme.
+pub fn asset_references(html: &str) -> Vec<String> {
+    let mut references = Vec::new();
+    for attribute in ["src=\"", "href=\""] {
+        let mut rest = html;
+        while let Some(start) = rest.find(attribute) {
+            rest = &rest[start + attribute.len()..];
+            let Some(end) = rest.find('"') else {
+                break;
+            };
+            let value = &rest[..end];
+            let is_file = !value.is_empty()
+                && !value.starts_with(['?', '#'])
+                && !value.starts_with("//")
+                && !value.contains(':');
+            if is_file && !references.iter().any(|known| known == value) {
+                references.push(value.to_string());
+            }
+            rest = &rest[end..];
+        }
+    }
+    references
+}
+
+/// Split a glossary file into `(term, definition_html)` pairs.
+///
+/// Each `## Term` heading starts a term, defined by the Markdown up to the
+/// next heading. Definitions are rendered as inline HTML (paragraphs joined
+/// with `<br>`), so they can be shown inside a sentence.
+pub fn parse_glossary(markdown: &str) -> Vec<(String, String)> {
+    let mut terms: Vec<(String, String)> = Vec::new();
+    let mut current: Option<(String, String)> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') {
+            terms.extend(current.take());
+            if let Some(term) = trimmed.strip_prefix("## ") {
+                current = Some((term.trim().to_string(), String::new()));
+            }
+        } else if let Some((_, definition)) = current.as_mut() {
+            definition.push_str(line);
+            definition.push('\n');
+        }
+    }
+    terms.extend(current);
+    terms
+        .into_iter()
+        .filter(|(term, _)| !term.is_empty())
+        .map(|(term, definition)| (term, inline_html(&markdown_to_html(&definition, false))))
+        .collect()
+}
+
+/// Turn the paragraphs of `html` into lines of inline HTML.
+fn inline_html(html: &str) -> String {
+    html.trim()
+        .trim_start_matches("<p>")
+        .trim_end_matches("</p>")
+        .replace("</p>\n<p>", "<br>")
+}
@@ -0,0 +1,85 @@
+//! Addons: custom panels contributed to the story page.
+//!
+//! An [`Addon`] adds a tab to the story preview's side panel and, optionally,
+//! a button to the top bar while a story is selected. Addons are registered
+//! either globally with [`inventory::submit!`] and an [`AddonRegistration`],
+//! which lets a third-party crate ship one as a dependency, or per storybook
+//! with [`StorybookConfig::with_addon`](crate::StorybookConfig::with_addon).
+
+use dioxus::prelude::*;
+
+/// The story an addon is rendered for.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AddonContext {
+    pub component_name: String,
+    /// Index of the story in the component's stories.
+    pub story_index: usize,
+    /// Stable id of the story, see [`StoryInfo::id`](crate::StoryInfo::id).
+    pub story_id: String,
+    /// DOM id of the preview iframe, to inspect or drive the rendered story
+    /// with `document::eval`.
+    pub iframe_id: String,
+    /// The props the story is rendered with, as JSON: the edited props in the
+    /// side panel, the story's own props in the top bar.
+    pub props_json: String,
+}
+
+/// A custom panel of the story page.
+///
+/// [`render`](Self::render) and [`toolbar_button`](Self::toolbar_button) are
+/// rendered as components of their own, so they can use hooks; the tab
+/// [`icon`](Self::icon) can't.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use storybook::{Addon, AddonContext, AddonRegistration};
+///
+/// struct PropsSize;
+///
+/// impl Addon for PropsSize {
+///     fn name(&self) -> &'static str {
+///         "Props size"
+///     }
+///
+///     fn render(&self, ctx: AddonContext) -> Element {
+///         rsx! { p { "{ctx.props_json.len()} bytes" } }
+///     }
+/// }
+///
+/// storybook::inventory::submit! {
+///     AddonRegistration { addon: &PropsSize }
+/// }
+/// ```
+pub trait Addon: Send + Sync {
+    /// Label of the addon's tab, also used to tell addons apart: an addon
+    /// named like another one is ignored.
+    fn name(&self) -> &'static str;
+
+    /// Icon shown before the tab label.
+    fn icon(&self) -> Option<Element> {
+        None
+    }
+
+    /// Content of the addon's tab.
+    fn render(&self, ctx: AddonContext) -> Element;
+
+    /// Button shown in the top bar while a story is selected.
+    fn toolbar_button(&self, ctx: AddonContext) -> Option<Element> {
+        let _ = ctx;
+        None
+    }
+}
+
+/// Registration record for an [`Addon`] available to every storybook.
+pub struct AddonRegistration {
+    pub addon: &'static dyn Addon,
+}
+
+inventory::collect!(AddonRegistration);
+
+/// Returns an iterator over every addon registered with an
+/// [`AddonRegistration`].
+pub fn get_addons() -> impl Iterator<Item = &'static dyn Addon> {
+    inventory::iter::<AddonRegistration>().map(|registration| registration.addon)
+}
@@ -0,0 +1,77 @@
+//! Errors reported by the storybook's public API.
+
+use std::fmt;
+
+/// An error resolving a component, story or documentation page, or decoding
+/// a story's props.
+///
+/// Returned by [`find_component`](crate::find_component),
+/// [`find_doc`](crate::find_doc) and the [`ComponentRegistration`](crate::ComponentRegistration)
+/// helpers, and shown inline by the storybook UI when a story or a
+/// documentation embed can't be rendered.
+///
+/// New variants, and new fields of [`Error::PropsDeserialize`], may be added
+/// in minor releases: matches need a wildcard arm.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// No component with this name is registered.
+    ComponentNotFound(String),
+    /// The component has no story with this title (or index, as `#3`).
+    StoryNotFound {
+        component_name: String,
+        story: String,
+    },
+    /// No documentation page is registered at this path.
+    DocNotFound(String),
+    /// A story path (e.g. in a `@[story:…]` embed) is not of the form
+    /// `Category/Component/Story`.
+    InvalidStoryPath(String),
+    /// A props JSON document doesn't match the component's props.
+    #[non_exhaustive]
+    PropsDeserialize {
+        component_name: String,
+        /// The prop the error is about, when it can be told.
+        field: Option<String>,
+        /// The `serde_json` error, with line and column.
+        message: String,
+    },
+    /// The generated props schema is not an object schema.
+    SchemaInvalid {
+        component_name: String,
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ComponentNotFound(name) => write!(f, "Component not found: {name}"),
+            Error::StoryNotFound {
+                component_name,
+                story,
+            } => write!(f, "Story not found: {story} in {component_name}"),
+            Error::DocNotFound(path) => write!(f, "Documentation page not found: {path}"),
+            Error::InvalidStoryPath(path) => write!(f, "Invalid story path: {path}"),
+            Error::PropsDeserialize {
+                component_name,
+                field: Some(field),
+                message,
+            } => write!(
+                f,
+                "Invalid props for {component_name}, field `{field}`: {message}"
+            ),
+            Error::PropsDeserialize {
+                component_name,
+                field: None,
+                message,
+            } => write!(f, "Invalid props for {component_name}: {message}"),
+            Error::SchemaInvalid {
+                component_name,
+                message,
+            } => write!(f, "Invalid props schema for {component_name}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,74 @@
+//! Globals: settings shared by every story, such as the locale or the text
+//! direction.
+//!
+//! Globals are declared with
+//! [`StorybookConfig::with_global`](crate::StorybookConfig::with_global) and
+//! picked in the top bar. Stories and decorators read the current values
+//! with [`globals()`], and re-render when they change.
+
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+/// A global setting and the values it can take, see
+/// [`StorybookConfig::with_global`](crate::StorybookConfig::with_global).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GlobalSetting {
+    pub name: String,
+    /// The allowed values, the first one being the default.
+    pub values: Vec<String>,
+}
+
+/// Current value of every global setting.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Globals {
+    values: BTreeMap<String, String>,
+}
+
+impl Globals {
+    /// Every setting at its default value.
+    pub(crate) fn defaults(settings: &[GlobalSetting]) -> Self {
+        Self {
+            values: settings
+                .iter()
+                .filter_map(|setting| Some((setting.name.clone(), setting.values.first()?.clone())))
+                .collect(),
+        }
+    }
+
+    /// The current value of the global `name`, if it is declared.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    /// Set the global `name` to `value`.
+    pub(crate) fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// Iterate over the `(name, value)` pairs.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// The current globals of the storybook, empty outside of it.
+///
+/// Not a hook: it can be called anywhere while rendering, including in a
+/// [`Decorator`](crate::Decorator). The calling component re-renders when a
+/// global changes.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// fn with_direction(story: Element) -> Element {
+///     let dir = storybook::globals().get("direction").unwrap_or("ltr").to_string();
+///     rsx! { div { dir, {story} } }
+/// }
+/// ```
+pub fn globals() -> Globals {
+    try_consume_context::<Signal<Globals>>()
+        .map(|globals| globals())
+        .unwrap_or_default()
+}
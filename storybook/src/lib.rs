@@ -53,7 +53,7 @@
 //! ## Features
 //!
 //! - **Story-centric navigation** — sidebar tree with Category → Component → Story,
-//!   just like Storybook.js.
+//!   just like Storybook.js, filterable by story tags ([`Story::with_tags`]).
 //! - **Live props editor** — auto-generated from [`schemars::JsonSchema`]; edit props
 //!   in real time and see the component update.
 //! - **Decorators** — wrap stories with extra markup via [`Decorator`] functions
@@ -61,6 +61,8 @@
 //! - **Documentation pages** — embed Markdown docs in the sidebar with the
 //!   [`storydoc!`] macro, including live story previews.
 //! - **Dark / light theme** — toggle between themes from the toolbar.
+//! - **Play functions** — script interactions and assertions that run after a
+//!   story renders, via [`Story::with_play`].
 //! - **Zero-config registration** — the [`storybook`](macro@storybook) attribute macro
 //!   and the [`inventory`] crate handle compile-time discovery automatically.
 //!
@@ -99,6 +101,30 @@
 //! @[story:Examples/ExampleCard/Default]
 //! ```
 //!
+//! A component's props table and all of its stories can be embedded too:
+//!
+//! ```markdown
+//! @[props:ExampleCard]
+//! @[canvas:ExampleCard]
+//! ```
+//!
+//! Markdown is converted at compile time, so edits normally require a rebuild.
+//! Enable the `hot-docs` feature during development to have doc pages re-fetch
+//! their Markdown file and re-render whenever it changes.
+//!
+//! ## Play functions
+//!
+//! Attach a play function to a story to click, type and assert against it
+//! once it renders. Results are shown in the story's "Interactions" panel:
+//!
+//! ```rust,ignore
+//! Story::new("Saving", FormProps::default()).with_play(|ctx| async move {
+//!     ctx.type_text("input[name=title]", "Hello").await;
+//!     ctx.click("button").await;
+//!     ctx.assert_text("Saved").await;
+//! })
+//! ```
+//!
 //! ## Re-exports
 //!
 //! This crate re-exports several dependencies so that downstream crates do not
@@ -114,8 +140,19 @@ pub use inventory;
 pub use schemars;
 pub use serde;
 pub use serde_json;
+pub use storybook_macro::config;
 pub use storybook_macro::storybook;
 pub use storybook_macro::storydoc;
+pub use storybook_macro::storyglossary;
+
+pub use addon::{Addon, AddonContext, AddonRegistration, get_addons};
+pub use error::Error;
+pub use globals::{GlobalSetting, Globals, globals};
+pub use loader::PropsLoader;
+pub use naming::{NamingRules, NamingViolation, TitleCase, lint_story_names};
+pub use play::{PlayContext, PlayFn, PlayStep};
+pub use summary::{BrokenLink, RegistrySummary, registry_summary};
+pub use ui::models::ViewportSize;
 
 use crate::ui::App;
 use dioxus::prelude::*;
@@ -123,7 +160,21 @@ use schemars::Schema;
 
 pub const STORYBOOK_CSS: Asset = asset!("../assets/storybook.scss");
 
+/// Bundled [normalize.css](https://necolas.github.io/normalize.css/) v8.0.1,
+/// used by [`Reset::Normalize`].
+pub const NORMALIZE_CSS: Asset = asset!("../assets/normalize.css");
+
+mod addon;
+mod error;
+mod globals;
+mod loader;
+mod naming;
+mod play;
+pub mod scaffold;
+mod summary;
+pub mod tailwind;
 mod ui;
+pub mod watch;
 
 /// Configuration for the storybook application.
 ///
@@ -145,8 +196,384 @@ pub struct StorybookConfig {
     /// CSS URLs to inject into the component preview iframes.
     /// This should include the CSS for your component library.
     pub component_css: Vec<Asset>,
+    /// CSS injected after `component_css` while the dark preview background
+    /// is active, see [`with_dark_css`](Self::with_dark_css).
+    pub dark_css: Vec<Asset>,
+    /// Raw HTML appended to the `<head>` of the preview iframes, see
+    /// [`with_head_html`](Self::with_head_html).
+    pub head_html: Vec<String>,
     /// Optional title for the storybook (displayed in the header).
     pub title: Option<String>,
+    /// Optional CSS reset loaded into preview iframes before `component_css`.
+    pub css_reset: Option<Reset>,
+    /// How previews are isolated, see [`PreviewIsolation`].
+    pub preview_isolation: PreviewIsolation,
+    /// Generated Tailwind stylesheet, see [`with_tailwind`](Self::with_tailwind).
+    pub tailwind_css: Option<Asset>,
+    /// Preview settings scoped to categories, see [`for_category`](Self::for_category).
+    pub category_configs: Vec<(String, CategoryConfig)>,
+    /// Explicit sidebar order of categories and folders, see
+    /// [`with_category_order`](Self::with_category_order).
+    pub category_order: Vec<String>,
+    /// Tag patterns of the components to ship, see
+    /// [`include_tags`](Self::include_tags); empty includes every component.
+    pub include_tags: Vec<String>,
+    /// Tag patterns of the components to hide, see
+    /// [`exclude_tags`](Self::exclude_tags).
+    pub exclude_tags: Vec<String>,
+    /// Renamed story ids (`old -> new`), see
+    /// [`with_story_migrations`](Self::with_story_migrations).
+    pub story_migrations: Vec<(String, String)>,
+    /// Conventions the story titles are checked against, see
+    /// [`with_naming_rules`](Self::with_naming_rules).
+    pub naming_rules: Option<NamingRules>,
+    /// Values of `${NAME}` placeholders, see [`with_var`](Self::with_var).
+    pub vars: Vec<(String, String)>,
+    /// Whether placeholders are substituted in story props, see
+    /// [`with_prop_placeholders`](Self::with_prop_placeholders).
+    pub prop_placeholders: bool,
+    /// Whether the session activity page is available, see
+    /// [`with_session_activity`](Self::with_session_activity).
+    pub session_activity: bool,
+    /// Whether clicking a sidebar category opens its overview page, see
+    /// [`with_category_overview`](Self::with_category_overview).
+    pub category_overview: bool,
+    /// Story ids and doc paths presented in order by the presentation mode,
+    /// see [`with_presentation`](Self::with_presentation).
+    pub presentation: Vec<String>,
+    /// Seconds per slide when the presentation autoplays, see
+    /// [`with_presentation_interval`](Self::with_presentation_interval).
+    pub presentation_interval: Option<u32>,
+    /// Named collections of story ids and doc paths, see
+    /// [`with_playlist`](Self::with_playlist).
+    pub playlists: Vec<(String, Vec<String>)>,
+    /// Bounds and step of the preview zoom, see [`with_zoom`](Self::with_zoom).
+    pub zoom: ZoomConfig,
+    /// Stories rendered at once by batch snapshots, see
+    /// [`with_snapshot_concurrency`](Self::with_snapshot_concurrency).
+    pub snapshot_concurrency: Option<usize>,
+    /// Branding of the storybook UI, see [`with_theme`](Self::with_theme).
+    pub theme: StorybookTheme,
+    /// Custom content of the top bar, see [`with_header`](Self::with_header).
+    pub header: Option<HeaderFn>,
+    /// Extra top bar controls, see [`with_toolbar_item`](Self::with_toolbar_item).
+    pub toolbar_items: Vec<ToolbarItemFn>,
+    /// Post-processing of the preview iframes' HTML, see
+    /// [`with_srcdoc_transform`](Self::with_srcdoc_transform).
+    pub srcdoc_transforms: Vec<SrcdocTransform>,
+    /// Replacements of built-in UI parts, see
+    /// [`with_override`](Self::with_override).
+    pub overrides: Vec<(Slot, SlotFn)>,
+    /// Settings shared by every story, see [`with_global`](Self::with_global).
+    pub globals: Vec<GlobalSetting>,
+    /// Days a doc page may lag behind its components before being flagged
+    /// as stale, see [`with_doc_staleness`](Self::with_doc_staleness).
+    pub doc_staleness_days: Option<u32>,
+    /// Handlers of custom doc directives, by kind, see
+    /// [`with_doc_directive`](Self::with_doc_directive).
+    pub doc_directives: Vec<(String, DocDirectiveFn)>,
+    /// Post-processing of the doc pages' HTML, see
+    /// [`with_doc_transform`](Self::with_doc_transform).
+    pub doc_transforms: Vec<DocTransform>,
+    /// Addons of this storybook, see [`with_addon`](Self::with_addon).
+    pub addons: Vec<&'static dyn Addon>,
+    /// Tag patterns of the active [`Profile`]; empty shows every component.
+    pub(crate) visible_tags: Vec<String>,
+}
+
+/// Preview environment applied to every story of the matching categories.
+///
+/// Registered with [`StorybookConfig::for_category`].
+#[derive(Clone, Default)]
+pub struct CategoryConfig {
+    /// Extra CSS injected into the preview iframes after the global component CSS.
+    pub css: Vec<Asset>,
+    /// Decorators wrapping every story, outside the story's own decorators.
+    pub decorators: Vec<Decorator>,
+    /// Light-mode preview background (any CSS color), replacing the default white.
+    pub background: Option<String>,
+}
+
+impl CategoryConfig {
+    fn merge(mut self, other: &CategoryConfig) -> Self {
+        self.css.extend(other.css.iter().copied());
+        self.decorators.extend(other.decorators.iter().copied());
+        if other.background.is_some() {
+            self.background = other.background.clone();
+        }
+        self
+    }
+}
+
+/// Comma-separated component or story names read from the `STORYBOOK_ONLY`
+/// environment variable when building the storybook, e.g.
+/// `STORYBOOK_ONLY=ExampleButton,ExampleCard/Default`.
+pub(crate) const STORYBOOK_ONLY: Option<&str> = option_env!("STORYBOOK_ONLY");
+
+/// Returns `true` if the story `story_title` of `component_name` passes the
+/// `STORYBOOK_ONLY` filter: an entry matches either the whole component
+/// (`ExampleButton`) or a single story (`ExampleButton/Disabled`).
+pub(crate) fn is_focused(component_name: &str, story_title: &str) -> bool {
+    let Some(only) = STORYBOOK_ONLY.filter(|only| !only.trim().is_empty()) else {
+        return true;
+    };
+    only.split(',')
+        .map(str::trim)
+        .any(|entry| match entry.split_once('/') {
+            Some((component, story)) => component == component_name && story == story_title,
+            None => entry == component_name,
+        })
+}
+
+/// Returns `true` if the `/`-separated `category` matches `pattern`.
+///
+/// `*` matches any single segment, and a trailing `/**` matches the category
+/// and all its descendants (`**` alone matches everything).
+fn category_matches(pattern: &str, category: &str) -> bool {
+    if pattern == "**" {
+        return true;
+    }
+    let (pattern, recursive) = match pattern.strip_suffix("/**") {
+        Some(prefix) => (prefix, true),
+        None => (pattern, false),
+    };
+    let pattern: Vec<&str> = pattern.split('/').collect();
+    let category: Vec<&str> = category.split('/').collect();
+    let depth_matches = if recursive {
+        category.len() >= pattern.len()
+    } else {
+        category.len() == pattern.len()
+    };
+    depth_matches
+        && pattern
+            .iter()
+            .zip(&category)
+            .all(|(p, c)| *p == "*" || p == c)
+}
+
+/// Range and step of the preview zoom levels, in percent.
+///
+/// Set with [`StorybookConfig::with_zoom`]; the default range is 25 %–200 %
+/// in 25 % steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ZoomConfig {
+    /// Smallest zoom level.
+    pub min: i32,
+    /// Largest zoom level.
+    pub max: i32,
+    /// Change applied by the zoom in / zoom out buttons.
+    pub step: i32,
+}
+
+impl Default for ZoomConfig {
+    fn default() -> Self {
+        ZoomConfig {
+            min: 25,
+            max: 200,
+            step: 25,
+        }
+    }
+}
+
+impl ZoomConfig {
+    /// Keep `zoom_level` within the range.
+    pub fn clamp(&self, zoom_level: i32) -> i32 {
+        zoom_level.clamp(self.min, self.max)
+    }
+
+    /// The zoom level one step above `zoom_level`.
+    pub fn zoom_in(&self, zoom_level: i32) -> i32 {
+        self.clamp(zoom_level + self.step)
+    }
+
+    /// The zoom level one step below `zoom_level`.
+    pub fn zoom_out(&self, zoom_level: i32) -> i32 {
+        self.clamp(zoom_level - self.step)
+    }
+}
+
+/// Branding of the storybook UI itself (not of the previewed components).
+///
+/// Set with [`StorybookConfig::with_theme`]. Each value overrides a CSS
+/// custom property of the storybook stylesheet; unset values keep the
+/// defaults.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// StorybookConfig::default().with_theme(
+///     StorybookTheme::default()
+///         .with_brand_color("#6200ee")
+///         .with_logo(asset!("/assets/logo.svg"))
+///         .with_font_family("Inter, sans-serif")
+///         .with_sidebar_width(260)
+///         .with_radius(8),
+/// )
+/// ```
+#[derive(Clone, Default)]
+pub struct StorybookTheme {
+    /// Accent color of selections, links and active buttons (any CSS color).
+    pub brand_color: Option<String>,
+    /// Logo shown at the start of the top bar.
+    pub logo: Option<Asset>,
+    /// Font stack of the storybook UI.
+    pub font_family: Option<String>,
+    /// Width of the sidebar, in pixels (300 by default).
+    pub sidebar_width: Option<u32>,
+    /// Corner radius of buttons, nodes and panels, in pixels (4 by default).
+    pub radius: Option<u32>,
+}
+
+impl StorybookTheme {
+    pub fn with_brand_color(mut self, color: impl Into<String>) -> Self {
+        self.brand_color = Some(color.into());
+        self
+    }
+
+    pub fn with_logo(mut self, logo: Asset) -> Self {
+        self.logo = Some(logo);
+        self
+    }
+
+    pub fn with_font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = Some(font_family.into());
+        self
+    }
+
+    pub fn with_sidebar_width(mut self, pixels: u32) -> Self {
+        self.sidebar_width = Some(pixels);
+        self
+    }
+
+    pub fn with_radius(mut self, pixels: u32) -> Self {
+        self.radius = Some(pixels);
+        self
+    }
+
+    /// The `:root` rule setting the CSS custom properties of the theme, or
+    /// an empty string when nothing is customized.
+    pub fn css_variables(&self) -> String {
+        let mut variables = Vec::new();
+        if let Some(color) = &self.brand_color {
+            variables.push(format!("--storybook-brand: {color};"));
+            variables.push(format!(
+                "--storybook-brand-bg: color-mix(in srgb, {color} 12%, white);"
+            ));
+        }
+        if let Some(font_family) = &self.font_family {
+            variables.push(format!("--storybook-font: {font_family};"));
+        }
+        if let Some(width) = self.sidebar_width {
+            variables.push(format!("--storybook-sidebar-width: {width}px;"));
+        }
+        if let Some(radius) = self.radius {
+            variables.push(format!("--storybook-radius: {radius}px;"));
+        }
+        if variables.is_empty() {
+            return String::new();
+        }
+        format!(":root {{ {} }}", variables.join(" "))
+    }
+}
+
+/// A CSS reset loaded into preview iframes before the component CSS.
+///
+/// Preview iframes start from the browser's default styles. Component
+/// libraries written against a reset (normalize.css, Tailwind's preflight,
+/// a custom one) can render differently there than in the host application.
+#[derive(Clone, PartialEq)]
+pub enum Reset {
+    /// The bundled [`NORMALIZE_CSS`].
+    Normalize,
+    /// A custom reset stylesheet.
+    Custom(Asset),
+}
+
+impl Reset {
+    /// URL of the reset stylesheet.
+    pub fn href(&self) -> String {
+        match self {
+            Reset::Normalize => NORMALIZE_CSS.to_string(),
+            Reset::Custom(asset) => asset.to_string(),
+        }
+    }
+}
+
+/// A part of the storybook UI that can be replaced or filled in, see
+/// [`StorybookConfig::with_override`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Slot {
+    /// The page shown while nothing is selected ("Select a story").
+    EmptyState,
+    /// The cards reporting an error, such as a story that failed to resolve
+    /// or a broken embed in a doc page.
+    Error,
+    /// The placeholders shown while something renders, such as a story
+    /// thumbnail.
+    Loading,
+    /// A footer below every page, e.g. with build information. Empty by
+    /// default.
+    Footer,
+    /// Extra sections at the bottom of the sidebar, e.g. links to the team's
+    /// chat or issue tracker. Empty by default.
+    Sidebar,
+}
+
+/// What an overridden [`Slot`] is rendered for.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SlotContext {
+    /// The error to report, for [`Slot::Error`].
+    pub message: Option<String>,
+}
+
+/// Placement of a story in its preview, see [`Story::with_layout`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Centered horizontally and vertically.
+    Centered,
+    /// Edge to edge, without padding.
+    Fullscreen,
+    /// With the default padding around it (the default).
+    #[default]
+    Padded,
+}
+
+/// How story previews are isolated from the storybook UI.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PreviewIsolation {
+    /// Each preview is an iframe with its own document (the default).
+    ///
+    /// Gives full document isolation: media queries follow the selected
+    /// viewport and the measure tool and layout / accessibility audits are
+    /// available.
+    #[default]
+    Iframe,
+    /// Each preview renders into a shadow root of the storybook page, with
+    /// the component CSS scoped to it.
+    ///
+    /// Lighter than an iframe, but media queries see the storybook window,
+    /// scripts added with [`StorybookConfig::with_head_html`] don't run, and
+    /// the measure tool and layout / accessibility audits are unavailable.
+    ShadowDom,
+    /// Stories render directly in the storybook's tree, without isolation.
+    ///
+    /// The component CSS is loaded into the storybook page, so it also
+    /// applies to the storybook UI. This is the only mode on native targets
+    /// (e.g. Dioxus desktop), where the other modes fall back to it.
+    Inline,
+}
+
+impl PreviewIsolation {
+    /// The isolation actually used on the current target: native targets
+    /// can't mount stories into iframes or shadow roots and always render
+    /// them [`Inline`](Self::Inline).
+    pub fn effective(self) -> Self {
+        if cfg!(target_family = "wasm") {
+            self
+        } else {
+            PreviewIsolation::Inline
+        }
+    }
 }
 
 impl StorybookConfig {
@@ -156,10 +583,702 @@ impl StorybookConfig {
         self
     }
 
-    /// Set the title for the storybook.
-    pub fn with_title(mut self, title: impl Into<String>) -> Self {
-        self.title = Some(title.into());
-        self
+    /// Add a stylesheet injected into the preview iframes only while the
+    /// dark preview background is active.
+    ///
+    /// It is loaded after the component CSS, so it can either override a
+    /// few rules or hold a complete dark theme.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_css(THEME_CSS)
+    ///     .with_dark_css(THEME_DARK_CSS)
+    /// ```
+    pub fn with_dark_css(mut self, dark_css: Asset) -> Self {
+        self.dark_css.push(dark_css);
+        self
+    }
+
+    /// Append raw HTML to the `<head>` of the preview iframes, after the
+    /// component stylesheets (e.g. `<meta>` tags or third-party scripts).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_head_html(r#"<script src="https://kit.fontawesome.com/abc.js"></script>"#)
+    /// ```
+    pub fn with_head_html(mut self, html: impl Into<String>) -> Self {
+        self.head_html.push(html.into());
+        self
+    }
+
+    /// Load a web font (or any remote stylesheet, such as an icon font CDN)
+    /// into the preview iframes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_font("https://fonts.googleapis.com/css2?family=Inter:wght@400;600&display=swap")
+    /// ```
+    pub fn with_font(self, url: impl Into<String>) -> Self {
+        let href = escape_attribute(&url.into());
+        self.with_head_html(format!(r#"<link rel="stylesheet" href="{href}">"#))
+    }
+
+    /// Set the title for the storybook.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Load a CSS reset into the preview iframes before the component CSS.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_css_reset(Reset::Normalize)
+    ///     .with_css(MY_CSS)
+    /// ```
+    pub fn with_css_reset(mut self, reset: Reset) -> Self {
+        self.css_reset = Some(reset);
+        self
+    }
+
+    /// Choose how story previews are isolated from the storybook UI.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default().with_preview_isolation(PreviewIsolation::ShadowDom)
+    /// ```
+    pub fn with_preview_isolation(mut self, isolation: PreviewIsolation) -> Self {
+        self.preview_isolation = isolation;
+        self
+    }
+
+    /// The isolation the previews of `story` actually use: its own, or the
+    /// storybook's default, falling back to inline on native targets.
+    pub(crate) fn isolation_for(&self, story: &StoryInfo) -> PreviewIsolation {
+        story
+            .preview_isolation
+            .unwrap_or(self.preview_isolation)
+            .effective()
+    }
+
+    /// Enable the Tailwind integration with the generated utility stylesheet.
+    ///
+    /// The stylesheet is loaded into the preview iframes before the component
+    /// CSS, and story pages get a "Classes" panel listing the utility classes
+    /// present in the rendered story. Use [`tailwind::write_safelist`] so
+    /// classes only used in stories are not purged.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
+    ///
+    /// StorybookConfig::default().with_tailwind(TAILWIND_CSS)
+    /// ```
+    pub fn with_tailwind(mut self, tailwind_css: Asset) -> Self {
+        self.tailwind_css = Some(tailwind_css);
+        self
+    }
+
+    /// Apply a [`CategoryConfig`] to every component whose tag matches `pattern`.
+    ///
+    /// `pattern` is a tag path where `*` matches one segment and a trailing
+    /// `/**` matches the category and everything below it. When several
+    /// patterns match, their CSS and decorators are combined in registration
+    /// order and the last background wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default().for_category(
+    ///     "Marketing/**",
+    ///     CategoryConfig {
+    ///         css: vec![MARKETING_CSS],
+    ///         decorators: vec![|story| rsx! { div { class: "landing", {story} } }],
+    ///         ..Default::default()
+    ///     },
+    /// )
+    /// ```
+    pub fn for_category(mut self, pattern: impl Into<String>, config: CategoryConfig) -> Self {
+        self.category_configs.push((pattern.into(), config));
+        self
+    }
+
+    /// Set the sidebar order of categories and folders.
+    ///
+    /// Entries are category paths (`"Atoms"`, `"Examples/Forms"`). Listed
+    /// entries come first, in the given order; the others follow
+    /// alphabetically. Components inside a folder are ordered by their
+    /// `#[storybook(weight = ..)]`, then by name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_category_order(&["Getting Started", "Atoms", "Molecules"])
+    /// ```
+    pub fn with_category_order(mut self, order: &[&str]) -> Self {
+        self.category_order = order.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Only show the components whose tag matches one of `patterns`.
+    ///
+    /// Patterns use the [`for_category`](Self::for_category) syntax. Combined
+    /// with [`exclude_tags`](Self::exclude_tags), this lets a deployment ship
+    /// a subset of the registered components without touching annotations.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .include_tags(["Widgets/**", "Forms/**"])
+    ///     .exclude_tags(["*/Experimental/**"])
+    /// ```
+    pub fn include_tags(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.include_tags
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Hide the components whose tag matches one of `patterns`, even when
+    /// they are included by [`include_tags`](Self::include_tags).
+    pub fn exclude_tags(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.exclude_tags
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Map story ids that no longer exist to their replacement, so deep links
+    /// and snapshot baselines keep working after a story is renamed.
+    ///
+    /// Story ids are the [`Story::with_id`] ids, or derived from the component
+    /// name and story title (see [`story_id`]). Migrations can be chained.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_story_migrations([("button--default", "button--primary")])
+    /// ```
+    pub fn with_story_migrations(
+        mut self,
+        migrations: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.story_migrations.extend(
+            migrations
+                .into_iter()
+                .map(|(old, new)| (old.into(), new.into())),
+        );
+        self
+    }
+
+    /// Check the story titles against `rules` when the storybook starts,
+    /// listing the titles that break them in the startup banner next to the
+    /// other registry problems (see [`RegistrySummary`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default().with_naming_rules(
+    ///     NamingRules::default()
+    ///         .with_first_story("Default")
+    ///         .with_title_case(TitleCase::Title)
+    ///         .with_max_title_length(40),
+    /// )
+    /// ```
+    pub fn with_naming_rules(mut self, rules: NamingRules) -> Self {
+        self.naming_rules = Some(rules);
+        self
+    }
+
+    /// Resolve `id` through the [story migrations](Self::with_story_migrations),
+    /// returning the current id of the story.
+    pub fn migrate_story_id<'a>(&'a self, mut id: &'a str) -> &'a str {
+        // Bounded so that a migration cycle can't loop forever.
+        for _ in 0..self.story_migrations.len() {
+            match self.story_migrations.iter().find(|(old, _)| old == id) {
+                Some((_, new)) => id = new,
+                None => break,
+            }
+        }
+        id
+    }
+
+    /// Set the value of the `${name}` placeholder.
+    ///
+    /// Placeholders can be used in the title, [head HTML](Self::with_head_html),
+    /// [fonts](Self::with_font) and category backgrounds, and in story string
+    /// props with [`with_prop_placeholders`](Self::with_prop_placeholders), so
+    /// the same stories work against different backends or asset hosts. They
+    /// are resolved when the storybook launches.
+    ///
+    /// On native targets, placeholders without a value set here are read from
+    /// the environment. Web builds have no environment at runtime: pass the
+    /// value captured at compile time instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_var("STORYBOOK_ASSETS", option_env!("STORYBOOK_ASSETS").unwrap_or("/assets"))
+    ///     .with_font("${STORYBOOK_ASSETS}/fonts/inter.css")
+    /// ```
+    pub fn with_var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.vars.push((name.into(), value.into()));
+        self
+    }
+
+    /// Also substitute `${NAME}` placeholders (see [`with_var`](Self::with_var))
+    /// in story string props.
+    ///
+    /// The props editor keeps showing the placeholders; they are substituted
+    /// when the story renders.
+    pub fn with_prop_placeholders(mut self) -> Self {
+        self.prop_placeholders = true;
+        self
+    }
+
+    /// Set the slides of the presentation mode.
+    ///
+    /// Each entry is a story id (see [`story_id`]) or a documentation page
+    /// path. Without slides, the presentation steps through every story in
+    /// sidebar order. The presentation is started from the top bar and hides
+    /// all the storybook chrome; the arrow keys move between slides, `A`
+    /// toggles autoplay and `Escape` leaves.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_presentation(["Getting Started/Welcome", "button--primary", "button--disabled"])
+    ///     .with_presentation_interval(15)
+    /// ```
+    pub fn with_presentation(
+        mut self,
+        entries: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.presentation = entries.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Seconds each slide stays on screen when the presentation autoplays
+    /// (10 by default).
+    pub fn with_presentation_interval(mut self, seconds: u32) -> Self {
+        self.presentation_interval = Some(seconds.max(1));
+        self
+    }
+
+    /// Number of stories a batch snapshot renders and captures at once (8 by
+    /// default). The stories are queued and captured batch by batch, so
+    /// higher values are faster for large collections but mount more
+    /// previews at the same time.
+    pub fn with_snapshot_concurrency(mut self, stories: usize) -> Self {
+        self.snapshot_concurrency = Some(stories.max(1));
+        self
+    }
+
+    /// Brand the storybook UI: accent color, logo, font, sidebar width and
+    /// corner radius. See [`StorybookTheme`].
+    pub fn with_theme(mut self, theme: StorybookTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Warn on doc pages last updated more than `days` before the latest
+    /// change to the source of one of the components they document (the
+    /// components listed under the page's folder).
+    ///
+    /// Dates are recorded at compile time: the modification time of doc
+    /// files, and the `STORYBOOK_SOURCE_DATE` variable set by the build
+    /// script for components. `SOURCE_DATE_EPOCH` overrides both.
+    pub fn with_doc_staleness(mut self, days: u32) -> Self {
+        self.doc_staleness_days = Some(days);
+        self
+    }
+
+    /// Render the custom directive `@[kind:argument]` of doc comments and
+    /// `storydoc!` pages with `render`, called with the argument. A directive
+    /// goes on its own line, like `@[story:…]`; without a handler for its
+    /// kind, it is shown as written. Registering a kind again replaces its
+    /// handler; the built-in kinds (`story`, `props`, `canvas`, `prop`,
+    /// `video` and `iframe`) can't be overridden.
+    ///
+    /// `render` is called while rendering a component of its own, so it can
+    /// use hooks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn figma(url: String) -> Element {
+    ///     let src = format!("https://www.figma.com/embed?embed_host=storybook&url={url}");
+    ///     rsx! { iframe { class: "figma-embed", src, width: "100%", height: "450" } }
+    /// }
+    ///
+    /// // docs/button.md: @[figma:https://www.figma.com/file/…]
+    /// StorybookConfig::default().with_doc_directive("figma", figma)
+    /// ```
+    pub fn with_doc_directive(mut self, kind: impl Into<String>, render: DocDirectiveFn) -> Self {
+        let kind = kind.into();
+        self.doc_directives.retain(|(known, _)| *known != kind);
+        self.doc_directives.push((kind, render));
+        self
+    }
+
+    /// Post-process the HTML of doc pages (`storydoc!` files and component
+    /// descriptions) before it is displayed, e.g. to rewrite links or to
+    /// expand a house syntax into markup. Transforms run in the order they
+    /// are added, before embeds and directives are resolved, so they may
+    /// also emit `<div class="storybook-directive" …>` markers.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn open_links_in_tab(html: String) -> String {
+    ///     html.replace("<a href=\"http", "<a target=\"_blank\" href=\"http")
+    /// }
+    ///
+    /// StorybookConfig::default().with_doc_transform(open_links_in_tab)
+    /// ```
+    pub fn with_doc_transform(mut self, transform: DocTransform) -> Self {
+        self.doc_transforms.push(transform);
+        self
+    }
+
+    /// Show `logo` at the start of the top bar, a shortcut for the
+    /// [`StorybookTheme`] logo.
+    pub fn with_logo(mut self, logo: Asset) -> Self {
+        self.theme.logo = Some(logo);
+        self
+    }
+
+    /// Render `header` in the top bar, between the built-in controls and
+    /// the profile / presentation buttons, e.g. links to the design-system
+    /// repository or a version badge.
+    ///
+    /// `header` is a regular component function without props; it can use
+    /// hooks and read the [`StorybookConfig`] context.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[component]
+    /// fn Links() -> Element {
+    ///     rsx! {
+    ///         a { href: "https://github.com/acme/design-system", "Repository" }
+    ///     }
+    /// }
+    ///
+    /// StorybookConfig::default().with_header(Links)
+    /// ```
+    pub fn with_header(mut self, header: HeaderFn) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// Add a control to the top bar, after the built-in preview toggles
+    /// (theme, grid, outline, measure), e.g. a locale switcher or a feature
+    /// flag toggle. Items show in the order they are added.
+    ///
+    /// Like [`with_header`](Self::with_header), `item` is a component
+    /// function without props that can use hooks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[component]
+    /// fn BetaToggle() -> Element {
+    ///     let mut beta = use_context::<Signal<BetaFlag>>();
+    ///     rsx! {
+    ///         button { onclick: move |_| beta.write().toggle(), "Beta" }
+    ///     }
+    /// }
+    ///
+    /// StorybookConfig::default().with_toolbar_item(BetaToggle)
+    /// ```
+    pub fn with_toolbar_item(mut self, item: ToolbarItemFn) -> Self {
+        self.toolbar_items.push(item);
+        self
+    }
+
+    /// Replace a built-in part of the UI — the empty state, the error cards
+    /// or the loading placeholders — with your own component, so an embedded
+    /// storybook matches the host product's look, or fill in the shell's
+    /// empty slots: a footer ([`Slot::Footer`]) and extra sidebar sections
+    /// ([`Slot::Sidebar`]). Custom top-bar content goes through
+    /// [`with_header`](Self::with_header). Overriding a slot again replaces
+    /// the previous override.
+    ///
+    /// `render` receives a [`SlotContext`] (the error message, for
+    /// [`Slot::Error`]) and is called while rendering a component of its
+    /// own, so it can use hooks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use storybook::{Slot, SlotContext};
+    ///
+    /// fn error_card(ctx: SlotContext) -> Element {
+    ///     rsx! {
+    ///         div { class: "acme-alert", {ctx.message.unwrap_or_default()} }
+    ///     }
+    /// }
+    ///
+    /// StorybookConfig::default().with_override(Slot::Error, error_card)
+    /// ```
+    pub fn with_override(mut self, slot: Slot, render: SlotFn) -> Self {
+        self.overrides.retain(|(known, _)| *known != slot);
+        self.overrides.push((slot, render));
+        self
+    }
+
+    /// The override of `slot`, see [`with_override`](Self::with_override).
+    pub(crate) fn slot_override(&self, slot: Slot) -> Option<SlotFn> {
+        self.overrides
+            .iter()
+            .find(|(known, _)| *known == slot)
+            .map(|(_, render)| *render)
+    }
+
+    /// Post-process the HTML document of the preview iframes before it is
+    /// displayed, e.g. to inject a polyfill or an analytics script, or to
+    /// rewrite asset URLs. Transforms run in the order they are added, on
+    /// the document of every story preview, card and snapshot.
+    ///
+    /// The story itself is mounted into the document afterwards, so
+    /// transforms see its empty mount point, not its markup. Shadow root and
+    /// inline previews (see [`with_preview_isolation`](Self::with_preview_isolation))
+    /// have no document of their own and are not transformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn add_polyfills(srcdoc: String) -> String {
+    ///     srcdoc.replacen("<head>", r#"<head><script src="/polyfills.js"></script>"#, 1)
+    /// }
+    ///
+    /// StorybookConfig::default().with_srcdoc_transform(add_polyfills)
+    /// ```
+    pub fn with_srcdoc_transform(mut self, transform: SrcdocTransform) -> Self {
+        self.srcdoc_transforms.push(transform);
+        self
+    }
+
+    /// Declare a global setting, such as the locale or the text direction,
+    /// shared by every story. The top bar gets a selector listing `values`,
+    /// the first one being the default; stories and decorators read the
+    /// current value with [`globals()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn with_locale(story: Element) -> Element {
+    ///     let locale = storybook::globals().get("locale").unwrap_or("en").to_string();
+    ///     rsx! { I18nProvider { locale, {story} } }
+    /// }
+    ///
+    /// StorybookConfig::default()
+    ///     .with_global("locale", ["en", "fr", "de"])
+    ///     .for_category(
+    ///         "**",
+    ///         CategoryConfig {
+    ///             decorators: vec![with_locale],
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    /// ```
+    pub fn with_global<I, S>(mut self, name: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let name = name.into();
+        self.globals.retain(|global| global.name != name);
+        self.globals.push(GlobalSetting {
+            name,
+            values: values.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Add a panel to the story page, next to the addons registered with an
+    /// [`AddonRegistration`]. Addons show in the order they are added.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default().with_addon(&PropsSize)
+    /// ```
+    pub fn with_addon(mut self, addon: &'static dyn Addon) -> Self {
+        self.addons.push(addon);
+        self
+    }
+
+    /// Set the range of the preview zoom (in percent) and the step of the
+    /// zoom in / zoom out buttons. Gesture zooming and fit-to-width stay
+    /// within the same range.
+    ///
+    /// The minimum is at least 1 %, the maximum at least the minimum and
+    /// the step at least 1 %.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default().with_zoom(50, 400, 10)
+    /// ```
+    pub fn with_zoom(mut self, min: i32, max: i32, step: i32) -> Self {
+        let min = min.max(1);
+        self.zoom = ZoomConfig {
+            min,
+            max: max.max(min),
+            step: step.max(1),
+        };
+        self
+    }
+
+    /// Register a read-only playlist: a named collection of story ids (see
+    /// [`story_id`]) and documentation page paths.
+    ///
+    /// Playlists are listed in the sidebar, next to the ones users assemble
+    /// themselves (saved in the browser). The active playlist replaces the
+    /// [presentation](Self::with_presentation) slides, and any playlist can
+    /// be shared as a link.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// StorybookConfig::default()
+    ///     .with_playlist("Q3 release review", ["button--primary", "card--with-image"])
+    /// ```
+    pub fn with_playlist(
+        mut self,
+        name: impl Into<String>,
+        entries: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.playlists
+            .push((name.into(), entries.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Open an overview page when a sidebar category or folder is clicked.
+    ///
+    /// The page shows the first story of every component in the category
+    /// and its subfolders, as a visual index of that section of the design
+    /// system. Clicking a preview opens the component's story gallery.
+    pub fn with_category_overview(mut self) -> Self {
+        self.category_overview = true;
+        self
+    }
+
+    /// Track which stories are opened, and how often, during the session.
+    ///
+    /// A top bar button opens the session activity page listing every story
+    /// with its view count, so a design review can confirm each story was
+    /// looked at. Nothing leaves the browser and nothing is persisted: the
+    /// counts reset when the page reloads.
+    pub fn with_session_activity(mut self) -> Self {
+        self.session_activity = true;
+        self
+    }
+
+    /// The value of the `${name}` placeholder.
+    pub fn var(&self, name: &str) -> Option<String> {
+        if let Some((_, value)) = self.vars.iter().rev().find(|(n, _)| n == name) {
+            return Some(value.clone());
+        }
+        if cfg!(target_family = "wasm") {
+            None
+        } else {
+            std::env::var(name).ok()
+        }
+    }
+
+    /// Replace the `${NAME}` placeholders of `text`; unknown placeholders are
+    /// left untouched.
+    pub fn substitute(&self, text: &str) -> String {
+        substitute_placeholders(text, |name| self.var(name))
+    }
+
+    /// Substitute the placeholders of the string props in `props_json`, when
+    /// [`with_prop_placeholders`](Self::with_prop_placeholders) is enabled.
+    pub(crate) fn substitute_props(&self, props_json: &str) -> String {
+        if !self.prop_placeholders {
+            return props_json.to_string();
+        }
+        // Values are inserted into JSON strings, so they are escaped as such.
+        substitute_placeholders(props_json, |name| {
+            let value = serde_json::to_string(&self.var(name)?).ok()?;
+            Some(value[1..value.len() - 1].to_string())
+        })
+    }
+
+    /// Resolve the placeholders of the configured URLs and HTML.
+    fn resolve_placeholders(self) -> Self {
+        let title = self.title.as_deref().map(|title| self.substitute(title));
+        let head_html = self
+            .head_html
+            .iter()
+            .map(|html| self.substitute(html))
+            .collect();
+        let category_configs = self
+            .category_configs
+            .iter()
+            .cloned()
+            .map(|(pattern, mut category)| {
+                category.background = category.background.map(|bg| self.substitute(&bg));
+                (pattern, category)
+            })
+            .collect();
+        Self {
+            title,
+            head_html,
+            category_configs,
+            ..self
+        }
+    }
+
+    /// Returns `true` if components tagged `tag` are shown with this config.
+    pub(crate) fn shows_tag(&self, tag: &str) -> bool {
+        let matches_any = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| category_matches(pattern, tag))
+        };
+        (self.include_tags.is_empty() || matches_any(&self.include_tags))
+            && (self.visible_tags.is_empty() || matches_any(&self.visible_tags))
+            && !matches_any(&self.exclude_tags)
+    }
+
+    /// The sidebar paths of `registration` shown with this config.
+    pub(crate) fn visible_tags_of(
+        &self,
+        registration: &ComponentRegistration,
+    ) -> Vec<&'static str> {
+        registration
+            .tags
+            .iter()
+            .copied()
+            .filter(|tag| self.shows_tag(tag))
+            .collect()
+    }
+
+    /// The combined [`CategoryConfig`] of every pattern matching `category`.
+    pub fn category_config(&self, category: &str) -> CategoryConfig {
+        self.category_configs
+            .iter()
+            .filter(|(pattern, _)| category_matches(pattern, category))
+            .fold(CategoryConfig::default(), |acc, (_, config)| {
+                acc.merge(config)
+            })
     }
 }
 
@@ -182,6 +1301,468 @@ impl StorybookConfig {
 /// }
 /// ```
 pub fn launch(config: StorybookConfig) {
+    // Store the config in static so the App component can access it
+    // We use a context provider inside App to make it available to child components
+    CONFIG.with(|c| *c.borrow_mut() = Some(config));
+    start();
+}
+
+/// A named storybook configuration served to one audience.
+///
+/// Several profiles can be served by one binary with [`launch_profiles`];
+/// each has its own [`StorybookConfig`] and only shows the components whose
+/// tag matches one of its patterns (same syntax as
+/// [`StorybookConfig::for_category`]).
+#[derive(Clone)]
+pub struct Profile {
+    /// Display name (e.g. `"Public widgets"`).
+    pub name: String,
+    pub config: StorybookConfig,
+    /// Tag patterns of the components shown; empty shows every component.
+    pub tags: Vec<String>,
+}
+
+impl Profile {
+    /// Create a profile showing every component.
+    pub fn new(name: impl Into<String>, config: StorybookConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Restrict the profile to components whose tag matches one of `patterns`.
+    pub fn with_tags(mut self, patterns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags.extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// URL-safe identifier used in the `?profile=` query parameter
+    /// (`"Internal admin"` becomes `"internal-admin"`).
+    pub fn slug(&self) -> String {
+        slugify(&self.name)
+    }
+}
+
+/// Escape `value` for a double-quoted HTML attribute.
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Lowercase `name` and join its alphanumeric words with dashes.
+fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Default id of a story without [`Story::with_id`]: the slugs of the
+/// component name and the story title (`"button--with-icon"` for the
+/// `"With Icon"` story of `Button`).
+pub fn story_id(component_name: &str, title: &str) -> String {
+    format!("{}--{}", slugify(component_name), slugify(title))
+}
+
+/// Group the stories generated by `#[storybook(auto_variants = [...])]` are
+/// listed under.
+const AUTO_VARIANTS_GROUP: &str = "Variants";
+
+/// Most stories `#[storybook(auto_variants = [...])]` generates for a
+/// component; the remaining combinations are left out, with a watch-mode
+/// warning.
+const MAX_AUTO_VARIANTS: usize = 64;
+
+/// Append a story to `stories` for each combination of the values of
+/// `props`, based on the first story.
+///
+/// Called by the stories function `#[storybook(auto_variants = [...])]`
+/// generates. The values of each prop are read from the props `schema`:
+/// the variants of unit enums and `true` / `false` for booleans (plus
+/// `null` for `Option`s). Props without such values are skipped, and so
+/// are combinations whose title a story already has. Past
+/// `MAX_AUTO_VARIANTS` combinations, the others are left out and reported
+/// with [`watch`].
+#[doc(hidden)]
+pub fn push_auto_variants(
+    stories: &mut Vec<StoryInfo>,
+    component_name: &str,
+    schema: &Schema,
+    props: &[&str],
+) {
+    let Some(base) = stories.first().cloned() else {
+        return;
+    };
+    let Ok(base_props) = serde_json::from_str::<serde_json::Value>(&base.props_json) else {
+        return;
+    };
+    let defs = schema
+        .get("$defs")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+    let properties = schema
+        .get("properties")
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+
+    let mut combinations: Vec<Vec<(&str, serde_json::Value)>> = vec![Vec::new()];
+    for prop in props {
+        let values = properties
+            .get(*prop)
+            .map(|prop_schema| schema_values(prop_schema, &defs))
+            .unwrap_or_default();
+        if values.is_empty() {
+            continue;
+        }
+        combinations = combinations
+            .into_iter()
+            .flat_map(|combination| {
+                values.iter().map(move |value| {
+                    let mut combination = combination.clone();
+                    combination.push((*prop, value.clone()));
+                    combination
+                })
+            })
+            .collect();
+    }
+
+    combinations.retain(|combination| !combination.is_empty());
+    if combinations.len() > MAX_AUTO_VARIANTS {
+        watch::auto_variants_truncated(component_name, combinations.len(), MAX_AUTO_VARIANTS);
+        combinations.truncate(MAX_AUTO_VARIANTS);
+    }
+
+    for combination in combinations {
+        let title = combination
+            .iter()
+            .map(|(prop, value)| {
+                let value = value
+                    .as_str()
+                    .map(String::from)
+                    .unwrap_or(value.to_string());
+                format!("{prop}: {value}")
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        if stories.iter().any(|story| story.title == title) {
+            continue;
+        }
+        let mut props_json = base_props.clone();
+        if let Some(obj) = props_json.as_object_mut() {
+            obj.extend(
+                combination
+                    .into_iter()
+                    .map(|(prop, value)| (prop.to_string(), value)),
+            );
+        }
+        stories.push(StoryInfo {
+            id: story_id(component_name, &title),
+            title,
+            description: None,
+            props_json: serde_json::to_string_pretty(&props_json).unwrap_or_default(),
+            // The base story's interactions may not hold for other props
+            play: None,
+            loader: None,
+            group: Some(AUTO_VARIANTS_GROUP.to_string()),
+            ..base.clone()
+        });
+    }
+}
+
+/// The values a prop schema enumerates: unit enum variants, `true` and
+/// `false` for booleans, and `null` for optional props. Empty for other
+/// types.
+fn schema_values(
+    schema: &serde_json::Value,
+    defs: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<serde_json::Value> {
+    let Some(obj) = schema.as_object() else {
+        return Vec::new();
+    };
+    if let Some(def) = obj
+        .get("$ref")
+        .and_then(|v| v.as_str())
+        .and_then(|path| defs.get(path.rsplit('/').next()?))
+    {
+        return schema_values(def, defs);
+    }
+    if let Some(values) = obj.get("enum").and_then(|v| v.as_array()) {
+        return values.clone();
+    }
+    if let Some(value) = obj.get("const") {
+        return vec![value.clone()];
+    }
+    let mut values = ["oneOf", "anyOf"]
+        .iter()
+        .filter_map(|key| obj.get(*key).and_then(|v| v.as_array()))
+        .flatten()
+        .flat_map(|variant| schema_values(variant, defs))
+        .collect::<Vec<_>>();
+    let types = match obj.get("type") {
+        Some(serde_json::Value::String(t)) => vec![t.as_str()],
+        Some(serde_json::Value::Array(arr)) => arr.iter().filter_map(|v| v.as_str()).collect(),
+        _ => Vec::new(),
+    };
+    if types.contains(&"boolean") {
+        values.extend([
+            serde_json::Value::Bool(true),
+            serde_json::Value::Bool(false),
+        ]);
+    }
+    if types.contains(&"null") {
+        values.push(serde_json::Value::Null);
+    }
+    values
+}
+
+/// Remove `fields` from the `required` list of a props schema.
+///
+/// Called by the schema function `#[storybook]` generates, with the props
+/// the component can be rendered without (`#[props(default)]`, `Option<T>`),
+/// so the props table and editor follow the component rather than the serde
+/// schema of its story props.
+#[doc(hidden)]
+pub fn mark_optional_props(schema: &mut Schema, fields: &[&str]) {
+    if let Some(required) = schema
+        .as_object_mut()
+        .and_then(|schema| schema.get_mut("required"))
+        .and_then(|required| required.as_array_mut())
+    {
+        required.retain(|name| !name.as_str().is_some_and(|name| fields.contains(&name)));
+    }
+}
+
+/// The [`Error::PropsDeserialize`] of `props_json`, naming the prop `err`
+/// points at.
+///
+/// Reported by the render function `#[storybook]` generates when the story
+/// falls back to its default props, and returned by
+/// [`ComponentRegistration::check_props`].
+#[doc(hidden)]
+pub fn props_deserialize_error(
+    component_name: &str,
+    props_json: &str,
+    err: &serde_json::Error,
+) -> Error {
+    Error::PropsDeserialize {
+        component_name: component_name.to_string(),
+        field: props_error_field(props_json, err),
+        message: err.to_string(),
+    }
+}
+
+/// The prop a props deserialization error is about: the one named by a
+/// missing or unknown field error, else the top-level key whose value holds
+/// the error position.
+fn props_error_field(props_json: &str, err: &serde_json::Error) -> Option<String> {
+    let message = err.to_string();
+    for prefix in ["missing field `", "unknown field `"] {
+        if let Some(rest) = message.strip_prefix(prefix) {
+            return rest.split('`').next().map(str::to_string);
+        }
+    }
+    if err.line() == 0 {
+        return None;
+    }
+    let offset = props_json
+        .split('\n')
+        .take(err.line() - 1)
+        .map(|line| line.len() + 1)
+        .sum::<usize>()
+        + err.column().saturating_sub(1);
+
+    let mut depth = 0usize;
+    let mut string_start = None;
+    let mut escaped = false;
+    let mut last_string = None;
+    let mut key = None;
+    for (i, c) in props_json.char_indices().take_while(|(i, _)| *i < offset) {
+        if let Some(start) = string_start {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => {
+                    // With its quotes, to decode escapes
+                    last_string = Some(&props_json[start - 1..=i]);
+                    string_start = None;
+                }
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => string_start = Some(i + 1),
+            '{' | '[' => depth += 1,
+            '}' | ']' => depth = depth.saturating_sub(1),
+            ':' if depth == 1 => key = last_string.and_then(|key| serde_json::from_str(key).ok()),
+            _ => {}
+        }
+    }
+    key
+}
+
+/// Props the storybook can edit: they are sent to the preview as JSON and
+/// described by their schema in the props editor.
+///
+/// The story props generated by `#[storybook]` serialize, deserialize and
+/// describe each prop through it, so that an unsupported type is reported
+/// once, on its field, rather than in the generated code.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the prop type `{Self}` can't be edited in the storybook",
+    label = "needs `Serialize`, `Deserialize` and `JsonSchema`",
+    note = "derive `serde::Serialize`, `serde::Deserialize` and `schemars::JsonSchema` for `{Self}`",
+    note = "`EventHandler`, `Callback` and `Element` props are supported and shown as non-editable"
+)]
+pub trait EditableProp: Sized {
+    fn serialize_prop<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn deserialize_prop<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error>;
+
+    fn prop_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema;
+
+    /// The schema of the prop with its `default` value
+    fn prop_schema_with_default(
+        generator: &mut schemars::SchemaGenerator,
+        default: &Self,
+    ) -> schemars::Schema {
+        let mut schema = Self::prop_schema(generator);
+        if let Ok(default) = default.serialize_prop(serde_json::value::Serializer) {
+            schema.insert("default".to_string(), default);
+        }
+        schema
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T: serde::Serialize + serde::de::DeserializeOwned + schemars::JsonSchema> EditableProp for T {
+    fn serialize_prop<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+
+    fn deserialize_prop<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        T::deserialize(deserializer)
+    }
+
+    fn prop_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        generator.subschema_for::<T>()
+    }
+}
+
+/// Bytes of borrowed prop values the storybook keeps at most. Edits to
+/// borrowed props past it are ignored.
+const INTERNED_BYTES_BUDGET: usize = 4 * 1024 * 1024;
+
+/// A `&'static str` with the content of `value`, for the `&'static str`
+/// props of a component whose story props own a `String`.
+///
+/// Each distinct value is leaked once and reused afterwards, so editing a
+/// prop only grows the memory by the values actually typed, up to
+/// [`INTERNED_BYTES_BUDGET`]. `None` once that budget is spent.
+#[doc(hidden)]
+pub fn static_str(value: &str) -> Option<&'static str> {
+    let mut hasher = std::hash::DefaultHasher::new();
+    std::hash::Hash::hash(value, &mut hasher);
+    intern(
+        std::hash::Hasher::finish(&hasher),
+        value.len(),
+        |interned: &String| interned == value,
+        || value.to_string(),
+    )
+    .map(String::as_str)
+}
+
+/// A `&'static [T]` with the content of `value`, for the borrowed slice
+/// props of a component whose story props own a `Vec<T>`. Values are
+/// interned like [`static_str`], slices of the same length being compared.
+#[doc(hidden)]
+pub fn static_slice<T: Clone + PartialEq + 'static>(value: &[T]) -> Option<&'static [T]> {
+    intern(
+        value.len() as u64,
+        std::mem::size_of_val(value),
+        |interned: &Vec<T>| interned == value,
+        || value.to_vec(),
+    )
+    .map(Vec::as_slice)
+}
+
+/// Values leaked by [`intern`], by type and hash.
+#[derive(Default)]
+struct Interned {
+    values: std::collections::HashMap<(std::any::TypeId, u64), Vec<&'static dyn std::any::Any>>,
+    /// Bytes of the values leaked so far.
+    bytes: usize,
+    /// Whether a value was refused for exceeding the budget.
+    budget_spent: bool,
+}
+
+/// The interned value with this `hash` matching `is_value`, or a leaked
+/// `make()` of `size` bytes when there is none yet. `None` when leaking it
+/// would exceed [`INTERNED_BYTES_BUDGET`].
+fn intern<T: 'static>(
+    hash: u64,
+    size: usize,
+    is_value: impl Fn(&T) -> bool,
+    make: impl FnOnce() -> T,
+) -> Option<&'static T> {
+    let key = (std::any::TypeId::of::<T>(), hash);
+    INTERNED.with(|interned| {
+        let mut interned = interned.borrow_mut();
+        if let Some(value) = interned.values.get(&key).and_then(|values| {
+            values
+                .iter()
+                .find_map(|&value| value.downcast_ref::<T>().filter(|value| is_value(value)))
+        }) {
+            return Some(value);
+        }
+        if interned.bytes + size > INTERNED_BYTES_BUDGET {
+            if !std::mem::replace(&mut interned.budget_spent, true) {
+                watch::borrowed_props_budget_spent(INTERNED_BYTES_BUDGET);
+            }
+            return None;
+        }
+        let value: &'static T = Box::leak(Box::new(make()));
+        interned.bytes += size;
+        interned.values.entry(key).or_default().push(value);
+        Some(value)
+    })
+}
+
+/// Launch the storybook with several named [`Profile`]s.
+///
+/// The active profile is chosen with the `?profile=<slug>` URL parameter
+/// (see [`Profile::slug`]). Without it, a profile picker is shown at
+/// startup — unless there is a single profile — and the top bar offers a
+/// switcher to change profile.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// fn main() {
+///     storybook::launch_profiles(vec![
+///         storybook::Profile::new("Internal admin", admin_config()).with_tags(["Admin/**"]),
+///         storybook::Profile::new("Public widgets", widgets_config()).with_tags(["Widgets/**"]),
+///     ]);
+/// }
+/// ```
+pub fn launch_profiles(profiles: Vec<Profile>) {
+    PROFILES.with(|p| *p.borrow_mut() = profiles);
+    start();
+}
+
+fn start() {
     // On WASM targets the linker synthesises `__wasm_call_ctors` which runs
     // all static constructors (including those generated by `inventory::submit!`).
     // We must call it explicitly so that component and doc registrations are
@@ -196,23 +1777,80 @@ pub fn launch(config: StorybookConfig) {
         }
     }
 
-    // Store the config in static so the App component can access it
-    // We use a context provider inside App to make it available to child components
-    CONFIG.with(|c| *c.borrow_mut() = Some(config));
+    // Print the startup banner in the terminal the storybook was started
+    // from; on the web it is printed by the app (see `watch`)
+    #[cfg(not(target_family = "wasm"))]
+    watch::registry_summary(&summary::startup_summary());
+
     dioxus::launch(App);
 }
 
 // Thread-local storage for the config (set before launch, read by App)
 std::thread_local! {
     static CONFIG: std::cell::RefCell<Option<StorybookConfig>> = const { std::cell::RefCell::new(None) };
+    static PROFILES: std::cell::RefCell<Vec<Profile>> = const { std::cell::RefCell::new(Vec::new()) };
+    static INTERNED: std::cell::RefCell<Interned> = std::cell::RefCell::new(Interned::default());
 }
 
 /// Get the stored configuration (called by App during initialization).
 ///
 /// Uses `.clone()` instead of `.take()` so the value survives hot-reloads —
 /// when the `App` component re-runs the config is still available.
+///
+/// When profiles are registered, returns the active profile's config.
 pub(crate) fn take_config() -> StorybookConfig {
-    CONFIG.with(|c| c.borrow().clone()).unwrap_or_default()
+    if let Some(profile) = active_profile() {
+        let mut config = profile.config;
+        config.visible_tags = profile.tags;
+        return config.resolve_placeholders();
+    }
+    CONFIG
+        .with(|c| c.borrow().clone())
+        .unwrap_or_default()
+        .resolve_placeholders()
+}
+
+/// Replace each `${NAME}` of `text` with `value(NAME)`, leaving the
+/// placeholder untouched when it has no value.
+fn substitute_placeholders(text: &str, value: impl Fn(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find('}') {
+            Some(end) => {
+                let name = &after[..end];
+                match value(name) {
+                    Some(v) => out.push_str(&v),
+                    None => out.push_str(&rest[start..start + 3 + end]),
+                }
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// All profiles registered with [`launch_profiles`].
+pub(crate) fn get_profiles() -> Vec<Profile> {
+    PROFILES.with(|p| p.borrow().clone())
+}
+
+/// The profile selected by the `?profile=` URL parameter, or the only
+/// registered profile.
+pub(crate) fn active_profile() -> Option<Profile> {
+    let profiles = get_profiles();
+    match crate::ui::services::profile::requested_profile_slug() {
+        Some(slug) => profiles.into_iter().find(|p| p.slug() == slug),
+        None if profiles.len() == 1 => profiles.into_iter().next(),
+        None => None,
+    }
 }
 
 /// Type alias for a decorator function.
@@ -240,66 +1878,388 @@ pub(crate) fn take_config() -> StorybookConfig {
 /// ```
 pub type Decorator = fn(Element) -> Element;
 
-/// A single story configuration for a component.
-///
-/// Each story represents a specific state or configuration of the component
-/// that should be displayed in the storybook.
-#[derive(Clone)]
-pub struct Story<T> {
-    /// The title of the story (e.g., "Default", "Loading State", "Error State")
-    pub title: &'static str,
-    /// Optional description explaining this story
-    pub description: Option<&'static str>,
-    /// The props to render the component with
-    pub props: T,
-    /// Optional decorators to wrap the story rendering.
-    /// Decorators are applied in order, with the first decorator being the outermost wrapper.
-    pub decorators: Vec<Decorator>,
-}
+/// Type-erased function providing Dioxus contexts to a story, attached via
+/// [`Story::with_context`].
+///
+/// Compared by pointer identity so [`StoryInfo`] can stay `PartialEq`.
+#[derive(Clone)]
+pub struct ContextFn(std::rc::Rc<dyn Fn()>);
+
+impl ContextFn {
+    /// Wrap a function calling `provide_context`; its result is ignored.
+    pub fn new<T>(provide: impl Fn() -> T + 'static) -> Self {
+        Self(std::rc::Rc::new(move || {
+            provide();
+        }))
+    }
+
+    /// Provide the contexts to the current scope.
+    pub fn provide(&self) {
+        (self.0)()
+    }
+}
+
+impl PartialEq for ContextFn {
+    fn eq(&self, other: &Self) -> bool {
+        std::rc::Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A component function rendering custom top bar content, see
+/// [`StorybookConfig::with_header`].
+pub type HeaderFn = fn() -> Element;
+
+/// A function rewriting the HTML document of the preview iframes, see
+/// [`StorybookConfig::with_srcdoc_transform`].
+pub type SrcdocTransform = fn(String) -> String;
+
+/// A function rendering a custom doc directive from its argument, see
+/// [`StorybookConfig::with_doc_directive`].
+pub type DocDirectiveFn = fn(String) -> Element;
+
+/// A function rewriting the HTML of doc pages, see
+/// [`StorybookConfig::with_doc_transform`].
+pub type DocTransform = fn(String) -> String;
+
+/// A function rendering an overridden [`Slot`], see
+/// [`StorybookConfig::with_override`].
+pub type SlotFn = fn(SlotContext) -> Element;
+
+/// A component function rendering a top bar control, see
+/// [`StorybookConfig::with_toolbar_item`].
+pub type ToolbarItemFn = fn() -> Element;
+
+/// A single story configuration for a component.
+///
+/// Each story represents a specific state or configuration of the component
+/// that should be displayed in the storybook.
+#[derive(Clone)]
+pub struct Story<T> {
+    /// The title of the story (e.g., "Default", "Loading State", "Error State")
+    pub title: &'static str,
+    /// Optional description explaining this story
+    pub description: Option<&'static str>,
+    /// The props to render the component with
+    pub props: T,
+    /// Optional decorators to wrap the story rendering.
+    /// Decorators are applied in order, with the first decorator being the outermost wrapper.
+    pub decorators: Vec<Decorator>,
+    /// Functions providing the Dioxus contexts the component reads, see
+    /// [`with_context`](Self::with_context).
+    pub contexts: Vec<ContextFn>,
+    /// Optional play function run after the story renders.
+    pub play: Option<PlayFn>,
+    /// Loader of the props shown once they resolve, see
+    /// [`with_loader`](Self::with_loader) and
+    /// [`with_try_loader`](Self::with_try_loader).
+    pub loader: Option<PropsLoader<T>>,
+    /// Free-form labels (e.g. `"stable"`, `"wip"`) used to filter stories
+    /// in the sidebar.
+    pub tags: Vec<&'static str>,
+    /// Hidden from the sidebar, see [`skip`](Self::skip).
+    pub skipped: bool,
+    /// Stable id, see [`with_id`](Self::with_id).
+    pub id: Option<&'static str>,
+    /// Preferred preview viewport, see [`with_viewport`](Self::with_viewport).
+    pub viewport: Option<ViewportSize>,
+    /// Preferred light preview background, see
+    /// [`with_background`](Self::with_background).
+    pub background: Option<&'static str>,
+    /// Placement of the story in the preview, see
+    /// [`with_layout`](Self::with_layout).
+    pub layout: Layout,
+    /// How the story's previews are isolated, overriding the storybook's
+    /// default, see [`with_preview_isolation`](Self::with_preview_isolation).
+    pub preview_isolation: Option<PreviewIsolation>,
+    /// Section of the component's stories, see [`in_group`](Self::in_group).
+    pub group: Option<&'static str>,
+    /// Regions left out of snapshots, see [`with_mask`](Self::with_mask).
+    pub masks: Vec<&'static str>,
+}
+
+impl<T> Story<T> {
+    /// Create a new story with just a title and props
+    pub fn new(title: &'static str, props: T) -> Self {
+        Self {
+            title,
+            description: None,
+            props,
+            decorators: Vec::new(),
+            contexts: Vec::new(),
+            play: None,
+            loader: None,
+            tags: Vec::new(),
+            skipped: false,
+            id: None,
+            viewport: None,
+            background: None,
+            layout: Layout::Padded,
+            preview_isolation: None,
+            group: None,
+            masks: Vec::new(),
+        }
+    }
+
+    /// Create a new story with title, description, and props
+    pub fn with_description(title: &'static str, description: &'static str, props: T) -> Self {
+        Self {
+            title,
+            description: Some(description),
+            props,
+            decorators: Vec::new(),
+            contexts: Vec::new(),
+            play: None,
+            loader: None,
+            tags: Vec::new(),
+            skipped: false,
+            id: None,
+            viewport: None,
+            background: None,
+            layout: Layout::Padded,
+            preview_isolation: None,
+            group: None,
+            masks: Vec::new(),
+        }
+    }
+
+    /// Add a decorator to this story.
+    ///
+    /// Decorators wrap the story's rendered element. Multiple decorators
+    /// are applied in order, with the first decorator being the outermost wrapper.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("With Padding", MyProps::default())
+    ///     .with_decorator(|story| rsx! {
+    ///         div { style: "padding: 20px;", {story} }
+    ///     })
+    /// ```
+    pub fn with_decorator(mut self, decorator: Decorator) -> Self {
+        self.decorators.push(decorator);
+        self
+    }
+
+    /// Add multiple decorators to this story.
+    ///
+    /// Decorators are applied in order, with the first decorator being the outermost wrapper.
+    pub fn with_decorators(mut self, decorators: impl IntoIterator<Item = Decorator>) -> Self {
+        self.decorators.extend(decorators);
+        self
+    }
+
+    /// Provide Dioxus contexts to this story, e.g. a mock API client or auth
+    /// state read by the component with `use_context`.
+    ///
+    /// `provide` runs once in the scope rendering the story, before the
+    /// component mounts; the story's decorators can read the contexts too.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Signed in", ProfileMenuProps::default())
+    ///     .with_context(|| provide_context(MockAuth::signed_in("Ada")))
+    ///     .with_context(|| provide_context(MockApi::new()))
+    /// ```
+    pub fn with_context<R>(mut self, provide: impl Fn() -> R + 'static) -> Self {
+        self.contexts.push(ContextFn::new(provide));
+        self
+    }
+
+    /// Add tags to this story.
+    ///
+    /// Tags are free-form labels; the sidebar's tag filter can show only the
+    /// stories carrying a tag or hide them.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("New Layout", MyProps::default())
+    ///     .with_tags(["experimental", "wip"])
+    /// ```
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Self {
+        self.tags.extend(tags);
+        self
+    }
+
+    /// Attach a play function to this story.
+    ///
+    /// The play function interacts with the rendered story and asserts on it
+    /// through its [`PlayContext`], each step running when it is awaited; the
+    /// pass/fail status of each step is shown in the story view.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Saving", FormProps::default())
+    ///     .with_play(|ctx| async move {
+    ///         ctx.click("button").await;
+    ///         ctx.assert_text("Saved").await;
+    ///     })
+    /// ```
+    pub fn with_play<F, Fut>(mut self, play: F) -> Self
+    where
+        F: Fn(PlayContext) -> Fut + 'static,
+        Fut: std::future::Future<Output = ()> + 'static,
+    {
+        self.play = Some(PlayFn::new(play));
+        self
+    }
+
+    /// Load this story's props at runtime, e.g. from a sample API payload.
+    ///
+    /// The story shows a loading state until `loader` resolves, then renders
+    /// and edits the loaded props. The story's own props stand in for them
+    /// elsewhere, e.g. in thumbnails and auto-generated variants.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("From API", UserCardProps::default())
+    ///     .with_loader(|| async {
+    ///         let user = fetch_sample_user().await;
+    ///         UserCardProps { name: user.name, avatar: user.avatar_url }
+    ///     })
+    /// ```
+    pub fn with_loader<F, Fut>(mut self, loader: F) -> Self
+    where
+        T: 'static,
+        F: Fn() -> Fut + 'static,
+        Fut: std::future::Future<Output = T> + 'static,
+    {
+        self.loader = Some(PropsLoader::new(loader));
+        self
+    }
+
+    /// Load this story's props at runtime with a loader that can fail.
+    ///
+    /// Like [`with_loader`](Self::with_loader); when `loader` returns an
+    /// error, it's shown above the preview, which renders the story's own
+    /// props.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("From API", UserCardProps::default())
+    ///     .with_try_loader(|| async {
+    ///         let user = fetch_sample_user().await?;
+    ///         Ok::<_, FetchError>(UserCardProps { name: user.name, avatar: user.avatar_url })
+    ///     })
+    /// ```
+    pub fn with_try_loader<F, Fut, E>(mut self, loader: F) -> Self
+    where
+        T: 'static,
+        F: Fn() -> Fut + 'static,
+        Fut: std::future::Future<Output = Result<T, E>> + 'static,
+        E: std::fmt::Display,
+    {
+        self.loader = Some(PropsLoader::try_new(loader));
+        self
+    }
 
-impl<T> Story<T> {
-    /// Create a new story with just a title and props
-    pub fn new(title: &'static str, props: T) -> Self {
-        Self {
-            title,
-            description: None,
-            props,
-            decorators: Vec::new(),
-        }
+    /// Give this story a stable id, used in deep links (`?story=<id>`) and
+    /// snapshot baselines instead of the id derived from its title (see
+    /// [`story_id`]), so that renaming the story doesn't break them.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Primary (large)", MyProps::default()).with_id("button--primary")
+    /// ```
+    pub fn with_id(mut self, id: &'static str) -> Self {
+        self.id = Some(id);
+        self
     }
 
-    /// Create a new story with title, description, and props
-    pub fn with_description(title: &'static str, description: &'static str, props: T) -> Self {
-        Self {
-            title,
-            description: Some(description),
-            props,
-            decorators: Vec::new(),
-        }
+    /// Open this story in the `viewport` preset, e.g. for a mobile-only
+    /// component. The top bar can still switch to another viewport; leaving
+    /// the story restores the previous one.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Bottom Sheet", MyProps::default()).with_viewport(ViewportSize::SmallMobile)
+    /// ```
+    pub fn with_viewport(mut self, viewport: ViewportSize) -> Self {
+        self.viewport = Some(viewport);
+        self
     }
 
-    /// Add a decorator to this story.
+    /// Render this story on the `background` color (any CSS color) instead
+    /// of the default or category light background, e.g. for a component
+    /// meant for dark surfaces. The top bar's dark background toggle still
+    /// applies.
     ///
-    /// Decorators wrap the story's rendered element. Multiple decorators
-    /// are applied in order, with the first decorator being the outermost wrapper.
+    /// # Example
+    /// ```ignore
+    /// Story::new("On Dark", MyProps::default()).with_background("#000")
+    /// ```
+    pub fn with_background(mut self, background: &'static str) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Place this story in the preview according to `layout`: centered for
+    /// small components such as buttons, edge to edge for pages and app
+    /// shells, or with the default padding.
     ///
     /// # Example
     /// ```ignore
-    /// Story::new("With Padding", MyProps::default())
-    ///     .with_decorator(|story| rsx! {
-    ///         div { style: "padding: 20px;", {story} }
-    ///     })
+    /// Story::new("Dashboard", MyProps::default()).with_layout(Layout::Fullscreen)
     /// ```
-    pub fn with_decorator(mut self, decorator: Decorator) -> Self {
-        self.decorators.push(decorator);
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
         self
     }
 
-    /// Add multiple decorators to this story.
+    /// Isolate this story's previews with `isolation` rather than the
+    /// storybook's default ([`StorybookConfig::with_preview_isolation`]):
+    /// trivial stories can render inline, without the cost of an iframe,
+    /// while stories relying on media queries or global styles keep theirs.
     ///
-    /// Decorators are applied in order, with the first decorator being the outermost wrapper.
-    pub fn with_decorators(mut self, decorators: impl IntoIterator<Item = Decorator>) -> Self {
-        self.decorators.extend(decorators);
+    /// # Example
+    /// ```ignore
+    /// Story::new("Default", BadgeProps::default())
+    ///     .with_preview_isolation(PreviewIsolation::Inline)
+    /// ```
+    pub fn with_preview_isolation(mut self, isolation: PreviewIsolation) -> Self {
+        self.preview_isolation = Some(isolation);
+        self
+    }
+
+    /// Hide this story from the sidebar, e.g. while it is broken or being
+    /// reworked, without deleting it.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Legacy", MyProps::default()).skip()
+    /// ```
+    pub fn skip(mut self) -> Self {
+        self.skipped = true;
+        self
+    }
+
+    /// List this story under the `group` section of its component, e.g.
+    /// "Sizes" or "States". The sidebar and the component gallery show
+    /// each group as a collapsible section, after the ungrouped stories, in
+    /// the order the groups first appear.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Large", ButtonProps::default()).in_group("Sizes")
+    /// ```
+    pub fn in_group(mut self, group: &'static str) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Mask the elements matching the CSS `selector` in batch snapshots,
+    /// e.g. timestamps or random avatars. Their content and attributes
+    /// (except `class`, `style`, `width` and `height`) are left out, so they
+    /// neither show up in the bundle nor make the snapshot differ from its
+    /// baseline.
+    ///
+    /// Components and decorators can also mask an element by giving it the
+    /// `data-storybook-mask` attribute.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Story::new("Comment", CommentProps::default()).with_mask(".timestamp")
+    /// ```
+    pub fn with_mask(mut self, selector: &'static str) -> Self {
+        self.masks.push(selector);
         self
     }
 }
@@ -331,12 +2291,62 @@ impl<T> Story<T> {
 ///     }
 /// }
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no stories",
+    label = "registered with `#[storybook]` here",
+    note = "implement `storybook::Stories` for `{Self}` to list the stories of the component"
+)]
 pub trait Stories {
     fn stories() -> Vec<Story<Self>>
     where
         Self: Sized;
 }
 
+/// Implements [`Stories`] from a list of stories.
+///
+/// Each story is a title, an optional `desc` description and the fields of
+/// its props, optionally followed by [`Story`] builder calls. Several props
+/// types can be given in one invocation.
+///
+/// # Example
+/// ```rust,ignore
+/// storybook::stories! {
+///     MyButtonProps {
+///         "Default" => { label: "Click me".to_string(), disabled: false },
+///         "Disabled" desc "A disabled button that cannot be clicked" => {
+///             label: "Can't click".to_string(),
+///             disabled: true,
+///         }.with_tags(["state"]),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! stories {
+    ($(
+        $props:ty {
+            $(
+                $title:literal $(desc $description:literal)? => { $($fields:tt)* }
+                $(.$method:ident($($args:tt)*))*
+            ),* $(,)?
+        }
+    )*) => {$(
+        impl $crate::Stories for $props {
+            fn stories() -> ::std::vec::Vec<$crate::Story<Self>> {
+                ::std::vec![$(
+                    $crate::stories!(@story $title $($description)?, Self { $($fields)* })
+                        $(.$method($($args)*))*
+                ),*]
+            }
+        }
+    )*};
+    (@story $title:literal, $props:expr) => {
+        $crate::Story::new($title, $props)
+    };
+    (@story $title:literal $description:literal, $props:expr) => {
+        $crate::Story::with_description($title, $description, $props)
+    };
+}
+
 /// Newtype wrapper around a function pointer that renders a component from
 /// a JSON-encoded props string.
 ///
@@ -369,6 +2379,12 @@ pub type GetStoriesFn = fn() -> Vec<StoryInfo>;
 /// Generated automatically by the [`#[storybook]`](macro@storybook) macro.
 pub type GetPropSchemaFn = fn() -> Schema;
 
+/// Function pointer that checks a props JSON document against a component's
+/// props, returning the `serde_json` error when it doesn't match.
+///
+/// Generated automatically by the [`#[storybook]`](macro@storybook) macro.
+pub type CheckPropsFn = fn(&str) -> Result<(), serde_json::Error>;
+
 /// Runtime representation of a story with serialized (JSON) props.
 ///
 /// This is the type-erased counterpart of [`Story<T>`] — it is produced by
@@ -384,6 +2400,30 @@ pub struct StoryInfo {
     pub props_json: String,
     /// Decorators to wrap the story rendering
     pub decorators: Vec<Decorator>,
+    /// Functions providing Dioxus contexts to the story
+    pub contexts: Vec<ContextFn>,
+    /// Play function run after the story renders
+    pub play: Option<PlayFn>,
+    /// Loader of the props JSON shown once it resolves
+    pub loader: Option<PropsLoader<String>>,
+    /// Tags used to filter stories in the sidebar
+    pub tags: Vec<String>,
+    /// Whether the story is hidden from the sidebar
+    pub skipped: bool,
+    /// Stable id used in deep links and snapshot baselines
+    pub id: String,
+    /// Viewport selected when the story opens
+    pub viewport: Option<ViewportSize>,
+    /// Light preview background of the story
+    pub background: Option<String>,
+    /// Placement of the story in the preview
+    pub layout: Layout,
+    /// Isolation of the story's previews, when it overrides the default
+    pub preview_isolation: Option<PreviewIsolation>,
+    /// Section the story is listed under
+    pub group: Option<String>,
+    /// CSS selectors of the regions masked in snapshots
+    pub masks: Vec<String>,
 }
 
 impl std::fmt::Debug for StoryInfo {
@@ -396,6 +2436,18 @@ impl std::fmt::Debug for StoryInfo {
                 "decorators",
                 &format!("[{} decorators]", self.decorators.len()),
             )
+            .field("contexts", &format!("[{} contexts]", self.contexts.len()))
+            .field("play", &self.play.is_some())
+            .field("loader", &self.loader.is_some())
+            .field("tags", &self.tags)
+            .field("skipped", &self.skipped)
+            .field("id", &self.id)
+            .field("viewport", &self.viewport)
+            .field("background", &self.background)
+            .field("layout", &self.layout)
+            .field("preview_isolation", &self.preview_isolation)
+            .field("group", &self.group)
+            .field("masks", &self.masks)
             .finish()
     }
 }
@@ -405,6 +2457,18 @@ impl PartialEq for StoryInfo {
         self.title == other.title
             && self.description == other.description
             && self.props_json == other.props_json
+            && self.play == other.play
+            && self.loader == other.loader
+            && self.contexts == other.contexts
+            && self.tags == other.tags
+            && self.skipped == other.skipped
+            && self.id == other.id
+            && self.viewport == other.viewport
+            && self.background == other.background
+            && self.layout == other.layout
+            && self.preview_isolation == other.preview_isolation
+            && self.group == other.group
+            && self.masks == other.masks
             && self.decorators.len() == other.decorators.len()
             // Compare function pointers by address
             && self.decorators.iter().zip(other.decorators.iter())
@@ -416,11 +2480,58 @@ impl PartialEq for StoryInfo {
 #[derive(Clone, Debug, PartialEq)]
 struct SchemaFieldInfo {
     name: String,
+    /// Label from `#[storybook(rename = "…")]`, the schema's `title`.
+    label: Option<String>,
     type_name: String,
     /// The JSON Schema "type" string (e.g. "boolean", "string", "integer", "number", "null").
     schema_type: Option<String>,
     is_required: bool,
     description: Option<String>,
+    /// The schema's `default` value, rendered as JSON (e.g. `false`, `"info"`).
+    default_value: Option<String>,
+    /// The `#[props(default = …)]` expression, as written in the source.
+    default_expr: Option<String>,
+}
+
+impl SchemaFieldInfo {
+    /// The name shown in the props editor: the label if there is one.
+    fn display_name(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+
+    /// The default to document: the `#[props(default = …)]` expression if
+    /// there is one, otherwise the JSON default value.
+    fn default_display(&self) -> Option<&str> {
+        self.default_expr
+            .as_deref()
+            .or(self.default_value.as_deref())
+    }
+}
+
+/// Maturity of a component, set with `#[storybook(status = "...")]` and
+/// shown as a badge in the sidebar and on the story page.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentStatus {
+    Stable,
+    Beta,
+    Experimental,
+    /// Kept for compatibility, to be replaced or removed.
+    Deprecated,
+}
+
+impl ComponentStatus {
+    /// Lowercase name, as written in the attribute (e.g. `"beta"`).
+    pub fn name(self) -> &'static str {
+        match self {
+            ComponentStatus::Stable => "stable",
+            ComponentStatus::Beta => "beta",
+            ComponentStatus::Experimental => "experimental",
+            ComponentStatus::Deprecated => "deprecated",
+        }
+    }
 }
 
 /// Compile-time registration record for a storybook component.
@@ -431,8 +2542,20 @@ struct SchemaFieldInfo {
 pub struct ComponentRegistration {
     /// Component name (e.g. `"MyButton"`).
     pub name: &'static str,
-    /// Sidebar category / folder path (e.g. `"Forms/Inputs"`).
+    /// Sidebar category / folder paths (e.g. `["Forms/Inputs", "Deprecated"]`).
+    /// The component is listed under each of them.
+    pub tags: &'static [&'static str],
+    /// The first of [`tags`](Self::tags).
+    #[deprecated(note = "components can have several sidebar paths, use `tags`")]
     pub tag: &'static str,
+    /// Sort weight among the components of the same folder (lower first).
+    pub weight: i32,
+    /// Maturity badge, if the component sets one.
+    pub status: Option<ComponentStatus>,
+    /// When the component's crate sources last changed, in seconds since the
+    /// Unix epoch (the `STORYBOOK_SOURCE_DATE` or `SOURCE_DATE_EPOCH`
+    /// variable). Recorded at compile time.
+    pub source_updated: Option<u64>,
     /// Component description extracted from doc comments (HTML format)
     pub description: &'static str,
     /// Renders the component with props from JSON string
@@ -441,18 +2564,74 @@ pub struct ComponentRegistration {
     pub get_stories: GetStoriesFn,
     /// Gets the JSON schema for the props struct
     pub get_prop_schema: GetPropSchemaFn,
+    /// Checks a props JSON document against the props struct
+    pub check_props: CheckPropsFn,
 }
 
 impl std::fmt::Debug for ComponentRegistration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ComponentRegistration")
             .field("name", &self.name)
-            .field("tag", &self.tag)
+            .field("tags", &self.tags)
+            .field("weight", &self.weight)
+            .field("status", &self.status)
             .field("description", &self.description)
             .finish()
     }
 }
 
+impl ComponentRegistration {
+    /// The primary sidebar path, i.e. the first of [`tags`](Self::tags).
+    ///
+    /// Per-category configuration is resolved against this path.
+    pub fn primary_tag(&self) -> &'static str {
+        self.tags.first().copied().unwrap_or_default()
+    }
+
+    /// Look up a story of this component by title.
+    pub fn story(&self, title: &str) -> Result<StoryInfo, Error> {
+        (self.get_stories)()
+            .into_iter()
+            .find(|story| story.title == title)
+            .ok_or_else(|| Error::StoryNotFound {
+                component_name: self.name.to_string(),
+                story: title.to_string(),
+            })
+    }
+
+    /// The JSON Schema of the component's props.
+    ///
+    /// Fails with [`Error::SchemaInvalid`] if the schema doesn't describe an
+    /// object, which the props editor and the `@[props:…]` table rely on.
+    pub fn prop_schema(&self) -> Result<Schema, Error> {
+        let schema = (self.get_prop_schema)();
+        let invalid = |message: &str| Error::SchemaInvalid {
+            component_name: self.name.to_string(),
+            message: message.to_string(),
+        };
+        let object = schema
+            .as_object()
+            .ok_or_else(|| invalid("expected an object schema"))?;
+        match object.get("type").and_then(|t| t.as_str()) {
+            None | Some("object") => Ok(schema),
+            Some(other) => Err(invalid(&format!("expected type `object`, found `{other}`"))),
+        }
+    }
+
+    /// Check that `props_json` deserializes into the component's props.
+    pub fn check_props(&self, props_json: &str) -> Result<(), Error> {
+        (self.check_props)(props_json)
+            .map_err(|err| props_deserialize_error(self.name, props_json, &err))
+    }
+
+    /// Render the component with `props_json`, failing instead of falling
+    /// back to the default story props when the JSON doesn't match.
+    pub fn render(&self, props_json: &str) -> Result<Element, Error> {
+        self.check_props(props_json)?;
+        Ok((self.render_with_props.0)(props_json))
+    }
+}
+
 inventory::collect!(ComponentRegistration);
 
 /// Returns an iterator over every [`ComponentRegistration`] collected at
@@ -463,9 +2642,12 @@ pub fn get_components() -> impl Iterator<Item = &'static ComponentRegistration>
 
 /// Look up a [`ComponentRegistration`] by its component name.
 ///
-/// Returns `None` if no component with the given name has been registered.
-pub fn find_component(name: &str) -> Option<&'static ComponentRegistration> {
-    inventory::iter::<ComponentRegistration>().find(|c| c.name == name)
+/// Fails with [`Error::ComponentNotFound`] if no component with the given
+/// name has been registered.
+pub fn find_component(name: &str) -> Result<&'static ComponentRegistration, Error> {
+    inventory::iter::<ComponentRegistration>()
+        .find(|c| c.name == name)
+        .ok_or_else(|| Error::ComponentNotFound(name.to_string()))
 }
 
 /// Compile-time registration record for a documentation page.
@@ -480,6 +2662,18 @@ pub struct DocRegistration {
     pub path: &'static str,
     /// The HTML content of the documentation (converted from markdown)
     pub content_html: &'static str,
+    /// The markdown file, relative to the registering crate's manifest directory
+    pub source_path: &'static str,
+    /// The markdown file bundled as an asset, so it can be re-fetched and
+    /// re-rendered at runtime. Only set when the `hot-docs` feature is enabled.
+    pub source_asset: Option<Asset>,
+    /// When the markdown file last changed, in seconds since the Unix epoch
+    /// (its modification time, or `SOURCE_DATE_EPOCH` when set). Recorded
+    /// at compile time.
+    pub last_updated: Option<u64>,
+    /// Files referenced by the page (images, downloads…) that were missing
+    /// at compile time, see [`RegistrySummary::broken_links`].
+    pub missing_assets: &'static [&'static str],
 }
 
 inventory::collect!(DocRegistration);
@@ -492,10 +2686,67 @@ pub fn get_docs() -> impl Iterator<Item = &'static DocRegistration> {
 
 /// Look up a [`DocRegistration`] by its tree path.
 ///
-/// Returns `None` if no documentation page with the given path has been
-/// registered.
-pub fn find_doc(path: &str) -> Option<&'static DocRegistration> {
-    inventory::iter::<DocRegistration>().find(|d| d.path == path)
+/// Fails with [`Error::DocNotFound`] if no documentation page with the given
+/// path has been registered.
+pub fn find_doc(path: &str) -> Result<&'static DocRegistration, Error> {
+    inventory::iter::<DocRegistration>()
+        .find(|d| d.path == path)
+        .ok_or_else(|| Error::DocNotFound(path.to_string()))
+}
+
+/// The component a doc page belongs to, if any: the component of a
+/// `__component__/{name}` autodocs page, or the component whose sidebar path
+/// followed by its name is the page's path (e.g. a [`storydoc!`] page at
+/// `"Examples/Buttons/ExampleButton"`), which is shown as a tab of the
+/// component's docs rather than as a folder of its own.
+pub fn doc_component(path: &str) -> Option<&'static ComponentRegistration> {
+    if let Some(name) = path.strip_prefix("__component__/") {
+        return find_component(name).ok();
+    }
+    let (folder, name) = path.rsplit_once('/').unwrap_or(("", path));
+    inventory::iter::<ComponentRegistration>().find(|component| {
+        component.name == name
+            && if folder.is_empty() {
+                component.tags.is_empty()
+            } else {
+                component.tags.contains(&folder)
+            }
+    })
+}
+
+/// The [`storydoc!`] page attached to a component, see [`doc_component`].
+pub fn find_component_doc(component: &ComponentRegistration) -> Option<&'static DocRegistration> {
+    get_docs().find(|doc| doc_component(doc.path).is_some_and(|owner| owner.name == component.name))
+}
+
+/// Sidebar path of the page listing the terms of [`storyglossary!`].
+pub const GLOSSARY_PATH: &str = "Glossary";
+
+/// A term of the design language, registered with [`storyglossary!`].
+#[derive(Debug)]
+pub struct GlossaryTerm {
+    pub term: &'static str,
+    /// The definition, as inline HTML.
+    pub definition_html: &'static str,
+}
+
+/// Compile-time registration record for a glossary file.
+///
+/// Created by the [`storyglossary!`] macro, together with a
+/// [`DocRegistration`] at [`GLOSSARY_PATH`] listing the terms.
+#[derive(Debug)]
+pub struct GlossaryRegistration {
+    /// The markdown file, relative to the registering crate's manifest directory
+    pub source_path: &'static str,
+    pub terms: &'static [GlossaryTerm],
+}
+
+inventory::collect!(GlossaryRegistration);
+
+/// Returns an iterator over every glossary term registered with
+/// [`storyglossary!`].
+pub fn get_glossary_terms() -> impl Iterator<Item = &'static GlossaryTerm> {
+    inventory::iter::<GlossaryRegistration>().flat_map(|glossary| glossary.terms.iter())
 }
 
 /// Extract field information from a JSON Schema
@@ -523,7 +2774,11 @@ fn extract_fields_from_schema(schema: &Schema) -> Vec<SchemaFieldInfo> {
     // Get properties from the schema
     if let Some(properties) = schema.get("properties").and_then(|v| v.as_object()) {
         for (name, prop_value) in properties {
-            let (type_name, schema_type, description) =
+            let label = prop_value
+                .get("title")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+            let (type_name, schema_type, description, default_value, default_expr) =
                 if let Some(prop_obj) = prop_value.as_object() {
                     let schema_type = get_schema_type(prop_obj);
                     let type_name = get_type_name_from_value(prop_obj, &defs);
@@ -531,18 +2786,26 @@ fn extract_fields_from_schema(schema: &Schema) -> Vec<SchemaFieldInfo> {
                         .get("description")
                         .and_then(|v| v.as_str())
                         .map(String::from);
-                    (type_name, schema_type, desc)
+                    let default_value = prop_obj.get("default").map(|v| v.to_string());
+                    let default_expr = prop_obj
+                        .get("x-default-expr")
+                        .and_then(|v| v.as_str())
+                        .map(String::from);
+                    (type_name, schema_type, desc, default_value, default_expr)
                 } else {
                     // Bool schema (true/false)
-                    ("any".to_string(), None, None)
+                    ("any".to_string(), None, None, None, None)
                 };
 
             fields.push(SchemaFieldInfo {
                 name: name.clone(),
+                label,
                 type_name,
                 schema_type,
                 is_required: required.contains(name),
                 description,
+                default_value,
+                default_expr,
             });
         }
     }
@@ -632,6 +2895,53 @@ fn update_prop_value(props_json: &mut Signal<String>, field_name: &str, value: s
     }
 }
 
+/// Validate a props JSON document against the schema fields, returning one
+/// message per problem (syntax error, unknown or missing field, wrong type).
+fn validate_props_json(json: &str, fields: &[SchemaFieldInfo]) -> Vec<String> {
+    let value = match serde_json::from_str::<serde_json::Value>(json) {
+        Ok(value) => value,
+        Err(e) => return vec![format!("Invalid JSON: {e}")],
+    };
+    let Some(obj) = value.as_object() else {
+        return vec!["Props must be a JSON object".to_string()];
+    };
+
+    let mut errors = Vec::new();
+    for name in obj.keys() {
+        if !fields.iter().any(|f| &f.name == name) {
+            errors.push(format!("Unknown field `{name}`"));
+        }
+    }
+    for field in fields {
+        let Some(value) = obj.get(&field.name) else {
+            if field.is_required {
+                errors.push(format!("Missing field `{}`", field.name));
+            }
+            continue;
+        };
+        let matches = match field.schema_type.as_deref() {
+            _ if value.is_null() => {
+                !field.is_required || field.schema_type.as_deref() == Some("null")
+            }
+            Some("boolean") => value.is_boolean(),
+            Some("integer") => value.is_i64() || value.is_u64(),
+            Some("number") => value.is_number(),
+            Some("string") => value.is_string(),
+            Some("array") => value.is_array(),
+            Some("object") => value.is_object(),
+            Some("null") => false,
+            _ => true,
+        };
+        if !matches {
+            errors.push(format!(
+                "Field `{}` expects {}, found {value}",
+                field.name, field.type_name
+            ));
+        }
+    }
+    errors
+}
+
 /// Parse an input string value into the appropriate JSON value based on schema type.
 ///
 /// The `schema_type` is a JSON Schema type string such as `"boolean"`,
@@ -659,3 +2969,369 @@ fn parse_input_value(value: &str, schema_type: Option<&str>) -> serde_json::Valu
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, serde::Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[allow(dead_code)]
+    struct Props {
+        label: String,
+        count: u32,
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(default, rename = "say \"hi\"")]
+        greeting: Option<String>,
+    }
+
+    fn error_field(props_json: &str) -> Option<String> {
+        let err = serde_json::from_str::<Props>(props_json).expect_err("invalid props");
+        props_error_field(props_json, &err)
+    }
+
+    #[test]
+    fn props_error_field_names_missing_fields() {
+        assert_eq!(
+            error_field(r#"{"label": "Save"}"#).as_deref(),
+            Some("count")
+        );
+    }
+
+    #[test]
+    fn props_error_field_names_unknown_fields() {
+        assert_eq!(
+            error_field(r#"{"label": "Save", "count": 1, "size": "big"}"#).as_deref(),
+            Some("size")
+        );
+    }
+
+    #[test]
+    fn props_error_field_finds_invalid_values() {
+        assert_eq!(
+            error_field(r#"{"label": "Save", "count": "one"}"#).as_deref(),
+            Some("count")
+        );
+        // Errors nested in a value are reported on its top-level key
+        assert_eq!(
+            error_field(r#"{"label": "Save", "count": 1, "tags": ["a", 2]}"#).as_deref(),
+            Some("tags")
+        );
+    }
+
+    #[test]
+    fn props_error_field_handles_pretty_json() {
+        let props_json = "{\n  \"label\": \"Save\",\n  \"count\": -1,\n  \"tags\": []\n}";
+        assert_eq!(error_field(props_json).as_deref(), Some("count"));
+    }
+
+    #[test]
+    fn props_error_field_handles_escaped_quotes() {
+        let props_json = r#"{"label": "a \"quoted\" label", "say \"hi\"": 3, "count": 1}"#;
+        assert_eq!(error_field(props_json).as_deref(), Some("say \"hi\""));
+    }
+
+    #[test]
+    fn props_error_field_is_none_outside_any_key() {
+        assert_eq!(error_field("").as_deref(), None);
+    }
+
+    #[test]
+    fn with_font_escapes_the_url() {
+        let config = StorybookConfig::default()
+            .with_font("https://fonts.example.com/css?family=Inter&display=\"swap\"");
+        assert_eq!(
+            config.head_html,
+            [
+                r#"<link rel="stylesheet" href="https://fonts.example.com/css?family=Inter&amp;display=&quot;swap&quot;">"#
+            ]
+        );
+    }
+
+    #[test]
+    fn static_str_reuses_equal_values() {
+        let first = static_str("Click me").unwrap();
+        let second = static_str(String::from("Click me").as_str()).unwrap();
+        assert!(std::ptr::eq(first, second));
+        assert_ne!(static_str("Click"), Some(first));
+    }
+
+    #[test]
+    fn static_slice_reuses_equal_values() {
+        let first = static_slice(&[1, 2, 3]).unwrap();
+        let second = static_slice(Vec::from([1, 2, 3]).as_slice()).unwrap();
+        assert!(std::ptr::eq(first, second));
+        assert_eq!(static_slice(&[3, 2, 1]), Some(&[3, 2, 1][..]));
+    }
+
+    #[test]
+    fn static_str_refuses_values_over_the_budget() {
+        assert_eq!(static_str(&"a".repeat(INTERNED_BYTES_BUDGET + 1)), None);
+        assert_eq!(static_str("small"), Some("small"));
+    }
+
+    #[test]
+    fn slugify_joins_lowercase_words_with_dashes() {
+        assert_eq!(slugify("With Icon"), "with-icon");
+        assert_eq!(slugify("  Save & Close!  "), "save-close");
+        assert_eq!(slugify("Größe 2"), "gr-e-2");
+        assert_eq!(slugify("!!"), "");
+    }
+
+    #[test]
+    fn story_id_joins_component_and_title_slugs() {
+        assert_eq!(story_id("Button", "With Icon"), "button--with-icon");
+        assert_eq!(story_id("DataTable", "Empty"), "datatable--empty");
+    }
+
+    #[test]
+    fn migrate_story_id_follows_chained_migrations() {
+        let config = StorybookConfig::default().with_story_migrations([
+            ("button--default", "button--primary"),
+            ("button--primary", "button--filled"),
+        ]);
+        assert_eq!(config.migrate_story_id("button--default"), "button--filled");
+        assert_eq!(
+            config.migrate_story_id("button--outline"),
+            "button--outline"
+        );
+    }
+
+    #[test]
+    fn migrate_story_id_stops_on_cycles() {
+        let config =
+            StorybookConfig::default().with_story_migrations([("a--x", "a--y"), ("a--y", "a--x")]);
+        assert_eq!(config.migrate_story_id("a--x"), "a--x");
+    }
+
+    fn placeholder(name: &str) -> Option<String> {
+        match name {
+            "API_URL" => Some("https://api.example.com".to_string()),
+            "ENV" => Some("staging".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn substitute_placeholders_replaces_known_names() {
+        assert_eq!(
+            substitute_placeholders("${API_URL}/users", placeholder),
+            "https://api.example.com/users"
+        );
+    }
+
+    #[test]
+    fn substitute_placeholders_keeps_unknown_names() {
+        assert_eq!(
+            substitute_placeholders("${TOKEN} on ${ENV}", placeholder),
+            "${TOKEN} on staging"
+        );
+    }
+
+    #[test]
+    fn substitute_placeholders_keeps_unclosed_placeholders() {
+        assert_eq!(
+            substitute_placeholders("${ENV} ${API_URL", placeholder),
+            "staging ${API_URL"
+        );
+    }
+
+    #[test]
+    fn substitute_placeholders_handles_adjacent_placeholders() {
+        assert_eq!(
+            substitute_placeholders("${ENV}${ENV}-${TOKEN}${ENV}", placeholder),
+            "stagingstaging-${TOKEN}staging"
+        );
+    }
+
+    fn field(name: &str, schema_type: &str, is_required: bool) -> SchemaFieldInfo {
+        SchemaFieldInfo {
+            name: name.to_string(),
+            label: None,
+            type_name: schema_type.to_string(),
+            schema_type: Some(schema_type.to_string()),
+            is_required,
+            description: None,
+            default_value: None,
+            default_expr: None,
+        }
+    }
+
+    fn button_fields() -> Vec<SchemaFieldInfo> {
+        vec![
+            field("label", "string", true),
+            field("disabled", "boolean", false),
+        ]
+    }
+
+    #[test]
+    fn validate_props_json_accepts_matching_props() {
+        let errors =
+            validate_props_json(r#"{"label": "Save", "disabled": true}"#, &button_fields());
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(validate_props_json(r#"{"label": "Save"}"#, &button_fields()).is_empty());
+    }
+
+    #[test]
+    fn validate_props_json_reports_missing_required_fields() {
+        assert_eq!(
+            validate_props_json(r#"{"disabled": false}"#, &button_fields()),
+            ["Missing field `label`"]
+        );
+    }
+
+    #[test]
+    fn validate_props_json_reports_wrong_types() {
+        assert_eq!(
+            validate_props_json(r#"{"label": 3, "disabled": "no"}"#, &button_fields()),
+            [
+                "Field `label` expects string, found 3",
+                "Field `disabled` expects boolean, found \"no\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_props_json_reports_unknown_keys() {
+        assert_eq!(
+            validate_props_json(r#"{"label": "Save", "size": "big"}"#, &button_fields()),
+            ["Unknown field `size`"]
+        );
+    }
+
+    #[test]
+    fn validate_props_json_rejects_non_objects() {
+        assert_eq!(
+            validate_props_json(r#"["Save"]"#, &button_fields()),
+            ["Props must be a JSON object"]
+        );
+        assert!(validate_props_json("{", &button_fields())[0].starts_with("Invalid JSON"));
+    }
+
+    fn base_story() -> StoryInfo {
+        StoryInfo {
+            title: "Default".to_string(),
+            description: None,
+            props_json: r#"{"label": "Save", "size": "small", "disabled": false}"#.to_string(),
+            decorators: Vec::new(),
+            contexts: Vec::new(),
+            play: None,
+            loader: None,
+            tags: Vec::new(),
+            skipped: false,
+            id: "button--default".to_string(),
+            viewport: None,
+            background: None,
+            layout: Layout::default(),
+            preview_isolation: None,
+            group: None,
+            masks: Vec::new(),
+        }
+    }
+
+    fn variant_titles(schema: &Schema, props: &[&str]) -> Vec<String> {
+        let mut stories = vec![base_story()];
+        push_auto_variants(&mut stories, "Button", schema, props);
+        assert!(
+            stories[1..]
+                .iter()
+                .all(|story| story.group.as_deref() == Some(AUTO_VARIANTS_GROUP))
+        );
+        stories
+            .into_iter()
+            .skip(1)
+            .map(|story| story.title)
+            .collect()
+    }
+
+    fn button_schema() -> Schema {
+        schemars::json_schema!({
+            "type": "object",
+            "properties": {
+                "label": { "type": "string" },
+                "size": { "$ref": "#/$defs/Size" },
+                "disabled": { "type": "boolean" }
+            },
+            "$defs": {
+                "Size": { "type": "string", "enum": ["small", "large"] }
+            }
+        })
+    }
+
+    #[test]
+    fn auto_variants_enumerate_enum_variants() {
+        assert_eq!(
+            variant_titles(&button_schema(), &["size"]),
+            ["size: small", "size: large"]
+        );
+    }
+
+    #[test]
+    fn auto_variants_enumerate_booleans() {
+        let mut stories = vec![base_story()];
+        push_auto_variants(&mut stories, "Button", &button_schema(), &["disabled"]);
+        let titles: Vec<&str> = stories[1..].iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["disabled: true", "disabled: false"]);
+        let props: serde_json::Value = serde_json::from_str(&stories[1].props_json).unwrap();
+        assert_eq!(props["disabled"], true);
+        assert_eq!(props["label"], "Save");
+    }
+
+    #[test]
+    fn auto_variants_combine_props_and_skip_others() {
+        assert_eq!(
+            variant_titles(&button_schema(), &["size", "label", "disabled"]),
+            [
+                "size: small, disabled: true",
+                "size: small, disabled: false",
+                "size: large, disabled: true",
+                "size: large, disabled: false",
+            ]
+        );
+    }
+
+    #[test]
+    fn auto_variants_stop_at_the_limit() {
+        let props: Vec<String> = (0..7).map(|i| format!("flag{i}")).collect();
+        let properties: serde_json::Map<String, serde_json::Value> = props
+            .iter()
+            .map(|prop| (prop.clone(), serde_json::json!({ "type": "boolean" })))
+            .collect();
+        let schema = schemars::json_schema!({ "type": "object", "properties": properties });
+        let props: Vec<&str> = props.iter().map(String::as_str).collect();
+        // 2^7 = 128 combinations
+        let titles = variant_titles(&schema, &props);
+        assert_eq!(titles.len(), MAX_AUTO_VARIANTS);
+        assert!(titles[0].starts_with("flag0: true, flag1: true"));
+    }
+
+    #[test]
+    fn category_matches_nested_categories() {
+        assert!(category_matches("Forms/Inputs", "Forms/Inputs"));
+        assert!(!category_matches("Forms", "Forms/Inputs"));
+        assert!(category_matches("Forms/**", "Forms"));
+        assert!(category_matches("Forms/**", "Forms/Inputs/Text"));
+        assert!(!category_matches("Forms/**", "Formsets"));
+        assert!(category_matches("*/Inputs", "Forms/Inputs"));
+        assert!(!category_matches("*/Inputs", "Forms/Inputs/Text"));
+        assert!(category_matches("**", "Forms/Inputs/Text"));
+    }
+
+    #[test]
+    fn category_matches_is_case_sensitive() {
+        assert!(!category_matches("forms/**", "Forms/Inputs"));
+        assert!(!category_matches("Forms/inputs", "Forms/Inputs"));
+    }
+
+    #[test]
+    fn category_matches_empty_pattern_only_matches_the_root() {
+        assert!(category_matches("", ""));
+        assert!(!category_matches("", "Forms"));
+        // No include filter shows every category
+        let config = StorybookConfig::default().exclude_tags(["Forms/Experimental/**"]);
+        assert!(config.shows_tag("Forms/Inputs"));
+        assert!(config.shows_tag(""));
+        assert!(!config.shows_tag("Forms/Experimental/Slider"));
+    }
+}
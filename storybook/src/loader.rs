@@ -0,0 +1,108 @@
+//! Props loaders: async functions providing a story's props at runtime.
+//!
+//! A story with a loader is shown with a loading state until its props
+//! resolve, e.g. from a sample API payload, and is then rendered and edited
+//! like any other story. When the loader fails, its error is shown above the
+//! preview, which renders the story's own props.
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+type LoaderFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+
+/// Type-erased async function returning props, or why they couldn't be
+/// loaded, attached to a story via
+/// [`Story::with_loader`](crate::Story::with_loader) or
+/// [`Story::with_try_loader`](crate::Story::with_try_loader).
+///
+/// [`StoryInfo`](crate::StoryInfo) holds the loader of the props JSON.
+/// Compared by pointer identity so `StoryInfo` can stay `PartialEq`.
+pub struct PropsLoader<T>(Rc<dyn Fn() -> LoaderFuture<Result<T, String>>>);
+
+impl<T: 'static> PropsLoader<T> {
+    /// Wrap an async function as a props loader.
+    pub fn new<F, Fut>(load: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = T> + 'static,
+    {
+        Self(Rc::new(move || {
+            let loaded = load();
+            Box::pin(async move { Ok(loaded.await) })
+        }))
+    }
+
+    /// Wrap an async function that can fail as a props loader.
+    pub fn try_new<F, Fut, E>(load: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+        E: Display,
+    {
+        Self(Rc::new(move || {
+            let loaded = load();
+            Box::pin(async move { loaded.await.map_err(|err| err.to_string()) })
+        }))
+    }
+
+    /// Run the loader.
+    pub async fn load(&self) -> Result<T, String> {
+        (self.0)().await
+    }
+
+    /// A loader converting the props of this one with `f`.
+    pub fn map<U: 'static>(self, f: impl Fn(T) -> U + 'static) -> PropsLoader<U> {
+        let f = Rc::new(f);
+        PropsLoader(Rc::new(move || {
+            let loaded = (self.0)();
+            let f = f.clone();
+            Box::pin(async move { loaded.await.map(&*f) })
+        }))
+    }
+}
+
+impl<T> Clone for PropsLoader<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for PropsLoader<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn maps_loaded_props() {
+        let loader = PropsLoader::new(|| async { 2 }).map(|n| n.to_string());
+        assert_eq!(block_on(loader.load()), Ok("2".to_string()));
+    }
+
+    #[test]
+    fn keeps_loader_errors_through_map() {
+        let loader = PropsLoader::try_new(|| async { "12a".parse::<u32>() }).map(|n| n * 2);
+        assert_eq!(
+            block_on(loader.load()),
+            Err("invalid digit found in string".to_string())
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Naming conventions for story titles, checked by the registry health
+//! summary.
+//!
+//! Rules are set with
+//! [`StorybookConfig::with_naming_rules`](crate::StorybookConfig::with_naming_rules),
+//! reported in the startup banner, and checked in CI with
+//! [`RegistrySummary::with_naming_rules`](crate::RegistrySummary::with_naming_rules).
+
+use crate::{AUTO_VARIANTS_GROUP, get_components};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Words left lowercase inside [`TitleCase::Title`] titles.
+const MINOR_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "by", "for", "in", "of", "on", "or", "the", "to", "with",
+];
+
+/// Naming rules for story titles, keeping large catalogs consistent.
+///
+/// Titles must be unique within their component; the other rules are
+/// opt-in.
+///
+/// # Example
+/// ```rust,ignore
+/// NamingRules::default()
+///     .with_first_story("Default")
+///     .with_title_case(TitleCase::Title)
+///     .with_max_title_length(40)
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamingRules {
+    /// Whether two stories of a component may share a title.
+    pub unique_titles: bool,
+    /// Title the first story of every component must have.
+    pub first_story: Option<&'static str>,
+    /// Capitalization of the titles.
+    pub title_case: Option<TitleCase>,
+    /// Longest title allowed, in characters.
+    pub max_title_length: Option<usize>,
+}
+
+impl Default for NamingRules {
+    fn default() -> Self {
+        Self {
+            unique_titles: true,
+            first_story: None,
+            title_case: None,
+            max_title_length: None,
+        }
+    }
+}
+
+impl NamingRules {
+    /// Require the first story of every component to be titled `title`,
+    /// e.g. "Default".
+    pub fn with_first_story(mut self, title: &'static str) -> Self {
+        self.first_story = Some(title);
+        self
+    }
+
+    /// Require titles to follow `case`.
+    pub fn with_title_case(mut self, case: TitleCase) -> Self {
+        self.title_case = Some(case);
+        self
+    }
+
+    /// Reject titles longer than `length` characters.
+    pub fn with_max_title_length(mut self, length: usize) -> Self {
+        self.max_title_length = Some(length);
+        self
+    }
+
+    /// Allow several stories of a component to share a title.
+    pub fn allow_duplicate_titles(mut self) -> Self {
+        self.unique_titles = false;
+        self
+    }
+}
+
+/// Capitalization of story titles, see [`NamingRules::with_title_case`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TitleCase {
+    /// Every word starts with a capital, except short words such as "of" or
+    /// "with" after the first one: "Primary With Icon", "Size of Text".
+    Title,
+    /// The first word starts with a capital: "Primary with icon".
+    Sentence,
+}
+
+impl TitleCase {
+    /// Whether `title` follows this case. Words not starting with a letter
+    /// (numbers, symbols) are ignored.
+    fn matches(self, title: &str) -> bool {
+        let starts_lowercase = |word: &str| word.chars().next().is_some_and(char::is_lowercase);
+        let mut words = title.split_whitespace();
+        if words.next().is_some_and(starts_lowercase) {
+            return false;
+        }
+        match self {
+            TitleCase::Title => {
+                words.all(|word| !starts_lowercase(word) || MINOR_WORDS.contains(&word))
+            }
+            TitleCase::Sentence => true,
+        }
+    }
+}
+
+/// A story title breaking a [`NamingRules`] rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamingViolation {
+    pub component: String,
+    pub story: String,
+    /// The rule broken, e.g. "duplicate title".
+    pub message: String,
+}
+
+impl fmt::Display for NamingViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} / {}: {}", self.component, self.story, self.message)
+    }
+}
+
+/// Check the story titles of every registered component against `rules`.
+///
+/// Stories generated with `#[storybook(auto_variants = [...])]` are left
+/// out of the casing and length checks.
+pub fn lint_story_names(rules: &NamingRules) -> Vec<NamingViolation> {
+    let mut violations = Vec::new();
+    for component in get_components() {
+        let stories = (component.get_stories)();
+        let mut violation = |story: &str, message: String| {
+            violations.push(NamingViolation {
+                component: component.name.to_string(),
+                story: story.to_string(),
+                message,
+            });
+        };
+
+        if let Some(expected) = rules.first_story
+            && let Some(first) = stories.first()
+            && first.title != expected
+        {
+            violation(
+                &first.title,
+                format!("the first story must be \"{expected}\""),
+            );
+        }
+
+        if rules.unique_titles {
+            let mut titles = BTreeMap::<&str, usize>::new();
+            for story in &stories {
+                *titles.entry(&story.title).or_default() += 1;
+            }
+            for (title, _) in titles.into_iter().filter(|(_, count)| *count > 1) {
+                violation(title, "duplicate title".to_string());
+            }
+        }
+
+        for story in stories
+            .iter()
+            .filter(|story| story.group.as_deref() != Some(AUTO_VARIANTS_GROUP))
+        {
+            if let Some(case) = rules.title_case
+                && !case.matches(&story.title)
+            {
+                violation(&story.title, format!("not in {case:?} case"));
+            }
+            if let Some(max) = rules.max_title_length
+                && story.title.chars().count() > max
+            {
+                violation(&story.title, format!("longer than {max} characters"));
+            }
+        }
+    }
+    violations
+}
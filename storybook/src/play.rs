@@ -0,0 +1,131 @@
+//! Play functions: scripted interactions that run after a story renders.
+//!
+//! A play function receives a [`PlayContext`] and awaits its steps (clicks,
+//! typing, assertions) one after the other. Each step runs against the story
+//! mounted in the preview when it is awaited, and the storybook UI reports it
+//! as passed, failed or skipped in the story's "Interactions" panel.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// A single interaction or assertion of a play function.
+///
+/// Selectors are CSS selectors resolved inside the story's root element.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayStep {
+    /// Click the first element matching `selector`.
+    Click { selector: String },
+    /// Set the value of the input matching `selector` and fire an `input` event.
+    Type { selector: String, text: String },
+    /// Assert that the story's text content contains `text`.
+    AssertText { text: String },
+    /// Assert that an element matching `selector` exists.
+    AssertExists { selector: String },
+    /// Wait `ms` milliseconds before running the next step.
+    Wait { ms: u32 },
+}
+
+impl PlayStep {
+    /// Human-readable description shown in the interactions panel.
+    pub fn label(&self) -> String {
+        match self {
+            PlayStep::Click { selector } => format!("click {selector}"),
+            PlayStep::Type { selector, text } => format!("type \"{text}\" into {selector}"),
+            PlayStep::AssertText { text } => format!("expect text \"{text}\""),
+            PlayStep::AssertExists { selector } => format!("expect element {selector}"),
+            PlayStep::Wait { ms } => format!("wait {ms}ms"),
+        }
+    }
+}
+
+/// Handle passed to a play function to interact with the story.
+///
+/// Each method runs a [`PlayStep`] against the rendered story and resolves
+/// once it is done. After a step fails, the following ones are skipped.
+#[derive(Clone)]
+pub struct PlayContext {
+    run_step: Rc<dyn Fn(PlayStep) -> PlayFuture>,
+}
+
+impl PlayContext {
+    /// A context running each step with `run_step`.
+    pub(crate) fn new<F, Fut>(run_step: F) -> Self
+    where
+        F: Fn(PlayStep) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self {
+            run_step: Rc::new(move |step| Box::pin(run_step(step))),
+        }
+    }
+
+    /// Click the first element matching `selector`.
+    pub async fn click(&self, selector: impl Into<String>) {
+        self.run(PlayStep::Click {
+            selector: selector.into(),
+        })
+        .await;
+    }
+
+    /// Type `text` into the input matching `selector`.
+    pub async fn type_text(&self, selector: impl Into<String>, text: impl Into<String>) {
+        self.run(PlayStep::Type {
+            selector: selector.into(),
+            text: text.into(),
+        })
+        .await;
+    }
+
+    /// Assert that the rendered story contains `text`.
+    pub async fn assert_text(&self, text: impl Into<String>) {
+        self.run(PlayStep::AssertText { text: text.into() }).await;
+    }
+
+    /// Assert that an element matching `selector` exists.
+    pub async fn assert_exists(&self, selector: impl Into<String>) {
+        self.run(PlayStep::AssertExists {
+            selector: selector.into(),
+        })
+        .await;
+    }
+
+    /// Wait `ms` milliseconds, e.g. for an animation or a timer to complete.
+    pub async fn wait(&self, ms: u32) {
+        self.run(PlayStep::Wait { ms }).await;
+    }
+
+    async fn run(&self, step: PlayStep) {
+        (self.run_step)(step).await;
+    }
+}
+
+type PlayFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Type-erased play function attached to a story via [`Story::with_play`](crate::Story::with_play).
+///
+/// Compared by pointer identity so [`StoryInfo`](crate::StoryInfo) can stay `PartialEq`.
+#[derive(Clone)]
+pub struct PlayFn(Rc<dyn Fn(PlayContext) -> PlayFuture>);
+
+impl PlayFn {
+    /// Wrap an async closure as a play function.
+    pub fn new<F, Fut>(play: F) -> Self
+    where
+        F: Fn(PlayContext) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self(Rc::new(move |ctx| Box::pin(play(ctx))))
+    }
+
+    /// Run the play function, its steps going through `ctx`.
+    pub(crate) async fn run(&self, ctx: PlayContext) {
+        (self.0)(ctx).await;
+    }
+}
+
+impl PartialEq for PlayFn {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
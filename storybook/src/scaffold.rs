@@ -0,0 +1,264 @@
+//! Starter files for new storybook components.
+//!
+//! [`component`] generates a Rust file holding a `#[storybook]` component
+//! skeleton and its [`Stories`](crate::Stories) impl with a "Default" story,
+//! and optionally a Markdown guide registered with `storydoc!`:
+//!
+//! ```rust,ignore
+//! storybook::scaffold::component("MyWidget", "Forms/Inputs")
+//!     .with_doc("docs")
+//!     .write("src/components")?;
+//! ```
+//!
+//! The same is available from the command line by calling [`run_cli`] at the
+//! start of the storybook's `main`:
+//!
+//! ```text
+//! cargo run -- scaffold MyWidget Forms/Inputs --out src/components --doc docs
+//! ```
+//!
+//! Teams porting a Storybook.js design system can generate the skeletons of
+//! their existing stories with [`csf::import_dir`] (`import-csf` on the
+//! command line).
+
+pub mod csf;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A new component's starter files, see [`component`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scaffold {
+    /// Component name, e.g. `MyWidget`
+    pub name: String,
+    /// Sidebar category, e.g. `Forms/Inputs`
+    pub tag: String,
+    /// Directory of the Markdown guide, relative to the crate root, when one
+    /// is generated
+    pub doc_dir: Option<String>,
+}
+
+/// Starter files for the component `name` placed under `tag` in the sidebar.
+pub fn component(name: impl Into<String>, tag: impl Into<String>) -> Scaffold {
+    Scaffold {
+        name: name.into(),
+        tag: tag.into(),
+        doc_dir: None,
+    }
+}
+
+impl Scaffold {
+    /// Also generate a Markdown guide in `dir` (relative to the crate root,
+    /// as `storydoc!` paths are), shown as the "Guide" tab of the component.
+    pub fn with_doc(mut self, dir: impl Into<String>) -> Self {
+        self.doc_dir = Some(dir.into());
+        self
+    }
+
+    /// File stem of the generated files, the component name in snake case:
+    /// `my_widget`.
+    pub fn file_stem(&self) -> String {
+        snake_case(&self.name)
+    }
+
+    /// Path of the component in the storybook: `Forms/Inputs/MyWidget`.
+    fn story_path(&self) -> String {
+        format!("{}/{}", self.tag.trim_matches('/'), self.name)
+    }
+
+    /// Path of the Markdown guide, relative to the crate root.
+    fn doc_path(&self) -> Option<String> {
+        self.doc_dir
+            .as_ref()
+            .map(|dir| format!("{}/{}.md", dir.trim_end_matches('/'), self.file_stem()))
+    }
+
+    /// Content of the Rust file.
+    pub fn source(&self) -> String {
+        let name = &self.name;
+        let tag = &self.tag;
+        let path = self.story_path();
+        let storydoc = match self.doc_path() {
+            Some(doc) => format!("\nstorybook::storydoc!(\"{path}\", \"{doc}\");\n"),
+            None => String::new(),
+        };
+        format!(
+            r#"use dioxus::prelude::*;
+use storybook::{{Stories, Story, storybook}};
+
+/// TODO: describe what `{name}` is for.
+///
+/// @[story:{path}/Default]
+#[storybook(tag = "{tag}")]
+#[component]
+pub fn {name}(label: String) -> Element {{
+    rsx! {{
+        div {{ "{{label}}" }}
+    }}
+}}
+
+impl Stories for {name}Props {{
+    fn stories() -> Vec<Story<Self>> {{
+        vec![Story::new(
+            "Default",
+            Self {{
+                label: "{name}".to_string(),
+            }},
+        )]
+    }}
+}}
+{storydoc}"#
+        )
+    }
+
+    /// Content of the Markdown guide, when one is generated.
+    pub fn doc(&self) -> Option<String> {
+        self.doc_dir.as_ref()?;
+        let name = &self.name;
+        let path = self.story_path();
+        Some(format!(
+            "# {name}\n\nTODO: explain when to use `{name}`.\n\n@[story:{path}/Default]\n\n@[props:{path}]\n"
+        ))
+    }
+
+    /// Write the Rust file to `dir` and the Markdown guide, if any, to its
+    /// directory relative to the current one (the crate root under
+    /// `cargo run`). Returns the paths written.
+    ///
+    /// Fails with [`io::ErrorKind::InvalidInput`] if the name isn't a
+    /// component name (an identifier starting with an uppercase letter), and
+    /// with [`io::ErrorKind::AlreadyExists`] rather than overwriting a file.
+    pub fn write(&self, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        let mut chars = self.name.chars();
+        let valid_name = chars.next().is_some_and(|c| c.is_ascii_uppercase())
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{}` is not a component name", self.name),
+            ));
+        }
+
+        let mut files = vec![(
+            dir.as_ref().join(format!("{}.rs", self.file_stem())),
+            self.source(),
+        )];
+        if let (Some(path), Some(doc)) = (self.doc_path(), self.doc()) {
+            files.push((PathBuf::from(path), doc));
+        }
+        write_files(files)
+    }
+}
+
+/// `MyWidget` -> `my_widget`
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+/// Write `(path, content)` files, creating their directories. Nothing is
+/// written if one of the files already exists.
+fn write_files(files: Vec<(PathBuf, String)>) -> io::Result<Vec<PathBuf>> {
+    if let Some((path, _)) = files.iter().find(|(path, _)| path.exists()) {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", path.display()),
+        ));
+    }
+    for (path, content) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Run the `scaffold` or `import-csf` subcommand when the program was
+/// started with one:
+///
+/// ```text
+/// <program> scaffold <Name> <tag> [--out <dir>] [--doc <dir>]
+/// <program> import-csf <stories dir> [--out <dir>]
+/// ```
+///
+/// `--out` defaults to `src`. Returns `false` for any other command line, so
+/// the storybook can be launched as usual:
+///
+/// ```rust,ignore
+/// fn main() {
+///     if storybook::scaffold::run_cli() {
+///         return;
+///     }
+///     storybook::launch(StorybookConfig::default());
+/// }
+/// ```
+pub fn run_cli() -> bool {
+    let mut args = std::env::args().skip(1);
+    let command = args.next();
+    let (command, usage) = match command.as_deref() {
+        Some("scaffold") => ("scaffold", "<Name> <tag> [--out <dir>] [--doc <dir>]"),
+        Some("import-csf") => ("import-csf", "<stories dir> [--out <dir>]"),
+        _ => return false,
+    };
+
+    let mut positional = Vec::new();
+    let mut out = "src".to_string();
+    let mut doc_dir = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--out" => out = args.next().unwrap_or(out),
+            "--doc" => doc_dir = Some(args.next().unwrap_or_else(|| "docs".to_string())),
+            _ => positional.push(arg),
+        }
+    }
+
+    let written = match (command, positional.as_slice()) {
+        ("scaffold", [name, tag]) => {
+            let mut scaffold = component(name.as_str(), tag.as_str());
+            if let Some(dir) = doc_dir {
+                scaffold = scaffold.with_doc(dir);
+            }
+            scaffold.write(&out)
+        }
+        ("import-csf", [dir]) => csf::import_dir(dir, &out),
+        _ => {
+            eprintln!("usage: {command} {usage}");
+            std::process::exit(2);
+        }
+    };
+    match written {
+        Ok(paths) => {
+            for path in &paths {
+                println!("created {}", path.display());
+            }
+            let modules = paths
+                .iter()
+                .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+                .filter_map(|path| path.file_stem())
+                .map(|stem| format!("`mod {};`", stem.to_string_lossy()))
+                .collect::<Vec<_>>();
+            println!(
+                "declare {} in the module of {} to build the generated code",
+                modules.join(", "),
+                Path::new(&out).display()
+            );
+        }
+        Err(err) => {
+            eprintln!("{command}: {err}");
+            std::process::exit(1);
+        }
+    }
+    true
+}
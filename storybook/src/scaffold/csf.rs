@@ -0,0 +1,762 @@
+//! Import of Storybook.js stories written in Component Story Format (CSF).
+//!
+//! [`parse`] reads the metadata of a `*.stories.js` / `*.stories.ts` file:
+//! the `title` of its default export, and the name and `args` of each story
+//! export (CSF 3 objects as well as CSF 2 `Story.args = {...}` assignments).
+//! Arg values are kept when they can be represented in JSON; functions, JSX
+//! and references to other variables are left out.
+//!
+//! [`CsfFile::to_rust`] turns them into a Rust skeleton: a `#[storybook]`
+//! component under the same sidebar path, with a prop per arg, and a
+//! [`Stories`](crate::Stories) impl with the same stories and arg values.
+//! The component's body is left for the port.
+
+use super::{snake_case, write_files};
+use serde_json::{Map, Value};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Extensions of the story files read by [`import_dir`].
+const STORY_EXTENSIONS: &[&str] = &["js", "jsx", "mjs", "ts", "tsx"];
+
+/// Rust keywords that can't be used as prop names as is.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "crate", "do", "dyn", "else",
+    "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+    "move", "mut", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+    "unsafe", "use", "where", "while", "yield",
+];
+
+/// The story metadata of a CSF file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsfFile {
+    /// Sidebar path of the component, e.g. `Forms/Inputs/Button`
+    pub title: String,
+    /// Name of the default export's `component`, if any
+    pub component: Option<String>,
+    /// Args shared by every story (the default export's `args`)
+    pub args: Map<String, Value>,
+    pub stories: Vec<CsfStory>,
+}
+
+/// A story of a [`CsfFile`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsfStory {
+    /// Name of the export, e.g. `PrimaryButton`
+    pub export_name: String,
+    /// Display name: the story's `name` (or CSF 2 `storyName`), else the
+    /// export name in words (`Primary Button`)
+    pub name: String,
+    /// `parameters.docs.description.story`
+    pub description: Option<String>,
+    /// The story's own args, on top of the file's
+    pub args: Map<String, Value>,
+}
+
+/// Read the story metadata of a CSF file. `default_title` is used when the
+/// default export has no `title` (Storybook then derives it from the file
+/// path). Returns `None` when `source` has no default export.
+pub fn parse(source: &str, default_title: &str) -> Option<CsfFile> {
+    let source = strip_comments(source);
+
+    let meta_start = source.find("export default")? + "export default".len();
+    let meta = match Parser::new(&source[meta_start..]).object_entries() {
+        Some(entries) => entries,
+        // `export default meta;`
+        None => {
+            let name = identifier_at(&source[meta_start..])?;
+            Parser::new(&source[declaration(&source, name)?..]).object_entries()?
+        }
+    };
+    let mut file = CsfFile {
+        title: string_entry(&meta, "title").unwrap_or_else(|| default_title.to_string()),
+        component: meta.iter().find_map(|(key, value)| match value {
+            Entry::Expression(expression) if key == "component" => {
+                identifier_at(expression).map(str::to_string)
+            }
+            _ => None,
+        }),
+        args: object_entry(&meta, "args").unwrap_or_default(),
+        stories: Vec::new(),
+    };
+
+    let mut rest = source.as_str();
+    while let Some(start) = rest.find("export const ") {
+        rest = &rest[start + "export const ".len()..];
+        let Some(export_name) = identifier_at(rest) else {
+            continue;
+        };
+        // `__namedExportsOrder` and other internal exports
+        if export_name.starts_with("__") {
+            continue;
+        }
+        let object = rest
+            .find('=')
+            .and_then(|eq| Parser::new(&rest[eq + 1..]).object_entries())
+            .unwrap_or_default();
+        let mut story = CsfStory {
+            export_name: export_name.to_string(),
+            name: string_entry(&object, "name").unwrap_or_else(|| words(export_name)),
+            description: json_entry(&object, "parameters").and_then(|parameters| {
+                parameters
+                    .get("docs")?
+                    .get("description")?
+                    .get("story")?
+                    .as_str()
+                    .map(str::to_string)
+            }),
+            args: object_entry(&object, "args").unwrap_or_default(),
+        };
+        // CSF 2: `Primary.args = {...}` and `Primary.storyName = '...'`
+        if let Some(args) =
+            assignment(&source, export_name, "args").and_then(|value| Parser::new(value).object())
+        {
+            story.args.extend(args);
+        }
+        if let Some(Value::String(name)) = assignment(&source, export_name, "storyName")
+            .and_then(|value| Parser::new(value).json())
+        {
+            story.name = name;
+        }
+        file.stories.push(story);
+    }
+    Some(file)
+}
+
+impl CsfFile {
+    /// Name of the Rust component: the last segment of the title, in
+    /// UpperCamelCase.
+    pub fn component_name(&self) -> String {
+        let last = self.title.rsplit('/').next().unwrap_or_default();
+        let name: String = last
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .flat_map(|word| {
+                let mut chars = word.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase())
+                    .into_iter()
+                    .chain(chars)
+            })
+            .collect();
+        if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            name
+        } else {
+            format!("Component{name}")
+        }
+    }
+
+    /// Sidebar category of the component: the title without its last
+    /// segment.
+    pub fn tag(&self) -> String {
+        match self.title.trim_matches('/').rsplit_once('/') {
+            Some((tag, _)) => tag.to_string(),
+            None => "Imported".to_string(),
+        }
+    }
+
+    /// The props of the component, in order of first appearance in the args,
+    /// with the first non-null value found for each.
+    fn props(&self) -> Vec<(&str, &Value)> {
+        let mut props: Vec<(&str, &Value)> = Vec::new();
+        let all_args = std::iter::once(&self.args).chain(self.stories.iter().map(|s| &s.args));
+        for (key, value) in all_args.flatten() {
+            match props.iter_mut().find(|(prop, _)| *prop == key.as_str()) {
+                Some((_, known)) if known.is_null() => *known = value,
+                Some(_) => {}
+                None => props.push((key.as_str(), value)),
+            }
+        }
+        props
+    }
+
+    /// The Rust skeleton of the component and its stories. `origin` (e.g.
+    /// the path of the CSF file) is mentioned in the component's doc
+    /// comment.
+    pub fn to_rust(&self, origin: &str) -> String {
+        let name = self.component_name();
+        let mut props = self.props();
+        // A component without props has no props struct to implement
+        // `Stories` for
+        let placeholder = Value::String(name.clone());
+        if props.is_empty() {
+            props.push(("label", &placeholder));
+        }
+        // The storybook renders the first story by default
+        let default_story = [CsfStory {
+            export_name: "Default".to_string(),
+            name: "Default".to_string(),
+            description: None,
+            args: Map::new(),
+        }];
+        let stories = if self.stories.is_empty() {
+            &default_story[..]
+        } else {
+            &self.stories[..]
+        };
+
+        let mut out = String::new();
+        out.push_str("use dioxus::prelude::*;\nuse storybook::{Stories, Story, storybook};\n\n");
+        let _ = writeln!(
+            out,
+            "/// TODO: port `{}` from `{origin}`.",
+            self.component.as_deref().unwrap_or(&name)
+        );
+        let _ = writeln!(out, "#[storybook(tag = {:?})]", self.tag());
+        out.push_str("#[component]\n");
+        let _ = writeln!(out, "pub fn {name}(");
+        for (key, value) in &props {
+            let _ = writeln!(out, "    {}: {},", field_name(key), rust_type(value));
+        }
+        out.push_str(") -> Element {\n");
+        out.push_str("    rsx! {\n        div {\n");
+        for (key, _) in &props {
+            let label = key
+                .replace('{', "{{")
+                .replace('}', "}}")
+                .replace('"', "\\\"");
+            let _ = writeln!(
+                out,
+                "            div {{ \"{label}: \" {{format!(\"{{:?}}\", {})}} }}",
+                field_name(key)
+            );
+        }
+        out.push_str("        }\n    }\n}\n\n");
+
+        let _ = writeln!(out, "impl Stories for {name}Props {{");
+        out.push_str("    fn stories() -> Vec<Story<Self>> {\n        vec![\n");
+        for story in stories {
+            let constructor = match &story.description {
+                Some(description) => format!(
+                    "Story::with_description(\n                {:?},\n                {description:?},",
+                    story.name
+                ),
+                None => format!("Story::new(\n                {:?},", story.name),
+            };
+            let _ = writeln!(out, "            {constructor}");
+            out.push_str("                Self {\n");
+            for (key, fallback) in &props {
+                let value = story.args.get(*key).or_else(|| self.args.get(*key));
+                let _ = writeln!(
+                    out,
+                    "                    {}: {},",
+                    field_name(key),
+                    rust_value(value, fallback)
+                );
+            }
+            out.push_str("                },\n            ),\n");
+        }
+        out.push_str("        ]\n    }\n}\n");
+        out
+    }
+}
+
+/// Import every CSF file under `dir` (recursively), writing a Rust file per
+/// component to `out`. Returns the paths written.
+///
+/// Titles missing from the files are derived from their path under `dir`,
+/// as Storybook does. Fails with [`io::ErrorKind::AlreadyExists`] rather
+/// than overwriting a file; nothing is written then.
+pub fn import_dir(dir: impl AsRef<Path>, out: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    let mut sources = Vec::new();
+    collect_story_files(dir, &mut sources)?;
+    sources.sort();
+
+    let mut files = Vec::new();
+    for path in sources {
+        let source = fs::read_to_string(&path)?;
+        let relative = path.strip_prefix(dir).unwrap_or(&path);
+        let default_title = relative
+            .to_string_lossy()
+            .replace('\\', "/")
+            .split(".stories.")
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        if let Some(file) = parse(&source, &default_title) {
+            let target = out
+                .as_ref()
+                .join(format!("{}.rs", snake_case(&file.component_name())));
+            if files.iter().any(|(path, _)| *path == target) {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!(
+                        "{} defines a component already imported to {}",
+                        relative.display(),
+                        target.display()
+                    ),
+                ));
+            }
+            files.push((target, file.to_rust(&relative.display().to_string())));
+        }
+    }
+    write_files(files)
+}
+
+fn collect_story_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned();
+        if path.is_dir() {
+            if name != "node_modules" {
+                collect_story_files(&path, files)?;
+            }
+        } else if let Some((_, extension)) = name.split_once(".stories.")
+            && STORY_EXTENSIONS.contains(&extension)
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// `aria-label` / `isPrimary` -> `aria_label` / `is_primary`
+fn field_name(key: &str) -> String {
+    let name = snake_case(key).replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+    if KEYWORDS.contains(&name.as_str()) {
+        format!("r#{name}")
+    } else if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+/// `PrimaryButton` -> `Primary Button`
+fn words(name: &str) -> String {
+    let mut words = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c == '_' {
+            words.push(' ');
+        } else {
+            if i > 0 && c.is_uppercase() && !words.ends_with(' ') {
+                words.push(' ');
+            }
+            words.push(c);
+        }
+    }
+    words
+}
+
+/// The Rust type of a prop, from an example value.
+fn rust_type(value: &Value) -> &'static str {
+    match value {
+        Value::Bool(_) => "bool",
+        Value::Number(number) if number.is_i64() || number.is_u64() => "i64",
+        Value::Number(_) => "f64",
+        Value::String(_) => "String",
+        Value::Array(items) if items.iter().all(Value::is_string) => "Vec<String>",
+        _ => "storybook::serde_json::Value",
+    }
+}
+
+/// A Rust expression of a prop's `value`, typed after the prop's `example`
+/// value; the type's default when the story doesn't set it.
+fn rust_value(value: Option<&Value>, example: &Value) -> String {
+    match (rust_type(example), value) {
+        ("bool", Some(Value::Bool(b))) => b.to_string(),
+        ("bool", _) => "false".to_string(),
+        ("i64", Some(Value::Number(n))) if n.is_i64() || n.is_u64() => n.to_string(),
+        ("i64", _) => "0".to_string(),
+        ("f64", Some(Value::Number(n))) => format!("{:?}", n.as_f64().unwrap_or_default()),
+        ("f64", _) => "0.0".to_string(),
+        ("String", Some(Value::String(s))) => format!("{s:?}.to_string()"),
+        ("String", _) => "String::new()".to_string(),
+        ("Vec<String>", Some(Value::Array(items))) => {
+            let items = items
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|s| format!("{s:?}.to_string()"))
+                .collect::<Vec<_>>();
+            format!("vec![{}]", items.join(", "))
+        }
+        ("Vec<String>", _) => "Vec::new()".to_string(),
+        (_, Some(value)) => format!("storybook::serde_json::json!({value})"),
+        (_, None) => "storybook::serde_json::Value::Null".to_string(),
+    }
+}
+
+/// Remove `//` and `/* */` comments, keeping string contents.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote = None;
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) => {
+                out.push(c);
+                if c == '\\' {
+                    out.extend(chars.next());
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '/' && chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            None if c == '/' && chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            None => {
+                if matches!(c, '"' | '\'' | '`') {
+                    quote = Some(c);
+                }
+                out.push(c);
+            }
+        }
+    }
+    out
+}
+
+/// The identifier at the start of `source`, after whitespace.
+fn identifier_at(source: &str) -> Option<&str> {
+    let source = source.trim_start();
+    let end = source
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(source.len());
+    (end > 0).then(|| &source[..end])
+}
+
+/// Offset of the value of `const name = …` (or `let` / `var`).
+fn declaration(source: &str, name: &str) -> Option<usize> {
+    ["const", "let", "var"].iter().find_map(|keyword| {
+        let pattern = format!("{keyword} {name}");
+        let start = source.match_indices(&pattern).find_map(|(start, _)| {
+            let after = &source[start + pattern.len()..];
+            after
+                .starts_with(|c: char| c.is_whitespace() || c == ':' || c == '=')
+                .then_some(start + pattern.len())
+        })?;
+        Some(start + source[start..].find('=')? + 1)
+    })
+}
+
+/// The value of a `name.property = …` assignment.
+fn assignment<'a>(source: &'a str, name: &str, property: &str) -> Option<&'a str> {
+    let pattern = format!("{name}.{property}");
+    source.match_indices(&pattern).find_map(|(start, _)| {
+        let preceded_by_identifier = source[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$');
+        if preceded_by_identifier {
+            return None;
+        }
+        let after = source[start + pattern.len()..].trim_start();
+        after
+            .strip_prefix('=')
+            .filter(|value| !value.starts_with('='))
+    })
+}
+
+/// A property of an object literal, see [`Parser::object_entries`].
+enum Entry {
+    /// A JSON-representable value
+    Json(Value),
+    /// Any other expression, as written
+    Expression(String),
+}
+
+fn string_entry(entries: &[(String, Entry)], key: &str) -> Option<String> {
+    match json_entry(entries, key)? {
+        Value::String(value) => Some(value),
+        _ => None,
+    }
+}
+
+fn json_entry(entries: &[(String, Entry)], key: &str) -> Option<Value> {
+    entries.iter().find_map(|(k, entry)| match entry {
+        Entry::Json(value) if k == key => Some(value.clone()),
+        _ => None,
+    })
+}
+
+fn object_entry(entries: &[(String, Entry)], key: &str) -> Option<Map<String, Value>> {
+    match json_entry(entries, key)? {
+        Value::Object(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// A minimal parser of JavaScript literals, keeping what JSON can represent.
+struct Parser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.bump();
+        }
+        eaten
+    }
+
+    /// The value at the cursor, if JSON can represent it.
+    fn json(&mut self) -> Option<Value> {
+        match self.value() {
+            Entry::Json(value) => Some(value),
+            Entry::Expression(_) => None,
+        }
+    }
+
+    /// The JSON-representable properties of the object literal at the
+    /// cursor.
+    fn object(&mut self) -> Option<Map<String, Value>> {
+        let entries = self.object_entries()?;
+        Some(
+            entries
+                .into_iter()
+                .filter_map(|(key, entry)| match entry {
+                    Entry::Json(value) => Some((key, value)),
+                    Entry::Expression(_) => None,
+                })
+                .collect(),
+        )
+    }
+
+    /// The properties of the object literal at the cursor, or `None` when
+    /// there is no object literal there.
+    fn object_entries(&mut self) -> Option<Vec<(String, Entry)>> {
+        if !self.eat('{') {
+            return None;
+        }
+        let mut entries = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some('}') => {
+                    self.bump();
+                    break;
+                }
+                Some(',') => {
+                    self.bump();
+                    continue;
+                }
+                _ => {}
+            }
+            if self.source[self.pos..].starts_with("...") {
+                self.pos += 3;
+                self.expression();
+                continue;
+            }
+            let key = match self.peek() {
+                Some(q @ ('"' | '\'')) => {
+                    self.bump();
+                    self.string(q)
+                }
+                Some('[') => {
+                    // Computed key
+                    self.expression();
+                    continue;
+                }
+                _ => {
+                    let start = self.pos;
+                    while self
+                        .peek()
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$')
+                    {
+                        self.bump();
+                    }
+                    if self.pos == start {
+                        // Not a property: skip to the next one
+                        self.bump();
+                        self.expression();
+                        continue;
+                    }
+                    Some(self.source[start..self.pos].to_string())
+                }
+            };
+            let entry = if self.eat(':') {
+                self.value()
+            } else {
+                // Shorthand property or method
+                Entry::Expression(self.expression())
+            };
+            if let Some(key) = key {
+                entries.push((key, entry));
+            }
+        }
+        Some(entries)
+    }
+
+    fn value(&mut self) -> Entry {
+        self.skip_whitespace();
+        let start = self.pos;
+        let value = match self.peek() {
+            Some('{') => self.object().map(Value::Object),
+            Some('[') => self.array(),
+            Some(q @ ('"' | '\'' | '`')) => {
+                self.bump();
+                self.string(q).map(Value::String)
+            }
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '.' => self.number(),
+            Some(c) if c.is_alphabetic() => {
+                let word = identifier_at(&self.source[self.pos..]).unwrap_or_default();
+                let value = match word {
+                    "true" => Some(Value::Bool(true)),
+                    "false" => Some(Value::Bool(false)),
+                    "null" => Some(Value::Null),
+                    _ => None,
+                };
+                if value.is_some() {
+                    self.pos += word.len();
+                }
+                value
+            }
+            _ => None,
+        };
+        self.skip_whitespace();
+        // `as const` / `satisfies Type` keep the value
+        for keyword in ["as ", "satisfies "] {
+            if value.is_some() && self.source[self.pos..].starts_with(keyword) {
+                self.expression();
+            }
+        }
+        match value {
+            Some(value) if self.at_delimiter() => Entry::Json(value),
+            _ => {
+                self.pos = start;
+                Entry::Expression(self.expression())
+            }
+        }
+    }
+
+    fn at_delimiter(&self) -> bool {
+        matches!(self.peek(), None | Some(',' | '}' | ']' | ')' | ';'))
+    }
+
+    fn array(&mut self) -> Option<Value> {
+        self.bump();
+        let mut items = Vec::new();
+        let mut representable = true;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None => return None,
+                Some(']') => {
+                    self.bump();
+                    break;
+                }
+                Some(',') => {
+                    self.bump();
+                }
+                _ => {
+                    let start = self.pos;
+                    match self.value() {
+                        Entry::Json(value) => items.push(value),
+                        Entry::Expression(_) => representable = false,
+                    }
+                    // Unbalanced closing bracket
+                    if self.pos == start {
+                        self.bump();
+                    }
+                }
+            }
+        }
+        representable.then_some(Value::Array(items))
+    }
+
+    /// The rest of a string literal opened with `quote`; `None` for template
+    /// literals with placeholders.
+    fn string(&mut self, quote: char) -> Option<String> {
+        let mut value = String::new();
+        let mut representable = true;
+        while let Some(c) = self.bump() {
+            match c {
+                '\\' => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(c) => value.push(c),
+                    None => break,
+                },
+                '$' if quote == '`' && self.peek() == Some('{') => {
+                    representable = false;
+                    value.push(c);
+                }
+                c if c == quote => return representable.then_some(value),
+                c => value.push(c),
+            }
+        }
+        None
+    }
+
+    fn number(&mut self) -> Option<Value> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+' | '_'))
+        {
+            self.bump();
+        }
+        let literal = self.source[start..self.pos].replace('_', "");
+        serde_json::from_str::<serde_json::Number>(&literal)
+            .ok()
+            .map(Value::Number)
+    }
+
+    /// Skip the expression at the cursor, up to the next `,` or closing
+    /// bracket outside of nested brackets and strings, and return it.
+    fn expression(&mut self) -> String {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => break,
+                ')' | ']' | '}' => depth -= 1,
+                ',' | ';' if depth == 0 => break,
+                '"' | '\'' | '`' => {
+                    self.bump();
+                    self.string(c);
+                    continue;
+                }
+                _ => {}
+            }
+            self.bump();
+        }
+        self.source[start..self.pos].trim().to_string()
+    }
+}
@@ -0,0 +1,281 @@
+//! Health summary of the compile-time registrations.
+
+use crate::ui::models::DocPart;
+use crate::ui::services::doc_anchors::prop_row_id;
+use crate::ui::services::doc_parser::parse_doc_content;
+use crate::ui::services::location::search_param;
+use crate::ui::services::story_ids::find_story;
+use crate::ui::viewmodels::embedded_story_vm::resolve_embedded_story;
+use crate::{
+    NamingRules, NamingViolation, extract_fields_from_schema, find_component, find_doc,
+    get_components, get_docs, lint_story_names,
+};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Counts and problems of the components and documentation pages registered
+/// with [`#[storybook]`](macro@crate::storybook) and [`storydoc!`](crate::storydoc!).
+///
+/// Printed as a banner when the storybook starts (see [`crate::watch`]), so
+/// misconfigured registrations are noticed instead of showing up as an empty
+/// or incomplete sidebar.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RegistrySummary {
+    /// Number of registered components.
+    pub components: usize,
+    /// Number of stories, skipped ones included.
+    pub stories: usize,
+    /// Number of stories hidden with [`Story::skip`](crate::Story::skip).
+    pub skipped_stories: usize,
+    /// Number of documentation pages.
+    pub docs: usize,
+    /// Component names registered more than once; only the first is shown.
+    pub duplicate_components: Vec<String>,
+    /// Documentation paths registered more than once.
+    pub duplicate_docs: Vec<String>,
+    /// Story ids shared by several stories (see [`Story::with_id`](crate::Story::with_id)).
+    pub duplicate_story_ids: Vec<String>,
+    /// Components whose `Stories` impl returns no story: they can't be
+    /// rendered.
+    pub components_without_stories: Vec<String>,
+    /// Links, embeds and files of the documentation pages and component
+    /// descriptions that don't resolve.
+    pub broken_links: Vec<BrokenLink>,
+    /// Story titles breaking the naming rules, once checked with
+    /// [`with_naming_rules`](Self::with_naming_rules).
+    pub naming_violations: Vec<NamingViolation>,
+}
+
+/// A reference of a documentation page that doesn't resolve: a
+/// `@[story:…]`, `@[props:…]` or `@[canvas:…]` embed of an unknown story or
+/// component, a `?doc=` / `?story=` link or `@[prop:…]` chip to a missing
+/// page, story or prop, or a missing file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BrokenLink {
+    /// The documentation path, or `"Component (docs)"` for a component
+    /// description.
+    pub page: String,
+    /// The reference as written in the page.
+    pub target: String,
+}
+
+impl fmt::Display for BrokenLink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} → {}", self.page, self.target)
+    }
+}
+
+impl RegistrySummary {
+    /// Human-readable description of each problem found.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        let mut warn = |items: &[String], what: &str| {
+            if !items.is_empty() {
+                warnings.push(format!("{what}: {}", items.join(", ")));
+            }
+        };
+        warn(&self.duplicate_components, "duplicate components");
+        warn(&self.duplicate_docs, "duplicate documentation pages");
+        warn(&self.duplicate_story_ids, "duplicate story ids");
+        warn(
+            &self.components_without_stories,
+            "components without stories",
+        );
+        let broken_links: Vec<String> = self.broken_links.iter().map(ToString::to_string).collect();
+        warn(&broken_links, "broken doc links");
+        let naming_violations: Vec<String> = self
+            .naming_violations
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        warn(&naming_violations, "story naming");
+        if self.components == 0 {
+            warnings.push(
+                "no components registered: annotate components with #[storybook] \
+                 and check that their module is compiled"
+                    .to_string(),
+            );
+        }
+        warnings
+    }
+
+    /// Also check the story titles against `rules` (see
+    /// [`lint_story_names`]), e.g. in a test:
+    ///
+    /// ```rust,ignore
+    /// let summary = registry_summary().with_naming_rules(&NamingRules::default());
+    /// assert!(summary.is_healthy(), "{:?}", summary.warnings());
+    /// ```
+    pub fn with_naming_rules(mut self, rules: &NamingRules) -> Self {
+        self.naming_violations = lint_story_names(rules);
+        self
+    }
+
+    /// Returns `true` when no problem was found.
+    pub fn is_healthy(&self) -> bool {
+        self.warnings().is_empty()
+    }
+}
+
+impl fmt::Display for RegistrySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} components, {} stories",
+            self.components, self.stories
+        )?;
+        if self.skipped_stories > 0 {
+            write!(f, " ({} skipped)", self.skipped_stories)?;
+        }
+        write!(f, ", {} docs", self.docs)
+    }
+}
+
+/// Summarize the registered components and documentation pages.
+pub fn registry_summary() -> RegistrySummary {
+    let mut summary = RegistrySummary::default();
+
+    let mut component_names = BTreeMap::<&str, usize>::new();
+    let mut story_ids = BTreeMap::<String, usize>::new();
+    for component in get_components() {
+        summary.components += 1;
+        *component_names.entry(component.name).or_default() += 1;
+        let stories = (component.get_stories)();
+        if stories.is_empty() {
+            summary
+                .components_without_stories
+                .push(component.name.to_string());
+        }
+        for story in stories {
+            summary.stories += 1;
+            summary.skipped_stories += usize::from(story.skipped);
+            *story_ids.entry(story.id).or_default() += 1;
+        }
+    }
+
+    let mut doc_paths = BTreeMap::<&str, usize>::new();
+    for doc in get_docs() {
+        summary.docs += 1;
+        *doc_paths.entry(doc.path).or_default() += 1;
+    }
+
+    summary.duplicate_components = duplicates(component_names);
+    summary.duplicate_docs = duplicates(doc_paths);
+    summary.duplicate_story_ids = duplicates(story_ids);
+    summary.broken_links = broken_links();
+    summary
+}
+
+/// The summary printed as the startup banner, checked against the naming
+/// rules of the active configuration.
+pub(crate) fn startup_summary() -> RegistrySummary {
+    let summary = registry_summary();
+    match &crate::take_config().naming_rules {
+        Some(rules) => summary.with_naming_rules(rules),
+        None => summary,
+    }
+}
+
+/// Check the references of every documentation page and component
+/// description.
+fn broken_links() -> Vec<BrokenLink> {
+    let pages = get_docs()
+        .map(|doc| (doc.path.to_string(), doc.content_html, doc.missing_assets))
+        .chain(get_components().map(|component| {
+            (
+                format!("{} (docs)", component.name),
+                component.description,
+                &[][..],
+            )
+        }));
+    let mut broken = Vec::new();
+    for (page, html, missing_assets) in pages {
+        let targets = unresolved_references(html)
+            .into_iter()
+            .chain(missing_assets.iter().map(|asset| asset.to_string()));
+        broken.extend(targets.map(|target| BrokenLink {
+            page: page.clone(),
+            target,
+        }));
+    }
+    broken
+}
+
+/// The embeds and storybook links of `html` that don't resolve.
+fn unresolved_references(html: &str) -> Vec<String> {
+    let mut unresolved = Vec::new();
+    for part in parse_doc_content(html) {
+        match part {
+            DocPart::StoryEmbed {
+                story_path,
+                story_name,
+                props_override,
+            } => {
+                if resolve_embedded_story(&story_path, &story_name, props_override.as_deref())
+                    .is_err()
+                {
+                    unresolved.push(format!("@[story:{story_path}]"));
+                }
+            }
+            DocPart::PropsTable { component_name } => {
+                if find_component(&component_name).is_err() {
+                    unresolved.push(format!("@[props:{component_name}]"));
+                }
+            }
+            DocPart::Canvas { component_name } => {
+                if find_component(&component_name).is_err() {
+                    unresolved.push(format!("@[canvas:{component_name}]"));
+                }
+            }
+            DocPart::Html(html) => {
+                unresolved.extend(page_links(&html).filter(|link| !link_resolves(link)));
+            }
+            DocPart::Directive { .. } => {}
+        }
+    }
+    unresolved
+}
+
+/// The `href`s of `html` pointing at a page of the storybook (`?story=…`,
+/// `?doc=…`).
+fn page_links(html: &str) -> impl Iterator<Item = String> + '_ {
+    html.split("href=\"").skip(1).filter_map(|rest| {
+        let link = &rest[..rest.find('"')?];
+        link.starts_with('?').then(|| link.replace("&amp;", "&"))
+    })
+}
+
+/// Whether the storybook page `link` points at exists: the story of its
+/// `?story=` id, the doc page of its `?doc=` path, and for the docs page of
+/// a component, the props table row of its `#prop-…` fragment.
+fn link_resolves(link: &str) -> bool {
+    if let Some(id) = search_param(link, "story") {
+        return find_story(&id).is_some();
+    }
+    let Some(path) = search_param(link, "doc") else {
+        return true;
+    };
+    let Some(component_name) = path.strip_prefix("__component__/") else {
+        return find_doc(&path).is_ok();
+    };
+    let Ok(component) = find_component(component_name) else {
+        return false;
+    };
+    match link.split_once('#') {
+        Some((_, row)) if row.starts_with("prop-") => component.prop_schema().is_ok_and(|schema| {
+            extract_fields_from_schema(&schema)
+                .iter()
+                .any(|field| prop_row_id(component_name, &field.name) == row)
+        }),
+        _ => true,
+    }
+}
+
+/// The keys counted more than once.
+fn duplicates<K: ToString>(counts: BTreeMap<K, usize>) -> Vec<String> {
+    counts
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .map(|(key, _)| key.to_string())
+        .collect()
+}
@@ -0,0 +1,180 @@
+//! Tailwind CSS integration helpers.
+//!
+//! Tailwind only generates the utilities it finds while scanning its
+//! configured sources. Classes that only appear in stories — e.g. in a
+//! `stories.rs` module outside the scanned paths, or in a crate compiled with
+//! a `storybook` feature only — get purged, and the previews render unstyled.
+//!
+//! [`write_safelist`] scans Rust sources for class names and writes them to a
+//! file Tailwind can be pointed at. Call it from a build script:
+//!
+//! ```rust,ignore
+//! // build.rs
+//! fn main() {
+//!     storybook::tailwind::write_safelist(&["src"], "tailwind.safelist").unwrap();
+//!     println!("cargo:rerun-if-changed=src");
+//! }
+//! ```
+//!
+//! then add the file to Tailwind's sources (`@source "../tailwind.safelist";`
+//! with Tailwind v4, or the `content` array with v3), and load the generated
+//! stylesheet with [`StorybookConfig::with_tailwind`](crate::StorybookConfig::with_tailwind).
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Collect every class-name-like token found in string literals of the `.rs`
+/// files under `dirs` (recursively).
+pub fn collect_classes(dirs: &[impl AsRef<Path>]) -> io::Result<BTreeSet<String>> {
+    let mut classes = BTreeSet::new();
+    for dir in dirs {
+        collect_from_dir(dir.as_ref(), &mut classes)?;
+    }
+    Ok(classes)
+}
+
+/// Write the classes found by [`collect_classes`] to `out`, one per line.
+///
+/// The file is only rewritten when its content changes, so Tailwind's watch
+/// mode is not retriggered on every build.
+pub fn write_safelist(dirs: &[impl AsRef<Path>], out: impl AsRef<Path>) -> io::Result<()> {
+    let classes = collect_classes(dirs)?;
+    let content = classes.into_iter().collect::<Vec<_>>().join("\n") + "\n";
+    let out = out.as_ref();
+    if fs::read_to_string(out).is_ok_and(|existing| existing == content) {
+        return Ok(());
+    }
+    fs::write(out, content)
+}
+
+fn collect_from_dir(dir: &Path, classes: &mut BTreeSet<String>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_from_dir(&path, classes)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let source = fs::read_to_string(&path)?;
+            for literal in string_literals(&source) {
+                classes.extend(
+                    literal
+                        .split_whitespace()
+                        .filter(|token| is_class_candidate(token))
+                        .map(str::to_string),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Extract the contents of the (raw) string literals in Rust source code.
+fn string_literals(source: &str) -> Vec<&str> {
+    let bytes = source.as_bytes();
+    let mut literals = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            // Line comment: skip to the end of the line.
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = source[i..].find('\n').map_or(bytes.len(), |n| i + n);
+            }
+            // Block comment, possibly nested: skip past its end.
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let mut depth = 0usize;
+                while i < bytes.len() {
+                    if bytes[i..].starts_with(b"/*") {
+                        depth += 1;
+                        i += 2;
+                    } else if bytes[i..].starts_with(b"*/") {
+                        depth -= 1;
+                        i += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+            // Character literal such as '"' — skip so it doesn't open a string.
+            b'\'' if bytes.get(i + 2) == Some(&b'\'') => i += 3,
+            b'\'' if bytes.get(i + 1) == Some(&b'\\') && bytes.get(i + 3) == Some(&b'\'') => i += 4,
+            b'r' if matches!(bytes.get(i + 1), Some(b'"' | b'#')) => {
+                let hashes = bytes[i + 1..].iter().take_while(|b| **b == b'#').count();
+                let start = i + 1 + hashes;
+                if bytes.get(start) != Some(&b'"') {
+                    i += 1;
+                    continue;
+                }
+                let terminator = format!("\"{}", "#".repeat(hashes));
+                let end = source[start + 1..]
+                    .find(&terminator)
+                    .map_or(bytes.len(), |n| start + 1 + n);
+                literals.push(&source[start + 1..end]);
+                i = end + terminator.len();
+            }
+            b'"' => {
+                let start = i + 1;
+                let mut end = start;
+                while end < bytes.len() && bytes[end] != b'"' {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                let end = end.min(bytes.len());
+                literals.push(&source[start..end]);
+                i = end + 1;
+            }
+            _ => i += 1,
+        }
+    }
+    literals
+}
+
+/// Returns `true` for tokens that could be Tailwind utilities
+/// (e.g. `px-4`, `md:hover:bg-blue-500`, `w-[32rem]`, `!mt-0`).
+fn is_class_candidate(token: &str) -> bool {
+    token.len() > 1
+        && token.chars().any(|c| c.is_ascii_lowercase())
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_:/.[]#%!()&@,=".contains(c))
+        && !token.starts_with(['/', '.', '#', '('])
+        && !token.ends_with([':', ','])
+        && !token.contains("::")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_string_and_raw_string_literals() {
+        let source = r##"rsx! { div { class: "px-4 py-2", r#"text-"red"-500"# } }"##;
+        assert_eq!(string_literals(source), ["px-4 py-2", r#"text-"red"-500"#]);
+    }
+
+    #[test]
+    fn skips_line_comments() {
+        let source = "// class: \"hidden\"\nlet class = \"flex\";";
+        assert_eq!(string_literals(source), ["flex"]);
+    }
+
+    #[test]
+    fn skips_block_comments() {
+        let source = r#"/* class: "hidden" */ let class = "flex";
+            /* outer /* "nested" */ "still a comment" */ let other = "grid";"#;
+        assert_eq!(string_literals(source), ["flex", "grid"]);
+    }
+
+    #[test]
+    fn skips_quote_characters() {
+        let source = r#"let quote = '"'; let escaped = '\''; let class = "block";"#;
+        assert_eq!(string_literals(source), ["block"]);
+    }
+
+    #[test]
+    fn keeps_escaped_quotes_in_strings() {
+        assert_eq!(string_literals(r#""a \"b\" c""#), [r#"a \"b\" c"#]);
+    }
+}
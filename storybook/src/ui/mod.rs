@@ -1,7 +1,18 @@
-use crate::ui::models::{ComponentInfo, Selection};
-use crate::ui::view::doc_page::DocPage;
+use crate::ui::models::{ComponentInfo, Selection, TagFilter};
+use crate::ui::view::activity::ActivityPage;
+use crate::ui::view::doc_page::{ComponentDocsPage, DocPage};
+use crate::ui::view::not_found::NotFoundPage;
+use crate::ui::view::onboarding::Onboarding;
+use crate::ui::view::playlist::PlaylistPage;
+use crate::ui::view::presentation::Presentation;
+use crate::ui::view::profile_picker::ProfilePicker;
+use crate::ui::view::shared::Overridable;
 use crate::ui::view::sidebar::Sidebar;
-use crate::{STORYBOOK_CSS, find_component, find_doc, get_components, take_config};
+use crate::ui::view::snapshot_review::SnapshotReviewPage;
+use crate::{
+    Error, STORYBOOK_CSS, Slot, StorybookConfig, doc_component, find_doc, get_components,
+    take_config, watch,
+};
 use dioxus::prelude::*;
 
 // MVVM layers
@@ -16,43 +27,142 @@ pub mod view;
 pub(crate) use view::top_bar::TopBar;
 pub use viewmodels::UiSettings;
 
-use crate::ui::view::story::StoryPage;
-use crate::ui::viewmodels::story_page_vm::{StoryPageError, resolve_story_page};
+use crate::ui::view::story::{CategoryGallery, ComponentGallery, StoryPage, ThumbnailStage};
+use crate::ui::viewmodels::activity_vm::SessionActivity;
+use crate::ui::viewmodels::deep_link_vm::{initial_selection, use_deep_link};
+use crate::ui::viewmodels::doc_page_vm::{resolve_doc_freshness, use_doc_links};
+use crate::ui::viewmodels::globals_vm::use_globals_provider;
+use crate::ui::viewmodels::onboarding_vm::needs_onboarding;
+use crate::ui::viewmodels::playlist_vm::use_playlist_store;
+use crate::ui::viewmodels::presentation_vm::resolve_slides;
+use crate::ui::viewmodels::profile_vm::resolve_profiles;
+use crate::ui::viewmodels::sidebar_vm::{
+    get_all_tags, has_matching_stories, use_sidebar_bookmarks,
+};
+use crate::ui::viewmodels::snapshot_vm::use_snapshot_review;
+use crate::ui::viewmodels::story_page_vm::resolve_story_page;
+use crate::ui::viewmodels::thumbnail_vm::use_thumbnail_store;
+use crate::ui::viewmodels::zoom_vm::use_gesture_zoom;
 
 #[component]
 pub(crate) fn App() -> Element {
     // Take the config from thread-local storage and provide it as context
-    let _config = use_context_provider(take_config);
+    let config = use_context_provider(take_config);
 
     // Provide UI settings as context
-    let _ui_settings = use_context_provider(UiSettings::default);
+    // Previews load the configured CSS reset, if any, until it is removed
+    // from the layout audit panel
+    let _ui_settings = use_context_provider(|| UiSettings {
+        css_reset: Signal::new(config.css_reset.is_some()),
+        ..UiSettings::with_zoom(config.zoom)
+    });
+
+    // Provide the registered profiles (see `launch_profiles`) as context
+    let profiles = use_context_provider(resolve_profiles);
+
+    // Report panics to the watch-mode summary (see `storybook::watch`)
+    use_hook(watch::install_panic_hook);
+
+    // Print the startup banner with the registry health summary; native
+    // targets print it from `launch`, before the window opens
+    #[cfg(target_family = "wasm")]
+    use_hook(|| watch::registry_summary(&crate::summary::startup_summary()));
+
+    let theme_css = config.theme.css_variables();
 
     rsx! {
         Stylesheet { href: STORYBOOK_CSS }
-        Storybook {}
+        if !theme_css.is_empty() {
+            style { "{theme_css}" }
+        }
+        if profiles.needs_picker() {
+            ProfilePicker { profiles: profiles.profiles }
+        } else {
+            Storybook {}
+        }
     }
 }
 
 #[component]
 fn Storybook() -> Element {
     let ui_settings = use_context::<UiSettings>();
+    let config = use_context::<StorybookConfig>();
     let search_query = use_signal(String::new);
-    let selected = use_signal(|| Option::<Selection>::None);
+    let tag_filter = use_signal(TagFilter::default);
+    let tags = use_hook(|| get_all_tags(&config));
+    let selected = use_signal(|| initial_selection(&config));
+    let onboarding = use_hook(|| needs_onboarding(&config));
     let components = use_store(|| viewmodels::ComponentStore {
         components: get_components()
-            .map(|c| {
-                (
-                    c.name.to_string(),
-                    ComponentInfo {
-                        name: c.name.to_string(),
-                        category: c.tag.to_string(),
-                    },
-                )
+            .filter_map(|c| {
+                let categories = config.visible_tags_of(c);
+                (!categories.is_empty()).then(|| {
+                    (
+                        c.name.to_string(),
+                        ComponentInfo {
+                            name: c.name.to_string(),
+                            categories: categories.iter().map(|t| t.to_string()).collect(),
+                            weight: c.weight,
+                        },
+                    )
+                })
             })
             .collect(),
     });
 
-    let filtered_components = use_memo(move || components().search(&search_query()));
+    let filtered_components = use_memo(move || {
+        let tag_filter = tag_filter();
+        components()
+            .search(&search_query())
+            .into_iter()
+            .filter(|c| has_matching_stories(&c.name, &tag_filter))
+            .collect::<Vec<_>>()
+    });
+
+    // Globals picked in the top bar, read by the stories
+    use_globals_provider(&config);
+
+    // Mirror the selection in the `?story=` URL parameter
+    use_deep_link(selected);
+
+    // Follow `@[link:…]` and `@[doc:…]` links of doc pages without reloading
+    use_doc_links(selected);
+
+    // Ctrl+wheel and pinch zoom over the story preview
+    use_gesture_zoom(ui_settings.zoom_level, ui_settings.zoom);
+
+    // Count story views for the session activity page
+    let activity = use_context_provider(SessionActivity::default);
+    let session_activity = config.session_activity;
+
+    // Report the selected story to the watch-mode summary
+    use_effect(move || {
+        if let Some(Selection::Story(component_name, story_index)) = selected() {
+            if session_activity {
+                activity.record(&component_name, story_index);
+            }
+            match resolve_story_page(&component_name, story_index) {
+                Ok(data) => watch::story_selected(&component_name, &data.story_title),
+                Err(e) => watch::story_unresolved(&e.to_string()),
+            }
+        }
+    });
+
+    // Approved snapshot baselines and the snapshots awaiting review
+    use_snapshot_review();
+
+    // Favorites and recently viewed stories, listed in the sidebar
+    use_sidebar_bookmarks(selected);
+
+    // Story thumbnails of the sidebar and the galleries, captured on demand
+    use_thumbnail_store();
+
+    // Playlists, the active one replacing the configured presentation slides
+    let playlists = use_playlist_store(selected);
+    let slides_config = config.clone();
+    let slides =
+        use_memo(move || resolve_slides(&slides_config, playlists.active_entries().as_deref()));
+    let mut presenting = ui_settings.presenting;
 
     let container_class = use_memo(move || {
         let mut classes = vec!["storybook-container"];
@@ -62,11 +172,20 @@ fn Storybook() -> Element {
         classes.join(" ")
     });
 
+    if presenting() {
+        return rsx! {
+            Presentation { slides: slides(), on_exit: move |_| presenting.set(false) }
+        };
+    }
+
     rsx! {
         div { class: "{container_class}",
+            ThumbnailStage {}
             if !(ui_settings.fullscreen)() {
                 Sidebar {
                     search_query,
+                    tag_filter,
+                    tags: tags.clone(),
                     components: filtered_components(),
                     selected,
                 }
@@ -85,48 +204,97 @@ fn Storybook() -> Element {
                                     story_title: data.story_title,
                                     render_fn: data.render_fn,
                                     prop_schema: data.prop_schema,
+                                    status: data.status,
                                 }
                             },
-                            Err(StoryPageError::ComponentNotFound(name)) => rsx! {
-                                div { class: "error", "Component not found: {name}" }
+                            Err(Error::ComponentNotFound(_) | Error::StoryNotFound { .. }) => rsx! {
+                                NotFoundPage { target: component_name, selected }
                             },
-                            Err(StoryPageError::StoryNotFound { component_name, story_index }) => {
-                                rsx! {
-                                    div { class: "error", "Story not found: index {story_index} for {component_name}" }
+                            Err(e) => rsx! {
+                                Overridable {
+                                    slot: Slot::Error,
+                                    message: e.to_string(),
+                                    div { class: "error", "{e}" }
                                 }
-                            }
+                            },
                         }
                     }
+                    Some(Selection::NotFound(target)) => rsx! {
+                        NotFoundPage { target, selected }
+                    },
+                    Some(Selection::Playlist(name)) => rsx! {
+                        PlaylistPage { key: "{name}", name, selected }
+                    },
+                    Some(Selection::Activity) => rsx! {
+                        ActivityPage { selected }
+                    },
+                    Some(Selection::SnapshotReview) => rsx! {
+                        SnapshotReviewPage { selected }
+                    },
+                    Some(Selection::Category(path)) => rsx! {
+                        CategoryGallery { key: "{path}", path, selected }
+                    },
+                    Some(Selection::Component(component_name)) => rsx! {
+                        ComponentGallery { key: "{component_name}", component_name, selected }
+                    },
                     Some(Selection::DocPage(doc_path)) => {
-                        // First try DocRegistration (from storydoc! macro)
-                        if let Some(doc) = find_doc(&doc_path) {
+                        // First try DocRegistration (from storydoc! macro), shown
+                        // as a tab of its component's docs when attached to one
+                        if let Some(component) = find_doc(&doc_path)
+                            .ok()
+                            .and_then(|doc| doc_component(doc.path))
+                        {
+                            rsx! {
+                                ComponentDocsPage {
+                                    key: "{doc_path}",
+                                    component_name: component.name.to_string(),
+                                    selected,
+                                    guide: true,
+                                }
+                            }
+                        } else if let Ok(doc) = find_doc(&doc_path) {
                             rsx! {
-                                DocPage { key: "{doc_path}", content_html: doc.content_html.to_string() }
+                                DocPage {
+                                    key: "{doc_path}",
+                                    content_html: doc.content_html.to_string(),
+                                    source: doc.source_asset,
+                                    freshness: resolve_doc_freshness(doc, &config),
+                                }
                             }
-                            // Then try component description (from doc comments)
+                            // Then try the component's auto-generated docs page
                         } else if let Some(component_name) = doc_path.strip_prefix("__component__/")
                         {
-                            match find_component(component_name) {
-                                Some(reg) if !reg.description.is_empty() => rsx! {
-                                    DocPage { key: "{doc_path}", content_html: reg.description.to_string() }
-                                },
-                                _ => rsx! {
-                                    div { class: "error", "Documentation not found: {doc_path}" }
-                                },
+                            rsx! {
+                                ComponentDocsPage {
+                                    key: "{doc_path}",
+                                    component_name: component_name.to_string(),
+                                    selected,
+                                }
                             }
                         } else {
                             rsx! {
-                                div { class: "error", "Documentation not found: {doc_path}" }
+                                NotFoundPage { target: doc_path, selected }
                             }
                         }
                     }
+                    None if onboarding => rsx! {
+                        Onboarding { selected }
+                    },
                     None => rsx! {
-                        div { class: "empty-state",
-                            h2 { "Select a story" }
-                            p { "Choose a component and story from the sidebar to preview it" }
+                        Overridable {
+                            slot: Slot::EmptyState,
+                            div { class: "empty-state",
+                                h2 { "Select a story" }
+                                p { "Choose a component and story from the sidebar to preview it" }
+                            }
                         }
                     },
                 }
+                if config.slot_override(Slot::Footer).is_some() {
+                    footer { class: "storybook-footer",
+                        Overridable { slot: Slot::Footer }
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,97 @@
+use serde::Deserialize;
+
+/// Severity of an accessibility rule violation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum A11ySeverity {
+    /// The content is unusable for some assistive technology users.
+    Critical,
+    /// The content is hard to use for some users.
+    Serious,
+}
+
+impl A11ySeverity {
+    /// Returns the severity label.
+    pub fn label(self) -> &'static str {
+        match self {
+            A11ySeverity::Critical => "Critical",
+            A11ySeverity::Serious => "Serious",
+        }
+    }
+
+    /// CSS modifier used to color the violation.
+    pub fn class(self) -> &'static str {
+        match self {
+            A11ySeverity::Critical => "critical",
+            A11ySeverity::Serious => "serious",
+        }
+    }
+}
+
+/// An accessibility rule checked by the audit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum A11yRule {
+    /// `<img>` without an `alt` attribute.
+    ImageAlt,
+    /// Form control without an associated label.
+    FormLabel,
+    /// Button without an accessible name.
+    ButtonName,
+    /// Inline text color with insufficient contrast against its background.
+    ColorContrast,
+}
+
+impl A11yRule {
+    /// Every rule the audit checks.
+    pub const ALL: [A11yRule; 4] = [
+        A11yRule::ImageAlt,
+        A11yRule::FormLabel,
+        A11yRule::ButtonName,
+        A11yRule::ColorContrast,
+    ];
+
+    /// Returns the rule label.
+    pub fn label(self) -> &'static str {
+        match self {
+            A11yRule::ImageAlt => "Missing alt text",
+            A11yRule::FormLabel => "Missing form label",
+            A11yRule::ButtonName => "Button without text",
+            A11yRule::ColorContrast => "Low color contrast",
+        }
+    }
+
+    /// Returns the severity of a violation of this rule.
+    pub fn severity(self) -> A11ySeverity {
+        match self {
+            A11yRule::ImageAlt | A11yRule::FormLabel | A11yRule::ButtonName => {
+                A11ySeverity::Critical
+            }
+            A11yRule::ColorContrast => A11ySeverity::Serious,
+        }
+    }
+}
+
+/// A single accessibility violation found in the story preview.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct A11yViolation {
+    /// CSS selector of the offending element.
+    pub selector: String,
+    pub rule: A11yRule,
+    /// Human-readable explanation (e.g. the measured contrast ratio).
+    pub detail: String,
+}
+
+/// Result of auditing the story preview for accessibility problems.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct A11yAuditReport {
+    pub violations: Vec<A11yViolation>,
+}
+
+impl A11yAuditReport {
+    /// Violations ordered by severity, most severe first.
+    pub fn sorted_violations(&self) -> Vec<&A11yViolation> {
+        let mut violations: Vec<_> = self.violations.iter().collect();
+        violations.sort_by_key(|v| v.rule.severity());
+        violations
+    }
+}
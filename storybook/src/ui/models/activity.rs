@@ -0,0 +1,9 @@
+/// How often a story was opened during the session.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StoryActivity {
+    pub component_name: String,
+    pub story_index: usize,
+    pub story_title: String,
+    /// Number of times the story was selected; `0` if it was never opened.
+    pub views: u32,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// The sidebar's favorites and recently viewed stories, persisted in the
+/// browser.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Bookmarks {
+    /// Names of the favorite components.
+    pub components: Vec<String>,
+    /// Ids (see [`story_id`](crate::story_id)) of the favorite stories.
+    pub stories: Vec<String>,
+    /// Ids of the last viewed stories, most recent first.
+    pub recent: Vec<String>,
+}
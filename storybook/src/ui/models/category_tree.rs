@@ -18,9 +18,22 @@ pub struct CategoryTreeNode {
     pub full_path: String,
     /// Whether this node has an associated doc page
     pub has_doc: bool,
+    /// Position in [`StorybookConfig::category_order`](crate::StorybookConfig::category_order),
+    /// if listed there
+    pub position: Option<usize>,
 }
 
 impl CategoryTreeNode {
+    /// Full paths of the nodes below this one, this one excluded.
+    pub fn descendant_paths(&self) -> Vec<String> {
+        self.children
+            .values()
+            .flat_map(|child| {
+                std::iter::once(child.full_path.clone()).chain(child.descendant_paths())
+            })
+            .collect()
+    }
+
     /// Insert a component at the given path
     pub(crate) fn insert(&mut self, path: &[&str], component_name: String, current_path: &str) {
         if path.is_empty() {
@@ -36,7 +49,7 @@ impl CategoryTreeNode {
                     .entry(path[0].to_string())
                     .or_insert_with(|| CategoryTreeNode {
                         full_path: new_path.clone(),
-                        has_doc: crate::find_doc(&new_path).is_some(),
+                        has_doc: crate::find_doc(&new_path).is_ok(),
                         ..Default::default()
                     });
             child.insert(&path[1..], component_name, &new_path);
@@ -62,13 +75,41 @@ impl CategoryTreeNode {
                     .entry(path[0].to_string())
                     .or_insert_with(|| CategoryTreeNode {
                         full_path: new_path.clone(),
-                        has_doc: crate::find_doc(&new_path).is_some(),
+                        has_doc: crate::find_doc(&new_path).is_ok(),
                         ..Default::default()
                     });
             child.insert_doc_path(&path[1..], &new_path);
         }
     }
 
+    /// Subcategories in sidebar order: explicitly ordered ones first, then
+    /// the others alphabetically.
+    pub fn ordered_children(&self) -> Vec<(&String, &CategoryTreeNode)> {
+        let mut children: Vec<_> = self.children.iter().collect();
+        children.sort_by_key(|(_, child)| child.position.unwrap_or(usize::MAX));
+        children
+    }
+
+    /// Components in sidebar order: those of the subcategories (in
+    /// [`ordered_children`](Self::ordered_children) order) first, then the
+    /// ones directly under this node. A component listed under several
+    /// categories appears once per category.
+    pub fn ordered_components(&self) -> Vec<&str> {
+        self.ordered_children()
+            .into_iter()
+            .flat_map(|(_, child)| child.ordered_components())
+            .chain(self.components.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Record each descendant's position in `order` (a list of full paths).
+    pub(crate) fn apply_order(&mut self, order: &[String]) {
+        for child in self.children.values_mut() {
+            child.position = order.iter().position(|path| *path == child.full_path);
+            child.apply_order(order);
+        }
+    }
+
     /// Count all components in this node and all its children recursively
     pub fn component_count(&self) -> usize {
         let direct_count = self.components.len();
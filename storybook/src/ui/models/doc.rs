@@ -5,5 +5,52 @@ pub enum DocPart {
     StoryEmbed {
         story_path: String,
         story_name: String,
+        /// JSON object overriding some of the story's props for this embed
+        /// (`@[story:…]{ … }`).
+        props_override: Option<String>,
     },
+    /// Props table of a component (`@[props:Component]`).
+    PropsTable {
+        component_name: String,
+    },
+    /// Every story of a component in a grid (`@[canvas:Component]`).
+    Canvas {
+        component_name: String,
+    },
+    /// A custom directive (`@[kind:argument]`), rendered by the handler
+    /// registered with
+    /// [`StorybookConfig::with_doc_directive`](crate::StorybookConfig::with_doc_directive).
+    Directive {
+        kind: String,
+        argument: String,
+    },
+}
+
+/// When a `storydoc!` page was last updated, and whether it lags behind the
+/// components it documents.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DocFreshness {
+    /// Date of the last change to the markdown file, as `YYYY-MM-DD`.
+    pub last_updated: String,
+    /// Set when the page is older than the configured staleness threshold
+    /// relative to one of its components.
+    pub stale: Option<DocStaleness>,
+}
+
+/// The most recently changed component of a stale doc page.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DocStaleness {
+    pub component_name: String,
+    /// Days between the doc's last update and the component's.
+    pub days_behind: u64,
+}
+
+/// A heading listed in the table of contents of a doc page.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TocEntry {
+    /// Heading level, 2 or 3.
+    pub level: u8,
+    /// DOM id of the heading, the target of its `#fragment`.
+    pub id: String,
+    pub title: String,
 }
@@ -0,0 +1,60 @@
+use serde::Deserialize;
+
+/// The kind of layout problem found by the layout audit.
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutIssueKind {
+    /// The element's content is wider than its box (`scrollWidth > clientWidth`)
+    /// and spills out visibly.
+    Overflow,
+    /// The element's box extends past the left or right edge of the viewport.
+    EscapesViewport,
+}
+
+impl LayoutIssueKind {
+    /// Returns a human-readable label for display in the audit panel.
+    pub fn label(self) -> &'static str {
+        match self {
+            LayoutIssueKind::Overflow => "Horizontal overflow",
+            LayoutIssueKind::EscapesViewport => "Escapes viewport",
+        }
+    }
+}
+
+/// A single offending element reported by the layout audit.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct LayoutIssue {
+    /// CSS selector identifying the element inside the preview document.
+    pub selector: String,
+    pub kind: LayoutIssueKind,
+    /// Measured sizes explaining the issue (e.g. "612px content in 375px box").
+    pub detail: String,
+}
+
+/// An element whose box changes when a CSS reset is applied.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct CascadeDiff {
+    /// CSS selector identifying the element inside the preview document.
+    pub selector: String,
+    /// Box size without and with the reset (e.g. "120×40 → 112×36").
+    pub detail: String,
+}
+
+/// Result of rendering the preview with and without a CSS reset.
+///
+/// A non-empty `changed` list means the component's look depends on global
+/// styles the storybook preview does not provide, so it may render
+/// differently here than in the host application.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct CascadeReport {
+    /// Outermost elements whose size differs with the reset applied.
+    pub changed: Vec<CascadeDiff>,
+}
+
+/// Result of auditing the preview document at the current viewport.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct LayoutAuditReport {
+    /// Width of the preview viewport the audit ran against, in CSS pixels.
+    pub viewport_width: f64,
+    pub issues: Vec<LayoutIssue>,
+}
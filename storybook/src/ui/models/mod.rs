@@ -1,9 +1,33 @@
+mod a11y_audit;
+mod activity;
+mod bookmarks;
 mod category_tree;
 mod doc;
+mod layout_audit;
+mod performance;
+mod play;
+mod playlist;
+mod profile;
+mod report;
 mod selection;
+mod snapshot;
+mod story_group;
+mod tag_filter;
 mod viewport;
 
+pub use a11y_audit::{A11yAuditReport, A11yRule, A11ySeverity};
+pub use activity::StoryActivity;
+pub use bookmarks::Bookmarks;
 pub use category_tree::CategoryTreeNode;
-pub use doc::DocPart;
+pub use doc::{DocFreshness, DocPart, DocStaleness, TocEntry};
+pub use layout_audit::{CascadeReport, LayoutAuditReport};
+pub use performance::{PerformanceReport, RenderStats, SLOW_RENDER_MS};
+pub use play::{PlayStatus, PlayStepOutcome, PlayStepResult, PlayStepStatus};
+pub use playlist::Playlist;
+pub use profile::ProfileInfo;
+pub use report::{TestCase, TestOutcome, TestSuite};
 pub use selection::{ComponentInfo, NodeType, Selection};
-pub use viewport::ViewportSize;
+pub use snapshot::{BaselineStatus, CapturedSnapshot, DiffSegment, PendingSnapshot};
+pub use story_group::StoryGroup;
+pub use tag_filter::TagFilter;
+pub use viewport::{Device, ViewportSize};
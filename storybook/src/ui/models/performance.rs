@@ -0,0 +1,42 @@
+use std::collections::VecDeque;
+
+/// Render durations above this take more than a frame at 60 fps.
+pub const SLOW_RENDER_MS: f64 = 16.0;
+
+/// Render timings of a story preview.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RenderStats {
+    /// Renders of the story's render root since it was mounted, whatever
+    /// triggered them.
+    pub renders: usize,
+    /// Durations of the latest renders in milliseconds, oldest first.
+    pub recent_ms: VecDeque<f64>,
+}
+
+impl RenderStats {
+    /// Duration of the latest render.
+    pub fn last_ms(&self) -> Option<f64> {
+        self.recent_ms.back().copied()
+    }
+
+    /// Average duration of the latest renders.
+    pub fn average_ms(&self) -> Option<f64> {
+        (!self.recent_ms.is_empty())
+            .then(|| self.recent_ms.iter().sum::<f64>() / self.recent_ms.len() as f64)
+    }
+
+    /// Duration of the slowest of the latest renders.
+    pub fn max_ms(&self) -> Option<f64> {
+        self.recent_ms.iter().copied().reduce(f64::max)
+    }
+}
+
+/// Measures shown by the performance panel.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PerformanceReport {
+    pub render: RenderStats,
+    /// Time taken to capture the preview's HTML, `None` when it failed.
+    pub capture_ms: Option<f64>,
+    /// Size of the captured HTML in bytes.
+    pub html_bytes: usize,
+}
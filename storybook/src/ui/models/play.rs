@@ -0,0 +1,48 @@
+use crate::PlayStep;
+use serde::Deserialize;
+
+/// Outcome of a single play step.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PlayStepStatus {
+    Passed,
+    Failed(String),
+    /// Not run because an earlier step failed.
+    Skipped,
+}
+
+/// A play step together with its outcome.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayStepResult {
+    pub step: PlayStep,
+    pub status: PlayStepStatus,
+}
+
+/// Overall status of a story's play function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlayStatus {
+    /// The play function has not run yet.
+    Idle,
+    Running,
+    Passed,
+    Failed,
+}
+
+impl PlayStatus {
+    /// CSS modifier used for status badges.
+    pub fn class(self) -> &'static str {
+        match self {
+            PlayStatus::Idle => "idle",
+            PlayStatus::Running => "running",
+            PlayStatus::Passed => "passed",
+            PlayStatus::Failed => "failed",
+        }
+    }
+}
+
+/// Result returned by a step script evaluated in the browser.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PlayStepOutcome {
+    pub ok: bool,
+    #[serde(default)]
+    pub message: Option<String>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// A named collection of story ids and documentation page paths.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Playlist {
+    pub name: String,
+    /// Story ids (see [`story_id`](crate::story_id)) and doc paths, in order.
+    pub entries: Vec<String>,
+    /// Registered with [`StorybookConfig::with_playlist`](crate::StorybookConfig::with_playlist):
+    /// read-only and not persisted.
+    #[serde(skip)]
+    pub builtin: bool,
+}
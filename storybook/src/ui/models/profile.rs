@@ -0,0 +1,12 @@
+/// A registered [`Profile`](crate::Profile), as shown in the profile picker
+/// and switcher.
+#[cfg_attr(
+    feature = "self-stories",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProfileInfo {
+    pub name: String,
+    /// Value of the `?profile=` URL parameter selecting this profile.
+    pub slug: String,
+}
@@ -0,0 +1,50 @@
+use serde::Serialize;
+
+/// Outcome of a test case of a machine-readable report.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "status", content = "message", rename_all = "snake_case")]
+pub enum TestOutcome {
+    Passed,
+    /// Failed, with the reason.
+    Failed(String),
+    /// Not run or not checked, with the reason.
+    Skipped(String),
+}
+
+/// A check of a story in a machine-readable report: a play step, an
+/// accessibility rule or a snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TestCase {
+    /// What is tested, usually the story id (JUnit `classname`).
+    pub classname: String,
+    pub name: String,
+    /// Milliseconds the check took, when measured.
+    pub time_ms: Option<f64>,
+    #[serde(flatten)]
+    pub outcome: TestOutcome,
+}
+
+/// A named set of test cases, exported as one JUnit `<testsuite>` or JSON
+/// report for CI systems.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TestSuite {
+    pub name: String,
+    pub cases: Vec<TestCase>,
+}
+
+impl TestSuite {
+    pub fn failures(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Failed(_)))
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Skipped(_)))
+    }
+
+    fn count(&self, predicate: impl Fn(&TestOutcome) -> bool) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| predicate(&case.outcome))
+            .count()
+    }
+}
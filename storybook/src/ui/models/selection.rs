@@ -6,7 +6,10 @@
 #[derive(Clone, PartialEq, Debug)]
 pub struct ComponentInfo {
     pub name: String,
-    pub category: String,
+    /// Sidebar paths the component is listed under (see `#[storybook(tags = [..])]`).
+    pub categories: Vec<String>,
+    /// Sort weight within the category (see `#[storybook(weight = ..)]`).
+    pub weight: i32,
 }
 
 /// Selection type - a story, component, or doc page
@@ -18,8 +21,21 @@ pub struct ComponentInfo {
 pub enum Selection {
     /// A specific story within a component (component_name, story_index)
     Story(String, usize),
+    /// The gallery of every story of a component
+    Component(String),
+    /// The overview of every component in a category or folder (full path)
+    Category(String),
     /// A documentation page
     DocPage(String),
+    /// The session activity page
+    Activity,
+    /// A playlist page (playlist name)
+    Playlist(String),
+    /// The review of the snapshots differing from their baselines
+    SnapshotReview,
+    /// A link to a story or page that doesn't exist (the story id or doc
+    /// path it asked for)
+    NotFound(String),
 }
 
 /// The type of node in the hierarchy tree
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+/// The HTML of a story preview, captured by a batch snapshot.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CapturedSnapshot {
+    /// Story id (see [`story_id`](crate::story_id)).
+    pub id: String,
+    pub component: String,
+    pub story: String,
+    /// Whether the preview rendered anything before it was captured.
+    pub rendered: bool,
+    /// The preview document, with absolute stylesheet links and no scripts.
+    pub html: String,
+    /// Milliseconds the preview took to render, or until it was given up on.
+    #[serde(default)]
+    pub duration_ms: f64,
+}
+
+/// How a captured snapshot compares with its approved baseline.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BaselineStatus {
+    /// No baseline was approved for the story yet.
+    New,
+    /// The snapshot differs from the baseline.
+    Changed,
+    /// The snapshot matches the baseline.
+    Unchanged,
+}
+
+impl BaselineStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            BaselineStatus::New => "new",
+            BaselineStatus::Changed => "changed",
+            BaselineStatus::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// A snapshot awaiting review because it has no baseline or differs from it.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PendingSnapshot {
+    pub snapshot: CapturedSnapshot,
+    /// The approved baseline HTML, `None` for a new story.
+    pub baseline: Option<String>,
+}
+
+impl PendingSnapshot {
+    pub fn status(&self) -> BaselineStatus {
+        if self.baseline.is_some() {
+            BaselineStatus::Changed
+        } else {
+            BaselineStatus::New
+        }
+    }
+}
+
+/// A piece of an HTML diff between a baseline and a new snapshot.
+#[derive(Clone, PartialEq, Debug)]
+pub enum DiffSegment {
+    /// Present on both sides.
+    Same(String),
+    /// Only in the baseline.
+    Removed(String),
+    /// Only in the new snapshot.
+    Added(String),
+}
@@ -0,0 +1,13 @@
+/// Stories of a component listed under the same
+/// [`in_group`](crate::Story::in_group) section.
+#[cfg_attr(
+    feature = "self-stories",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+#[derive(Clone, PartialEq, Debug)]
+pub struct StoryGroup<T = (usize, String)> {
+    /// Section name, `None` for the stories outside any group.
+    pub name: Option<String>,
+    /// The stories of the section, by default `(story_index, title)` pairs.
+    pub stories: Vec<T>,
+}
@@ -0,0 +1,46 @@
+/// Sidebar filter on story tags (see [`Story::with_tags`](crate::Story::with_tags)).
+#[cfg_attr(
+    feature = "self-stories",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema)
+)]
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum TagFilter {
+    /// Show every story.
+    #[default]
+    All,
+    /// Show only stories carrying this tag.
+    Only(String),
+    /// Hide stories carrying this tag.
+    Hide(String),
+}
+
+impl TagFilter {
+    /// Returns `true` if a story with the given tags passes the filter.
+    pub fn matches(&self, tags: &[String]) -> bool {
+        match self {
+            TagFilter::All => true,
+            TagFilter::Only(tag) => tags.contains(tag),
+            TagFilter::Hide(tag) => !tags.contains(tag),
+        }
+    }
+
+    /// Returns the `<option>` value encoding this filter.
+    pub fn value(&self) -> String {
+        match self {
+            TagFilter::All => "all".to_string(),
+            TagFilter::Only(tag) => format!("only:{tag}"),
+            TagFilter::Hide(tag) => format!("hide:{tag}"),
+        }
+    }
+
+    /// Parse an `<option>` value produced by [`value`](Self::value).
+    pub fn from_value(value: &str) -> Self {
+        if let Some(tag) = value.strip_prefix("only:") {
+            TagFilter::Only(tag.to_string())
+        } else if let Some(tag) = value.strip_prefix("hide:") {
+            TagFilter::Hide(tag.to_string())
+        } else {
+            TagFilter::All
+        }
+    }
+}
@@ -50,6 +50,16 @@ impl ViewportSize {
         }
     }
 
+    /// Returns the device whose frame is drawn around this preset, or `None`
+    /// when the preset does not emulate a physical device.
+    pub fn device_frame(self) -> Option<Device> {
+        match self {
+            ViewportSize::FullWidth => None,
+            ViewportSize::SmallMobile | ViewportSize::LargeMobile => Some(Device::Phone),
+            ViewportSize::Tablet => Some(Device::Tablet),
+        }
+    }
+
     /// All variants in display order.
     pub fn all() -> &'static [ViewportSize] {
         &[
@@ -60,3 +70,20 @@ impl ViewportSize {
         ]
     }
 }
+
+/// The physical devices whose frame can be drawn around the preview.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Device {
+    Phone,
+    Tablet,
+}
+
+impl Device {
+    /// Returns the short string used in the frame's `device-frame-*` class.
+    pub fn value(self) -> &'static str {
+        match self {
+            Device::Phone => "phone",
+            Device::Tablet => "tablet",
+        }
+    }
+}
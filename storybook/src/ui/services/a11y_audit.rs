@@ -0,0 +1,115 @@
+use crate::ui::services::iframe::SELECTOR_FOR_SCRIPT;
+
+/// Build the script that audits the story HTML in the preview iframe for
+/// basic accessibility problems.
+///
+/// Rules checked:
+///
+/// - `image_alt` — `<img>` elements without an `alt` attribute,
+/// - `form_label` — `<input>`, `<select>` and `<textarea>` elements without a
+///   `<label>`, `aria-label` or `aria-labelledby`,
+/// - `button_name` — buttons with neither text content nor an accessible name,
+/// - `color_contrast` — elements with an inline `color` whose contrast ratio
+///   against the nearest opaque background is below the WCAG AA minimum
+///   (4.5:1, or 3:1 for large text).
+///
+/// The script returns a JSON [`A11yAuditReport`](crate::ui::models::A11yAuditReport)
+/// or `null` when the iframe is not available.
+pub fn build_a11y_audit_script(iframe_id: &str) -> String {
+    format!(
+        r##"
+        var frame = document.getElementById("{iframe_id}");
+        var doc = frame && frame.contentDocument;
+        if (!doc || !doc.body) {{ return null; }}
+        var root = doc.documentElement;
+        var win = doc.defaultView;
+        var violations = [];
+
+        {SELECTOR_FOR_SCRIPT}
+
+        function report(el, rule, detail) {{
+            violations.push({{ selector: selectorFor(el), rule: rule, detail: detail }});
+        }}
+
+        function accessibleName(el) {{
+            var name = el.getAttribute("aria-label") || el.getAttribute("title") || "";
+            var labelledBy = el.getAttribute("aria-labelledby");
+            if (labelledBy) {{
+                labelledBy.split(/\s+/).forEach(function(id) {{
+                    var target = doc.getElementById(id);
+                    if (target) {{ name += target.textContent; }}
+                }});
+            }}
+            return name.trim();
+        }}
+
+        function parseColor(value) {{
+            var m = value && value.match(/rgba?\(([^)]+)\)/);
+            if (!m) {{ return null; }}
+            var parts = m[1].split(",").map(function(p) {{ return parseFloat(p); }});
+            return {{ r: parts[0], g: parts[1], b: parts[2], a: parts.length > 3 ? parts[3] : 1 }};
+        }}
+
+        function luminance(c) {{
+            var channel = function(v) {{
+                v /= 255;
+                return v <= 0.03928 ? v / 12.92 : Math.pow((v + 0.055) / 1.055, 2.4);
+            }};
+            return 0.2126 * channel(c.r) + 0.7152 * channel(c.g) + 0.0722 * channel(c.b);
+        }}
+
+        function backgroundOf(el) {{
+            while (el) {{
+                var bg = parseColor(win.getComputedStyle(el).backgroundColor);
+                if (bg && bg.a > 0) {{ return bg; }}
+                el = el.parentElement;
+            }}
+            return {{ r: 255, g: 255, b: 255, a: 1 }};
+        }}
+
+        doc.body.querySelectorAll("img").forEach(function(el) {{
+            if (!el.hasAttribute("alt") && el.getAttribute("role") !== "presentation") {{
+                report(el, "image_alt", "image has no alt attribute");
+            }}
+        }});
+
+        doc.body.querySelectorAll("input, select, textarea").forEach(function(el) {{
+            var type = (el.getAttribute("type") || "").toLowerCase();
+            if (["hidden", "submit", "reset", "button", "image"].indexOf(type) !== -1) {{ return; }}
+            var labelled = accessibleName(el) !== ""
+                || el.closest("label")
+                || (el.id && doc.querySelector('label[for="' + el.id + '"]'));
+            if (!labelled) {{
+                report(el, "form_label", el.tagName.toLowerCase() + " has no associated label");
+            }}
+        }});
+
+        doc.body.querySelectorAll("button, [role=button]").forEach(function(el) {{
+            var images = Array.prototype.some.call(el.querySelectorAll("img[alt]"), function(img) {{
+                return img.getAttribute("alt").trim() !== "";
+            }});
+            if (el.textContent.trim() === "" && accessibleName(el) === "" && !images) {{
+                report(el, "button_name", "button has no text or aria-label");
+            }}
+        }});
+
+        doc.body.querySelectorAll("[style]").forEach(function(el) {{
+            if (!el.style.color || el.textContent.trim() === "") {{ return; }}
+            var style = win.getComputedStyle(el);
+            var fg = parseColor(style.color);
+            if (!fg) {{ return; }}
+            var l1 = luminance(fg);
+            var l2 = luminance(backgroundOf(el));
+            var ratio = (Math.max(l1, l2) + 0.05) / (Math.min(l1, l2) + 0.05);
+            var size = parseFloat(style.fontSize);
+            var bold = parseInt(style.fontWeight, 10) >= 700;
+            var minimum = size >= 24 || (bold && size >= 18.66) ? 3 : 4.5;
+            if (ratio < minimum) {{
+                report(el, "color_contrast", "contrast ratio " + ratio.toFixed(2) + ":1, expected at least " + minimum + ":1");
+            }}
+        }});
+
+        return {{ violations: violations }};
+        "##
+    )
+}
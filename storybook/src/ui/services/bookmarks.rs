@@ -0,0 +1,35 @@
+//! Sidebar favorites and recently viewed stories: persistence in
+//! `localStorage`.
+
+use crate::ui::models::Bookmarks;
+use crate::ui::services::play::js_string;
+
+/// `localStorage` key holding the favorites and recent stories.
+const BOOKMARKS_KEY: &str = "storybook:bookmarks";
+
+/// Build the script returning the bookmarks saved in `localStorage` (empty
+/// ones when there are none, or when storage is unavailable).
+pub fn build_load_bookmarks_script() -> String {
+    format!(
+        r#"
+        try {{
+            var saved = JSON.parse(window.localStorage.getItem({key}) || "{{}}");
+            return saved && typeof saved === "object" && !Array.isArray(saved) ? saved : {{}};
+        }} catch (e) {{
+            return {{}};
+        }}
+        "#,
+        key = js_string(BOOKMARKS_KEY),
+    )
+}
+
+/// Build the script saving `bookmarks` to `localStorage`.
+pub fn build_save_bookmarks_script(bookmarks: &Bookmarks) -> String {
+    format!(
+        r#"
+        try {{ window.localStorage.setItem({key}, {json}); }} catch (e) {{}}
+        "#,
+        key = js_string(BOOKMARKS_KEY),
+        json = js_string(&serde_json::to_string(bookmarks).unwrap_or_else(|_| "{}".to_string())),
+    )
+}
@@ -1,26 +1,47 @@
+use crate::StorybookConfig;
 use crate::ui::models::{CategoryTreeNode, ComponentInfo};
 
 /// Build a tree structure from flat component info **and** doc registrations.
 ///
+/// Components are inserted under each of their categories, sorted by
+/// weight, then name, and categories are
+/// ordered according to [`StorybookConfig::with_category_order`]. Doc pages
+/// of categories hidden by the active [`Profile`](crate::Profile) are
+/// skipped; the root doc page is always kept.
+///
 /// After inserting every component, the builder iterates over all
 /// [`DocRegistration`](crate::DocRegistration) entries and ensures that a tree
 /// node exists for each doc path — even when no components share that path.
 /// This allows root-level (or otherwise orphan) doc pages to appear in the
-/// sidebar.
-pub fn build_category_tree(components: &[ComponentInfo]) -> CategoryTreeNode {
+/// sidebar. Doc pages attached to a component are left out: they're shown as a
+/// tab of the component's docs.
+pub fn build_category_tree(
+    components: &[ComponentInfo],
+    config: &StorybookConfig,
+) -> CategoryTreeNode {
     let mut root = CategoryTreeNode::default();
 
     // 1. Insert components
+    let mut components: Vec<&ComponentInfo> = components.iter().collect();
+    components.sort_by(|a, b| a.weight.cmp(&b.weight).then_with(|| a.name.cmp(&b.name)));
     for component in components {
-        let path_segments: Vec<&str> = component.category.split('/').collect();
-        root.insert(&path_segments, component.name.clone(), "");
+        for category in &component.categories {
+            let path_segments: Vec<&str> = category.split('/').collect();
+            root.insert(&path_segments, component.name.clone(), "");
+        }
     }
 
     // 2. Ensure tree nodes exist for every doc registration path
-    for doc in crate::get_docs() {
+    for doc in crate::get_docs()
+        .filter(|doc| doc.path.is_empty() || config.shows_tag(doc.path))
+        .filter(|doc| crate::doc_component(doc.path).is_none())
+    {
         let path_segments: Vec<&str> = doc.path.split('/').collect();
         root.insert_doc_path(&path_segments, "");
     }
 
+    // 3. Apply the explicit category order
+    root.apply_order(&config.category_order);
+
     root
 }
@@ -0,0 +1,26 @@
+/// Count the CSS classes used in captured story HTML.
+///
+/// Scans every `class="…"` attribute and returns `(class, occurrences)`
+/// pairs sorted by class name.
+pub fn extract_classes(html: &str) -> Vec<(String, usize)> {
+    let mut counts = std::collections::BTreeMap::<&str, usize>::new();
+    let mut rest = html;
+    while let Some(pos) = rest.find("class=\"") {
+        rest = &rest[pos + "class=\"".len()..];
+        let end = rest.find('"').unwrap_or(rest.len());
+        for class in rest[..end].split_whitespace() {
+            *counts.entry(class).or_default() += 1;
+        }
+        rest = &rest[end..];
+    }
+    counts
+        .into_iter()
+        .map(|(class, count)| (class.to_string(), count))
+        .collect()
+}
+
+/// Returns the variant prefix of a utility class (`"md:hover:"` for
+/// `md:hover:bg-blue-500`), or `""` for unprefixed classes.
+pub fn variant_prefix(class: &str) -> &str {
+    class.rfind(':').map_or("", |pos| &class[..=pos])
+}
@@ -1,4 +1,4 @@
-use crate::Decorator;
+use crate::{ContextFn, Decorator, watch};
 use dioxus::prelude::*;
 
 /// Apply decorators to an element.
@@ -9,3 +9,55 @@ pub fn apply_decorators(element: Element, decorators: &[Decorator]) -> Element {
         .rev()
         .fold(element, |acc, decorator| decorator(acc))
 }
+
+/// Wrap a rendered story in an error boundary, so a failing component shows
+/// its error inline with a retry button instead of taking down the
+/// storybook. Catches the errors components return or throw (panics abort
+/// the wasm preview and are only reported by the panic hook), and reports
+/// them to the watch-mode summary.
+///
+/// Styled inline: the preview documents only load the component styles.
+pub fn catch_story_errors(story: Element) -> Element {
+    rsx! {
+        ErrorBoundary {
+            handle_error: |errors: ErrorContext| rsx! {
+                StoryError { errors }
+            },
+            {story}
+        }
+    }
+}
+
+/// Error card shown in place of a story that failed to render. The error is
+/// reported when it is caught, not on every render of the card.
+#[component]
+fn StoryError(errors: ErrorContext) -> Element {
+    let message = errors
+        .error()
+        .map(|error| error.to_string())
+        .unwrap_or_default();
+    use_effect(use_reactive((&message,), |(message,)| {
+        watch::render_error(&message)
+    }));
+
+    rsx! {
+        div {
+            class: "story-error",
+            role: "alert",
+            style: "margin: 8px; padding: 12px; border: 1px solid #e53935; border-radius: 4px; background: #fdecea; color: #b71c1c; font: 13px sans-serif;",
+            strong { "This story failed to render" }
+            pre { style: "margin: 8px 0; white-space: pre-wrap; font-size: 12px;",
+                "{message}"
+            }
+            button { onclick: move |_| errors.clear_errors(), "Retry" }
+        }
+    }
+}
+
+/// Provide a story's contexts to the current scope, before the story mounts.
+/// Called once, from a `use_hook`.
+pub fn provide_contexts(contexts: &[ContextFn]) {
+    for context in contexts {
+        context.provide();
+    }
+}
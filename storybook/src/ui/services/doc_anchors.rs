@@ -0,0 +1,141 @@
+//! Doc page anchors: copying links to a section, following `@[prop:…]`
+//! chips to their props table row, table of contents links to their
+//! section and `@[link:…]`/`@[doc:…]` links to their page, and scrolling to
+//! the `#heading` fragment of the page URL.
+
+/// Attribute marking the document once the anchor click handler is installed.
+const ANCHORS_READY_ATTRIBUTE: &str = "data-storybook-anchors";
+
+/// How long a copied anchor shows its `copied` state, and a props table row
+/// reached from a prop chip its `highlighted` state, in milliseconds.
+const COPIED_FEEDBACK_MS: u32 = 1500;
+
+/// Distance from the top of the viewport, in pixels, above which a heading
+/// counts as the current section of the table of contents.
+const TOC_ACTIVE_OFFSET_PX: u32 = 96;
+
+/// DOM id of the row of `field` in the props table of `component_name`, the
+/// target of `@[prop:Component.field]` chips (see
+/// `storybook_markdown::prop_row_id`).
+pub fn prop_row_id(component_name: &str, field: &str) -> String {
+    format!("prop-{component_name}-{field}")
+}
+
+/// Build the script installing (once) the click handler of the
+/// `.heading-anchor` links — which point the page URL at the heading, scroll
+/// to it and copy the link to the clipboard — and of the `.prop-chip` links,
+/// which scroll to their props table row when it is on the page (and open
+/// the component's docs page otherwise), and of the `.doc-toc-link` links,
+/// which point the page URL at their heading and scroll to it; then
+/// scrolling to the heading or row named by the URL fragment, if any.
+pub fn build_heading_anchors_script() -> String {
+    format!(
+        r#"
+        var root = document.documentElement;
+        function highlight(row) {{
+            row.scrollIntoView({{ behavior: "smooth", block: "center" }});
+            row.classList.add("highlighted");
+            setTimeout(function() {{ row.classList.remove("highlighted"); }}, {copied_ms});
+        }}
+        if (!root.hasAttribute("{ready}")) {{
+            root.setAttribute("{ready}", "");
+            document.addEventListener("click", function(event) {{
+                var chip = event.target.closest && event.target.closest(".prop-chip");
+                var row = chip && document.getElementById(chip.getAttribute("data-prop-row"));
+                if (row) {{
+                    event.preventDefault();
+                    highlight(row);
+                    return;
+                }}
+                var tocLink = event.target.closest && event.target.closest(".doc-toc-link");
+                if (tocLink) {{
+                    event.preventDefault();
+                    var section = document.getElementById(tocLink.getAttribute("href").slice(1));
+                    if (!section) {{ return; }}
+                    var sectionUrl = new URL(window.location.href);
+                    sectionUrl.hash = tocLink.getAttribute("href");
+                    window.history.replaceState(null, "", sectionUrl);
+                    section.scrollIntoView({{ behavior: "smooth", block: "start" }});
+                    return;
+                }}
+                var anchor = event.target.closest && event.target.closest(".heading-anchor");
+                if (!anchor) {{ return; }}
+                event.preventDefault();
+                var url = new URL(window.location.href);
+                url.hash = anchor.getAttribute("href");
+                window.history.replaceState(null, "", url);
+                anchor.parentElement.scrollIntoView({{ behavior: "smooth", block: "start" }});
+                try {{ navigator.clipboard.writeText(url.toString()); }} catch (e) {{}}
+                anchor.classList.add("copied");
+                setTimeout(function() {{ anchor.classList.remove("copied"); }}, {copied_ms});
+            }});
+        }}
+        var id = decodeURIComponent(window.location.hash.slice(1));
+        if (id) {{
+            setTimeout(function() {{
+                var target = document.getElementById(id);
+                if (!target) {{ return; }}
+                if (target.classList.contains("prop-row")) {{
+                    highlight(target);
+                }} else {{
+                    target.scrollIntoView({{ block: "start" }});
+                }}
+            }}, 50);
+        }}
+        "#,
+        ready = ANCHORS_READY_ATTRIBUTE,
+        copied_ms = COPIED_FEEDBACK_MS,
+    )
+}
+
+/// Build the script keeping the `active` class of the table of contents on
+/// the link of the section being read: the last heading scrolled above the
+/// top of the viewport, or the first one. Replaces the listener of the
+/// previously shown table of contents.
+pub fn build_toc_sync_script() -> String {
+    format!(
+        r#"
+        if (window.__storybookTocSync) {{
+            document.removeEventListener("scroll", window.__storybookTocSync, true);
+        }}
+        function sync() {{
+            var links = document.querySelectorAll(".doc-toc-link");
+            var current = null;
+            links.forEach(function(link) {{
+                var heading = document.getElementById(link.getAttribute("href").slice(1));
+                if (heading && (current === null || heading.getBoundingClientRect().top <= {offset})) {{
+                    current = link;
+                }}
+            }});
+            links.forEach(function(link) {{ link.classList.toggle("active", link === current); }});
+        }}
+        window.__storybookTocSync = sync;
+        document.addEventListener("scroll", sync, {{ capture: true, passive: true }});
+        setTimeout(sync, 50);
+        "#,
+        offset = TOC_ACTIVE_OFFSET_PX,
+    )
+}
+
+/// Build the long-running script sending (with `dioxus.send`) the target of
+/// every `.storybook-link` clicked — `["component", name]` or
+/// `["doc", path]` — instead of following its `href`. Clicks opening a new
+/// tab or window are left to the browser.
+pub fn build_listen_doc_links_script() -> String {
+    r#"
+    document.addEventListener("click", function(event) {
+        var link = event.target.closest && event.target.closest(".storybook-link");
+        if (!link || event.button !== 0 || event.metaKey || event.ctrlKey || event.shiftKey || event.altKey) {
+            return;
+        }
+        event.preventDefault();
+        if (link.hasAttribute("data-component")) {
+            dioxus.send(["component", link.getAttribute("data-component")]);
+        } else {
+            dioxus.send(["doc", link.getAttribute("data-doc")]);
+        }
+    });
+    await new Promise(function() {});
+    "#
+    .to_string()
+}
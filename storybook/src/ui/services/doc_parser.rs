@@ -1,13 +1,22 @@
 use crate::ui::models::DocPart;
 
-/// Parse documentation content and extract story embed markers.
+/// Parse documentation content and extract embed markers.
 ///
-/// Story embeds are marked as: `<div class="storybook-embed" data-story-path="..." data-story-name="..."></div>`
+/// Embeds are marked by the markdown preprocessor as:
+///
+/// - `<div class="storybook-embed" data-story-path="..." data-story-name="..." data-props="..."></div>`
+///   (`data-props` is optional)
+/// - `<div class="storybook-props" data-component="..."></div>`
+/// - `<div class="storybook-canvas" data-component="..."></div>`
+/// - `<div class="storybook-directive" data-kind="..." data-argument="..."></div>`
+///
+/// Other `<div>`s, including ones with a different `storybook-` class, stay
+/// part of the surrounding HTML.
 pub fn parse_doc_content(content: &str) -> Vec<DocPart> {
     let mut parts = Vec::new();
     let mut remaining = content;
 
-    while let Some(start_idx) = remaining.find(r#"<div class="storybook-embed""#) {
+    while let Some(start_idx) = find_marker(remaining) {
         // Add HTML before the embed marker
         if start_idx > 0 {
             parts.push(DocPart::Html(remaining[..start_idx].to_string()));
@@ -17,16 +26,9 @@ pub fn parse_doc_content(content: &str) -> Vec<DocPart> {
         if let Some(end_idx) = remaining[start_idx..].find("</div>") {
             let embed_div = &remaining[start_idx..start_idx + end_idx + 6];
 
-            // Extract story path and name from data attributes
-            if let (Some(path), Some(name)) = (
-                extract_attr(embed_div, "data-story-path"),
-                extract_attr(embed_div, "data-story-name"),
-            ) {
-                parts.push(DocPart::StoryEmbed {
-                    story_path: path,
-                    story_name: name,
-                });
-            }
+            parts.push(
+                parse_embed(embed_div).unwrap_or_else(|| DocPart::Html(embed_div.to_string())),
+            );
 
             remaining = &remaining[start_idx + end_idx + 6..];
         } else {
@@ -42,6 +44,45 @@ pub fn parse_doc_content(content: &str) -> Vec<DocPart> {
     parts
 }
 
+/// Classes of the marker `<div>`s written by the markdown preprocessor.
+const MARKER_CLASSES: [&str; 4] = [
+    "storybook-embed",
+    "storybook-props",
+    "storybook-canvas",
+    "storybook-directive",
+];
+
+/// Byte offset of the first embed marker in `content`.
+fn find_marker(content: &str) -> Option<usize> {
+    MARKER_CLASSES
+        .iter()
+        .filter_map(|class| content.find(&format!(r#"<div class="{class}""#)))
+        .min()
+}
+
+/// Turn a single embed marker `<div>` into the matching [`DocPart`].
+fn parse_embed(embed_div: &str) -> Option<DocPart> {
+    match extract_attr(embed_div, "class")?.as_str() {
+        "storybook-embed" => Some(DocPart::StoryEmbed {
+            story_path: extract_attr(embed_div, "data-story-path")?,
+            story_name: extract_attr(embed_div, "data-story-name")?,
+            props_override: extract_attr(embed_div, "data-props")
+                .map(|props| unescape_attribute(&props)),
+        }),
+        "storybook-props" => Some(DocPart::PropsTable {
+            component_name: extract_attr(embed_div, "data-component")?,
+        }),
+        "storybook-canvas" => Some(DocPart::Canvas {
+            component_name: extract_attr(embed_div, "data-component")?,
+        }),
+        "storybook-directive" => Some(DocPart::Directive {
+            kind: extract_attr(embed_div, "data-kind")?,
+            argument: unescape_attribute(&extract_attr(embed_div, "data-argument")?),
+        }),
+        _ => None,
+    }
+}
+
 /// Extract an attribute value from an HTML element string.
 fn extract_attr(element: &str, attr_name: &str) -> Option<String> {
     let pattern = format!(r#"{}=""#, attr_name);
@@ -53,3 +94,46 @@ fn extract_attr(element: &str, attr_name: &str) -> Option<String> {
     }
     None
 }
+
+/// Undo the escaping of an attribute value written by the markdown
+/// preprocessor.
+fn unescape_attribute(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_generated_markers() {
+        let parts = parse_doc_content(concat!(
+            "<p>Intro</p>",
+            r#"<div class="storybook-props" data-component="Button"></div>"#,
+            "<p>Outro</p>",
+        ));
+        assert_eq!(parts.len(), 3);
+        assert!(
+            matches!(&parts[1], DocPart::PropsTable { component_name } if component_name == "Button")
+        );
+    }
+
+    #[test]
+    fn keeps_other_storybook_classes_as_html() {
+        let content = r#"<div class="storybook-note">Keep me</div><p>After</p>"#;
+        let parts = parse_doc_content(content);
+        assert_eq!(parts.len(), 1);
+        assert!(matches!(&parts[0], DocPart::Html(html) if html == content));
+    }
+
+    #[test]
+    fn keeps_malformed_markers_as_html() {
+        let content = r#"<div class="storybook-canvas"></div>"#;
+        let parts = parse_doc_content(content);
+        assert!(matches!(&parts[..], [DocPart::Html(html)] if html == content));
+    }
+}
@@ -0,0 +1,71 @@
+//! Table of contents of doc pages, built from the ids the markdown pipeline
+//! gives their headings.
+
+use crate::ui::models::TocEntry;
+
+/// Heading levels listed in the table of contents; the `h1` is the page
+/// title.
+const TOC_LEVELS: &[char] = &['2', '3'];
+
+/// The `h2` and `h3` headings of `html` that have an id, in order.
+pub fn extract_toc(html: &str) -> Vec<TocEntry> {
+    let mut entries = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find("<h") {
+        rest = &rest[start + 2..];
+        let Some(level) = rest.chars().next().filter(|c| TOC_LEVELS.contains(c)) else {
+            continue;
+        };
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let close = format!("</h{level}>");
+        let Some(end) = rest.find(&close) else {
+            break;
+        };
+        let attributes = &rest[..tag_end];
+        if let Some(id) = attribute(attributes, "id") {
+            let inner = &rest[tag_end + 1..end];
+            let inner = inner
+                .find("<a class=\"heading-anchor\"")
+                .map_or(inner, |anchor| &inner[..anchor]);
+            entries.push(TocEntry {
+                level: if level == '2' { 2 } else { 3 },
+                id: id.to_string(),
+                title: text_content(inner),
+            });
+        }
+        rest = &rest[end + close.len()..];
+    }
+    entries
+}
+
+/// The value of the double-quoted attribute `name` among `attributes`.
+fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(" {name}=\"");
+    let start = attributes.find(&pattern)? + pattern.len();
+    let end = attributes[start..].find('"')?;
+    Some(&attributes[start..start + end])
+}
+
+/// The text of `html`, tags removed and common character references
+/// decoded.
+fn text_content(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
@@ -0,0 +1,39 @@
+//! Fuzzy matching of names, for "did you mean" suggestions.
+
+/// Lowercase `text` and drop its separators, so `Example Button`,
+/// `example-button` and `example_button` compare equal.
+pub fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Levenshtein distance between `a` and `b`, in characters.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// How far `candidate` is from `query`, both [`normalize`]d, or `None` when
+/// they are too different to be suggested: the candidate must contain the
+/// query (or the reverse), or be within a third of its length in edits.
+pub fn fuzzy_distance(query: &str, candidate: &str) -> Option<usize> {
+    if query.is_empty() || candidate.is_empty() {
+        return None;
+    }
+    if candidate.contains(query) || query.contains(candidate) {
+        return Some(candidate.len().abs_diff(query.len()) / 4);
+    }
+    let distance = edit_distance(query, candidate);
+    (distance <= (query.chars().count() / 3).max(2)).then_some(distance)
+}
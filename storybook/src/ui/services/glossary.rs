@@ -0,0 +1,121 @@
+//! Glossary terms: linking their occurrences in doc page HTML to a
+//! definition popover.
+
+use crate::GlossaryTerm;
+use std::collections::HashSet;
+
+/// Elements whose text is never linked: links, code, headings and the
+/// already linked terms.
+const SKIPPED_TAGS: &[&str] = &[
+    "a", "code", "pre", "h1", "h2", "h3", "h4", "h5", "h6", "script", "style", "button",
+];
+
+/// Wrap the first occurrence of each of `terms` in the text of `html` —
+/// matched as a whole word, ignoring ASCII case — in a `.glossary-term`
+/// showing its definition on hover or focus.
+///
+/// Terms already in `linked` are skipped, and the linked ones are added to
+/// it, so a term is only linked once across the parts of a page.
+pub fn link_glossary_terms(
+    html: &str,
+    terms: &[&'static GlossaryTerm],
+    linked: &mut HashSet<&'static str>,
+) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut skipped: Vec<String> = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let end = if rest.starts_with('<') {
+            let end = rest.find('>').map_or(rest.len(), |index| index + 1);
+            track_skipped_tag(&rest[..end], &mut skipped);
+            output.push_str(&rest[..end]);
+            end
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            if skipped.is_empty() {
+                output.push_str(&link_text(&rest[..end], terms, linked));
+            } else {
+                output.push_str(&rest[..end]);
+            }
+            end
+        };
+        rest = &rest[end..];
+    }
+    output
+}
+
+/// Push the opening tags of [`SKIPPED_TAGS`] on `skipped`, pop their
+/// closing tags.
+fn track_skipped_tag(tag: &str, skipped: &mut Vec<String>) {
+    let inner = tag.trim_start_matches('<');
+    let (closing, inner) = match inner.strip_prefix('/') {
+        Some(inner) => (true, inner),
+        None => (false, inner),
+    };
+    let name: String = inner
+        .chars()
+        .take_while(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if !SKIPPED_TAGS.contains(&name.as_str()) {
+        return;
+    }
+    if closing {
+        if skipped.last() == Some(&name) {
+            skipped.pop();
+        }
+    } else if !tag.ends_with("/>") {
+        skipped.push(name);
+    }
+}
+
+fn link_text(
+    text: &str,
+    terms: &[&'static GlossaryTerm],
+    linked: &mut HashSet<&'static str>,
+) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    loop {
+        // The earliest match, the longest term first when several start there
+        let found = terms
+            .iter()
+            .filter(|term| !linked.contains(term.term))
+            .filter_map(|term| Some((find_word(rest, term.term)?, *term)))
+            .min_by_key(|(index, term)| (*index, std::cmp::Reverse(term.term.len())));
+        let Some((index, term)) = found else {
+            output.push_str(rest);
+            return output;
+        };
+        let end = index + term.term.len();
+        output.push_str(&rest[..index]);
+        output.push_str(&format!(
+            r#"<span class="glossary-term" tabindex="0">{text}<span class="glossary-definition" role="tooltip"><strong>{term}</strong> {definition}</span></span>"#,
+            text = &rest[index..end],
+            term = term.term,
+            definition = term.definition_html,
+        ));
+        linked.insert(term.term);
+        rest = &rest[end..];
+    }
+}
+
+/// Byte index of the first whole-word occurrence of `word` in `text`,
+/// ignoring ASCII case.
+fn find_word(text: &str, word: &str) -> Option<usize> {
+    let last = text.len().checked_sub(word.len())?;
+    (0..=last).find(|&index| {
+        let end = index + word.len();
+        text.is_char_boundary(index)
+            && text.is_char_boundary(end)
+            && text[index..end].eq_ignore_ascii_case(word)
+            && !text[..index]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+            && !text[end..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric)
+    })
+}
@@ -0,0 +1,737 @@
+//! Semantic diffs of captured HTML, for reviewing snapshots against their
+//! baselines and comparing stories.
+//!
+//! Both sides are parsed into a tree and normalized before being compared,
+//! so that changes which don't affect the rendered markup are not reported:
+//! attribute order, whitespace, class order, style declaration order,
+//! comments, Dioxus' hydration attributes and generated ids.
+
+use crate::ui::models::DiffSegment;
+use std::collections::HashMap;
+
+/// Above this many node pairs, a list of children is not searched for common
+/// nodes and is reported as entirely removed and added.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// Elements without content or closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elements whose content is text, not markup.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+/// Attributes added by the renderer rather than by the component.
+const IGNORED_ATTRIBUTES: &[&str] = &["data-dioxus-id", "data-node-hydration"];
+
+/// Prefix of the ids generated by the Dioxus component primitives.
+const GENERATED_ID_PREFIX: &str = "dxc-";
+
+/// Attributes holding space-separated references to element ids.
+const ID_REFERENCE_ATTRIBUTES: &[&str] = &[
+    "for",
+    "form",
+    "list",
+    "headers",
+    "aria-activedescendant",
+    "aria-controls",
+    "aria-describedby",
+    "aria-details",
+    "aria-errormessage",
+    "aria-labelledby",
+    "aria-owns",
+];
+
+/// A node of parsed HTML.
+#[derive(Clone, PartialEq, Debug)]
+enum Node {
+    Element {
+        tag: String,
+        /// Sorted by name once normalized.
+        attributes: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+    Text(String),
+}
+
+/// An element being parsed, waiting for its closing tag.
+struct OpenElement {
+    tag: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+impl OpenElement {
+    fn into_node(self) -> Node {
+        Node::Element {
+            tag: self.tag,
+            attributes: self.attributes,
+            children: self.children,
+        }
+    }
+}
+
+/// Decode the character references rendered markup commonly contains.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+/// Parse the inside of a start tag (`rest` follows its `<`). Returns the
+/// tag name, its attributes, whether it is self-closing and the remaining
+/// input.
+fn parse_start_tag(rest: &str) -> (String, Vec<(String, String)>, bool, &str) {
+    let name_end = rest
+        .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        .unwrap_or(rest.len());
+    let tag = rest[..name_end].to_ascii_lowercase();
+    let mut rest = &rest[name_end..];
+    let mut attributes = Vec::new();
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("/>") {
+            return (tag, attributes, true, after);
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            return (tag, attributes, false, after);
+        }
+        if rest.is_empty() {
+            return (tag, attributes, false, rest);
+        }
+        let name_end = rest
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        if name_end == 0 {
+            // A stray `/` or `=`
+            rest = &rest[1..];
+            continue;
+        }
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            let (raw, remaining) = match after.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let inner = &after[1..];
+                    let end = inner.find(quote).unwrap_or(inner.len());
+                    (&inner[..end], inner.get(end + 1..).unwrap_or_default())
+                }
+                _ => {
+                    let end = after
+                        .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                        .unwrap_or(after.len());
+                    (&after[..end], &after[end..])
+                }
+            };
+            value = decode_entities(raw);
+            rest = remaining;
+        }
+        attributes.push((name, value));
+    }
+}
+
+/// Parse `html` leniently, the way rendered markup is written: unknown
+/// closing tags are ignored and unclosed elements end with their parent.
+fn parse(html: &str) -> Vec<Node> {
+    let mut stack = vec![OpenElement {
+        tag: String::new(),
+        attributes: Vec::new(),
+        children: Vec::new(),
+    }];
+    let close = |stack: &mut Vec<OpenElement>| {
+        if let Some(element) = stack.pop()
+            && let Some(parent) = stack.last_mut()
+        {
+            parent.children.push(element.into_node());
+        }
+    };
+
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            // Doctype and processing instructions
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(after) = rest.strip_prefix("</") {
+            let end = after.find('>').unwrap_or(after.len());
+            let tag = after[..end].trim().to_ascii_lowercase();
+            rest = after.get(end + 1..).unwrap_or_default();
+            if let Some(depth) = stack.iter().rposition(|open| open.tag == tag)
+                && depth > 0
+            {
+                while stack.len() > depth {
+                    close(&mut stack);
+                }
+            }
+        } else if rest.len() > 1
+            && rest.starts_with('<')
+            && rest.as_bytes()[1].is_ascii_alphabetic()
+        {
+            let (tag, attributes, self_closing, after) = parse_start_tag(&rest[1..]);
+            rest = after;
+            let mut element = OpenElement {
+                tag,
+                attributes,
+                children: Vec::new(),
+            };
+            if RAW_TEXT_ELEMENTS.contains(&element.tag.as_str()) && !self_closing {
+                // ASCII lowercasing keeps byte offsets
+                let end = rest
+                    .to_ascii_lowercase()
+                    .find(&format!("</{}", element.tag))
+                    .unwrap_or(rest.len());
+                if end > 0 {
+                    element
+                        .children
+                        .push(Node::Text(decode_entities(&rest[..end])));
+                }
+                rest = &rest[end..];
+                rest = rest.find('>').map_or("", |close| &rest[close + 1..]);
+                stack.last_mut().unwrap().children.push(element.into_node());
+            } else if self_closing || VOID_ELEMENTS.contains(&element.tag.as_str()) {
+                stack.last_mut().unwrap().children.push(element.into_node());
+            } else {
+                stack.push(element);
+            }
+        } else {
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[first..]
+                .find('<')
+                .map_or(rest.len(), |index| index + first);
+            let text = decode_entities(&rest[..end]);
+            stack.last_mut().unwrap().children.push(Node::Text(text));
+            rest = &rest[end..];
+        }
+    }
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+    stack.pop().map(|root| root.children).unwrap_or_default()
+}
+
+/// Collapse runs of HTML whitespace (not non-breaking spaces) into a single
+/// space and trim the ends.
+fn collapse_whitespace(text: &str) -> String {
+    text.split(|c: char| c.is_ascii_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether `id` was generated rather than chosen by the component: the
+/// `dxc-<n>` ids of `use_unique_id` from the Dioxus component primitives,
+/// numbered in render order.
+fn is_generated_id(id: &str) -> bool {
+    id.strip_prefix(GENERATED_ID_PREFIX)
+        .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Number the generated ids of `nodes` in document order.
+fn collect_generated_ids(nodes: &[Node], ids: &mut HashMap<String, String>) {
+    for node in nodes {
+        if let Node::Element {
+            attributes,
+            children,
+            ..
+        } = node
+        {
+            for (name, value) in attributes {
+                if name == "id" && is_generated_id(value) && !ids.contains_key(value) {
+                    let renamed = format!("generated-{}", ids.len() + 1);
+                    ids.insert(value.clone(), renamed);
+                }
+            }
+            collect_generated_ids(children, ids);
+        }
+    }
+}
+
+/// Normalize an attribute value so equivalent values compare equal.
+fn normalize_attribute(name: &str, value: &str, ids: &HashMap<String, String>) -> String {
+    let rename = |token: &str| ids.get(token).cloned().unwrap_or_else(|| token.to_string());
+    match name {
+        "class" => {
+            let mut classes: Vec<&str> = value.split_ascii_whitespace().collect();
+            classes.sort_unstable();
+            classes.dedup();
+            classes.join(" ")
+        }
+        "style" => {
+            let mut declarations: Vec<String> = value
+                .split(';')
+                .filter_map(|declaration| {
+                    let (property, value) = declaration.split_once(':')?;
+                    Some(format!(
+                        "{}: {}",
+                        property.trim().to_ascii_lowercase(),
+                        collapse_whitespace(value)
+                    ))
+                })
+                .collect();
+            declarations.sort();
+            declarations.join("; ")
+        }
+        "id" => rename(value),
+        "href" => match value.strip_prefix('#') {
+            Some(target) => format!("#{}", rename(target)),
+            None => value.to_string(),
+        },
+        name if ID_REFERENCE_ATTRIBUTES.contains(&name) => value
+            .split_ascii_whitespace()
+            .map(rename)
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => value.to_string(),
+    }
+}
+
+/// Normalize `nodes`: collapse whitespace, drop blank text, drop and sort
+/// attributes, and rename generated ids.
+fn normalize(nodes: Vec<Node>, ids: &HashMap<String, String>) -> Vec<Node> {
+    nodes
+        .into_iter()
+        .filter_map(|node| match node {
+            Node::Text(text) => {
+                let text = collapse_whitespace(&text);
+                (!text.is_empty()).then_some(Node::Text(text))
+            }
+            Node::Element {
+                tag,
+                attributes,
+                children,
+            } => {
+                let mut attributes: Vec<(String, String)> = attributes
+                    .into_iter()
+                    .filter(|(name, _)| !IGNORED_ATTRIBUTES.contains(&name.as_str()))
+                    .map(|(name, value)| {
+                        let value = normalize_attribute(&name, &value, ids);
+                        (name, value)
+                    })
+                    .collect();
+                attributes.sort();
+                attributes.dedup_by(|a, b| a.0 == b.0);
+                Some(Node::Element {
+                    tag,
+                    attributes,
+                    children: normalize(children, ids),
+                })
+            }
+        })
+        .collect()
+}
+
+/// Parse and normalize `html`.
+fn parse_normalized(html: &str) -> Vec<Node> {
+    let nodes = parse(html);
+    let mut ids = HashMap::new();
+    collect_generated_ids(&nodes, &mut ids);
+    normalize(nodes, &ids)
+}
+
+/// Whether `before` and `after` render the same markup once normalized.
+pub fn html_equivalent(before: &str, after: &str) -> bool {
+    before == after || parse_normalized(before) == parse_normalized(after)
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;")
+}
+
+fn escape_attribute(value: &str) -> String {
+    value.replace('&', "&amp;").replace('"', "&quot;")
+}
+
+fn start_tag(tag: &str, attributes: &[(String, String)]) -> String {
+    let mut html = format!("<{tag}");
+    for (name, value) in attributes {
+        if value.is_empty() {
+            html.push_str(&format!(" {name}"));
+        } else {
+            html.push_str(&format!(" {name}=\"{}\"", escape_attribute(value)));
+        }
+    }
+    html.push('>');
+    html
+}
+
+/// Whether `children` fit on the line of their element.
+fn is_inline(children: &[Node]) -> bool {
+    matches!(children, [] | [Node::Text(_)])
+}
+
+/// Write `node` as indented lines, one tag or text per line.
+fn render(node: &Node, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    match node {
+        Node::Text(text) => out.push_str(&format!("{indent}{}\n", escape_text(text))),
+        Node::Element {
+            tag,
+            attributes,
+            children,
+        } => {
+            let start = start_tag(tag, attributes);
+            if VOID_ELEMENTS.contains(&tag.as_str()) {
+                out.push_str(&format!("{indent}{start}\n"));
+            } else if let [Node::Text(text)] = children.as_slice() {
+                out.push_str(&format!("{indent}{start}{}</{tag}>\n", escape_text(text)));
+            } else if children.is_empty() {
+                out.push_str(&format!("{indent}{start}</{tag}>\n"));
+            } else {
+                out.push_str(&format!("{indent}{start}\n"));
+                for child in children {
+                    render(child, depth + 1, out);
+                }
+                out.push_str(&format!("{indent}</{tag}>\n"));
+            }
+        }
+    }
+}
+
+/// Diff segments, merging consecutive segments of the same kind.
+#[derive(Default)]
+struct Segments(Vec<DiffSegment>);
+
+impl Segments {
+    fn push(&mut self, segment: DiffSegment) {
+        match (self.0.last_mut(), segment) {
+            (Some(DiffSegment::Same(last)), DiffSegment::Same(text))
+            | (Some(DiffSegment::Removed(last)), DiffSegment::Removed(text))
+            | (Some(DiffSegment::Added(last)), DiffSegment::Added(text)) => last.push_str(&text),
+            (_, segment) => self.0.push(segment),
+        }
+    }
+
+    fn node(&mut self, node: &Node, depth: usize, kind: fn(String) -> DiffSegment) {
+        let mut html = String::new();
+        render(node, depth, &mut html);
+        self.push(kind(html));
+    }
+}
+
+/// Whether `old` and `new` are the same element, possibly changed.
+fn same_element(old: &Node, new: &Node) -> bool {
+    matches!(
+        (old, new),
+        (Node::Element { tag: old, .. }, Node::Element { tag: new, .. }) if old == new
+    )
+}
+
+/// Diff two versions of an element: its start tag, then its children.
+fn diff_element(old: &Node, new: &Node, depth: usize, out: &mut Segments) {
+    let (
+        Node::Element {
+            tag,
+            attributes: old_attributes,
+            children: old_children,
+        },
+        Node::Element {
+            attributes: new_attributes,
+            children: new_children,
+            ..
+        },
+    ) = (old, new)
+    else {
+        return;
+    };
+    if is_inline(old_children) || is_inline(new_children) || VOID_ELEMENTS.contains(&tag.as_str()) {
+        out.node(old, depth, DiffSegment::Removed);
+        out.node(new, depth, DiffSegment::Added);
+        return;
+    }
+    let indent = "  ".repeat(depth);
+    if old_attributes == new_attributes {
+        out.push(DiffSegment::Same(format!(
+            "{indent}{}\n",
+            start_tag(tag, old_attributes)
+        )));
+    } else {
+        out.push(DiffSegment::Removed(format!(
+            "{indent}{}\n",
+            start_tag(tag, old_attributes)
+        )));
+        out.push(DiffSegment::Added(format!(
+            "{indent}{}\n",
+            start_tag(tag, new_attributes)
+        )));
+    }
+    diff_nodes(old_children, new_children, depth + 1, out);
+    out.push(DiffSegment::Same(format!("{indent}</{tag}>\n")));
+}
+
+/// Report the unmatched `removed` and `added` nodes between two common
+/// ones, descending into the elements present on both sides.
+fn diff_unmatched(removed: &[Node], added: &[Node], depth: usize, out: &mut Segments) {
+    let (mut removed, mut added) = (removed, added);
+    while let (Some(old), Some(new)) = (removed.first(), added.first()) {
+        if !same_element(old, new) {
+            break;
+        }
+        diff_element(old, new, depth, out);
+        removed = &removed[1..];
+        added = &added[1..];
+    }
+    for node in removed {
+        out.node(node, depth, DiffSegment::Removed);
+    }
+    for node in added {
+        out.node(node, depth, DiffSegment::Added);
+    }
+}
+
+/// Diff two lists of sibling nodes (longest common subsequence).
+fn diff_nodes(old: &[Node], new: &[Node], depth: usize, out: &mut Segments) {
+    if old.len().saturating_mul(new.len()) > MAX_DIFF_CELLS {
+        diff_unmatched(old, new, depth, out);
+        return;
+    }
+
+    // lengths[i][j]: length of the common subsequence of old[i..] and new[j..]
+    let mut lengths = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let (mut removed_from, mut added_from) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            diff_unmatched(&old[removed_from..i], &new[added_from..j], depth, out);
+            out.node(&old[i], depth, DiffSegment::Same);
+            i += 1;
+            j += 1;
+            removed_from = i;
+            added_from = j;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    diff_unmatched(&old[removed_from..], &new[added_from..], depth, out);
+}
+
+/// Diff `before` and `after` node by node after normalizing both. The
+/// segments are the normalized markup, one tag or text per line.
+pub fn diff_html(before: &str, after: &str) -> Vec<DiffSegment> {
+    let mut out = Segments::default();
+    diff_nodes(
+        &parse_normalized(before),
+        &parse_normalized(after),
+        0,
+        &mut out,
+    );
+    out.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element(tag: &str, attributes: &[(&str, &str)], children: Vec<Node>) -> Node {
+        Node::Element {
+            tag: tag.to_string(),
+            attributes: attributes
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            children,
+        }
+    }
+
+    fn text(text: &str) -> Node {
+        Node::Text(text.to_string())
+    }
+
+    fn removed(segments: &[DiffSegment]) -> String {
+        segments
+            .iter()
+            .filter_map(|segment| match segment {
+                DiffSegment::Removed(html) => Some(html.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn added(segments: &[DiffSegment]) -> String {
+        segments
+            .iter()
+            .filter_map(|segment| match segment {
+                DiffSegment::Added(html) => Some(html.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_start_tag_attributes() {
+        let (tag, attributes, self_closing, rest) =
+            parse_start_tag(r#"INPUT type=text value='a &amp; b' disabled/>after"#);
+        assert_eq!(tag, "input");
+        assert_eq!(
+            attributes,
+            vec![
+                ("type".to_string(), "text".to_string()),
+                ("value".to_string(), "a & b".to_string()),
+                ("disabled".to_string(), String::new()),
+            ]
+        );
+        assert!(self_closing);
+        assert_eq!(rest, "after");
+    }
+
+    #[test]
+    fn parses_void_and_raw_text_elements() {
+        let nodes = parse("<p>a<br>b</p><style>p > a { color: red }</style><img src=x.png>");
+        assert_eq!(
+            nodes,
+            vec![
+                element(
+                    "p",
+                    &[],
+                    vec![text("a"), element("br", &[], vec![]), text("b")]
+                ),
+                element("style", &[], vec![text("p > a { color: red }")]),
+                element("img", &[("src", "x.png")], vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn closes_unclosed_elements_with_their_parent() {
+        let nodes = parse("<ul><li>one<li>two</ul></span><p>end");
+        assert_eq!(
+            nodes,
+            vec![
+                element(
+                    "ul",
+                    &[],
+                    vec![element(
+                        "li",
+                        &[],
+                        vec![text("one"), element("li", &[], vec![text("two")])]
+                    )]
+                ),
+                element("p", &[], vec![text("end")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_attribute_class_and_style_order() {
+        assert!(html_equivalent(
+            r#"<div class="b a" id="main" style="color: red; margin: 0">x</div>"#,
+            r#"<div style="margin:0;color:red" id="main" class="a  b">x</div>"#,
+        ));
+    }
+
+    #[test]
+    fn ignores_comments_whitespace_and_hydration_attributes() {
+        assert!(html_equivalent(
+            r#"<div data-dioxus-id="3"><!--placeholder--><span data-node-hydration="1">Hello   world</span></div>"#,
+            "<div>\n  <span>Hello world</span>\n</div>",
+        ));
+    }
+
+    #[test]
+    fn renames_generated_ids_and_their_references() {
+        assert!(html_equivalent(
+            r#"<label for="dxc-12">Name</label><input id="dxc-12" aria-describedby="dxc-13"><p id="dxc-13">Required</p>"#,
+            r#"<label for="dxc-40">Name</label><input id="dxc-40" aria-describedby="dxc-41"><p id="dxc-41">Required</p>"#,
+        ));
+        // A reference to another element than before is a real change
+        assert!(!html_equivalent(
+            r#"<label for="dxc-1">A</label><input id="dxc-1"><input id="dxc-2">"#,
+            r#"<label for="dxc-2">A</label><input id="dxc-1"><input id="dxc-2">"#,
+        ));
+    }
+
+    #[test]
+    fn keeps_chosen_ids() {
+        assert!(!html_equivalent(
+            r#"<div id="header"></div>"#,
+            r#"<div id="footer"></div>"#,
+        ));
+        // Ids with digits or colons that the component chose
+        assert!(!html_equivalent(
+            r#"<h2 id="step-1"></h2><h2 id="step-2"></h2>"#,
+            r#"<h2 id="step-2"></h2><h2 id="step-1"></h2>"#,
+        ));
+        assert!(!html_equivalent(
+            r#"<p id="a:1"></p>"#,
+            r#"<p id="a:2"></p>"#
+        ));
+        assert!(!html_equivalent(
+            r#"<p id="dxc-"></p>"#,
+            r#"<p id="dxc-x"></p>"#
+        ));
+    }
+
+    #[test]
+    fn reports_text_changes() {
+        let segments = diff_html(
+            "<ul><li>One</li><li>Two</li></ul>",
+            "<ul><li>One</li><li>Three</li></ul>",
+        );
+        assert!(segments.contains(&DiffSegment::Same("<ul>\n  <li>One</li>\n".to_string())));
+        assert_eq!(removed(&segments), "  <li>Two</li>\n");
+        assert_eq!(added(&segments), "  <li>Three</li>\n");
+    }
+
+    #[test]
+    fn reports_attribute_changes() {
+        let segments = diff_html(
+            r#"<div class="card"><p>Body</p><p>Footer</p></div>"#,
+            r#"<div class="card active"><p>Body</p><p>Footer</p></div>"#,
+        );
+        assert_eq!(removed(&segments), "<div class=\"card\">\n");
+        assert_eq!(added(&segments), "<div class=\"active card\">\n");
+        assert!(segments.iter().any(
+            |segment| matches!(segment, DiffSegment::Same(html) if html.contains("<p>Body</p>"))
+        ));
+    }
+
+    #[test]
+    fn equivalent_markup_has_no_changes() {
+        let segments = diff_html(
+            r#"<div class="b a"><span>x</span></div>"#,
+            r#"<div class="a b"><!-- --><span>x</span></div>"#,
+        );
+        assert!(removed(&segments).is_empty());
+        assert!(added(&segments).is_empty());
+    }
+
+    #[test]
+    fn large_child_lists_skip_the_common_subsequence_search() {
+        let items = |count: usize, last: &str| {
+            format!("<ul>{}<li>{last}</li></ul>", "<li>a</li>".repeat(count - 1))
+        };
+
+        let segments = diff_html(&items(100, "a"), &items(100, "b"));
+        assert_eq!(removed(&segments), "  <li>a</li>\n");
+        assert_eq!(added(&segments), "  <li>b</li>\n");
+
+        // Past MAX_DIFF_CELLS, even the unchanged children are reported as
+        // removed and added
+        let segments = diff_html(&items(2001, "a"), &items(2001, "b"));
+        assert_eq!(removed(&segments).matches("<li>a</li>").count(), 2001);
+        assert_eq!(added(&segments).matches("<li>a</li>").count(), 2000);
+        assert_eq!(added(&segments).matches("<li>b</li>").count(), 1);
+    }
+}
@@ -1,15 +1,77 @@
-use crate::StorybookConfig;
+use crate::{
+    CategoryConfig, Decorator, Layout, PreviewIsolation, Reset, StorybookConfig, find_component,
+};
 
-/// Build the CSS `<link>` tags for component stylesheets.
-pub fn build_css_links(config: &StorybookConfig) -> String {
+/// Resolve the [`CategoryConfig`] applying to a registered component.
+pub fn category_config_for(config: &StorybookConfig, component_name: &str) -> CategoryConfig {
+    let category = find_component(component_name)
+        .map(|reg| reg.primary_tag())
+        .unwrap_or_default();
+    config.category_config(category)
+}
+
+/// Decorators wrapping a story: the category's decorators outermost, then
+/// the story's own.
+pub fn story_decorators(
+    category: &CategoryConfig,
+    story_decorators: &[Decorator],
+) -> Vec<Decorator> {
+    category
+        .decorators
+        .iter()
+        .chain(story_decorators)
+        .copied()
+        .collect()
+}
+
+/// Build the CSS `<link>` tags for the Tailwind, component, dark-theme
+/// (when `dark` is set) and category stylesheets.
+pub fn build_css_links(config: &StorybookConfig, category: &CategoryConfig, dark: bool) -> String {
+    let dark_css = if dark {
+        config.dark_css.as_slice()
+    } else {
+        &[]
+    };
     config
-        .component_css
+        .tailwind_css
         .iter()
+        .chain(config.component_css.iter())
+        .chain(dark_css.iter())
+        .chain(category.css.iter())
         .map(|css| format!(r#"<link rel="stylesheet" href="{}">"#, css))
         .collect::<Vec<_>>()
         .join("\n    ")
 }
 
+/// URL of the CSS reset previews can load: the one configured with
+/// [`StorybookConfig::with_css_reset`], the bundled normalize.css otherwise.
+pub fn reset_href(config: &StorybookConfig) -> String {
+    config
+        .css_reset
+        .as_ref()
+        .unwrap_or(&Reset::Normalize)
+        .href()
+}
+
+/// Build the `<link>` tag loading the CSS reset (see [`reset_href`]) if
+/// `enabled`.
+pub fn build_reset_link(config: &StorybookConfig, enabled: bool) -> String {
+    if enabled {
+        format!(
+            r#"<link id="{CSS_RESET_ID}" rel="stylesheet" href="{}">"#,
+            reset_href(config)
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// Build the custom `<head>` HTML registered with
+/// [`StorybookConfig::with_head_html`] and [`StorybookConfig::with_font`].
+pub fn build_head_html(config: &StorybookConfig) -> String {
+    config.head_html.join("\n    ")
+}
+
 /// Build the outline CSS rule if outlines are enabled.
 pub fn build_outline_css(enabled: bool) -> &'static str {
     if enabled {
@@ -19,6 +81,87 @@ pub fn build_outline_css(enabled: bool) -> &'static str {
     }
 }
 
+/// Script injected into previews when the measure tool is enabled.
+///
+/// On hover it overlays the element's margin (orange), padding (green) and
+/// content box (blue), with a label showing the element's size and its
+/// margin / padding values.
+const MEASURE_SCRIPT: &str = r#"<script>
+(function() {
+    var layer = document.createElement("div");
+    layer.style.cssText = "position:fixed;inset:0;pointer-events:none;z-index:2147483647;";
+    function box(color) {
+        var el = document.createElement("div");
+        el.style.cssText = "position:fixed;box-sizing:border-box;background:" + color + ";";
+        layer.appendChild(el);
+        return el;
+    }
+    var margin = box("rgba(246,178,107,0.5)");
+    var padding = box("rgba(147,196,125,0.55)");
+    var content = box("rgba(111,168,220,0.55)");
+    var label = document.createElement("div");
+    label.style.cssText = "position:fixed;padding:2px 6px;border-radius:3px;background:#333;color:#fff;font:11px/1.4 monospace;white-space:nowrap;";
+    layer.appendChild(label);
+
+    function place(el, left, top, width, height) {
+        el.style.left = left + "px";
+        el.style.top = top + "px";
+        el.style.width = Math.max(width, 0) + "px";
+        el.style.height = Math.max(height, 0) + "px";
+    }
+    function sides(style, prop) {
+        return ["Top", "Right", "Bottom", "Left"].map(function(side) {
+            return parseFloat(style[prop + side + (prop === "border" ? "Width" : "")]) || 0;
+        });
+    }
+    function round(values) {
+        return values.map(function(v) { return Math.round(v); }).join(" ");
+    }
+
+    document.addEventListener("mouseover", function(event) {
+        var el = event.target;
+        if (el === document.documentElement || el === document.body) {
+            layer.style.display = "none";
+            return;
+        }
+        if (!layer.parentNode) { document.body.appendChild(layer); }
+        layer.style.display = "block";
+        var rect = el.getBoundingClientRect();
+        var style = getComputedStyle(el);
+        var m = sides(style, "margin"), p = sides(style, "padding"), b = sides(style, "border");
+        place(margin, rect.left - m[3], rect.top - m[0], rect.width + m[1] + m[3], rect.height + m[0] + m[2]);
+        place(padding, rect.left + b[3], rect.top + b[0], rect.width - b[1] - b[3], rect.height - b[0] - b[2]);
+        place(content, rect.left + b[3] + p[3], rect.top + b[0] + p[0],
+            rect.width - b[1] - b[3] - p[1] - p[3], rect.height - b[0] - b[2] - p[0] - p[2]);
+        label.textContent = Math.round(rect.width) + " × " + Math.round(rect.height)
+            + "  margin " + round(m) + "  padding " + round(p);
+        label.style.left = Math.max(rect.left, 0) + "px";
+        label.style.top = (rect.bottom + m[2] + 4) + "px";
+    });
+    document.addEventListener("mouseleave", function() { layer.style.display = "none"; });
+})();
+</script>"#;
+
+/// Build the measure tool script if the measure tool is enabled.
+pub fn build_measure_script(enabled: bool) -> &'static str {
+    if enabled { MEASURE_SCRIPT } else { "" }
+}
+
+/// Build the CSS placing the story in the preview body according to its
+/// [`Layout`]; padded stories keep the default body padding.
+pub fn build_layout_css(layout: Layout) -> &'static str {
+    match layout {
+        Layout::Centered => concat!(
+            "body { ",
+            "display: flex; align-items: center; justify-content: center; ",
+            "min-height: 100vh; box-sizing: border-box; ",
+            "}"
+        ),
+        Layout::Fullscreen => "body { padding: 0; }",
+        Layout::Padded => "",
+    }
+}
+
 /// Build the grid overlay CSS applied to the iframe body when the grid is enabled.
 pub fn build_grid_css(enabled: bool) -> &'static str {
     if enabled {
@@ -49,45 +192,272 @@ pub fn build_zoom_css(zoom_level: i32) -> String {
     }
 }
 
+/// Background color of the preview iframe body.
+///
+/// `light` overrides the default light background (see
+/// [`CategoryConfig::background`]); the dark background is fixed.
+pub fn preview_background(dark: bool, light: Option<&str>) -> &str {
+    match (dark, light) {
+        (true, _) => "#1e1e1e",
+        (false, Some(light)) => light,
+        (false, None) => "#ffffff",
+    }
+}
+
+/// Value of the `data-theme` attribute set on the preview `<html>` element,
+/// so component CSS can target `[data-theme="dark"]`.
+pub fn preview_theme(dark: bool) -> &'static str {
+    if dark { "dark" } else { "light" }
+}
+
+/// Text direction of the preview body: `rtl` when the right-to-left toggle
+/// is on, so bidirectional layouts can be checked without changing the
+/// components.
+pub fn preview_direction(rtl: bool) -> &'static str {
+    if rtl { "rtl" } else { "ltr" }
+}
+
+/// DOM id of the `<link>` element loading the CSS reset in previews.
+pub const CSS_RESET_ID: &str = "storybook-css-reset";
+
+/// The pieces a preview iframe's srcdoc is assembled from.
+pub struct SrcdocParts<'a> {
+    /// `<link>` tags for component stylesheets.
+    pub css_links: &'a str,
+    /// `<link>` tag for the CSS reset, see [`build_reset_link`]; loaded
+    /// first.
+    pub reset_link: &'a str,
+    /// Custom `<head>` HTML (fonts, icon CDNs, ...), after the stylesheets.
+    pub head_html: &'a str,
+    pub outline_css: &'a str,
+    /// Placement of the story, see [`build_layout_css`].
+    pub layout_css: &'a str,
+    pub grid_css: &'a str,
+    pub zoom_css: &'a str,
+    /// Document body, usually the [`LIVE_ROOT_HTML`](super::live_mount::LIVE_ROOT_HTML)
+    /// mount point of the story.
+    pub body_html: &'a str,
+    /// `<script>` tags appended after the story HTML (e.g. the measure tool).
+    pub scripts: &'a str,
+    pub background_color: &'a str,
+    /// Value of the `data-theme` attribute on `<html>`.
+    pub theme: &'a str,
+    /// Value of the `dir` attribute on `<body>`, see [`preview_direction`].
+    pub direction: &'a str,
+}
+
 /// Build the full srcdoc HTML for an iframe preview.
-pub fn build_srcdoc(
-    css_links: &str,
-    outline_css: &str,
-    grid_css: &str,
-    zoom_css: &str,
-    body_html: &str,
-    background_color: &str,
-) -> String {
+pub fn build_srcdoc(parts: &SrcdocParts<'_>) -> String {
+    let SrcdocParts {
+        css_links,
+        reset_link,
+        head_html,
+        outline_css,
+        layout_css,
+        grid_css,
+        zoom_css,
+        body_html,
+        scripts,
+        background_color,
+        theme,
+        direction,
+    } = parts;
     format!(
         r#"<!DOCTYPE html>
-<html>
+<html data-theme="{theme}">
 <head>
+    {reset_link}
     {css_links}
+    {head_html}
     <style>
         body {{ margin: 0; padding: 16px; background: {background_color}; }}
+        {layout_css}
         {outline_css}
         {grid_css}
         {zoom_css}
     </style>
 </head>
-<body>
+<body dir="{direction}">
     {body_html}
+    {scripts}
 </body>
 </html>"#
     )
 }
 
-/// Capture the innerHTML from a hidden render container via web_sys.
-pub fn capture_inner_html(container_id: &str) -> Option<String> {
-    use web_sys::window;
-    let window = window()?;
-    let document = window.document()?;
-    let container = document.get_element_by_id(container_id)?;
-    Some(container.inner_html())
+/// Build the preview document matching `isolation`: the iframe srcdoc,
+/// passed through the [`StorybookConfig::with_srcdoc_transform`] transforms,
+/// the shadow root content, or nothing for inline previews.
+pub fn build_preview_document(
+    config: &StorybookConfig,
+    isolation: PreviewIsolation,
+    parts: &SrcdocParts<'_>,
+) -> String {
+    match isolation {
+        PreviewIsolation::Iframe => config
+            .srcdoc_transforms
+            .iter()
+            .fold(build_srcdoc(parts), |srcdoc, transform| transform(srcdoc)),
+        PreviewIsolation::ShadowDom => build_shadow_html(parts),
+        PreviewIsolation::Inline => String::new(),
+    }
+}
+
+/// Class of the element standing in for `<body>` inside a shadow root.
+pub(crate) const SHADOW_BODY_CLASS: &str = "storybook-shadow-body";
+
+/// Build the content of a preview shadow root (see
+/// [`PreviewIsolation::ShadowDom`](crate::PreviewIsolation::ShadowDom)).
+///
+/// Mirrors [`build_srcdoc`]: a wrapper `<div>` stands in for `<body>` and
+/// `<html>` (it carries the background and the `data-theme` and `dir`
+/// attributes), and
+/// the overlay rules go to a `<style data-adopt>` turned into an adopted
+/// stylesheet. Scripts are not run inside shadow roots, so `parts.scripts`
+/// is ignored.
+pub fn build_shadow_html(parts: &SrcdocParts<'_>) -> String {
+    let SrcdocParts {
+        css_links,
+        reset_link,
+        head_html,
+        outline_css,
+        layout_css,
+        grid_css,
+        zoom_css,
+        body_html,
+        background_color,
+        theme,
+        direction,
+        ..
+    } = parts;
+    // The layout, grid and zoom rules target `body`, which doesn't exist in
+    // a shadow root.
+    let body = format!(".{SHADOW_BODY_CLASS}");
+    let layout_css = scope_to_shadow_body(layout_css);
+    let grid_css = scope_to_shadow_body(grid_css);
+    let zoom_css = scope_to_shadow_body(zoom_css);
+    format!(
+        r#"{reset_link}
+{css_links}
+{head_html}
+<style data-adopt>
+    :host {{ display: block; }}
+    {body} {{ padding: 16px; background: {background_color}; }}
+    {layout_css}
+    {outline_css}
+    {grid_css}
+    {zoom_css}
+</style>
+<div class="{SHADOW_BODY_CLASS}" data-theme="{theme}" dir="{direction}">
+    {body_html}
+</div>"#
+    )
+}
+
+/// Rewrite the `html`, `body` and `:root` selectors of `css` to the element
+/// standing in for them inside a shadow root ([`SHADOW_BODY_CLASS`]).
+///
+/// Only selectors are rewritten: declarations and at-rule preludes are left
+/// as is, and rules nested in at-rules (e.g. `@media`) are rewritten too.
+fn scope_to_shadow_body(css: &str) -> String {
+    let mut scoped = String::with_capacity(css.len());
+    let mut prelude = String::new();
+    // Whether each open block holds rules (the top level, `@media`, ...)
+    // rather than declarations
+    let mut holds_rules = vec![true];
+    for c in css.chars() {
+        match c {
+            '{' => {
+                let is_rule = holds_rules.last().copied().unwrap_or(false);
+                let is_at_rule = prelude.trim_start().starts_with('@');
+                if is_rule && !is_at_rule {
+                    scoped.push_str(&scope_selectors(&prelude));
+                } else {
+                    scoped.push_str(&prelude);
+                }
+                prelude.clear();
+                scoped.push(c);
+                holds_rules.push(is_rule && is_at_rule);
+            }
+            '}' | ';' => {
+                scoped.push_str(&prelude);
+                prelude.clear();
+                scoped.push(c);
+                if c == '}' && holds_rules.len() > 1 {
+                    holds_rules.pop();
+                }
+            }
+            _ => prelude.push(c),
+        }
+    }
+    scoped.push_str(&prelude);
+    scoped
 }
 
-/// Generate a unique container ID for HTML capture.
-pub fn make_container_id(prefix: &str, component_name: &str, story_index: usize) -> String {
+/// Replace the `html`, `body` and `:root` selectors of a selector list by
+/// the [`SHADOW_BODY_CLASS`] selector, leaving e.g. `.body` or `tbody` alone.
+fn scope_selectors(selectors: &str) -> String {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_';
+    let mut scoped = String::with_capacity(selectors.len());
+    let mut rest = selectors;
+    while !rest.is_empty() {
+        let previous = scoped.chars().next_back();
+        let matched = [":root", "html", "body"].into_iter().find(|name| {
+            rest.starts_with(name)
+                && !rest[name.len()..].starts_with(is_name_char)
+                && !previous.is_some_and(|c| {
+                    is_name_char(c) || (!name.starts_with(':') && matches!(c, '.' | '#' | ':'))
+                })
+        });
+        match matched {
+            Some(name) => {
+                scoped.push('.');
+                scoped.push_str(SHADOW_BODY_CLASS);
+                rest = &rest[name.len()..];
+            }
+            None => {
+                let c = rest.chars().next().unwrap_or_default();
+                scoped.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    scoped
+}
+
+/// JavaScript helper `selectorFor(el)` building a readable CSS selector
+/// for an element of the preview document, used in audit reports.
+///
+/// Expects `doc` (the iframe document) and `root` (its `<html>` element)
+/// to be in scope.
+pub const SELECTOR_FOR_SCRIPT: &str = r##"function selectorFor(el) {
+            var parts = [];
+            while (el && el !== doc.body && el !== root) {
+                var part = el.tagName.toLowerCase();
+                if (el.id) {
+                    parts.unshift(part + "#" + el.id);
+                    break;
+                }
+                if (typeof el.className === "string" && el.className.trim()) {
+                    part += "." + el.className.trim().split(/\s+/).join(".");
+                }
+                var parent = el.parentElement;
+                if (parent) {
+                    var siblings = Array.prototype.filter.call(parent.children, function(s) {
+                        return s.tagName === el.tagName;
+                    });
+                    if (siblings.length > 1) {
+                        part += ":nth-of-type(" + (siblings.indexOf(el) + 1) + ")";
+                    }
+                }
+                parts.unshift(part);
+                el = parent;
+            }
+            return ["body"].concat(parts).join(" > ");
+        }"##;
+
+/// Generate a unique DOM id for a story's preview iframe.
+pub fn make_iframe_id(prefix: &str, component_name: &str, story_index: usize) -> String {
     format!(
         "{}-{}-story-{}",
         prefix,
@@ -95,3 +465,56 @@ pub fn make_container_id(prefix: &str, component_name: &str, story_index: usize)
         story_index
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scope_to_shadow_body_rewrites_body_selectors() {
+        assert_eq!(
+            scope_to_shadow_body("body { zoom: 2; }"),
+            ".storybook-shadow-body { zoom: 2; }"
+        );
+        assert_eq!(
+            scope_to_shadow_body("html, :root > body.dark { color: red; }"),
+            ".storybook-shadow-body, .storybook-shadow-body > .storybook-shadow-body.dark { color: red; }"
+        );
+    }
+
+    #[test]
+    fn scope_to_shadow_body_keeps_other_selectors() {
+        let css = ".body, #body, tbody, body-text, a:not(.x) { content: \"body\"; }";
+        assert_eq!(scope_to_shadow_body(css), css);
+    }
+
+    #[test]
+    fn scope_to_shadow_body_rewrites_rules_nested_in_at_rules() {
+        assert_eq!(
+            scope_to_shadow_body("@media (min-width: 600px) { body { padding: 0; } }"),
+            "@media (min-width: 600px) { .storybook-shadow-body { padding: 0; } }"
+        );
+    }
+
+    #[test]
+    fn build_shadow_html_scopes_the_layout_to_the_shadow_body() {
+        let parts = SrcdocParts {
+            css_links: "",
+            reset_link: "",
+            head_html: "",
+            outline_css: "",
+            layout_css: build_layout_css(Layout::Fullscreen),
+            grid_css: build_grid_css(true),
+            zoom_css: &build_zoom_css(150),
+            body_html: "",
+            background_color: "#fff",
+            theme: "light",
+            direction: "ltr",
+            scripts: "",
+        };
+        let html = build_shadow_html(&parts);
+        assert!(html.contains(".storybook-shadow-body { padding: 0; }"));
+        assert!(html.contains(".storybook-shadow-body { zoom: 1.5; }"));
+        assert!(html.contains(".storybook-shadow-body { background-size"));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::ui::services::iframe::{CSS_RESET_ID, SELECTOR_FOR_SCRIPT};
+
+/// Build the script that audits the preview iframe for horizontal overflow.
+///
+/// The preview iframes use `srcdoc`, so they share the storybook's origin and
+/// the script can inspect `contentDocument` directly. It reports:
+///
+/// - the document itself when it scrolls horizontally,
+/// - elements whose content is wider than their box while `overflow-x` is
+///   `visible` (the content visibly spills out),
+/// - elements whose box extends past the left or right viewport edge.
+///
+/// Only the outermost offending element of each subtree is reported, so a
+/// single too-wide container does not flood the panel with its descendants.
+/// The script returns a JSON [`LayoutAuditReport`](crate::ui::models::LayoutAuditReport)
+/// or `null` when the iframe is not available.
+pub fn build_layout_audit_script(iframe_id: &str) -> String {
+    format!(
+        r##"
+        var frame = document.getElementById("{iframe_id}");
+        var doc = frame && frame.contentDocument;
+        if (!doc || !doc.body) {{ return null; }}
+        var root = doc.documentElement;
+        var viewportWidth = root.clientWidth;
+        var issues = [];
+        var reported = [];
+
+        {SELECTOR_FOR_SCRIPT}
+
+        function insideReported(el) {{
+            return reported.some(function(r) {{ return r !== el && r.contains(el); }});
+        }}
+
+        if (root.scrollWidth > viewportWidth) {{
+            issues.push({{
+                selector: "html",
+                kind: "overflow",
+                detail: "document is " + root.scrollWidth + "px wide in a " + viewportWidth + "px viewport"
+            }});
+        }}
+
+        doc.body.querySelectorAll("*").forEach(function(el) {{
+            if (insideReported(el)) {{ return; }}
+            var style = doc.defaultView.getComputedStyle(el);
+            if (style.display === "none" || style.position === "fixed") {{ return; }}
+            var rect = el.getBoundingClientRect();
+            if (rect.right > viewportWidth + 1 || rect.left < -1) {{
+                reported.push(el);
+                issues.push({{
+                    selector: selectorFor(el),
+                    kind: "escapes_viewport",
+                    detail: "spans " + Math.round(rect.left) + "px to " + Math.round(rect.right) + "px of a " + viewportWidth + "px viewport"
+                }});
+            }} else if (el.scrollWidth > el.clientWidth + 1 && el.clientWidth > 0 && style.overflowX === "visible") {{
+                reported.push(el);
+                issues.push({{
+                    selector: selectorFor(el),
+                    kind: "overflow",
+                    detail: el.scrollWidth + "px of content in a " + el.clientWidth + "px box"
+                }});
+            }}
+        }});
+
+        return {{ viewport_width: viewportWidth, issues: issues }};
+        "##
+    )
+}
+
+/// Build the script that checks whether the preview depends on a CSS reset.
+///
+/// The script measures every element of the preview document with and
+/// without the reset stylesheet at `reset_href` — disabling the reset
+/// `<link>` when the preview loads it, or temporarily inserting its rules
+/// otherwise — and reports the outermost elements whose size changes. Both
+/// layouts are computed synchronously, so nothing visibly flickers. The
+/// script returns a JSON [`CascadeReport`](crate::ui::models::CascadeReport)
+/// or `null` when the iframe or the reset is not available.
+pub fn build_cascade_check_script(iframe_id: &str, reset_href: &str) -> String {
+    let reset_href = serde_json::to_string(reset_href).unwrap_or_default();
+    format!(
+        r##"
+        var frame = document.getElementById("{iframe_id}");
+        var doc = frame && frame.contentDocument;
+        if (!doc || !doc.body) {{ return null; }}
+        var root = doc.documentElement;
+        var elements = Array.prototype.slice.call(doc.body.querySelectorAll("*"));
+
+        {SELECTOR_FOR_SCRIPT}
+
+        function measure() {{
+            return elements.map(function(el) {{ return el.getBoundingClientRect(); }});
+        }}
+
+        var resetLink = doc.getElementById("{CSS_RESET_ID}");
+        var withReset, withoutReset;
+        if (resetLink && resetLink.sheet) {{
+            withReset = measure();
+            resetLink.disabled = true;
+            withoutReset = measure();
+            resetLink.disabled = false;
+        }} else {{
+            var resetCss;
+            try {{
+                var response = await fetch({reset_href});
+                if (!response.ok) {{ return null; }}
+                resetCss = await response.text();
+            }} catch (e) {{
+                return null;
+            }}
+            withoutReset = measure();
+            var temp = doc.createElement("style");
+            temp.textContent = resetCss;
+            doc.head.insertBefore(temp, doc.head.firstChild);
+            withReset = measure();
+            temp.remove();
+        }}
+
+        var changed = [];
+        var reported = [];
+        elements.forEach(function(el, i) {{
+            var a = withoutReset[i], b = withReset[i];
+            if (Math.abs(a.width - b.width) < 0.5 && Math.abs(a.height - b.height) < 0.5) {{ return; }}
+            if (reported.some(function(r) {{ return r.contains(el); }})) {{ return; }}
+            reported.push(el);
+            changed.push({{
+                selector: selectorFor(el),
+                detail: Math.round(a.width) + "×" + Math.round(a.height) + " → " + Math.round(b.width) + "×" + Math.round(b.height)
+            }});
+        }});
+
+        return {{ changed: changed }};
+        "##
+    )
+}
@@ -0,0 +1,207 @@
+//! Live story mounting.
+//!
+//! Each preview hosts its story as a nested Dioxus virtual DOM rendered into
+//! an isolated tree — the document of a preview iframe, or a shadow root
+//! (see [`PreviewIsolation`](crate::PreviewIsolation)) — so the story keeps
+//! its event handlers while its CSS stays isolated from the storybook UI.
+//!
+//! Mounting relies on `dioxus-web`, so it is only available on the web;
+//! native targets render stories inline (see
+//! [`PreviewIsolation::Inline`](crate::PreviewIsolation::Inline)).
+
+#[cfg(target_family = "wasm")]
+use crate::ui::services::decorators::{apply_decorators, catch_story_errors, provide_contexts};
+#[cfg(target_family = "wasm")]
+use crate::ui::services::performance::use_render_timing;
+use crate::ui::services::play::js_string;
+#[cfg(target_family = "wasm")]
+use crate::{ContextFn, Decorator, Globals, RenderFn};
+#[cfg(target_family = "wasm")]
+use dioxus::core::Task;
+#[cfg(target_family = "wasm")]
+use dioxus::prelude::*;
+#[cfg(target_family = "wasm")]
+use futures_channel::mpsc::{UnboundedReceiver, UnboundedSender, unbounded};
+#[cfg(target_family = "wasm")]
+use futures_util::StreamExt;
+#[cfg(target_family = "wasm")]
+use std::cell::RefCell;
+#[cfg(target_family = "wasm")]
+use std::rc::Rc;
+#[cfg(target_family = "wasm")]
+use web_sys::wasm_bindgen::JsCast;
+
+/// DOM id of the element stories are mounted into, inside the preview
+/// document or shadow root.
+pub const LIVE_ROOT_ID: &str = "storybook-root";
+
+/// Body of a preview document: the mount point of the story.
+pub const LIVE_ROOT_HTML: &str = r#"<div id="storybook-root"></div>"#;
+
+/// A story mounted into a preview.
+#[cfg(target_family = "wasm")]
+pub struct LiveMount {
+    /// Sends edited props JSON to the mounted story.
+    pub updates: UnboundedSender<String>,
+    /// Sends the globals picked in the top bar to the mounted story.
+    pub globals_updates: UnboundedSender<Globals>,
+    /// Task driving the nested virtual DOM; cancelling it unmounts the story.
+    pub task: Task,
+}
+
+/// Root props of a story's nested virtual DOM.
+#[cfg(target_family = "wasm")]
+#[derive(Clone)]
+struct LiveStoryProps {
+    preview_id: String,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: String,
+    updates: Rc<RefCell<Option<UnboundedReceiver<String>>>>,
+    globals: Globals,
+    globals_updates: Rc<RefCell<Option<UnboundedReceiver<Globals>>>>,
+}
+
+/// Root component of a story's nested virtual DOM: renders the story and
+/// re-renders it whenever new props arrive from the storybook. Provides the
+/// storybook's globals and the story's contexts to the story and its
+/// decorators.
+#[cfg(target_family = "wasm")]
+#[allow(non_snake_case)]
+fn LiveStory(props: LiveStoryProps) -> Element {
+    use_hook(|| provide_contexts(&props.contexts));
+    let mut props_json = use_signal(|| props.props_json.clone());
+    let updates = props.updates.clone();
+    use_future(move || {
+        let updates = updates.borrow_mut().take();
+        async move {
+            let Some(mut updates) = updates else {
+                return;
+            };
+            while let Some(json) = updates.next().await {
+                props_json.set(json);
+            }
+        }
+    });
+    use_render_timing(&props.preview_id);
+
+    let mut globals = use_context_provider(|| Signal::new(props.globals.clone()));
+    let globals_updates = props.globals_updates.clone();
+    use_future(move || {
+        let updates = globals_updates.borrow_mut().take();
+        async move {
+            let Some(mut updates) = updates else {
+                return;
+            };
+            while let Some(values) = updates.next().await {
+                globals.set(values);
+            }
+        }
+    });
+
+    catch_story_errors(apply_decorators(
+        (props.render_fn.0)(&props_json()),
+        &props.decorators,
+    ))
+}
+
+/// Mount a story into the live root of the preview `preview_id` (an iframe
+/// or a shadow host).
+///
+/// The nested virtual DOM runs in a task of the calling scope, so it is
+/// dropped together with that scope. Returns `None` when the preview has no
+/// live root (e.g. the iframe has not loaded yet).
+#[cfg(target_family = "wasm")]
+pub fn mount_live_story(
+    preview_id: &str,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: String,
+    globals: Globals,
+) -> Option<LiveMount> {
+    let root = live_root(preview_id)?;
+    let (updates, receiver) = unbounded();
+    let (globals_updates, globals_receiver) = unbounded();
+    let dom = VirtualDom::new_with_props(
+        LiveStory,
+        LiveStoryProps {
+            preview_id: preview_id.to_string(),
+            render_fn,
+            decorators,
+            contexts,
+            props_json,
+            updates: Rc::new(RefCell::new(Some(receiver))),
+            globals,
+            globals_updates: Rc::new(RefCell::new(Some(globals_receiver))),
+        },
+    );
+    let task = spawn(async move {
+        dioxus_web::run(dom, dioxus_web::Config::new().rootelement(root)).await;
+    });
+    Some(LiveMount {
+        updates,
+        globals_updates,
+        task,
+    })
+}
+
+/// Build the script returning the HTML currently rendered by the story in
+/// the preview `preview_id` (an iframe, a shadow host, or an inline
+/// container), or an empty string.
+pub fn build_capture_script(preview_id: &str) -> String {
+    format!(
+        r#"
+        var preview = document.getElementById({preview_id});
+        var doc = preview && (preview.shadowRoot || preview.contentDocument);
+        var root = doc ? doc.getElementById({root_id}) : preview;
+        return root ? root.innerHTML : "";
+        "#,
+        preview_id = js_string(preview_id),
+        root_id = js_string(LIVE_ROOT_ID),
+    )
+}
+
+/// Build the script filling the shadow root of the host `host_id` with
+/// `html` (see [`build_shadow_html`](super::iframe::build_shadow_html)).
+///
+/// The shadow root is created on first use. `<style data-adopt>` elements
+/// are turned into adopted stylesheets, which apply after the component
+/// stylesheets so the storybook overlays always win.
+#[cfg(target_family = "wasm")]
+pub fn build_shadow_setup_script(host_id: &str, html: &str) -> String {
+    format!(
+        r#"
+        var host = document.getElementById({host_id});
+        if (!host) {{ return false; }}
+        var shadow = host.shadowRoot || host.attachShadow({{ mode: "open" }});
+        shadow.innerHTML = {html};
+        var sheets = [];
+        shadow.querySelectorAll("style[data-adopt]").forEach(function(style) {{
+            var sheet = new CSSStyleSheet();
+            sheet.replaceSync(style.textContent);
+            sheets.push(sheet);
+            style.remove();
+        }});
+        shadow.adoptedStyleSheets = sheets;
+        return true;
+        "#,
+        host_id = js_string(host_id),
+        html = js_string(html),
+    )
+}
+
+/// The live root element of the preview `preview_id`: inside its shadow
+/// root for a shadow host, or inside its document for an iframe.
+#[cfg(target_family = "wasm")]
+fn live_root(preview_id: &str) -> Option<web_sys::Element> {
+    let preview = web_sys::window()?
+        .document()?
+        .get_element_by_id(preview_id)?;
+    if let Some(shadow) = preview.shadow_root() {
+        return shadow.get_element_by_id(LIVE_ROOT_ID);
+    }
+    let iframe = preview.dyn_into::<web_sys::HtmlIFrameElement>().ok()?;
+    iframe.content_document()?.get_element_by_id(LIVE_ROOT_ID)
+}
@@ -0,0 +1,86 @@
+/// Read the query parameter `name` from the page URL, percent-decoded.
+///
+/// Always `None` on native targets, which have no page URL.
+#[cfg(target_family = "wasm")]
+pub fn query_param(name: &str) -> Option<String> {
+    let search = web_sys::window()?.location().search().ok()?;
+    search_param(&search, name)
+}
+
+/// Read the query parameter `name` from `search`, the query string of a URL
+/// (with or without its leading `?`, a `#fragment` is ignored),
+/// percent-decoded.
+pub fn search_param(search: &str, name: &str) -> Option<String> {
+    let prefix = format!("{name}=");
+    let search = search.split('#').next().unwrap_or_default();
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .find_map(|pair| pair.strip_prefix(prefix.as_str()))
+        .filter(|value| !value.is_empty())
+        .map(percent_decode)
+}
+
+/// Decode an `application/x-www-form-urlencoded` query value; invalid
+/// escapes are kept as is.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match (bytes[i], escaped) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+            (b'+', _) => decoded.push(b' '),
+            (byte, _) => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub fn query_param(_name: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_param_reads_the_named_parameter() {
+        assert_eq!(
+            search_param("?doc=intro&story=button--primary", "story").as_deref(),
+            Some("button--primary")
+        );
+        assert_eq!(
+            search_param("story=a#story=b", "story").as_deref(),
+            Some("a")
+        );
+        assert_eq!(search_param("?story=", "story"), None);
+        assert_eq!(search_param("?history=a", "story"), None);
+    }
+
+    #[test]
+    fn search_param_percent_decodes_values() {
+        assert_eq!(
+            search_param("?doc=Getting%20Started%2FIntro", "doc").as_deref(),
+            Some("Getting Started/Intro")
+        );
+        assert_eq!(search_param("?q=a+b", "q").as_deref(), Some("a b"));
+        assert_eq!(
+            search_param("?q=%C3%A9t%C3%A9", "q").as_deref(),
+            Some("été")
+        );
+        assert_eq!(search_param("?q=100%", "q").as_deref(), Some("100%"));
+        assert_eq!(search_param("?q=%zz", "q").as_deref(), Some("%zz"));
+    }
+}
@@ -1,4 +1,25 @@
+pub mod a11y_audit;
+pub mod bookmarks;
 pub mod category_builder;
+pub mod class_list;
+pub mod dates;
 pub mod decorators;
+pub mod doc_anchors;
 pub mod doc_parser;
+pub mod doc_toc;
+pub mod fuzzy;
+pub mod glossary;
+pub mod html_diff;
 pub mod iframe;
+pub mod layout_audit;
+pub mod live_mount;
+pub mod location;
+pub mod performance;
+pub mod play;
+pub mod playlists;
+pub mod profile;
+pub mod report;
+pub mod snapshot;
+pub mod story_ids;
+pub mod thumbnails;
+pub mod zoom;
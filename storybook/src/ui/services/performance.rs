@@ -0,0 +1,73 @@
+//! Render timing of story previews.
+//!
+//! The render roots of the previews (the nested virtual DOM of a live
+//! preview, or the inline container) time each render of their story, from
+//! the root render to the effect running once the DOM is updated, and record
+//! it under the preview's DOM id. The performance panel reads the timings of
+//! the preview it inspects.
+
+use crate::ui::models::RenderStats;
+use dioxus::core::queue_effect;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Renders whose duration is kept per preview.
+const MAX_SAMPLES: usize = 40;
+
+std::thread_local! {
+    static RENDER_STATS: RefCell<HashMap<String, RenderStats>> = RefCell::new(HashMap::new());
+}
+
+/// Milliseconds elapsed since an arbitrary origin, for measuring durations.
+#[cfg(target_family = "wasm")]
+pub fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or_default()
+}
+
+/// Milliseconds elapsed since an arbitrary origin, for measuring durations.
+#[cfg(not(target_family = "wasm"))]
+pub fn now_ms() -> f64 {
+    static ORIGIN: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    ORIGIN
+        .get_or_init(std::time::Instant::now)
+        .elapsed()
+        .as_secs_f64()
+        * 1000.0
+}
+
+/// Record a render of the preview `preview_id` that took `duration_ms`.
+pub fn record_render(preview_id: &str, duration_ms: f64) {
+    RENDER_STATS.with(|stats| {
+        let mut stats = stats.borrow_mut();
+        let stats = stats.entry(preview_id.to_string()).or_default();
+        stats.renders += 1;
+        stats.recent_ms.push_back(duration_ms);
+        if stats.recent_ms.len() > MAX_SAMPLES {
+            stats.recent_ms.pop_front();
+        }
+    });
+}
+
+/// The renders recorded for the preview `preview_id`.
+pub fn render_stats(preview_id: &str) -> RenderStats {
+    RENDER_STATS.with(|stats| stats.borrow().get(preview_id).cloned().unwrap_or_default())
+}
+
+/// Forget the renders recorded for the preview `preview_id`.
+pub fn reset_render_stats(preview_id: &str) {
+    RENDER_STATS.with(|stats| {
+        stats.borrow_mut().remove(preview_id);
+    });
+}
+
+/// Hook timing every render of a story's render root, the component
+/// rendering it into the preview `preview_id`, whether props, globals or
+/// any other state it reads triggered it.
+pub fn use_render_timing(preview_id: &str) {
+    let started = now_ms();
+    let preview_id = preview_id.to_string();
+    queue_effect(move || record_render(&preview_id, now_ms() - started));
+}
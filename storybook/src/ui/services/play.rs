@@ -0,0 +1,78 @@
+use crate::PlayStep;
+use crate::ui::services::live_mount::LIVE_ROOT_ID;
+
+/// Build the script executing a single play step against the story mounted
+/// live in the preview `iframe_id` (an iframe, or a shadow host with
+/// [`PreviewIsolation::ShadowDom`](crate::PreviewIsolation::ShadowDom)).
+///
+/// The script first waits (up to two seconds) for the story to be mounted.
+/// After a click or an input it waits a frame so Dioxus can apply the
+/// resulting re-render before the next step runs. The script returns a JSON
+/// [`PlayStepOutcome`](crate::ui::models::PlayStepOutcome).
+pub fn build_play_step_script(iframe_id: &str, step: &PlayStep) -> String {
+    let action = match step {
+        PlayStep::Click { selector } => format!(
+            r#"var el = root.querySelector({selector});
+            if (!el) {{ return fail("no element matches " + {selector}); }}
+            el.click();
+            await settle();"#,
+            selector = js_string(selector),
+        ),
+        PlayStep::Type { selector, text } => format!(
+            r#"var el = root.querySelector({selector});
+            if (!el) {{ return fail("no element matches " + {selector}); }}
+            el.focus();
+            el.value = {text};
+            el.dispatchEvent(new Event("input", {{ bubbles: true }}));
+            el.dispatchEvent(new Event("change", {{ bubbles: true }}));
+            await settle();"#,
+            selector = js_string(selector),
+            text = js_string(text),
+        ),
+        PlayStep::AssertText { text } => format!(
+            r#"if (root.textContent.indexOf({text}) === -1) {{
+                return fail("text not found: " + {text});
+            }}"#,
+            text = js_string(text),
+        ),
+        PlayStep::AssertExists { selector } => format!(
+            r#"if (!root.querySelector({selector})) {{
+                return fail("no element matches " + {selector});
+            }}"#,
+            selector = js_string(selector),
+        ),
+        PlayStep::Wait { ms } => {
+            format!("await new Promise(function(r) {{ setTimeout(r, {ms}); }});")
+        }
+    };
+
+    format!(
+        r#"
+        function fail(message) {{ return {{ ok: false, message: message }}; }}
+        function settle() {{
+            return new Promise(function(r) {{ requestAnimationFrame(function() {{ setTimeout(r, 0); }}); }});
+        }}
+        async function liveRoot() {{
+            for (var i = 0; i < 100; i++) {{
+                var preview = document.getElementById({iframe_id});
+                var doc = preview && (preview.shadowRoot || preview.contentDocument);
+                var root = doc ? doc.getElementById({root_id}) : preview;
+                if (root && root.firstChild) {{ return root; }}
+                await new Promise(function(r) {{ setTimeout(r, 20); }});
+            }}
+            return null;
+        }}
+        var root = await liveRoot();
+        if (!root) {{ return fail("the story is not rendered"); }}
+        {action}
+        return {{ ok: true }};
+        "#,
+        iframe_id = js_string(iframe_id),
+        root_id = js_string(LIVE_ROOT_ID),
+    )
+}
+
+/// Encode `value` as a JavaScript string literal.
+pub fn js_string(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "\"\"".to_string())
+}
@@ -0,0 +1,60 @@
+//! Playlists: persistence in `localStorage` and share links.
+
+use crate::ui::models::Playlist;
+use crate::ui::services::location::query_param;
+use crate::ui::services::play::js_string;
+
+/// `localStorage` key holding the user's playlists.
+const PLAYLISTS_KEY: &str = "storybook:playlists";
+
+/// Query parameter carrying a shared playlist, as JSON.
+const PLAYLIST_PARAM: &str = "playlist";
+
+/// Build the script returning the playlists saved in `localStorage` (an
+/// empty list when there are none, or when storage is unavailable).
+pub fn build_load_playlists_script() -> String {
+    format!(
+        r#"
+        try {{
+            var saved = JSON.parse(window.localStorage.getItem({key}) || "[]");
+            return Array.isArray(saved) ? saved : [];
+        }} catch (e) {{
+            return [];
+        }}
+        "#,
+        key = js_string(PLAYLISTS_KEY),
+    )
+}
+
+/// Build the script saving `playlists` to `localStorage`.
+pub fn build_save_playlists_script(playlists: &[Playlist]) -> String {
+    format!(
+        r#"
+        try {{ window.localStorage.setItem({key}, {json}); }} catch (e) {{}}
+        "#,
+        key = js_string(PLAYLISTS_KEY),
+        json = js_string(&serde_json::to_string(playlists).unwrap_or_else(|_| "[]".to_string())),
+    )
+}
+
+/// Build the script copying a link to the storybook opening `playlist` to
+/// the clipboard, and returning the link.
+pub fn build_share_link_script(playlist: &Playlist) -> String {
+    format!(
+        r#"
+        var url = new URL(window.location.href);
+        url.search = "";
+        url.searchParams.set({param}, {json});
+        var link = url.toString();
+        try {{ await navigator.clipboard.writeText(link); }} catch (e) {{}}
+        return link;
+        "#,
+        param = js_string(PLAYLIST_PARAM),
+        json = js_string(&serde_json::to_string(playlist).unwrap_or_default()),
+    )
+}
+
+/// The playlist shared through the page URL, if any.
+pub fn shared_playlist() -> Option<Playlist> {
+    serde_json::from_str(&query_param(PLAYLIST_PARAM)?).ok()
+}
@@ -0,0 +1,47 @@
+#[cfg(target_family = "wasm")]
+use crate::ui::services::play::js_string;
+#[cfg(target_family = "wasm")]
+use dioxus::prelude::*;
+
+/// Read the `profile` parameter from the page URL's query string, or the
+/// `STORYBOOK_PROFILE` environment variable on native targets.
+#[cfg(target_family = "wasm")]
+pub fn requested_profile_slug() -> Option<String> {
+    super::location::query_param("profile")
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub fn requested_profile_slug() -> Option<String> {
+    std::env::var("STORYBOOK_PROFILE")
+        .ok()
+        .filter(|slug| !slug.is_empty())
+}
+
+/// Reload the page with the given profile selected, keeping the other query
+/// parameters (the selected story, doc page, ...).
+///
+/// Native windows can't be reloaded with another profile: the storybook has
+/// to be restarted with `STORYBOOK_PROFILE` set, which is logged instead.
+#[cfg(target_family = "wasm")]
+pub fn switch_profile(slug: &str) {
+    document::eval(&build_profile_switch_script(slug));
+}
+
+#[cfg(not(target_family = "wasm"))]
+pub fn switch_profile(slug: &str) {
+    tracing::warn!("restart the storybook with STORYBOOK_PROFILE={slug} to switch profiles");
+}
+
+/// Build the script setting the page URL's `?profile=` parameter to `slug`
+/// and reloading the page.
+#[cfg(target_family = "wasm")]
+fn build_profile_switch_script(slug: &str) -> String {
+    format!(
+        r#"
+        var url = new URL(window.location.href);
+        url.searchParams.set("profile", {slug});
+        window.location.href = url;
+        "#,
+        slug = js_string(slug),
+    )
+}
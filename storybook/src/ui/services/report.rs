@@ -0,0 +1,151 @@
+//! Machine-readable reports (JUnit XML and JSON) of play functions,
+//! accessibility audits and batch snapshots, for CI systems.
+
+use crate::ui::models::{TestOutcome, TestSuite};
+
+/// Escape `text` for XML text and attribute values.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Build the JUnit XML report of `suite`, in the format CI systems
+/// (GitHub Actions, GitLab, Jenkins) annotate pull requests from.
+pub fn build_junit_xml(suite: &TestSuite) -> String {
+    let seconds = |ms: Option<f64>| format!("{:.3}", ms.unwrap_or_default() / 1000.0);
+    let total_ms: f64 = suite.cases.iter().filter_map(|case| case.time_ms).sum();
+    let mut xml = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"{}\" time=\"{}\">\n",
+        escape_xml(&suite.name),
+        suite.cases.len(),
+        suite.failures(),
+        suite.skipped(),
+        seconds(Some(total_ms)),
+    );
+    for case in &suite.cases {
+        let open = format!(
+            "    <testcase classname=\"{}\" name=\"{}\" time=\"{}\"",
+            escape_xml(&case.classname),
+            escape_xml(&case.name),
+            seconds(case.time_ms),
+        );
+        match &case.outcome {
+            TestOutcome::Passed => xml.push_str(&format!("{open}/>\n")),
+            TestOutcome::Failed(message) => xml.push_str(&format!(
+                "{open}>\n      <failure message=\"{message}\">{message}</failure>\n    </testcase>\n",
+                message = escape_xml(message),
+            )),
+            TestOutcome::Skipped(message) => xml.push_str(&format!(
+                "{open}>\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                escape_xml(message),
+            )),
+        }
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+/// Build the JSON report of `suite`: its name, counts and cases.
+pub fn build_json_report(suite: &TestSuite) -> String {
+    serde_json::to_string_pretty(&serde_json::json!({
+        "name": suite.name,
+        "tests": suite.cases.len(),
+        "failures": suite.failures(),
+        "skipped": suite.skipped(),
+        "cases": suite.cases,
+    }))
+    .unwrap_or_default()
+}
+
+/// File name of the `kind` report (e.g. `a11y`) of `suite`, with
+/// `extension`.
+pub fn report_file_name(suite: &TestSuite, kind: &str, extension: &str) -> String {
+    let slug = suite
+        .name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if slug.is_empty() {
+        format!("storybook-{kind}.{extension}")
+    } else {
+        format!("{slug}-{kind}.{extension}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::models::TestCase;
+
+    fn suite() -> TestSuite {
+        TestSuite {
+            name: "Snapshots".to_string(),
+            cases: vec![
+                TestCase {
+                    classname: "forms-button--primary".to_string(),
+                    name: "Primary".to_string(),
+                    time_ms: Some(12.0),
+                    outcome: TestOutcome::Passed,
+                },
+                TestCase {
+                    classname: "forms-button--save-and-quit".to_string(),
+                    name: "Save & <Quit>".to_string(),
+                    time_ms: Some(1500.0),
+                    outcome: TestOutcome::Failed("Expected \"a\" & got <b>".to_string()),
+                },
+                TestCase {
+                    classname: "forms-button--loading".to_string(),
+                    name: "Loading".to_string(),
+                    time_ms: None,
+                    outcome: TestOutcome::Skipped("No baseline".to_string()),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn junit_xml_reports_each_outcome() {
+        assert_eq!(
+            build_junit_xml(&suite()),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="Snapshots" tests="3" failures="1" errors="0" skipped="1" time="1.512">
+    <testcase classname="forms-button--primary" name="Primary" time="0.012"/>
+    <testcase classname="forms-button--save-and-quit" name="Save &amp; &lt;Quit&gt;" time="1.500">
+      <failure message="Expected &quot;a&quot; &amp; got &lt;b&gt;">Expected &quot;a&quot; &amp; got &lt;b&gt;</failure>
+    </testcase>
+    <testcase classname="forms-button--loading" name="Loading" time="0.000">
+      <skipped message="No baseline"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+    }
+
+    #[test]
+    fn json_report_lists_cases_with_their_status() {
+        let report: serde_json::Value = serde_json::from_str(&build_json_report(&suite())).unwrap();
+        assert_eq!(report["tests"], 3);
+        assert_eq!(report["failures"], 1);
+        assert_eq!(report["skipped"], 1);
+        assert_eq!(report["cases"][0]["status"], "passed");
+        assert_eq!(report["cases"][1]["name"], "Save & <Quit>");
+        assert_eq!(report["cases"][1]["status"], "failed");
+        assert_eq!(report["cases"][1]["message"], "Expected \"a\" & got <b>");
+        assert_eq!(report["cases"][2]["status"], "skipped");
+    }
+
+    #[test]
+    fn report_file_names_are_slugs() {
+        assert_eq!(
+            report_file_name(&suite(), "snapshots", "xml"),
+            "snapshots-snapshots.xml"
+        );
+    }
+}
@@ -0,0 +1,349 @@
+//! Batch snapshots: capturing the HTML of mounted story previews, the
+//! downloadable bundle, and the approved baselines they are compared with.
+
+use crate::ui::models::{BaselineStatus, CapturedSnapshot};
+use crate::ui::services::live_mount::LIVE_ROOT_ID;
+use crate::ui::services::play::js_string;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Seconds to wait for the previews to mount before capturing them anyway.
+const SNAPSHOT_TIMEOUT_SECS: u32 = 10;
+
+/// `localStorage` key holding the approved baselines.
+const BASELINES_KEY: &str = "storybook:baselines";
+
+/// Attribute masking an element in snapshots, whatever the story's masks.
+const MASK_ATTRIBUTE: &str = "data-storybook-mask";
+
+/// Attribute replacing the attributes of a masked element.
+const MASKED_ATTRIBUTE: &str = "data-storybook-masked";
+
+/// Attributes a masked element keeps, so it takes the same room.
+const MASK_KEPT_ATTRIBUTES: &[&str] = &["class", "style", "width", "height"];
+
+/// A story preview to capture.
+#[derive(Serialize)]
+pub struct SnapshotTarget {
+    /// DOM id of the mounted preview.
+    pub preview_id: String,
+    pub story_id: String,
+    pub component: String,
+    pub story: String,
+    /// CSS selectors of the regions to mask.
+    pub masks: Vec<String>,
+}
+
+/// The snapshots of a batch, returned by the script built with
+/// [`build_capture_snapshots_script`].
+#[derive(Deserialize)]
+pub struct SnapshotCapture {
+    /// When the snapshots were taken, as an ISO 8601 date.
+    pub created: String,
+    pub snapshots: Vec<CapturedSnapshot>,
+    /// Wall-clock milliseconds the capture took.
+    pub duration_ms: f64,
+}
+
+/// Build the script capturing `targets` once their previews have mounted
+/// (or after [`SNAPSHOT_TIMEOUT_SECS`]). Returns a [`SnapshotCapture`].
+///
+/// Stylesheet links are made absolute so the snapshots keep their styles
+/// outside the storybook, and scripts are left out. Masked regions are
+/// emptied and drawn as a hatched box.
+pub fn build_capture_snapshots_script(targets: &[SnapshotTarget]) -> String {
+    format!(
+        r#"
+        var targets = {targets};
+        var rootId = {root_id};
+        function rootOf(el) {{
+            if (el.tagName === "IFRAME") {{
+                return el.contentDocument && el.contentDocument.getElementById(rootId);
+            }}
+            if (el.shadowRoot) {{ return el.shadowRoot.getElementById(rootId); }}
+            return el;
+        }}
+        function mounted(target) {{
+            var el = document.getElementById(target.preview_id);
+            var root = el && rootOf(el);
+            return !!(root && root.childNodes.length);
+        }}
+        var started = performance.now();
+        var durations = {{}};
+        function poll() {{
+            targets.forEach(function(target) {{
+                if (!(target.preview_id in durations) && mounted(target)) {{
+                    durations[target.preview_id] = performance.now() - started;
+                }}
+            }});
+            return targets.every(mounted);
+        }}
+        var deadline = Date.now() + {timeout_ms};
+        while (Date.now() < deadline && !poll()) {{
+            await new Promise(function(resolve) {{ setTimeout(resolve, 50); }});
+        }}
+        // Let the last previews settle (fonts, effects)
+        await new Promise(function(resolve) {{ setTimeout(resolve, 300); }});
+
+        function mask(node, target) {{
+            var selectors = target.masks.concat(["[" + {mask_attribute} + "]"]);
+            selectors.forEach(function(selector) {{
+                var matches;
+                try {{ matches = node.querySelectorAll(selector); }} catch (e) {{ return; }}
+                matches.forEach(function(el) {{
+                    Array.from(el.attributes).forEach(function(attribute) {{
+                        if ({kept_attributes}.indexOf(attribute.name) < 0) {{
+                            el.removeAttribute(attribute.name);
+                        }}
+                    }});
+                    el.setAttribute({masked_attribute}, "");
+                    el.innerHTML = "";
+                }});
+            }});
+        }}
+        function clean(node, target) {{
+            node.querySelectorAll("link[href]").forEach(function(link) {{
+                link.setAttribute("href", link.href);
+            }});
+            node.querySelectorAll("script").forEach(function(script) {{ script.remove(); }});
+            mask(node, target);
+        }}
+        var maskStyle = "[" + {masked_attribute} + "] {{ display: inline-block; min-width: 1em; "
+            + "min-height: 1em; color: transparent !important; "
+            + "background: repeating-linear-gradient(45deg, #d4d4d8 0 6px, #f4f4f5 6px 12px) !important; }}";
+        function capture(target) {{
+            var el = document.getElementById(target.preview_id);
+            if (!el) {{ return ""; }}
+            if (el.tagName === "IFRAME") {{
+                if (!el.contentDocument) {{ return ""; }}
+                var html = el.contentDocument.documentElement.cloneNode(true);
+                clean(html, target);
+                var head = html.querySelector("head");
+                if (head) {{
+                    var style = el.contentDocument.createElement("style");
+                    style.textContent = maskStyle;
+                    head.appendChild(style);
+                }}
+                return "<!DOCTYPE html>\n" + html.outerHTML;
+            }}
+            var wrapper = document.createElement("div");
+            (el.shadowRoot ? el.shadowRoot.childNodes : [el]).forEach(function(child) {{
+                wrapper.appendChild(child.cloneNode(true));
+            }});
+            clean(wrapper, target);
+            return "<!DOCTYPE html>\n<html><head><style>" + maskStyle + "</style></head><body>"
+                + wrapper.innerHTML + "</body></html>";
+        }}
+
+        var snapshots = targets.map(function(target) {{
+            return {{
+                id: target.story_id,
+                component: target.component,
+                story: target.story,
+                rendered: mounted(target),
+                html: capture(target),
+                duration_ms: target.preview_id in durations
+                    ? durations[target.preview_id]
+                    : performance.now() - started,
+            }};
+        }});
+        return {{
+            created: new Date().toISOString(),
+            snapshots: snapshots,
+            duration_ms: performance.now() - started,
+        }};
+        "#,
+        targets = serde_json::to_string(targets).unwrap_or_else(|_| "[]".to_string()),
+        root_id = js_string(LIVE_ROOT_ID),
+        mask_attribute = js_string(MASK_ATTRIBUTE),
+        masked_attribute = js_string(MASKED_ATTRIBUTE),
+        kept_attributes = serde_json::to_string(MASK_KEPT_ATTRIBUTES).unwrap_or_default(),
+        timeout_ms = SNAPSHOT_TIMEOUT_SECS * 1000,
+    )
+}
+
+/// Escape `text` for HTML text and attribute values.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// JSON report of a batch, embedded in the bundle.
+#[derive(Serialize)]
+struct BundleReport<'a> {
+    title: &'a str,
+    created: &'a str,
+    /// Wall-clock time of the whole batch.
+    duration_ms: f64,
+    stories: Vec<BundleReportEntry<'a>>,
+}
+
+#[derive(Serialize)]
+struct BundleReportEntry<'a> {
+    id: &'a str,
+    component: &'a str,
+    story: &'a str,
+    /// `ok` or `empty`.
+    status: &'static str,
+    /// Comparison with the approved baseline.
+    baseline: &'static str,
+    duration_ms: f64,
+}
+
+/// Build the self-contained HTML bundle of a batch titled `title`: a table
+/// of every story with its status, each snapshot in its own frame, and the
+/// report as JSON in a `<script id="storybook-snapshot-report">` for
+/// tooling. `statuses` holds the baseline comparison of each snapshot.
+pub fn build_snapshot_bundle(
+    title: &str,
+    capture: &SnapshotCapture,
+    statuses: &[BaselineStatus],
+) -> String {
+    let render_status =
+        |snapshot: &CapturedSnapshot| if snapshot.rendered { "ok" } else { "empty" };
+    let report = BundleReport {
+        title,
+        created: &capture.created,
+        duration_ms: capture.duration_ms,
+        stories: capture
+            .snapshots
+            .iter()
+            .zip(statuses)
+            .map(|(snapshot, baseline)| BundleReportEntry {
+                id: &snapshot.id,
+                component: &snapshot.component,
+                story: &snapshot.story,
+                status: render_status(snapshot),
+                baseline: baseline.label(),
+                duration_ms: snapshot.duration_ms,
+            })
+            .collect(),
+    };
+    let report_json = serde_json::to_string_pretty(&report)
+        .unwrap_or_default()
+        .replace('<', "\\u003c");
+
+    let mut rows = String::new();
+    let mut sections = String::new();
+    for (snapshot, baseline) in capture.snapshots.iter().zip(statuses) {
+        let id = escape_html(&snapshot.id);
+        let component = escape_html(&snapshot.component);
+        let story = escape_html(&snapshot.story);
+        let status = render_status(snapshot);
+        let baseline = baseline.label();
+        let duration = format_duration(snapshot.duration_ms);
+        rows.push_str(&format!(
+            r##"<tr><td><a href="#{id}">{id}</a></td><td>{component}</td><td>{story}</td><td class="{status}">{status}</td><td class="{baseline}">{baseline}</td><td class="time">{duration}</td></tr>"##
+        ));
+        sections.push_str(&format!(
+            r#"<section id="{id}"><h2>{component} / {story}</h2><iframe srcdoc="{html}"></iframe></section>"#,
+            html = escape_html(&snapshot.html),
+        ));
+    }
+    let captured = capture.snapshots.iter().filter(|s| s.rendered).count();
+    let total = capture.snapshots.len();
+    let title = escape_html(title);
+    let created = escape_html(&capture.created);
+    let duration = format_duration(capture.duration_ms);
+
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title} snapshots</title>
+<style>
+body {{ font-family: sans-serif; margin: 24px; }}
+table {{ border-collapse: collapse; }}
+td, th {{ border: 1px solid #ddd; padding: 4px 8px; text-align: left; }}
+.empty, .changed {{ color: #c00; }}
+.new {{ color: #b60; }}
+iframe {{ width: 100%; height: 480px; border: 1px solid #ddd; }}
+section {{ margin-top: 32px; }}
+.time {{ text-align: right; font-variant-numeric: tabular-nums; }}
+</style>
+<script type="application/json" id="storybook-snapshot-report">{report_json}</script>
+</head>
+<body>
+<h1>{title}</h1>
+<p>{captured} of {total} stories captured on {created} in {duration}</p>
+<table>
+<tr><th>Id</th><th>Component</th><th>Story</th><th>Status</th><th>Baseline</th><th>Render time</th></tr>
+{rows}
+</table>
+{sections}
+</body>
+</html>"#
+    )
+}
+
+/// Format `ms` for the bundle, e.g. `85 ms` or `2.4 s`.
+pub fn format_duration(ms: f64) -> String {
+    if ms < 1000.0 {
+        format!("{ms:.0} ms")
+    } else {
+        format!("{:.1} s", ms / 1000.0)
+    }
+}
+
+/// File name of the bundle downloaded for the collection `title`.
+pub fn bundle_file_name(title: &str) -> String {
+    let slug = title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase();
+    if slug.is_empty() {
+        "storybook-snapshots.html".to_string()
+    } else {
+        format!("{slug}-snapshots.html")
+    }
+}
+
+/// Build the script downloading `content` as a file named `file_name`.
+pub fn build_download_script(file_name: &str, mime: &str, content: &str) -> String {
+    format!(
+        r#"
+        var link = document.createElement("a");
+        link.href = URL.createObjectURL(new Blob([{content}], {{ type: {mime} }}));
+        link.download = {file_name};
+        document.body.appendChild(link);
+        link.click();
+        link.remove();
+        setTimeout(function() {{ URL.revokeObjectURL(link.href); }}, 1000);
+        "#,
+        content = js_string(content),
+        mime = js_string(mime),
+        file_name = js_string(file_name),
+    )
+}
+
+/// Build the script returning the approved baselines (story id to HTML)
+/// saved in `localStorage`, empty when there are none.
+pub fn build_load_baselines_script() -> String {
+    format!(
+        r#"
+        try {{
+            var saved = JSON.parse(window.localStorage.getItem({key}) || "{{}}");
+            return saved && typeof saved === "object" && !Array.isArray(saved) ? saved : {{}};
+        }} catch (e) {{
+            return {{}};
+        }}
+        "#,
+        key = js_string(BASELINES_KEY),
+    )
+}
+
+/// Build the script saving the approved `baselines` to `localStorage`.
+pub fn build_save_baselines_script(baselines: &BTreeMap<String, String>) -> String {
+    format!(
+        r#"
+        try {{ window.localStorage.setItem({key}, {json}); }} catch (e) {{}}
+        "#,
+        key = js_string(BASELINES_KEY),
+        json = js_string(&serde_json::to_string(baselines).unwrap_or_else(|_| "{}".to_string())),
+    )
+}
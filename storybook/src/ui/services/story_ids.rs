@@ -0,0 +1,69 @@
+//! Story ids: lookup, deep links and build-to-build diagnostics.
+
+use crate::get_components;
+use crate::ui::services::play::js_string;
+use std::collections::BTreeSet;
+
+/// `localStorage` key holding the story ids of the previous build.
+const STORY_IDS_KEY: &str = "storybook:story-ids";
+
+/// The component name and story index of the story with id `id`.
+pub fn find_story(id: &str) -> Option<(String, usize)> {
+    get_components().find_map(|component| {
+        (component.get_stories)()
+            .iter()
+            .position(|story| story.id == id)
+            .map(|index| (component.name.to_string(), index))
+    })
+}
+
+/// Every story id of this build.
+pub fn collect_story_ids() -> BTreeSet<String> {
+    get_components()
+        .flat_map(|component| (component.get_stories)())
+        .map(|story| story.id)
+        .collect()
+}
+
+/// Build the script pointing the page URL's `?story=` parameter at the
+/// story `id` and its `?doc=` parameter at the doc page `doc` (removing the
+/// ones that are `None`), without reloading the page.
+///
+/// The `#heading` fragment is dropped unless `keep_hash` is set: it only
+/// applies to the page it was opened with.
+pub fn build_page_link_script(id: Option<&str>, doc: Option<&str>, keep_hash: bool) -> String {
+    format!(
+        r#"
+        var url = new URL(window.location.href);
+        var params = {{ story: {id}, doc: {doc} }};
+        Object.keys(params).forEach(function(name) {{
+            if (params[name]) {{ url.searchParams.set(name, params[name]); }} else {{ url.searchParams.delete(name); }}
+        }});
+        if (!{keep_hash}) {{ url.hash = ""; }}
+        window.history.replaceState(null, "", url);
+        "#,
+        id = id.map(js_string).unwrap_or_else(|| "null".to_string()),
+        doc = doc.map(js_string).unwrap_or_else(|| "null".to_string()),
+    )
+}
+
+/// Build the script storing this build's story ids and returning the ids of
+/// the previous build that no longer exist.
+///
+/// Returns an empty list on the first run, or when storage is unavailable.
+pub fn build_story_set_check_script(ids: &BTreeSet<String>) -> String {
+    format!(
+        r#"
+        var current = {ids};
+        var previous = null;
+        try {{
+            previous = JSON.parse(window.localStorage.getItem({key}) || "null");
+            window.localStorage.setItem({key}, JSON.stringify(current));
+        }} catch (e) {{}}
+        if (!Array.isArray(previous)) {{ return []; }}
+        return previous.filter(function(id) {{ return current.indexOf(id) === -1; }});
+        "#,
+        ids = serde_json::to_string(ids).unwrap_or_else(|_| "[]".to_string()),
+        key = js_string(STORY_IDS_KEY),
+    )
+}
@@ -0,0 +1,146 @@
+//! Thumbnail cache: the captured story thumbnails saved in `localStorage`,
+//! each with the key of the story state it was captured from.
+//!
+//! Each thumbnail has its own entry, with the time it was last used. When
+//! saving one exceeds the storage quota, the least recently used thumbnails
+//! are evicted until it fits.
+
+use crate::ui::services::play::js_string;
+use serde::{Deserialize, Serialize};
+
+/// Prefix of the `localStorage` key of each cached thumbnail, followed by
+/// the story id.
+const THUMBNAIL_KEY_PREFIX: &str = "storybook:thumbnail:";
+
+/// `localStorage` key of the single entry earlier versions kept every
+/// thumbnail in, removed when loading.
+const LEGACY_THUMBNAILS_KEY: &str = "storybook:thumbnails";
+
+/// A captured thumbnail.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CachedThumbnail {
+    /// [`thumbnail_key`] of the story when it was captured; the thumbnail is
+    /// stale once it differs.
+    pub key: String,
+    /// The captured preview document.
+    pub html: String,
+}
+
+/// Key of the state a story's thumbnail is captured from: its props, its
+/// title and layout, and the registration of its component (name and
+/// source date). Any change makes the cached thumbnail stale.
+pub fn thumbnail_key(parts: &[&str]) -> String {
+    // FNV-1a: stable across builds, unlike the std hasher
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+/// Build the script returning the cached thumbnails (story id to
+/// [`CachedThumbnail`]) saved in `localStorage`, empty when there are none.
+pub fn build_load_thumbnails_script() -> String {
+    format!(
+        r#"
+        var thumbnails = {{}};
+        try {{
+            window.localStorage.removeItem({legacy_key});
+            for (var i = 0; i < window.localStorage.length; i++) {{
+                var key = window.localStorage.key(i);
+                if (!key || key.indexOf({prefix}) !== 0) {{ continue; }}
+                try {{
+                    var saved = JSON.parse(window.localStorage.getItem(key));
+                    if (saved && typeof saved.key === "string" && typeof saved.html === "string") {{
+                        thumbnails[key.slice({prefix}.length)] = {{ key: saved.key, html: saved.html }};
+                    }}
+                }} catch (e) {{}}
+            }}
+        }} catch (e) {{}}
+        return thumbnails;
+        "#,
+        legacy_key = js_string(LEGACY_THUMBNAILS_KEY),
+        prefix = js_string(THUMBNAIL_KEY_PREFIX),
+    )
+}
+
+/// Build the script saving the thumbnail of `story_id` to `localStorage`.
+///
+/// While it exceeds the storage quota, the least recently used thumbnails
+/// are evicted; it is dropped when evicting all of them isn't enough.
+pub fn build_save_thumbnail_script(story_id: &str, thumbnail: &CachedThumbnail) -> String {
+    format!(
+        r#"
+        var prefix = {prefix};
+        var key = prefix + {story_id};
+        var entry = {thumbnail};
+        entry.used = Date.now();
+        var value = JSON.stringify(entry);
+        function leastRecentlyUsed() {{
+            var oldest = null;
+            var oldestUsed = Infinity;
+            for (var i = 0; i < window.localStorage.length; i++) {{
+                var other = window.localStorage.key(i);
+                if (!other || other === key || other.indexOf(prefix) !== 0) {{ continue; }}
+                var used = 0;
+                try {{ used = JSON.parse(window.localStorage.getItem(other)).used || 0; }} catch (e) {{}}
+                if (used < oldestUsed) {{ oldest = other; oldestUsed = used; }}
+            }}
+            return oldest;
+        }}
+        for (;;) {{
+            try {{
+                window.localStorage.setItem(key, value);
+                return;
+            }} catch (e) {{
+                var evicted = leastRecentlyUsed();
+                if (!evicted) {{
+                    try {{ window.localStorage.removeItem(key); }} catch (e) {{}}
+                    return;
+                }}
+                window.localStorage.removeItem(evicted);
+            }}
+        }}
+        "#,
+        prefix = js_string(THUMBNAIL_KEY_PREFIX),
+        story_id = js_string(story_id),
+        thumbnail = serde_json::to_string(thumbnail).unwrap_or_else(|_| "{}".to_string()),
+    )
+}
+
+/// Build the script marking the saved thumbnail of `story_id` as used now,
+/// so it is evicted after the thumbnails shown less recently.
+pub fn build_touch_thumbnail_script(story_id: &str) -> String {
+    format!(
+        r#"
+        try {{
+            var key = {prefix} + {story_id};
+            var saved = JSON.parse(window.localStorage.getItem(key));
+            if (saved) {{
+                saved.used = Date.now();
+                window.localStorage.setItem(key, JSON.stringify(saved));
+            }}
+        }} catch (e) {{}}
+        "#,
+        prefix = js_string(THUMBNAIL_KEY_PREFIX),
+        story_id = js_string(story_id),
+    )
+}
+
+/// Build the script removing the saved thumbnails of `story_ids`, e.g.
+/// those of stories that no longer exist.
+pub fn build_remove_thumbnails_script(story_ids: &[String]) -> String {
+    format!(
+        r#"
+        var ids = {story_ids};
+        for (var i = 0; i < ids.length; i++) {{
+            try {{ window.localStorage.removeItem({prefix} + ids[i]); }} catch (e) {{}}
+        }}
+        "#,
+        prefix = js_string(THUMBNAIL_KEY_PREFIX),
+        story_ids = serde_json::to_string(story_ids).unwrap_or_else(|_| "[]".to_string()),
+    )
+}
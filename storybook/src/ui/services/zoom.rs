@@ -0,0 +1,142 @@
+//! Preview zoom: Ctrl+wheel and pinch gestures, live zoom updates and
+//! fit-to-width.
+
+use crate::ZoomConfig;
+use crate::ui::services::iframe::SHADOW_BODY_CLASS;
+use crate::ui::services::play::js_string;
+
+/// Apply a gesture step to `zoom_level`, within the range of `zoom`.
+/// `factor` is the natural log of the scale change, so opposite gestures
+/// cancel out exactly.
+pub fn zoom_by(zoom: &ZoomConfig, zoom_level: i32, factor: f64) -> i32 {
+    zoom.clamp((f64::from(zoom_level) * factor.exp()).round() as i32)
+}
+
+/// JavaScript shared by the storybook page and the previews: `zoomFactor`
+/// turns a wheel event into a log scale factor, and `listenZoomGestures`
+/// calls `send` with the factor of each Ctrl+wheel (trackpad pinches are
+/// reported as such) or Safari pinch gesture for which `accept` returns true.
+const GESTURE_HELPERS: &str = r#"
+    function zoomFactor(event) {
+        var delta = event.deltaY * (event.deltaMode === 1 ? 16 : 1);
+        return -delta * 0.002;
+    }
+    function listenZoomGestures(send, accept) {
+        document.addEventListener("wheel", function(event) {
+            if (!event.ctrlKey || !accept(event)) { return; }
+            event.preventDefault();
+            send(zoomFactor(event));
+        }, { passive: false });
+        var lastScale = 1;
+        document.addEventListener("gesturestart", function(event) {
+            if (!accept(event)) { return; }
+            event.preventDefault();
+            lastScale = 1;
+        });
+        document.addEventListener("gesturechange", function(event) {
+            if (!accept(event)) { return; }
+            event.preventDefault();
+            send(Math.log(event.scale / lastScale));
+            lastScale = event.scale;
+        });
+    }
+"#;
+
+/// Build the script injected into the fullscreen preview iframe, forwarding
+/// its zoom gestures to the storybook page (the iframe would otherwise
+/// swallow them).
+pub fn build_forward_zoom_script() -> String {
+    format!(
+        r#"<script>
+(function() {{
+    {GESTURE_HELPERS}
+    listenZoomGestures(function(factor) {{
+        window.parent.postMessage({{ storybookZoom: factor }}, "*");
+    }}, function() {{ return true; }});
+}})();
+</script>"#
+    )
+}
+
+/// Build the long-running script sending (with `dioxus.send`) the log scale
+/// factor of every zoom gesture made over the fullscreen preview, whether on
+/// the page itself (shadow DOM and inline previews) or forwarded by the
+/// preview iframe.
+pub fn build_listen_zoom_script() -> String {
+    format!(
+        r#"
+        {GESTURE_HELPERS}
+        listenZoomGestures(function(factor) {{ dioxus.send(factor); }}, function(event) {{
+            return event.target.closest && event.target.closest(".fullscreen-preview-area");
+        }});
+        window.addEventListener("message", function(event) {{
+            if (event.data && typeof event.data.storybookZoom === "number") {{
+                dioxus.send(event.data.storybookZoom);
+            }}
+        }});
+        await new Promise(function() {{}});
+        "#
+    )
+}
+
+/// JavaScript helper `zoomRoot(el)` returning the element the zoom applies
+/// to in a preview: the iframe body, the shadow root's stand-in body, or
+/// the inline preview itself.
+fn zoom_root_helper() -> String {
+    format!(
+        r#"function zoomRoot(el) {{
+            if (el.tagName === "IFRAME") {{ return el.contentDocument && el.contentDocument.body; }}
+            if (el.shadowRoot) {{ return el.shadowRoot.querySelector(".{SHADOW_BODY_CLASS}"); }}
+            return el;
+        }}"#
+    )
+}
+
+/// Build the script applying `zoom_level` to the loaded previews whose id
+/// starts with `id_prefix` (the preview and its comparison or theme sweep
+/// copies), without reloading them.
+pub fn build_apply_zoom_script(id_prefix: &str, zoom_level: i32) -> String {
+    let scale = f64::from(zoom_level) / 100.0;
+    format!(
+        r#"
+        {helper}
+        var prefix = {prefix};
+        document.querySelectorAll(".preview-iframe, .preview-shadow-host").forEach(function(el) {{
+            if (el.id.indexOf(prefix) !== 0) {{ return; }}
+            var root = zoomRoot(el);
+            if (root) {{ root.style.zoom = {scale} === 1 ? "" : "{scale}"; }}
+        }});
+        "#,
+        helper = zoom_root_helper(),
+        prefix = js_string(id_prefix),
+    )
+}
+
+/// Build the script returning the zoom level (in percent) at which the
+/// widest element of the preview `preview_id`, currently shown at
+/// `zoom_level`, fits the preview width. Returns `null` for an empty or
+/// missing preview.
+pub fn build_fit_width_script(preview_id: &str, zoom_level: i32) -> String {
+    format!(
+        r#"
+        {helper}
+        var el = document.getElementById({id});
+        var root = el && zoomRoot(el);
+        if (!root) {{ return null; }}
+        var left = Infinity, right = -Infinity;
+        root.querySelectorAll("*").forEach(function(node) {{
+            var rect = node.getBoundingClientRect();
+            if (rect.width === 0) {{ return; }}
+            left = Math.min(left, rect.left);
+            right = Math.max(right, rect.right);
+        }});
+        if (!(right > left)) {{ return null; }}
+        // Measured sizes include the current zoom, and so does the 16px
+        // body padding on each side
+        var content = (right - left) * 100 / {zoom_level} + 32;
+        return Math.floor(el.clientWidth / content * 100);
+        "#,
+        helper = zoom_root_helper(),
+        id = js_string(preview_id),
+    )
+}
@@ -0,0 +1,57 @@
+use crate::StorybookConfig;
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::activity_vm::{SessionActivity, resolve_activity};
+use dioxus::prelude::*;
+use lucide_dioxus::RotateCcw;
+
+/// Session activity page: every story with the number of times it was
+/// opened, never opened ones first (see
+/// [`StorybookConfig::with_session_activity`]).
+#[component]
+pub(crate) fn ActivityPage(mut selected: Signal<Option<Selection>>) -> Element {
+    let config = use_context::<StorybookConfig>();
+    let activity = use_context::<SessionActivity>();
+    let report = resolve_activity(&config, &(activity.views)());
+    let total = report.stories.len();
+
+    rsx! {
+        div { class: "story-page activity-page",
+            div { class: "story-page-header",
+                span { class: "story-page-component-name", "Session activity" }
+                span { class: "story-page-separator", "/" }
+                span { class: "story-page-story-name", "{report.viewed} of {total} stories viewed" }
+                button {
+                    class: "activity-reset",
+                    title: "Reset the view counts",
+                    onclick: move |_| activity.reset(),
+                    RotateCcw { size: 14, stroke_width: 2 }
+                    span { "Reset" }
+                }
+            }
+            progress { class: "activity-progress", max: "{total.max(1)}", value: "{report.viewed}" }
+            table { class: "props-table activity-table",
+                thead {
+                    tr {
+                        th { "Component" }
+                        th { "Story" }
+                        th { "Views" }
+                    }
+                }
+                tbody {
+                    for story in report.stories {
+                        tr {
+                            key: "{story.component_name}-{story.story_index}",
+                            class: if story.views == 0 { "activity-row unviewed" } else { "activity-row" },
+                            onclick: move |_| {
+                                selected.set(Some(Selection::Story(story.component_name.clone(), story.story_index)))
+                            },
+                            td { "{story.component_name}" }
+                            td { "{story.story_title}" }
+                            td { class: "activity-views", "{story.views}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
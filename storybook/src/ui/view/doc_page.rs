@@ -1,19 +1,185 @@
-use crate::ui::models::DocPart;
-use crate::ui::services::doc_parser::parse_doc_content;
+use crate::ui::models::{DocFreshness, DocPart, Selection, TocEntry};
+use crate::ui::view::shared::Overridable;
 use crate::ui::view::story::StoryCard;
-use crate::ui::viewmodels::doc_page_vm::{HLJS_SCRIPT_URL, use_hljs_theme};
-use crate::ui::viewmodels::embedded_story_vm::{EmbeddedStoryError, resolve_embedded_story};
+use crate::ui::viewmodels::doc_page_vm::{
+    HLJS_SCRIPT_URL, find_doc_directive, resolve_component_docs, resolve_doc_freshness,
+    resolve_doc_parts, resolve_doc_toc, use_doc_html, use_heading_anchors, use_hljs_theme,
+    use_toc_scroll_sync,
+};
+use crate::ui::viewmodels::embedded_story_vm::{
+    resolve_embedded_canvas, resolve_embedded_props, resolve_embedded_story,
+};
+use crate::{Error, Slot, StorybookConfig};
 use dioxus::prelude::*;
 
+mod props_table;
+pub(crate) use props_table::PropsTable;
+
 /// Component to render a documentation page.
 ///
-/// Pure presentational component — receives resolved `content_html` from the parent.
+/// Receives resolved `content_html` from the parent. When the page comes
+/// from a `storydoc!` file and the `hot-docs` feature is enabled, `source`
+/// points at the bundled markdown and the page re-renders when it changes.
+/// `freshness` adds a "Last updated" footer, and a warning when the page
+/// lags behind its components.
 #[component]
-pub fn DocPage(content_html: String) -> Element {
+pub fn DocPage(
+    content_html: String,
+    #[props(default)] source: Option<Asset>,
+    #[props(default)] freshness: Option<DocFreshness>,
+) -> Element {
     rsx! {
         div { class: "doc-page",
             document::Script { src: HLJS_SCRIPT_URL }
-            DocContent { content_html }
+            DocBody { content_html, source, freshness }
+        }
+    }
+}
+
+/// Content of a `storydoc!` page, with its freshness notes and, when it has
+/// enough headings, a table of contents beside it.
+#[component]
+fn DocBody(
+    content_html: String,
+    source: Option<Asset>,
+    freshness: Option<DocFreshness>,
+) -> Element {
+    let content_html = use_doc_html(content_html, source);
+    let toc = resolve_doc_toc(&content_html);
+    let has_toc = !toc.is_empty();
+
+    rsx! {
+        div { class: if has_toc { "doc-body with-toc" } else { "doc-body" },
+            div { class: "doc-main",
+                if let Some(stale) = freshness.as_ref().and_then(|f| f.stale.as_ref()) {
+                    div { class: "doc-stale-warning",
+                        "This page may be out of date: "
+                        strong { "{stale.component_name}" }
+                        " changed {stale.days_behind} days after its last update."
+                    }
+                }
+                DocContent { content_html }
+                if let Some(freshness) = freshness {
+                    div { class: "doc-last-updated", "Last updated {freshness.last_updated}" }
+                }
+            }
+            if has_toc {
+                DocToc { entries: toc }
+            }
+        }
+    }
+}
+
+/// Table of contents of a doc page, following the section being read.
+#[component]
+fn DocToc(entries: Vec<TocEntry>) -> Element {
+    use_toc_scroll_sync();
+
+    rsx! {
+        nav { class: "doc-toc", aria_label: "On this page",
+            div { class: "doc-toc-title", "On this page" }
+            for entry in entries {
+                a {
+                    key: "{entry.id}",
+                    class: "doc-toc-link level-{entry.level}",
+                    href: "#{entry.id}",
+                    "{entry.title}"
+                }
+            }
+        }
+    }
+}
+
+/// Auto-generated documentation page for a single component.
+///
+/// Combines the component's doc-comment description, its props table and
+/// every story rendered inline — like Storybook.js autodocs — without
+/// requiring a `storydoc!` file. When a `storydoc!` page is attached to the
+/// component, it's shown in a "Guide" tab next to this "Overview", `guide`
+/// selecting it.
+#[component]
+pub fn ComponentDocsPage(
+    component_name: String,
+    selected: Signal<Option<Selection>>,
+    #[props(default)] guide: bool,
+) -> Element {
+    use_heading_anchors();
+    let config = use_context::<StorybookConfig>();
+    let data = match resolve_component_docs(&component_name) {
+        Ok(data) => data,
+        Err(e) => return rsx! { EmbedError { error: e } },
+    };
+    let overview_path = format!("__component__/{component_name}");
+
+    rsx! {
+        div { class: "doc-page component-docs-page",
+            document::Script { src: HLJS_SCRIPT_URL }
+            div { class: "component-docs-header",
+                for tag in data.tags.iter() {
+                    span { class: "component-docs-tag", "{tag}" }
+                }
+                h1 { class: "component-docs-title", "{data.component_name}" }
+            }
+            if let Some(doc) = data.guide {
+                div { class: "component-docs-tabs",
+                    ComponentDocsTab {
+                        label: "Overview",
+                        path: overview_path,
+                        active: !guide,
+                        selected,
+                    }
+                    ComponentDocsTab {
+                        label: "Guide",
+                        path: doc.path.to_string(),
+                        active: guide,
+                        selected,
+                    }
+                }
+            }
+            if let Some(doc) = data.guide.filter(|_| guide) {
+                DocBody {
+                    content_html: doc.content_html.to_string(),
+                    source: doc.source_asset,
+                    freshness: resolve_doc_freshness(doc, &config),
+                }
+            } else {
+                if !data.description_html.is_empty() {
+                    DocContent { content_html: data.description_html }
+                }
+                h2 { class: "component-docs-section", "Props" }
+                div { class: "embedded-props",
+                    PropsTable { component_name: data.component_name.clone(), schema: data.prop_schema }
+                }
+                h2 { class: "component-docs-section", "Stories" }
+                for story in data.stories {
+                    div { class: "embedded-story", key: "{story.story_index}",
+                        StoryCard {
+                            story: story.story,
+                            component_name: story.component_name,
+                            story_index: story.story_index,
+                            render_fn: story.render_fn,
+                            prop_schema: story.prop_schema,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A tab of [`ComponentDocsPage`], selecting the doc page at `path`.
+#[component]
+fn ComponentDocsTab(
+    label: &'static str,
+    path: String,
+    active: bool,
+    mut selected: Signal<Option<Selection>>,
+) -> Element {
+    rsx! {
+        button {
+            class: if active { "component-docs-tab active" } else { "component-docs-tab" },
+            onclick: move |_| selected.set(Some(Selection::DocPage(path.clone()))),
+            "{label}"
         }
     }
 }
@@ -22,8 +188,10 @@ pub fn DocPage(content_html: String) -> Element {
 #[component]
 fn DocContent(content_html: String) -> Element {
     use_hljs_theme();
+    use_heading_anchors();
+    let config = use_context::<StorybookConfig>();
 
-    let parts = parse_doc_content(&content_html);
+    let parts = resolve_doc_parts(&config, &content_html);
 
     rsx! {
         div { class: "doc-content",
@@ -32,8 +200,9 @@ fn DocContent(content_html: String) -> Element {
                     DocPart::Html(html) => rsx! {
                         div { key: "html-{index}", class: "doc-html", dangerous_inner_html: "{html}" }
                     },
-                    DocPart::StoryEmbed { story_path, story_name } => {
-                        match resolve_embedded_story(story_path, story_name) {
+                    DocPart::StoryEmbed { story_path, story_name, props_override } => {
+                        let props_override = props_override.as_deref();
+                        match resolve_embedded_story(story_path, story_name, props_override) {
                             Ok(data) => rsx! {
                                 div { class: "embedded-story", key: "story-{index}",
                                     StoryCard {
@@ -45,25 +214,76 @@ fn DocContent(content_html: String) -> Element {
                                     }
                                 }
                             },
-                            Err(EmbeddedStoryError::InvalidPath(path)) => rsx! {
-                                div { key: "story-{index}", class: "embedded-story-error", "Invalid story path: {path}" }
+                            Err(e) => rsx! {
+                                EmbedError { key: "story-{index}", error: e }
+                            },
+                        }
+                    }
+                    DocPart::PropsTable { component_name } => {
+                        match resolve_embedded_props(component_name) {
+                            Ok(schema) => rsx! {
+                                div { class: "embedded-props", key: "props-{index}",
+                                    PropsTable { component_name: component_name.clone(), schema }
+                                }
                             },
-                            Err(EmbeddedStoryError::ComponentNotFound(name)) => rsx! {
-                                div { key: "story-{index}", class: "embedded-story-error", "Component not found: {name}" }
+                            Err(e) => rsx! {
+                                EmbedError { key: "props-{index}", error: e }
                             },
-                            Err(
-                                EmbeddedStoryError::StoryNotFound { component_name, story_name },
-                            ) => {
-                                rsx! {
-                                    div { key: "story-{index}", class: "embedded-story-error",
-                                        "Story not found: {story_name} in {component_name}"
+                        }
+                    }
+                    DocPart::Canvas { component_name } => {
+                        match resolve_embedded_canvas(component_name) {
+                            Ok(stories) => rsx! {
+                                div { class: "embedded-canvas", key: "canvas-{index}",
+                                    for data in stories {
+                                        StoryCard {
+                                            key: "{data.story_index}",
+                                            story: data.story,
+                                            component_name: data.component_name,
+                                            story_index: data.story_index,
+                                            render_fn: data.render_fn,
+                                            prop_schema: data.prop_schema,
+                                        }
                                     }
                                 }
-                            }
+                            },
+                            Err(e) => rsx! {
+                                EmbedError { key: "canvas-{index}", error: e }
+                            },
                         }
                     }
+                    DocPart::Directive { kind, argument } => rsx! {
+                        DocDirective { key: "directive-{index}", kind: kind.clone(), argument: argument.clone() }
+                    },
                 }
             }
         }
     }
 }
+
+/// A custom directive, rendered by its handler in a component of its own so
+/// the handler can use hooks, or shown as written when it has none.
+#[component]
+fn DocDirective(kind: String, argument: String) -> Element {
+    let config = use_context::<StorybookConfig>();
+    match find_doc_directive(&config, &kind) {
+        Some(render) => rsx! {
+            div { class: "doc-directive", {render(argument)} }
+        },
+        None => rsx! {
+            p { "@[{kind}:{argument}]" }
+        },
+    }
+}
+
+/// Inline error shown in place of an embed that could not be resolved.
+#[component]
+fn EmbedError(error: Error) -> Element {
+    rsx! {
+        Overridable {
+            slot: Slot::Error,
+            message: error.to_string(),
+            div { class: "embedded-story-error", "{error}" }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::extract_fields_from_schema;
+use crate::ui::services::doc_anchors::prop_row_id;
+use dioxus::prelude::*;
+use schemars::Schema;
+
+/// Read-only table documenting a component's props.
+///
+/// Lists every field of the props schema with its type, default value (the
+/// `#[props(default = …)]` expression when there is one), whether it is
+/// required, and its description. Used by `@[props:…]`
+/// embeds in documentation pages. Each row has the id `@[prop:…]` chips
+/// link to.
+#[component]
+pub(crate) fn PropsTable(component_name: String, schema: Schema) -> Element {
+    let fields = extract_fields_from_schema(&schema);
+
+    rsx! {
+        div { class: "doc-props-table",
+            if fields.is_empty() {
+                div { class: "props-empty", "This component has no props." }
+            } else {
+                table { class: "props-table",
+                    thead {
+                        tr {
+                            th { "Name" }
+                            th { "Type" }
+                            th { "Default" }
+                            th { "Required" }
+                            th { "Description" }
+                        }
+                    }
+                    tbody {
+                        for field in fields.iter() {
+                            tr {
+                                key: "{field.name}",
+                                id: prop_row_id(&component_name, &field.name),
+                                class: "prop-row",
+                                td { class: "prop-cell prop-name",
+                                    "{field.name}"
+                                    if let Some(label) = &field.label {
+                                        span { class: "prop-label", "{label}" }
+                                    }
+                                }
+                                td { class: "prop-cell prop-type",
+                                    if field.schema_type.as_deref() == Some("null") {
+                                        "non-editable"
+                                    } else {
+                                        "{field.type_name}"
+                                    }
+                                }
+                                td { class: "prop-cell prop-default",
+                                    if let Some(default) = field.default_display() {
+                                        code { "{default}" }
+                                    } else {
+                                        "—"
+                                    }
+                                }
+                                td { class: "prop-cell prop-required",
+                                    if field.is_required {
+                                        "Yes"
+                                    } else {
+                                        "No"
+                                    }
+                                }
+                                td { class: "prop-cell prop-description",
+                                    if let Some(desc) = &field.description {
+                                        "{desc}"
+                                    } else {
+                                        "—"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
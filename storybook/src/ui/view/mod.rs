@@ -1,5 +1,12 @@
+pub(crate) mod activity;
 pub mod doc_page;
+pub(crate) mod not_found;
+pub(crate) mod onboarding;
+pub(crate) mod playlist;
+pub(crate) mod presentation;
+pub(crate) mod profile_picker;
 pub mod shared;
 pub mod sidebar;
+pub(crate) mod snapshot_review;
 pub mod story;
 pub(crate) mod top_bar;
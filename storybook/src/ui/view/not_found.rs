@@ -0,0 +1,43 @@
+use crate::StorybookConfig;
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::not_found_vm::suggest;
+use dioxus::prelude::*;
+use lucide_dioxus::SearchX;
+
+/// Page shown for a link to a story or doc page that doesn't exist, e.g.
+/// after a rename, with the closest matches of the registry as
+/// suggestions.
+#[component]
+pub(crate) fn NotFoundPage(target: String, mut selected: Signal<Option<Selection>>) -> Element {
+    let config = use_context::<StorybookConfig>();
+    let suggestions = suggest(&config, &target);
+
+    rsx! {
+        div { class: "empty-state not-found",
+            SearchX { size: 32, stroke_width: 1 }
+            h2 { "Nothing found" }
+            p {
+                "No story or page matches "
+                code { "{target}" }
+                ". It may have been renamed or removed."
+            }
+            if !suggestions.is_empty() {
+                div { class: "not-found-suggestions",
+                    span { class: "not-found-title", "Did you mean" }
+                    for suggestion in suggestions {
+                        button {
+                            key: "{suggestion.label}",
+                            class: "not-found-suggestion",
+                            onclick: {
+                                let selection = suggestion.selection.clone();
+                                move |_| selected.set(Some(selection.clone()))
+                            },
+                            span { class: "not-found-kind", "{suggestion.kind}" }
+                            "{suggestion.label}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::StorybookConfig;
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::onboarding_vm::resolve_onboarding;
+use dioxus::prelude::*;
+use lucide_dioxus::{BookOpen, Component};
+
+/// Minimal component registration shown when nothing is registered.
+const EXAMPLE: &str = r#"use dioxus::prelude::*;
+use storybook::{storybook, Stories, Story};
+
+#[storybook(tag = "Examples")]
+#[component]
+pub fn MyButton(label: String) -> Element {
+    rsx! { button { "{label}" } }
+}
+
+impl Stories for MyButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new("Default", Self { label: "Click me".to_string() })]
+    }
+}"#;
+
+/// Page replacing the empty state when the sidebar lists no component,
+/// explaining the most common causes.
+#[component]
+pub(crate) fn Onboarding(mut selected: Signal<Option<Selection>>) -> Element {
+    let config = use_context::<StorybookConfig>();
+    let data = use_hook(|| resolve_onboarding(&config));
+
+    rsx! {
+        div { class: "onboarding",
+            Component { size: 40, stroke_width: 1 }
+            if data.all_hidden() {
+                h2 { "Every component is hidden" }
+                p { class: "onboarding-lead",
+                    "{data.registered} components are registered, but the current configuration hides all of them."
+                }
+                ul { class: "onboarding-causes",
+                    if let Some(focus) = data.focus {
+                        li {
+                            "The storybook was built with "
+                            code { "STORYBOOK_ONLY={focus}" }
+                            ", which matches no story. Unset it and rebuild."
+                        }
+                    }
+                    if !data.tag_patterns.is_empty() {
+                        li {
+                            "The tag patterns "
+                            code { "{data.tag_patterns.join(\", \")}" }
+                            " (from "
+                            code { "include_tags" }
+                            ", "
+                            code { "exclude_tags" }
+                            " or the active profile) match no component tag."
+                        }
+                    }
+                    li {
+                        "Every story is skipped with "
+                        code { "Story::skip" }
+                        "."
+                    }
+                }
+            } else {
+                h2 { "No components registered" }
+                p { class: "onboarding-lead",
+                    "Annotate a component with "
+                    code { "#[storybook]" }
+                    " and give its props some stories to see it here."
+                }
+                pre { class: "onboarding-example", code { "{EXAMPLE}" } }
+                h3 { "Components already annotated?" }
+                ul { class: "onboarding-causes",
+                    li {
+                        "Their crate isn't linked. Registrations are collected at link time, so a crate \
+                         the storybook binary never references is dropped: add it as a dependency and \
+                         reference it, e.g. "
+                        code { "use my_components as _;" }
+                        "."
+                    }
+                    li {
+                        "They sit behind a feature flag or "
+                        code { "#[cfg(...)]" }
+                        " that is disabled in this build. Enable the feature on the dependency."
+                    }
+                    li {
+                        "Their module isn't compiled: check that it is declared with "
+                        code { "mod" }
+                        " in its parent module."
+                    }
+                }
+            }
+            if let Some(welcome) = data.welcome_doc.clone() {
+                button {
+                    class: "onboarding-welcome",
+                    onclick: move |_| selected.set(Some(Selection::DocPage(welcome.clone()))),
+                    BookOpen { size: 16, stroke_width: 2 }
+                    span { "Open the Welcome page" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use crate::ui::models::Selection;
+use crate::ui::services::story_ids::find_story;
+use crate::ui::view::shared::Overridable;
+use crate::ui::view::story::SnapshotButton;
+use crate::ui::viewmodels::UiSettings;
+use crate::ui::viewmodels::playlist_vm::PlaylistStore;
+use crate::ui::viewmodels::snapshot_vm::playlist_snapshot_stories;
+use crate::{Slot, StorybookConfig, find_doc};
+use dioxus::prelude::*;
+use lucide_dioxus::{ArrowUp, Link, Presentation, Trash2, X};
+
+/// Page of a playlist: its entries, with buttons to present it, share it as
+/// a link, make it the active playlist and, for user playlists, reorder,
+/// remove entries or delete it.
+#[component]
+pub(crate) fn PlaylistPage(name: String, mut selected: Signal<Option<Selection>>) -> Element {
+    let store = use_context::<PlaylistStore>();
+    let config = use_context::<StorybookConfig>();
+    let mut presenting = use_context::<UiSettings>().presenting;
+    let mut active = store.active;
+    let mut copied = use_signal(|| false);
+
+    let Some(playlist) = store.get(&name) else {
+        return rsx! {
+            Overridable {
+                slot: Slot::Error,
+                message: format!("Playlist not found: {name}"),
+                div { class: "error", "Playlist not found: {name}" }
+            }
+        };
+    };
+    let is_active = active().as_deref() == Some(name.as_str());
+    let editable = !playlist.builtin;
+
+    // Resolve each entry to the selection it opens and a readable label
+    let entries = playlist
+        .entries
+        .iter()
+        .map(|entry| {
+            let target = find_story(config.migrate_story_id(entry))
+                .map(|(component_name, index)| Selection::Story(component_name, index))
+                .or_else(|| {
+                    find_doc(entry)
+                        .ok()
+                        .map(|_| Selection::DocPage(entry.clone()))
+                });
+            (entry.clone(), target)
+        })
+        .collect::<Vec<_>>();
+    let has_entries = !entries.is_empty();
+
+    let present_name = name.clone();
+    let share_name = name.clone();
+    let active_name = name.clone();
+    let delete_name = name.clone();
+
+    rsx! {
+        div { class: "story-page playlist-page",
+            div { class: "story-page-header",
+                span { class: "story-page-component-name", "Playlist" }
+                span { class: "story-page-separator", "/" }
+                span { class: "story-page-story-name", "{name}" }
+            }
+            div { class: "playlist-actions",
+                button {
+                    class: "playlist-btn",
+                    disabled: !has_entries,
+                    onclick: move |_| {
+                        active.set(Some(present_name.clone()));
+                        presenting.set(true);
+                    },
+                    Presentation { size: 14, stroke_width: 2 }
+                    span { "Present" }
+                }
+                button {
+                    class: "playlist-btn",
+                    onclick: move |_| {
+                        store.share(&share_name);
+                        copied.set(true);
+                    },
+                    Link { size: 14, stroke_width: 2 }
+                    span { if copied() { "Link copied" } else { "Copy link" } }
+                }
+                SnapshotButton {
+                    title: name.clone(),
+                    stories: playlist_snapshot_stories(&config, &playlist.entries),
+                    selected,
+                }
+                label { class: "playlist-active",
+                    input {
+                        r#type: "checkbox",
+                        checked: is_active,
+                        onchange: move |e: Event<FormData>| {
+                            active.set(e.checked().then(|| active_name.clone()));
+                        },
+                    }
+                    "Active playlist"
+                }
+                if editable {
+                    button {
+                        class: "playlist-btn playlist-delete",
+                        onclick: move |_| {
+                            store.delete(&delete_name);
+                            selected.set(None);
+                        },
+                        Trash2 { size: 14, stroke_width: 2 }
+                        span { "Delete" }
+                    }
+                }
+            }
+            if !has_entries {
+                p { class: "playlist-hint",
+                    "Add stories with the \"Add to playlist\" menu of a story page."
+                }
+            }
+            ol { class: "playlist-entries",
+                for (index , (entry , target)) in entries.into_iter().enumerate() {
+                    li {
+                        key: "{index}-{entry}",
+                        class: if target.is_some() { "playlist-entry" } else { "playlist-entry missing" },
+                        span {
+                            class: "playlist-entry-name",
+                            title: if target.is_none() { "No story or doc page has this id" },
+                            onclick: move |_| {
+                                if let Some(target) = target.clone() {
+                                    selected.set(Some(target));
+                                }
+                            },
+                            "{entry}"
+                        }
+                        if editable {
+                            {
+                                let up_name = name.clone();
+                                let remove_name = name.clone();
+                                rsx! {
+                                    button {
+                                        class: "playlist-entry-btn",
+                                        title: "Move up",
+                                        disabled: index == 0,
+                                        onclick: move |_| store.move_up(&up_name, index),
+                                        ArrowUp { size: 14, stroke_width: 2 }
+                                    }
+                                    button {
+                                        class: "playlist-entry-btn",
+                                        title: "Remove",
+                                        onclick: move |_| store.remove(&remove_name, index),
+                                        X { size: 14, stroke_width: 2 }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Dropdown adding `entry` (a story id or doc path) to one of the user
+/// playlists, or to a new one.
+#[component]
+pub(crate) fn AddToPlaylist(entry: String) -> Element {
+    let store = use_context::<PlaylistStore>();
+    let names = store.editable_names();
+
+    rsx! {
+        select {
+            class: "playlist-add",
+            title: "Add to playlist",
+            value: "",
+            onchange: move |e: Event<FormData>| {
+                let name = match e.value().as_str() {
+                    "" => return,
+                    "__new__" => store.create("Playlist"),
+                    name => name.to_string(),
+                };
+                store.add(&name, &entry);
+            },
+            option { value: "", "Add to playlist…" }
+            for name in names {
+                option { key: "{name}", value: "{name}", "{name}" }
+            }
+            option { value: "__new__", "New playlist" }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::ui::models::Selection;
+use crate::ui::view::doc_page::DocPage;
+use crate::ui::view::shared::Overridable;
+use crate::ui::view::story::PresentationSlide;
+use crate::ui::viewmodels::presentation_vm::use_presentation;
+use crate::ui::viewmodels::story_page_vm::resolve_story_page;
+use crate::{Slot, find_doc};
+use dioxus::prelude::*;
+use lucide_dioxus::{ChevronLeft, ChevronRight, Pause, Play, X};
+
+/// Full-screen presentation stepping through `slides`, without any of the
+/// storybook chrome.
+///
+/// The arrow keys (or space) move between slides, `A` toggles autoplay and
+/// `Escape` calls `on_exit`. Controls fade in when hovering the bottom of the
+/// screen.
+#[component]
+pub(crate) fn Presentation(slides: Vec<Selection>, on_exit: EventHandler<()>) -> Element {
+    let state = use_presentation(slides.len());
+    let mut autoplay = state.autoplay;
+    let index = (state.index)();
+
+    let title = match slides.get(index) {
+        Some(Selection::Story(component_name, story_index)) => {
+            resolve_story_page(component_name, *story_index)
+                .map(|data| format!("{component_name} / {}", data.story_title))
+                .unwrap_or_default()
+        }
+        Some(Selection::DocPage(path)) => path.clone(),
+        _ => String::new(),
+    };
+
+    rsx! {
+        div {
+            class: "presentation",
+            tabindex: 0,
+            onmounted: move |e| async move {
+                let _ = e.set_focus(true).await;
+            },
+            onkeydown: move |e| match e.key() {
+                Key::ArrowRight | Key::ArrowDown | Key::PageDown => state.next(),
+                Key::ArrowLeft | Key::ArrowUp | Key::PageUp => state.previous(),
+                Key::Character(c) if c == " " => state.next(),
+                Key::Character(c) if c.eq_ignore_ascii_case("a") => autoplay.toggle(),
+                Key::Escape => on_exit.call(()),
+                _ => {}
+            },
+            div { class: "presentation-slide",
+                match slides.get(index).cloned() {
+                    Some(Selection::Story(component_name, story_index)) => {
+                        match resolve_story_page(&component_name, story_index) {
+                            Ok(data) => rsx! {
+                                PresentationSlide {
+                                    key: "{component_name}-{story_index}",
+                                    component_name,
+                                    story_index,
+                                    story: data.story,
+                                    render_fn: data.render_fn,
+                                }
+                            },
+                            Err(e) => rsx! {
+                                Overridable {
+                                    slot: Slot::Error,
+                                    message: e.to_string(),
+                                    div { class: "error", "{e}" }
+                                }
+                            },
+                        }
+                    }
+                    Some(Selection::DocPage(path)) => match find_doc(&path) {
+                        Ok(doc) => rsx! {
+                            DocPage {
+                                key: "{path}",
+                                content_html: doc.content_html.to_string(),
+                                source: doc.source_asset,
+                            }
+                        },
+                        Err(e) => rsx! {
+                            Overridable {
+                                slot: Slot::Error,
+                                message: e.to_string(),
+                                div { class: "error", "{e}" }
+                            }
+                        },
+                    },
+                    _ => rsx! {
+                        div { class: "empty-state",
+                            h2 { "Nothing to present" }
+                            p { "The presentation has no slide" }
+                        }
+                    },
+                }
+            }
+            div { class: "presentation-controls",
+                button {
+                    class: "presentation-btn",
+                    title: "Previous slide",
+                    onclick: move |_| state.previous(),
+                    ChevronLeft { size: 18, stroke_width: 2 }
+                }
+                if !slides.is_empty() {
+                    span { class: "presentation-position", "{index + 1} / {slides.len()}" }
+                }
+                button {
+                    class: "presentation-btn",
+                    title: "Next slide",
+                    onclick: move |_| state.next(),
+                    ChevronRight { size: 18, stroke_width: 2 }
+                }
+                button {
+                    class: if autoplay() { "presentation-btn active" } else { "presentation-btn" },
+                    title: if autoplay() { "Pause autoplay (A)" } else { "Autoplay (A)" },
+                    onclick: move |_| autoplay.toggle(),
+                    if autoplay() {
+                        Pause { size: 18, stroke_width: 2 }
+                    } else {
+                        Play { size: 18, stroke_width: 2 }
+                    }
+                }
+                span { class: "presentation-title", "{title}" }
+                button {
+                    class: "presentation-btn",
+                    title: "Leave the presentation (Esc)",
+                    onclick: move |_| on_exit.call(()),
+                    X { size: 18, stroke_width: 2 }
+                }
+            }
+        }
+    }
+}
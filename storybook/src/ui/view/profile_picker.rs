@@ -0,0 +1,25 @@
+use crate::ui::models::ProfileInfo;
+use crate::ui::viewmodels::profile_vm::select_profile;
+use dioxus::prelude::*;
+use lucide_dioxus::LayoutGrid;
+
+/// Startup page letting the user pick one of several storybook profiles.
+#[component]
+pub(crate) fn ProfilePicker(profiles: Vec<ProfileInfo>) -> Element {
+    rsx! {
+        div { class: "profile-picker",
+            h2 { "Choose a storybook" }
+            div { class: "profile-picker-list",
+                for profile in profiles {
+                    button {
+                        key: "{profile.slug}",
+                        class: "profile-picker-item",
+                        onclick: move |_| select_profile(&profile.slug),
+                        LayoutGrid { size: 20, stroke_width: 2 }
+                        span { "{profile.name}" }
+                    }
+                }
+            }
+        }
+    }
+}
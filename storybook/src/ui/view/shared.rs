@@ -1,3 +1,6 @@
+mod badge;
+pub use badge::*;
+
 mod button;
 pub use button::*;
 
@@ -7,6 +10,9 @@ pub use select::*;
 mod input;
 pub use input::*;
 
+mod overridable;
+pub use overridable::*;
+
 mod table;
 pub use table::*;
 
@@ -0,0 +1,36 @@
+use crate::ComponentStatus;
+use dioxus::prelude::*;
+
+#[cfg(feature = "self-stories")]
+use crate::{self as storybook};
+
+#[cfg(feature = "self-stories")]
+use storybook_macro::storybook;
+
+/// Colored badge showing the maturity of a component.
+///
+/// Rendered next to the component in the sidebar and in the story page
+/// header. The color follows the status, e.g. red for `deprecated`.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `status` | `ComponentStatus` | Status set with `#[storybook(status = "...")]`. |
+///
+/// @[story:Atoms/StatusBadge/Stable]
+///
+/// @[story:Atoms/StatusBadge/Beta]
+///
+/// @[story:Atoms/StatusBadge/Experimental]
+///
+/// @[story:Atoms/StatusBadge/Deprecated]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn StatusBadge(status: ComponentStatus) -> Element {
+    let name = status.name();
+
+    rsx! {
+        span { class: "status-badge status-{name}", title: "Status: {name}", "{name}" }
+    }
+}
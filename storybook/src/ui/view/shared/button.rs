@@ -1,5 +1,13 @@
+use crate::ZoomConfig;
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::UiSettings;
+use crate::ui::viewmodels::zoom_vm::fit_to_width;
 use dioxus::prelude::*;
-use lucide_dioxus::{Grid3X3, Maximize2, Minimize2, Moon, RotateCcw, Square, Sun, ZoomIn, ZoomOut};
+use lucide_dioxus::{
+    Activity, ArrowRightLeft, Columns2, FileDiff, GitCompare, Grid3X3, Maximize2, Minimize2,
+    MonitorSmartphone, Moon, MoveHorizontal, Presentation, RotateCcw, Ruler, Smartphone, Square,
+    Sun, ZoomIn, ZoomOut,
+};
 
 #[cfg(feature = "self-stories")]
 use crate::{self as storybook};
@@ -63,6 +71,93 @@ pub fn OutlineButton(outline_enabled: Signal<bool>) -> Element {
     }
 }
 
+/// Toggle button for the measure tool on the story preview.
+///
+/// Renders a toolbar button with a `Ruler` icon. When active, hovering an
+/// element in the story preview highlights its content box, padding and
+/// margin, and shows its dimensions — like Storybook.js's measure addon.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `measure_enabled` | `Signal<bool>` | Reactive flag — `true` enables the measure overlay. |
+///
+/// @[story:Atoms/MeasureButton/Enabled]
+///
+/// @[story:Atoms/MeasureButton/Disabled]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn MeasureButton(measure_enabled: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: if measure_enabled() { "top-bar-btn active" } else { "top-bar-btn" },
+            title: if measure_enabled() { "Disable measure tool" } else { "Measure elements on hover" },
+            onclick: move |_| measure_enabled.toggle(),
+            Ruler {}
+        }
+    }
+}
+
+/// Toggle button for drawing a device bezel around mobile viewport previews.
+///
+/// Renders a toolbar button with a `Smartphone` icon. When active and a
+/// phone or tablet viewport preset is selected, the story preview is
+/// wrapped in a device frame (status bar, notch, home indicator) so
+/// screenshots look presentation-ready. The frame is never drawn for the
+/// full-width viewport.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `device_frame_enabled` | `Signal<bool>` | Reactive flag — `true` draws the device frame. |
+///
+/// @[story:Atoms/DeviceFrameButton/Enabled]
+///
+/// @[story:Atoms/DeviceFrameButton/Disabled]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn DeviceFrameButton(device_frame_enabled: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: if device_frame_enabled() { "top-bar-btn active" } else { "top-bar-btn" },
+            title: if device_frame_enabled() { "Hide device frame" } else { "Show device frame" },
+            onclick: move |_| device_frame_enabled.toggle(),
+            Smartphone {}
+        }
+    }
+}
+
+/// Toggle button for the responsive sweep view.
+///
+/// Renders a toolbar button with a `MonitorSmartphone` icon. When active,
+/// the story page renders the current story once per viewport preset,
+/// stacked vertically with width labels, so responsive behavior can be
+/// reviewed at a glance without toggling presets manually.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `responsive_sweep` | `Signal<bool>` | Reactive flag — `true` shows every viewport. |
+///
+/// @[story:Atoms/ResponsiveSweepButton/Enabled]
+///
+/// @[story:Atoms/ResponsiveSweepButton/Disabled]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn ResponsiveSweepButton(responsive_sweep: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: if responsive_sweep() { "top-bar-btn active" } else { "top-bar-btn" },
+            title: if responsive_sweep() { "Hide responsive sweep" } else { "Show all viewports" },
+            onclick: move |_| responsive_sweep.toggle(),
+            MonitorSmartphone {}
+        }
+    }
+}
+
 /// Toggle button for switching the story preview background between light
 /// and dark.
 ///
@@ -97,10 +192,192 @@ pub fn ThemeToggleButton(dark_preview_background: Signal<bool>) -> Element {
     }
 }
 
+/// Toggle button for the right-to-left preview.
+///
+/// Renders a toolbar button with an `ArrowRightLeft` icon. When active, the
+/// preview body gets `dir="rtl"`, so bidirectional layouts can be checked
+/// for every story without changing the components.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `rtl_enabled` | `Signal<bool>` | Reactive flag — `true` renders the previews right-to-left. |
+///
+/// @[story:Atoms/DirectionButton/Enabled]
+///
+/// @[story:Atoms/DirectionButton/Disabled]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn DirectionButton(rtl_enabled: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: if rtl_enabled() { "top-bar-btn active" } else { "top-bar-btn" },
+            title: if rtl_enabled() { "Preview: right-to-left" } else { "Preview: left-to-right" },
+            onclick: move |_| rtl_enabled.toggle(),
+            ArrowRightLeft {}
+        }
+    }
+}
+
+/// Toggle button for the light/dark split view.
+///
+/// Renders a toolbar button with a `Columns2` icon. When active, the story
+/// page renders the current story twice side by side — once with the light
+/// preview theme and once with the dark one — so theme parity is easy to
+/// verify. Both previews share the same props.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `theme_sweep` | `Signal<bool>` | Reactive flag — `true` shows light and dark side by side. |
+///
+/// @[story:Atoms/ThemeSweepButton/Enabled]
+///
+/// @[story:Atoms/ThemeSweepButton/Disabled]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn ThemeSweepButton(theme_sweep: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: if theme_sweep() { "top-bar-btn active" } else { "top-bar-btn" },
+            title: if theme_sweep() { "Hide light/dark split view" } else { "Show light and dark side by side" },
+            onclick: move |_| theme_sweep.toggle(),
+            Columns2 {}
+        }
+    }
+}
+
+/// Toggle button for the story comparison view.
+///
+/// Renders a toolbar button with a `GitCompare` icon. When active, the
+/// story page renders the current story next to a second story of the same
+/// component — or the same story with its own props — in viewports of the
+/// same size, to review the differences between variants.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `compare` | `Signal<bool>` | Reactive flag — `true` shows two stories side by side. |
+///
+/// @[story:Atoms/CompareButton/Enabled]
+///
+/// @[story:Atoms/CompareButton/Disabled]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn CompareButton(compare: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: if compare() { "top-bar-btn active" } else { "top-bar-btn" },
+            title: if compare() { "Hide comparison" } else { "Compare with another story" },
+            onclick: move |_| compare.toggle(),
+            GitCompare {}
+        }
+    }
+}
+
+/// Top bar button opening the session activity page.
+///
+/// Renders a toolbar button with an `Activity` icon, highlighted while the
+/// page is shown. Only displayed when
+/// [`StorybookConfig::with_session_activity`](crate::StorybookConfig::with_session_activity)
+/// is enabled.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `selected` | `Signal<Option<Selection>>` | Currently selected sidebar item (set on click). |
+///
+/// @[story:Atoms/ActivityButton/Default]
+///
+/// @[story:Atoms/ActivityButton/Active]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn ActivityButton(selected: Signal<Option<Selection>>) -> Element {
+    let active = selected() == Some(Selection::Activity);
+    rsx! {
+        button {
+            class: if active { "top-bar-btn active" } else { "top-bar-btn" },
+            title: "Session activity",
+            onclick: move |_| selected.set(Some(Selection::Activity)),
+            Activity {}
+        }
+    }
+}
+
+/// Top bar button opening the snapshot review page.
+///
+/// Renders a toolbar button with a `FileDiff` icon and the number of
+/// snapshots awaiting review, highlighted while the page is shown. Only
+/// displayed when a batch snapshot found new or changed stories.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `pending` | `usize` | Number of snapshots awaiting review. |
+/// | `selected` | `Signal<Option<Selection>>` | Currently selected sidebar item (set on click). |
+///
+/// @[story:Atoms/SnapshotReviewButton/Default]
+///
+/// @[story:Atoms/SnapshotReviewButton/Active]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn SnapshotReviewButton(pending: usize, selected: Signal<Option<Selection>>) -> Element {
+    let active = selected() == Some(Selection::SnapshotReview);
+    rsx! {
+        button {
+            class: if active { "top-bar-btn active" } else { "top-bar-btn" },
+            title: "Review snapshots",
+            onclick: move |_| selected.set(Some(Selection::SnapshotReview)),
+            FileDiff {}
+            span { class: "top-bar-badge", "{pending}" }
+        }
+    }
+}
+
+/// Top bar button starting the presentation mode.
+///
+/// Renders a toolbar button with a `Presentation` icon. Clicking it hides
+/// the storybook chrome and steps through the configured slides (see
+/// [`StorybookConfig::with_presentation`](crate::StorybookConfig::with_presentation)).
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `presenting` | `Signal<bool>` | Reactive flag — set to `true` on click. |
+///
+/// @[story:Atoms/PresentButton/Default]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn PresentButton(presenting: Signal<bool>) -> Element {
+    rsx! {
+        button {
+            class: "top-bar-btn",
+            title: "Start the presentation",
+            onclick: move |_| presenting.set(true),
+            Presentation {}
+        }
+    }
+}
+
+/// The [`ZoomConfig`] shared through [`UiSettings`], or the default one
+/// outside the storybook (e.g. in the buttons' own stories).
+fn shared_zoom() -> ZoomConfig {
+    try_use_context::<UiSettings>()
+        .map(|settings| settings.zoom)
+        .unwrap_or_default()
+}
+
 /// Toolbar button that decreases the story preview zoom level.
 ///
-/// Each click reduces `zoom_level` by 25 percentage points, with a minimum
-/// of 25 %. Renders a `ZoomOut` icon.
+/// Each click reduces `zoom_level` by one step of the shared
+/// [`ZoomConfig`] (25 points down to 25 % by default). Renders a `ZoomOut`
+/// icon.
 ///
 /// # Props
 ///
@@ -112,15 +389,14 @@ pub fn ThemeToggleButton(dark_preview_background: Signal<bool>) -> Element {
 #[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
 #[component]
 pub fn ZoomOutButton(zoom_level: Signal<i32>) -> Element {
+    let zoom = shared_zoom();
     rsx! {
         button {
             class: "top-bar-btn",
             title: "Zoom Out",
             onclick: move |_| {
                 let current = zoom_level();
-                if current > 25 {
-                    zoom_level.set(current - 25);
-                }
+                zoom_level.set(zoom.zoom_out(current));
             },
             ZoomOut {}
         }
@@ -129,8 +405,9 @@ pub fn ZoomOutButton(zoom_level: Signal<i32>) -> Element {
 
 /// Toolbar button that increases the story preview zoom level.
 ///
-/// Each click increases `zoom_level` by 25 percentage points, with a maximum
-/// of 200 %. Renders a `ZoomIn` icon.
+/// Each click increases `zoom_level` by one step of the shared
+/// [`ZoomConfig`] (25 points up to 200 % by default). Renders a `ZoomIn`
+/// icon.
 ///
 /// # Props
 ///
@@ -142,15 +419,14 @@ pub fn ZoomOutButton(zoom_level: Signal<i32>) -> Element {
 #[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
 #[component]
 pub fn ZoomInButton(zoom_level: Signal<i32>) -> Element {
+    let zoom = shared_zoom();
     rsx! {
         button {
             class: "top-bar-btn",
             title: "Zoom In",
             onclick: move |_| {
                 let current = (zoom_level)();
-                if current < 200 {
-                    zoom_level.set(current + 25);
-                }
+                zoom_level.set(zoom.zoom_in(current));
             },
             ZoomIn {}
         }
@@ -160,7 +436,8 @@ pub fn ZoomInButton(zoom_level: Signal<i32>) -> Element {
 /// Toolbar button that resets the story preview zoom to 100 %.
 ///
 /// Renders a `RotateCcw` icon. Clicking sets `zoom_level` back to `100`
-/// regardless of its current value.
+/// (or the nearest bound of the shared [`ZoomConfig`] when 100 % is out of
+/// range) regardless of its current value.
 ///
 /// # Props
 ///
@@ -172,16 +449,45 @@ pub fn ZoomInButton(zoom_level: Signal<i32>) -> Element {
 #[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
 #[component]
 pub fn ResetZoomButton(zoom_level: Signal<i32>) -> Element {
+    let zoom = shared_zoom();
     rsx! {
         button {
             class: "top-bar-btn",
             title: "Reset Zoom",
-            onclick: move |_| zoom_level.set(100),
+            onclick: move |_| zoom_level.set(zoom.clamp(100)),
             RotateCcw {}
         }
     }
 }
 
+/// Toolbar button zooming the preview so its content fits the preview width.
+///
+/// Measures the widest element of the preview `preview_id` and sets
+/// `zoom_level` accordingly, within the range of the shared [`ZoomConfig`].
+/// Renders a `MoveHorizontal` icon.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `zoom_level` | `Signal<i32>` | Current zoom percentage (mutated on click). |
+/// | `preview_id` | `String` | DOM id of the preview to fit. |
+///
+/// @[story:Atoms/FitWidthButton/Default]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Atoms"))]
+#[component]
+pub fn FitWidthButton(zoom_level: Signal<i32>, preview_id: String) -> Element {
+    let zoom = shared_zoom();
+    rsx! {
+        button {
+            class: "top-bar-btn",
+            title: "Fit to Width",
+            onclick: move |_| fit_to_width(zoom_level, zoom, preview_id.clone()),
+            MoveHorizontal {}
+        }
+    }
+}
+
 /// Toggle button for hiding or showing the sidebar.
 ///
 /// When fullscreen mode is active the sidebar is hidden and the story
@@ -0,0 +1,19 @@
+use crate::{Slot, SlotContext, StorybookConfig};
+use dioxus::prelude::*;
+
+/// A built-in part of the UI, `children`, replaced by the component the
+/// config overrides `slot` with, if any (see
+/// [`StorybookConfig::with_override`]). `message` is passed on to the
+/// override.
+#[component]
+pub fn Overridable(
+    slot: Slot,
+    #[props(default)] message: Option<String>,
+    children: Element,
+) -> Element {
+    let render = try_use_context::<StorybookConfig>().and_then(|config| config.slot_override(slot));
+    match render {
+        Some(render) => render(SlotContext { message }),
+        None => children,
+    }
+}
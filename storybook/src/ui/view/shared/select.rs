@@ -1,4 +1,5 @@
-use crate::ui::models::ViewportSize;
+use crate::ui::models::{ProfileInfo, ViewportSize};
+use crate::ui::viewmodels::profile_vm::select_profile;
 #[cfg(feature = "self-stories")]
 use crate::{self as storybook};
 #[cfg(feature = "self-stories")]
@@ -41,6 +42,62 @@ pub fn ViewPortSelector(viewport_width: Signal<ViewportSize>) -> Element {
     }
 }
 
+/// Dropdown switching between the storybook profiles.
+///
+/// Renders a `<select>` listing every registered
+/// [`Profile`](crate::Profile). Choosing another profile reloads the
+/// storybook with the `?profile=` URL parameter set.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `profiles` | `Vec<ProfileInfo>` | The registered profiles. |
+/// | `active` | `Option<String>` | Slug of the active profile. |
+///
+/// @[story:Molecules/ProfileSwitcher/Default]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Molecules"))]
+#[component]
+pub fn ProfileSwitcher(profiles: Vec<ProfileInfo>, active: Option<String>) -> Element {
+    rsx! {
+        select {
+            class: "top-bar-viewport-select",
+            title: "Storybook profile",
+            onchange: move |e: Event<FormData>| select_profile(&e.value()),
+            for profile in profiles.iter() {
+                option {
+                    key: "{profile.slug}",
+                    value: "{profile.slug}",
+                    selected: active.as_deref() == Some(profile.slug.as_str()),
+                    "{profile.name}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "self-stories")]
+impl Stories for ProfileSwitcherProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                profiles: vec![
+                    ProfileInfo {
+                        name: "Internal admin".to_string(),
+                        slug: "internal-admin".to_string(),
+                    },
+                    ProfileInfo {
+                        name: "Public widgets".to_string(),
+                        slug: "public-widgets".to_string(),
+                    },
+                ],
+                active: Some("public-widgets".to_string()),
+            },
+        )]
+    }
+}
+
 #[cfg(feature = "self-stories")]
 impl Stories for ViewPortSelectorProps {
     fn stories() -> Vec<Story<Self>> {
@@ -1,9 +1,13 @@
+use crate::ui::models::Selection;
 use crate::ui::view::shared::{
-    CheckboxProps, FullscreenButtonProps, GridButtonProps, OutlineButtonProps,
-    ResetZoomButtonProps, TdProps, TextInputProps, ThemeToggleButtonProps, TrProps,
-    ZoomInButtonProps, ZoomOutButtonProps,
+    ActivityButtonProps, CheckboxProps, CompareButtonProps, DeviceFrameButtonProps,
+    DirectionButtonProps, FitWidthButtonProps, FullscreenButtonProps, GridButtonProps,
+    MeasureButtonProps, OutlineButtonProps, PresentButtonProps, ResetZoomButtonProps,
+    ResponsiveSweepButtonProps, SnapshotReviewButtonProps, StatusBadgeProps, TdProps,
+    TextInputProps, ThemeSweepButtonProps, ThemeToggleButtonProps, TrProps, ZoomInButtonProps,
+    ZoomOutButtonProps,
 };
-use crate::{Stories, Story};
+use crate::{ComponentStatus, Stories, Story};
 use dioxus::prelude::*;
 
 impl Stories for GridButtonProps {
@@ -25,6 +29,25 @@ impl Stories for GridButtonProps {
     }
 }
 
+impl Stories for MeasureButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Enabled",
+                Self {
+                    measure_enabled: Signal::new(true),
+                },
+            ),
+            Story::new(
+                "Disabled",
+                Self {
+                    measure_enabled: Signal::new(false),
+                },
+            ),
+        ]
+    }
+}
+
 impl Stories for OutlineButtonProps {
     fn stories() -> Vec<Story<Self>> {
         vec![
@@ -43,6 +66,44 @@ impl Stories for OutlineButtonProps {
         ]
     }
 }
+impl Stories for DeviceFrameButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Enabled",
+                Self {
+                    device_frame_enabled: Signal::new(true),
+                },
+            ),
+            Story::new(
+                "Disabled",
+                Self {
+                    device_frame_enabled: Signal::new(false),
+                },
+            ),
+        ]
+    }
+}
+
+impl Stories for ResponsiveSweepButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Enabled",
+                Self {
+                    responsive_sweep: Signal::new(true),
+                },
+            ),
+            Story::new(
+                "Disabled",
+                Self {
+                    responsive_sweep: Signal::new(false),
+                },
+            ),
+        ]
+    }
+}
+
 impl Stories for ThemeToggleButtonProps {
     fn stories() -> Vec<Story<Self>> {
         vec![
@@ -62,6 +123,114 @@ impl Stories for ThemeToggleButtonProps {
     }
 }
 
+impl Stories for SnapshotReviewButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Default",
+                Self {
+                    pending: 3,
+                    selected: Signal::new(None),
+                },
+            ),
+            Story::new(
+                "Active",
+                Self {
+                    pending: 12,
+                    selected: Signal::new(Some(Selection::SnapshotReview)),
+                },
+            ),
+        ]
+    }
+}
+
+impl Stories for ActivityButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Default",
+                Self {
+                    selected: Signal::new(None),
+                },
+            ),
+            Story::new(
+                "Active",
+                Self {
+                    selected: Signal::new(Some(Selection::Activity)),
+                },
+            ),
+        ]
+    }
+}
+
+impl Stories for PresentButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                presenting: Signal::new(false),
+            },
+        )]
+    }
+}
+
+impl Stories for CompareButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Enabled",
+                Self {
+                    compare: Signal::new(true),
+                },
+            ),
+            Story::new(
+                "Disabled",
+                Self {
+                    compare: Signal::new(false),
+                },
+            ),
+        ]
+    }
+}
+
+impl Stories for DirectionButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Enabled",
+                Self {
+                    rtl_enabled: Signal::new(true),
+                },
+            ),
+            Story::new(
+                "Disabled",
+                Self {
+                    rtl_enabled: Signal::new(false),
+                },
+            ),
+        ]
+    }
+}
+
+impl Stories for ThemeSweepButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Enabled",
+                Self {
+                    theme_sweep: Signal::new(true),
+                },
+            ),
+            Story::new(
+                "Disabled",
+                Self {
+                    theme_sweep: Signal::new(false),
+                },
+            ),
+        ]
+    }
+}
+
 impl Stories for ZoomOutButtonProps {
     fn stories() -> Vec<Story<Self>> {
         vec![Story::new(
@@ -84,6 +253,18 @@ impl Stories for ZoomInButtonProps {
     }
 }
 
+impl Stories for FitWidthButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                zoom_level: Signal::new(100),
+                preview_id: "fullscreen-preview".to_string(),
+            },
+        )]
+    }
+}
+
 impl Stories for ResetZoomButtonProps {
     fn stories() -> Vec<Story<Self>> {
         vec![Story::new(
@@ -170,3 +351,17 @@ impl Stories for TdProps {
         )]
     }
 }
+
+impl Stories for StatusBadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        [
+            ("Stable", ComponentStatus::Stable),
+            ("Beta", ComponentStatus::Beta),
+            ("Experimental", ComponentStatus::Experimental),
+            ("Deprecated", ComponentStatus::Deprecated),
+        ]
+        .into_iter()
+        .map(|(title, status)| Story::new(title, Self { status }))
+        .collect()
+    }
+}
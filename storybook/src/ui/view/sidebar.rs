@@ -1,13 +1,25 @@
-use crate::ui::models::{ComponentInfo, NodeType, Selection};
+use crate::ui::models::{ComponentInfo, NodeType, Selection, TagFilter};
 use crate::ui::services::category_builder::build_category_tree;
+use crate::ui::view::shared::Overridable;
+use crate::ui::view::sidebar::bookmarks::BookmarkSections;
 use crate::ui::view::sidebar::node::ComponentNode;
+use crate::ui::view::sidebar::playlists::PlaylistList;
 use crate::ui::view::sidebar::search_input::SearchInput;
+use crate::ui::view::sidebar::tag_filter::TagFilterSelect;
 use crate::ui::view::sidebar::tree::{DocNode, TreeNode};
-use crate::ui::viewmodels::sidebar_vm::{get_story_titles, has_component_docs};
+use crate::ui::viewmodels::sidebar_vm::{
+    component_status, get_story_groups, has_story_match, use_sidebar_expansion,
+};
+use crate::{Slot, StorybookConfig, doc_component};
 use dioxus::prelude::*;
+use lucide_dioxus::{ChevronsDownUp, ChevronsUpDown};
 
+mod bookmarks;
+mod highlight;
 mod node;
+mod playlists;
 mod search_input;
+mod tag_filter;
 mod tree;
 
 #[cfg(feature = "self-stories")]
@@ -16,32 +28,74 @@ mod stories;
 #[component]
 pub fn Sidebar(
     search_query: Signal<String>,
+    tag_filter: Signal<TagFilter>,
+    tags: Vec<String>,
     components: Vec<ComponentInfo>,
     selected: Signal<Option<Selection>>,
 ) -> Element {
+    let config = use_context::<StorybookConfig>();
+
     rsx! {
         div { class: "sidebar",
             SearchInput { search_query }
-            ComponentTree { components, selected }
+            TagFilterSelect { tag_filter, tags }
+            BookmarkSections { selected }
+            ComponentTree {
+                components,
+                selected,
+                tag_filter,
+                search_query,
+            }
+            PlaylistList { selected }
+            if config.slot_override(Slot::Sidebar).is_some() {
+                div { class: "sidebar-extras",
+                    Overridable { slot: Slot::Sidebar }
+                }
+            }
         }
     }
 }
 
+/// The sidebar tree of categories, folders and components. While searching,
+/// the matches in names are highlighted and the components with matching
+/// stories are expanded.
 #[component]
 pub fn ComponentTree(
     components: Vec<ComponentInfo>,
     selected: Signal<Option<Selection>>,
+    tag_filter: Signal<TagFilter>,
+    search_query: Signal<String>,
 ) -> Element {
-    let tree = build_category_tree(&components);
+    let config = use_context::<StorybookConfig>();
+    let expansion = use_sidebar_expansion();
+    use_effect(move || expansion.search(&search_query()));
+    let query = search_query();
+    let tree = build_category_tree(&components, &config);
+    let children = tree.ordered_children();
+    let paths = tree.descendant_paths();
 
     rsx! {
+        div { class: "component-tree-toolbar",
+            button {
+                class: "component-tree-btn",
+                title: "Expand all",
+                onclick: move |_| expansion.expand_all(),
+                ChevronsUpDown { size: 14, stroke_width: 2 }
+            }
+            button {
+                class: "component-tree-btn",
+                title: "Collapse all",
+                onclick: move |_| expansion.collapse_all(paths.clone()),
+                ChevronsDownUp { size: 14, stroke_width: 2 }
+            }
+        }
         div { class: "component-tree",
             // Render root-level doc page (e.g. storydoc!("", "..."))
             if tree.has_doc {
                 DocNode { path: String::new(), selected }
             }
             // Render doc-only root nodes first (no components, no sub-children)
-            for (category_name , node) in tree.children.iter() {
+            for (category_name , node) in children.iter().copied() {
                 if node.has_doc && node.component_count() == 0 && node.children.is_empty() {
                     DocNode {
                         key: "{category_name}",
@@ -52,14 +106,16 @@ pub fn ComponentTree(
                 }
             }
             // Then render category tree nodes
-            for (category_name , node) in tree.children.iter() {
+            for (category_name , node) in children.iter().copied() {
                 if !(node.has_doc && node.component_count() == 0 && node.children.is_empty()) {
                     TreeNode {
                         key: "{category_name}",
                         name: category_name.clone(),
                         node: node.clone(),
                         selected,
+                        tag_filter,
                         node_type: NodeType::Category,
+                        query: query.clone(),
                     }
                 }
             }
@@ -67,14 +123,17 @@ pub fn ComponentTree(
             for component_name in tree.components.iter() {
                 {
                     let component_name = component_name.clone();
-                    let stories = get_story_titles(&component_name);
-                    let has_docs = has_component_docs(&component_name);
-                    let doc_path = format!("__component__/{component_name}");
+                    let stories = get_story_groups(&component_name, &tag_filter());
                     let is_active = matches!(
                         selected(),
-                        Some(Selection::Story(ref cn, _))
+                        Some(Selection::Story(ref cn, _) | Selection::Component(ref cn))
                         if cn == &component_name
-                    ) || selected() == Some(Selection::DocPage(doc_path));
+                    ) || matches!(
+                        selected(),
+                        Some(Selection::DocPage(ref path))
+                        if doc_component(path).is_some_and(|c| c.name == component_name)
+                    )
+                        || has_story_match(&stories, &query);
                     rsx! {
                         ComponentNode {
                             key: "{component_name}",
@@ -82,7 +141,8 @@ pub fn ComponentTree(
                             selected,
                             stories,
                             is_active,
-                            has_docs,
+                            query: query.clone(),
+                            status: component_status(&component_name),
                         }
                     }
                 }
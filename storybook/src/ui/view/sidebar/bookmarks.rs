@@ -0,0 +1,142 @@
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::sidebar_vm::{SidebarBookmarks, resolve_bookmarked_story, story_id_at};
+use dioxus::prelude::*;
+use lucide_dioxus::{BookOpen, Clock, Component, Star};
+
+/// Star toggling a component (or, with `story_index`, one of its stories)
+/// in the favorites. Renders nothing outside the sidebar (e.g. as a story
+/// preview).
+#[component]
+pub(super) fn FavoriteStar(component_name: String, story_index: Option<usize>) -> Element {
+    let Some(bookmarks) = try_use_context::<SidebarBookmarks>() else {
+        return rsx! {};
+    };
+    let story_id = match story_index {
+        Some(index) => match story_id_at(&component_name, index) {
+            Some(id) => Some(id),
+            None => return rsx! {},
+        },
+        None => None,
+    };
+    let is_favorite = match &story_id {
+        Some(id) => bookmarks.is_favorite_story(id),
+        None => bookmarks.is_favorite_component(&component_name),
+    };
+
+    rsx! {
+        button {
+            class: if is_favorite { "favorite-star active" } else { "favorite-star" },
+            title: if is_favorite { "Remove from favorites" } else { "Add to favorites" },
+            onclick: move |e| {
+                e.stop_propagation();
+                match &story_id {
+                    Some(id) => bookmarks.toggle_story(id),
+                    None => bookmarks.toggle_component(&component_name),
+                }
+            },
+            Star { size: 12, stroke_width: 2 }
+        }
+    }
+}
+
+/// Sidebar sections listing the favorite components and stories, and the
+/// recently viewed stories. Bookmarks of stories that no longer exist are
+/// left out.
+#[component]
+pub(super) fn BookmarkSections(mut selected: Signal<Option<Selection>>) -> Element {
+    let bookmarks = use_context::<SidebarBookmarks>();
+    let current = (bookmarks.bookmarks)();
+    let favorite_stories: Vec<_> = current
+        .stories
+        .iter()
+        .filter_map(|id| resolve_bookmarked_story(id))
+        .collect();
+    let recent: Vec<_> = current
+        .recent
+        .iter()
+        .filter_map(|id| resolve_bookmarked_story(id))
+        .collect();
+    let has_favorites = !current.components.is_empty() || !favorite_stories.is_empty();
+
+    rsx! {
+        if has_favorites {
+            div { class: "bookmark-list",
+                div { class: "bookmark-list-title", "Favorites" }
+                for name in current.components {
+                    {
+                        let is_selected = selected() == Some(Selection::Component(name.clone()));
+                        rsx! {
+                            div {
+                                key: "component-{name}",
+                                class: if is_selected { "bookmark-node selected" } else { "bookmark-node" },
+                                onclick: {
+                                    let name = name.clone();
+                                    move |_| selected.set(Some(Selection::Component(name.clone())))
+                                },
+                                span { class: "component-icon",
+                                    Component { size: 14, stroke_width: 2 }
+                                }
+                                span { class: "bookmark-name", "{name}" }
+                                FavoriteStar { component_name: name.clone() }
+                            }
+                        }
+                    }
+                }
+                for (component_name , story_index , label) in favorite_stories {
+                    BookmarkStoryNode {
+                        key: "story-{label}",
+                        component_name,
+                        story_index,
+                        label,
+                        selected,
+                        favorite: true,
+                    }
+                }
+            }
+        }
+        if !recent.is_empty() {
+            div { class: "bookmark-list",
+                div { class: "bookmark-list-title",
+                    Clock { size: 12, stroke_width: 2 }
+                    "Recent"
+                }
+                for (component_name , story_index , label) in recent {
+                    BookmarkStoryNode {
+                        key: "recent-{label}",
+                        component_name,
+                        story_index,
+                        label,
+                        selected,
+                        favorite: false,
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BookmarkStoryNode(
+    component_name: String,
+    story_index: usize,
+    label: String,
+    mut selected: Signal<Option<Selection>>,
+    favorite: bool,
+) -> Element {
+    let selection = Selection::Story(component_name.clone(), story_index);
+    let is_selected = selected() == Some(selection.clone());
+
+    rsx! {
+        div {
+            class: if is_selected { "bookmark-node selected" } else { "bookmark-node" },
+            onclick: move |_| selected.set(Some(selection.clone())),
+            span { class: "story-icon",
+                BookOpen { size: 12, stroke_width: 2 }
+            }
+            span { class: "bookmark-name", "{label}" }
+            if favorite {
+                FavoriteStar { component_name, story_index }
+            }
+        }
+    }
+}
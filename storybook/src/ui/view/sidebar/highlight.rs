@@ -0,0 +1,16 @@
+use crate::ui::viewmodels::sidebar_vm::highlight_parts;
+use dioxus::prelude::*;
+
+/// `text` with the matches of the sidebar search `query` highlighted.
+#[component]
+pub(super) fn Highlight(text: String, query: String) -> Element {
+    rsx! {
+        for (part , matched) in highlight_parts(&text, &query) {
+            if matched {
+                mark { class: "search-match", "{part}" }
+            } else {
+                "{part}"
+            }
+        }
+    }
+}
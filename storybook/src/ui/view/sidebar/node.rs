@@ -1,6 +1,12 @@
-use crate::ui::models::Selection;
+use crate::ui::models::{Selection, StoryGroup};
+use crate::ui::view::shared::{Overridable, StatusBadge};
+use crate::ui::view::sidebar::bookmarks::FavoriteStar;
+use crate::ui::view::sidebar::highlight::Highlight;
+use crate::ui::view::story::StoryThumbnail;
+use crate::ui::viewmodels::thumbnail_vm::{thumbnail_story, use_thumbnail};
+use crate::{ComponentStatus, Slot, doc_component};
 use dioxus::prelude::*;
-use lucide_dioxus::{BookOpen, ChevronRight, Component, FileText};
+use lucide_dioxus::{BookOpen, ChevronRight, Component, FileText, Layers};
 
 #[cfg(feature = "self-stories")]
 use crate::{self as storybook};
@@ -10,11 +16,13 @@ use storybook_macro::storybook;
 
 /// Expandable sidebar node representing a single registered component.
 ///
-/// When collapsed, shows the component name with a `Component` icon.
-/// When expanded (`is_active = true`), reveals a list of story links and
-/// an optional "Documentation" link (if the component has `///` doc
-/// comments). This is a pure presentational component — `is_active` is
-/// computed by the parent.
+/// When collapsed, shows the component name with a `Component` icon;
+/// clicking it opens the component's story gallery. When expanded (`is_active = true`), reveals a "Docs" link to the
+/// component's auto-generated documentation page (selected on its "Guide"
+/// tab too) followed by a list of
+/// story links, with the grouped stories under collapsible sections. This
+/// is a pure presentational component — `is_active` is computed by the
+/// parent.
 ///
 /// # Props
 ///
@@ -22,63 +30,78 @@ use storybook_macro::storybook;
 /// |------|------|---------|-------------|
 /// | `name` | `String` | — | The registered component name. |
 /// | `selected` | `Signal<Option<Selection>>` | — | Currently selected sidebar item. |
-/// | `stories` | `Vec<String>` | — | Titles of the component's stories. |
+/// | `stories` | `Vec<StoryGroup>` | — | Index and title of each listed story, by group. |
 /// | `is_active` | `bool` | — | Whether this node is currently expanded. |
-/// | `has_docs` | `bool` | `false` | Whether a "Documentation" link should be shown. |
+/// | `query` | `String` | `""` | Sidebar search query, highlighted in names. |
+/// | `status` | `Option<ComponentStatus>` | `None` | Status badge shown after the name. |
 ///
 /// @[story:Molecules/ComponentNode/Collapsed]
 ///
 /// @[story:Molecules/ComponentNode/Expanded]
 ///
-/// @[story:Molecules/ComponentNode/Expanded with Docs]
+/// @[story:Molecules/ComponentNode/Grouped]
+///
+/// @[story:Molecules/ComponentNode/Deprecated]
 #[cfg_attr(feature = "self-stories", storybook(tag = "Molecules"))]
 #[component]
 pub fn ComponentNode(
     name: String,
     selected: Signal<Option<Selection>>,
-    stories: Vec<String>,
+    stories: Vec<StoryGroup>,
     is_active: bool,
-    #[props(default = false)] has_docs: bool,
+    #[props(default)] query: String,
+    #[props(default)] status: Option<ComponentStatus>,
 ) -> Element {
     let component_name = name.clone();
     let doc_path = format!("__component__/{}", name);
 
     rsx! {
         div { class: "component-node-group",
-            RootNode { name: name.clone(), expanded: is_active, selected }
+            RootNode {
+                name: name.clone(),
+                query: query.clone(),
+                status,
+                expanded: is_active,
+                selected,
+            }
             if is_active {
                 div { class: "story-children",
-                    if has_docs {
-                        {
-                            let doc_path_click = doc_path.clone();
-                            let is_doc_selected = selected() == Some(Selection::DocPage(doc_path.clone()));
-                            rsx! {
-                                div {
-                                    class: if is_doc_selected { "doc-node selected" } else { "doc-node" },
-                                    onclick: move |_| {
-                                        selected.set(Some(Selection::DocPage(doc_path_click.clone())));
-                                    },
-                                    span { class: "doc-icon",
-                                        FileText { size: 14, stroke_width: 2 }
-                                    }
-                                    span { class: "doc-name", "Documentation" }
+                    {
+                        let doc_path_click = doc_path.clone();
+                        let is_doc_selected = matches!(
+                            selected(),
+                            Some(Selection::DocPage(ref path))
+                            if doc_component(path).is_some_and(|c| c.name == name)
+                        );
+                        rsx! {
+                            div {
+                                class: if is_doc_selected { "doc-node selected" } else { "doc-node" },
+                                onclick: move |_| {
+                                    selected.set(Some(Selection::DocPage(doc_path_click.clone())));
+                                },
+                                span { class: "doc-icon",
+                                    FileText { size: 14, stroke_width: 2 }
                                 }
+                                span { class: "doc-name", "Docs" }
                             }
                         }
                     }
-                    for (index , story_title) in stories.iter().enumerate() {
-                        {
-                            let component_name = component_name.clone();
-                            let is_selected = selected()
-                                == Some(Selection::Story(component_name.clone(), index));
-                            let story_title = story_title.clone();
-                            rsx! {
-                                StoryNode {
-                                    key: "{component_name}-story-{index}",
-                                    is_selected,
-                                    onclick: move |_| selected.set(Some(Selection::Story(component_name.clone(), index))),
-                                    story_title,
-                                }
+                    for group in stories {
+                        if let Some(name) = group.name {
+                            StoryGroupNode {
+                                key: "{component_name}-group-{name}",
+                                name,
+                                component_name: component_name.clone(),
+                                stories: group.stories,
+                                selected,
+                                query: query.clone(),
+                            }
+                        } else {
+                            StoryList {
+                                component_name: component_name.clone(),
+                                stories: group.stories,
+                                selected,
+                                query: query.clone(),
                             }
                         }
                     }
@@ -89,12 +112,20 @@ pub fn ComponentNode(
 }
 
 #[component]
-fn RootNode(name: String, expanded: bool, selected: Signal<Option<Selection>>) -> Element {
+fn RootNode(
+    name: String,
+    query: String,
+    status: Option<ComponentStatus>,
+    expanded: bool,
+    selected: Signal<Option<Selection>>,
+) -> Element {
+    let component_name = name.clone();
+
     rsx! {
         div {
             class: if expanded { "component-node active" } else { "component-node" },
             onclick: move |_| {
-                selected.set(Some(Selection::Story(name.clone(), 0)));
+                selected.set(Some(Selection::Component(component_name.clone())));
             },
             span { class: if expanded { "arrow expanded" } else { "arrow" },
                 ChevronRight { size: 12, stroke_width: 2 }
@@ -102,28 +133,132 @@ fn RootNode(name: String, expanded: bool, selected: Signal<Option<Selection>>) -
             span { class: "component-icon",
                 Component { size: 14, stroke_width: 2 }
             }
-            span { class: "component-name", "{name}" }
+            span { class: "component-name",
+                Highlight { text: name.clone(), query }
+            }
+            if let Some(status) = status {
+                StatusBadge { status }
+            }
+            FavoriteStar { component_name: name }
         }
     }
 }
+/// A collapsible section of a component's stories, expanded by default.
+#[component]
+fn StoryGroupNode(
+    name: String,
+    component_name: String,
+    stories: Vec<(usize, String)>,
+    selected: Signal<Option<Selection>>,
+    query: String,
+) -> Element {
+    let mut expanded = use_signal(|| true);
+
+    rsx! {
+        div {
+            class: "story-group-node",
+            onclick: move |_| expanded.toggle(),
+            span { class: if expanded() { "arrow expanded" } else { "arrow" },
+                ChevronRight { size: 12, stroke_width: 2 }
+            }
+            span { class: "story-group-icon",
+                Layers { size: 12, stroke_width: 2 }
+            }
+            span { class: "story-group-name", "{name}" }
+            span { class: "story-group-count", "{stories.len()}" }
+        }
+        if expanded() {
+            div { class: "story-group-children",
+                StoryList {
+                    component_name,
+                    stories,
+                    selected,
+                    query,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn StoryList(
+    component_name: String,
+    stories: Vec<(usize, String)>,
+    mut selected: Signal<Option<Selection>>,
+    query: String,
+) -> Element {
+    rsx! {
+        for (index , story_title) in stories {
+            {
+                let component_name = component_name.clone();
+                let is_selected = selected() == Some(Selection::Story(component_name.clone(), index));
+                rsx! {
+                    StoryNode {
+                        key: "{component_name}-story-{index}",
+                        component_name: component_name.clone(),
+                        story_index: index,
+                        is_selected,
+                        onclick: move |_| selected.set(Some(Selection::Story(component_name.clone(), index))),
+                        story_title,
+                        query: query.clone(),
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
 fn StoryNode(
+    component_name: String,
+    story_index: usize,
     is_selected: bool,
     story_title: String,
+    query: String,
     #[props(extends = GlobalAttributes, extends = tr)] attributes: Vec<Attribute>,
     onclick: EventHandler<MouseEvent>,
 ) -> Element {
+    // Pointer position while hovered, where the thumbnail is shown
+    let mut hovered = use_signal(|| None::<(f64, f64)>);
+    let story = match hovered() {
+        Some(_) => thumbnail_story(&component_name, story_index),
+        None => None,
+    };
+    let thumbnail = use_thumbnail(story, hovered().is_some());
+
     rsx! {
         div {
             class: if is_selected { "story-node selected" } else { "story-node" },
             onclick: move |e| {
                 onclick.call(e);
             },
+            onmouseenter: move |e| {
+                let point = e.client_coordinates();
+                hovered.set(Some((point.x, point.y)));
+            },
+            onmouseleave: move |_| hovered.set(None),
             ..attributes,
             span { class: "story-icon",
                 BookOpen { size: 12, stroke_width: 2 }
             }
-            span { class: "story-name", "{story_title}" }
+            span { class: "story-name",
+                Highlight { text: story_title, query }
+            }
+            FavoriteStar { component_name, story_index }
+            if let Some((x, y)) = hovered() {
+                div {
+                    class: "story-thumbnail-popover",
+                    style: "left: {x + 24.0}px; top: {y - 60.0}px;",
+                    if let Some(html) = thumbnail {
+                        StoryThumbnail { html }
+                    } else {
+                        Overridable {
+                            slot: Slot::Loading,
+                            div { class: "story-thumbnail-placeholder", "Rendering preview…" }
+                        }
+                    }
+                }
+            }
         }
     }
 }
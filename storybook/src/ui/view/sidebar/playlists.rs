@@ -0,0 +1,66 @@
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::playlist_vm::PlaylistStore;
+use dioxus::prelude::*;
+use lucide_dioxus::{ListMusic, Plus};
+
+/// Sidebar section listing the playlists, with an input creating a new
+/// one. The active playlist is marked with a dot.
+#[component]
+pub(crate) fn PlaylistList(mut selected: Signal<Option<Selection>>) -> Element {
+    let store = use_context::<PlaylistStore>();
+    let mut new_name = use_signal(String::new);
+    let active = (store.active)();
+
+    let mut create = move || {
+        let name = store.create(&new_name());
+        new_name.set(String::new());
+        selected.set(Some(Selection::Playlist(name)));
+    };
+
+    rsx! {
+        div { class: "playlist-list",
+            div { class: "playlist-list-title", "Playlists" }
+            for playlist in (store.playlists)() {
+                {
+                    let name = playlist.name.clone();
+                    let is_selected = selected() == Some(Selection::Playlist(name.clone()));
+                    let is_active = active.as_deref() == Some(name.as_str());
+                    rsx! {
+                        div {
+                            key: "{name}",
+                            class: if is_selected { "doc-node selected" } else { "doc-node" },
+                            onclick: move |_| selected.set(Some(Selection::Playlist(name.clone()))),
+                            span { class: "doc-icon",
+                                ListMusic { size: 14, stroke_width: 2 }
+                            }
+                            span { class: "doc-name", "{playlist.name}" }
+                            if is_active {
+                                span { class: "playlist-active-dot", title: "Active playlist" }
+                            }
+                        }
+                    }
+                }
+            }
+            div { class: "playlist-new",
+                input {
+                    class: "search-input",
+                    r#type: "text",
+                    placeholder: "New playlist...",
+                    value: "{new_name}",
+                    oninput: move |e| new_name.set(e.value()),
+                    onkeydown: move |e| {
+                        if e.key() == Key::Enter {
+                            create();
+                        }
+                    },
+                }
+                button {
+                    class: "playlist-entry-btn",
+                    title: "Create playlist",
+                    onclick: move |_| create(),
+                    Plus { size: 14, stroke_width: 2 }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use crate::ui::models::{NodeType, Selection};
+use crate::ui::models::{NodeType, Selection, StoryGroup, TagFilter};
 use crate::ui::view::sidebar::node::ComponentNodeProps;
 use crate::ui::view::sidebar::search_input::SearchInputProps;
+use crate::ui::view::sidebar::tag_filter::TagFilterSelectProps;
 use crate::ui::view::sidebar::tree::TreeNodeProps;
-use crate::{Stories, Story};
+use crate::{ComponentStatus, Stories, Story};
 use dioxus::prelude::Signal;
 
 impl Stories for SearchInputProps {
@@ -24,6 +25,28 @@ impl Stories for SearchInputProps {
     }
 }
 
+impl Stories for TagFilterSelectProps {
+    fn stories() -> Vec<Story<Self>> {
+        let tags = vec!["stable".to_string(), "wip".to_string()];
+        vec![
+            Story::new(
+                "Default",
+                Self {
+                    tag_filter: Signal::new(TagFilter::All),
+                    tags: tags.clone(),
+                },
+            ),
+            Story::new(
+                "Hiding WIP",
+                Self {
+                    tag_filter: Signal::new(TagFilter::Hide("wip".to_string())),
+                    tags,
+                },
+            ),
+        ]
+    }
+}
+
 impl Stories for TreeNodeProps {
     fn stories() -> Vec<Story<Self>> {
         vec![
@@ -33,7 +56,9 @@ impl Stories for TreeNodeProps {
                     name: "Components".to_string(),
                     node: Default::default(),
                     selected: Signal::new(None),
+                    tag_filter: Signal::new(TagFilter::All),
                     node_type: NodeType::Category,
+                    query: String::new(),
                 },
             ),
             Story::new(
@@ -42,7 +67,9 @@ impl Stories for TreeNodeProps {
                     name: "Buttons".to_string(),
                     node: Default::default(),
                     selected: Signal::new(None),
+                    tag_filter: Signal::new(TagFilter::All),
                     node_type: NodeType::Folder,
+                    query: String::new(),
                 },
             ),
         ]
@@ -57,9 +84,13 @@ impl Stories for ComponentNodeProps {
                 Self {
                     name: "ExampleButton".to_string(),
                     selected: Signal::new(None),
-                    stories: vec!["Default".to_string(), "Disabled".to_string()],
+                    stories: vec![StoryGroup {
+                        name: None,
+                        stories: vec![(0, "Default".to_string()), (1, "Disabled".to_string())],
+                    }],
                     is_active: false,
-                    has_docs: false,
+                    query: String::new(),
+                    status: None,
                 },
             ),
             Story::new(
@@ -67,19 +98,13 @@ impl Stories for ComponentNodeProps {
                 Self {
                     name: "ExampleButton".to_string(),
                     selected: Signal::new(Some(Selection::Story("ExampleButton".to_string(), 0))),
-                    stories: vec!["Default".to_string(), "Disabled".to_string()],
+                    stories: vec![StoryGroup {
+                        name: None,
+                        stories: vec![(0, "Default".to_string()), (1, "Disabled".to_string())],
+                    }],
                     is_active: true,
-                    has_docs: false,
-                },
-            ),
-            Story::new(
-                "Expanded with Docs",
-                Self {
-                    name: "ExampleButton".to_string(),
-                    selected: Signal::new(Some(Selection::Story("ExampleButton".to_string(), 0))),
-                    stories: vec!["Default".to_string(), "Disabled".to_string()],
-                    is_active: true,
-                    has_docs: true,
+                    query: String::new(),
+                    status: None,
                 },
             ),
             Story::with_description(
@@ -88,9 +113,53 @@ impl Stories for ComponentNodeProps {
                 Self {
                     name: "IconButton".to_string(),
                     selected: Signal::new(None),
-                    stories: vec!["Default".to_string()],
+                    stories: vec![StoryGroup {
+                        name: None,
+                        stories: vec![(0, "Default".to_string())],
+                    }],
+                    is_active: false,
+                    query: String::new(),
+                    status: None,
+                },
+            ),
+            Story::with_description(
+                "Deprecated",
+                "A component with a status badge",
+                Self {
+                    name: "LegacyButton".to_string(),
+                    selected: Signal::new(None),
+                    stories: vec![StoryGroup {
+                        name: None,
+                        stories: vec![(0, "Default".to_string())],
+                    }],
                     is_active: false,
-                    has_docs: false,
+                    query: String::new(),
+                    status: Some(ComponentStatus::Deprecated),
+                },
+            ),
+            Story::with_description(
+                "Grouped",
+                "Stories listed under collapsible sections",
+                Self {
+                    name: "ExampleButton".to_string(),
+                    selected: Signal::new(Some(Selection::Story("ExampleButton".to_string(), 2))),
+                    stories: vec![
+                        StoryGroup {
+                            name: None,
+                            stories: vec![(0, "Default".to_string())],
+                        },
+                        StoryGroup {
+                            name: Some("Sizes".to_string()),
+                            stories: vec![(1, "Small".to_string()), (2, "Large".to_string())],
+                        },
+                        StoryGroup {
+                            name: Some("States".to_string()),
+                            stories: vec![(3, "Disabled".to_string()), (4, "Loading".to_string())],
+                        },
+                    ],
+                    is_active: true,
+                    query: String::new(),
+                    status: None,
                 },
             ),
         ]
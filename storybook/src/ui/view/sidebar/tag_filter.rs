@@ -0,0 +1,60 @@
+use crate::ui::models::TagFilter;
+use dioxus::prelude::*;
+
+#[cfg(feature = "self-stories")]
+use crate::{self as storybook};
+
+#[cfg(feature = "self-stories")]
+use storybook_macro::storybook;
+
+/// Dropdown filtering the sidebar stories by tag.
+///
+/// Offers "All stories" plus, for each known tag, an option to show only
+/// the stories carrying it and one to hide them. Components left without a
+/// matching story disappear from the tree. Renders nothing when no story is
+/// tagged.
+///
+/// # Props
+///
+/// | Prop | Type | Description |
+/// |------|------|-------------|
+/// | `tag_filter` | `Signal<TagFilter>` | Two-way bound tag filter. |
+/// | `tags` | `Vec<String>` | Every tag used by a registered story. |
+///
+/// @[story:Molecules/TagFilterSelect/Default]
+///
+/// @[story:Molecules/TagFilterSelect/Hiding WIP]
+#[cfg_attr(feature = "self-stories", storybook(tag = "Molecules"))]
+#[component]
+pub fn TagFilterSelect(tag_filter: Signal<TagFilter>, tags: Vec<String>) -> Element {
+    if tags.is_empty() {
+        return rsx! {};
+    }
+
+    let options = std::iter::once(TagFilter::All)
+        .chain(tags.iter().map(|tag| TagFilter::Only(tag.clone())))
+        .chain(tags.iter().map(|tag| TagFilter::Hide(tag.clone())));
+
+    rsx! {
+        div { class: "tag-filter-container",
+            select {
+                class: "tag-filter-select",
+                title: "Filter stories by tag",
+                value: "{tag_filter().value()}",
+                onchange: move |e: Event<FormData>| tag_filter.set(TagFilter::from_value(&e.value())),
+                for option in options {
+                    option {
+                        key: "{option.value()}",
+                        value: "{option.value()}",
+                        selected: tag_filter() == option,
+                        match &option {
+                            TagFilter::All => "All stories".to_string(),
+                            TagFilter::Only(tag) => format!("Only \"{tag}\""),
+                            TagFilter::Hide(tag) => format!("Hide \"{tag}\""),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
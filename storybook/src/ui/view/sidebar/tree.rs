@@ -1,8 +1,12 @@
-use crate::ui::models::{CategoryTreeNode, NodeType, Selection};
+use crate::ui::models::{CategoryTreeNode, NodeType, Selection, TagFilter};
+use crate::ui::view::sidebar::highlight::Highlight;
 use crate::ui::view::sidebar::node::ComponentNode;
-use crate::ui::viewmodels::sidebar_vm::{get_story_titles, has_component_docs};
+use crate::ui::viewmodels::sidebar_vm::{
+    component_status, get_story_groups, has_story_match, use_tree_expansion,
+};
 #[cfg(feature = "self-stories")]
 use crate::{self as storybook};
+use crate::{StorybookConfig, doc_component};
 use dioxus::prelude::*;
 use lucide_dioxus::{ChevronRight, FileText, Folder, FolderOpen};
 
@@ -14,7 +18,10 @@ use storybook_macro::storybook;
 /// `TreeNode` is the backbone of the sidebar navigation. It renders a
 /// collapsible header (with a folder icon and component count badge) and
 /// recursively renders child folders, documentation links, and
-/// [`ComponentNode`] entries.
+/// [`ComponentNode`] entries. Whether it is expanded is kept in the
+/// sidebar's expansion state, so it is remembered while searching. With
+/// [`StorybookConfig::with_category_overview`], clicking the header's name
+/// also opens the category overview page; the arrow still collapses it.
 ///
 /// # Props
 ///
@@ -23,7 +30,9 @@ use storybook_macro::storybook;
 /// | `name` | `String` | Display name of this tree level. |
 /// | `node` | `CategoryTreeNode` | The tree data for this level (children, components, docs). |
 /// | `selected` | `Signal<Option<Selection>>` | Currently selected item in the sidebar. |
+/// | `tag_filter` | `Signal<TagFilter>` | Tag filter applied to the listed stories. |
 /// | `node_type` | `NodeType` | Whether this node is a top-level `Category` or a nested `Folder`. |
+/// | `query` | `String` | Sidebar search query, highlighted in names (empty by default). |
 ///
 /// @[story:Molecules/TreeNode/Category Node]
 ///
@@ -34,12 +43,16 @@ pub fn TreeNode(
     name: String,
     node: CategoryTreeNode,
     selected: Signal<Option<Selection>>,
+    tag_filter: Signal<TagFilter>,
     node_type: NodeType,
+    #[props(default)] query: String,
 ) -> Element {
-    let expanded = use_signal(|| true);
+    let expansion = use_tree_expansion();
     let component_count = node.component_count();
     let has_doc = node.has_doc;
     let full_path = node.full_path.clone();
+    let expanded = expansion.is_expanded(&full_path);
+    let toggled_path = full_path.clone();
 
     // Determine CSS class based on node type
     let node_class = match node_type {
@@ -49,34 +62,47 @@ pub fn TreeNode(
 
     rsx! {
         div { class: "{node_class}",
-            TreeNodeHeader { expanded, name: name.clone(), component_count }
-            if expanded() {
+            TreeNodeHeader {
+                expanded,
+                on_expand: move |expanded| expansion.set_expanded(&toggled_path, expanded),
+                name: name.clone(),
+                query: query.clone(),
+                component_count,
+                full_path: full_path.clone(),
+                selected,
+            }
+            if expanded {
                 div { class: "tree-children",
                     if has_doc {
                         DocNode { path: full_path.clone(), selected }
                     }
 
-                    for (child_name , child_node) in node.children.iter() {
+                    for (child_name , child_node) in node.ordered_children() {
                         TreeNode {
                             key: "{child_name}",
                             name: child_name.clone(),
                             node: child_node.clone(),
                             selected,
+                            tag_filter,
                             node_type: NodeType::Folder,
+                            query: query.clone(),
                         }
                     }
                     // Then render components at this level
                     for component_name in node.components.iter() {
                         {
                             let component_name = component_name.clone();
-                            let stories = get_story_titles(&component_name);
-                            let has_docs = has_component_docs(&component_name);
-                            let doc_path = format!("__component__/{component_name}");
+                            let stories = get_story_groups(&component_name, &tag_filter());
                             let is_active = matches!(
                                 selected(),
-                                Some(Selection::Story(ref cn, _))
+                                Some(Selection::Story(ref cn, _) | Selection::Component(ref cn))
                                 if cn == &component_name
-                            ) || selected() == Some(Selection::DocPage(doc_path));
+                            ) || matches!(
+                                selected(),
+                                Some(Selection::DocPage(ref path))
+                                if doc_component(path).is_some_and(|c| c.name == component_name)
+                            )
+                                || has_story_match(&stories, &query);
                             rsx! {
                                 ComponentNode {
                                     key: "{component_name}",
@@ -84,7 +110,8 @@ pub fn TreeNode(
                                     selected,
                                     stories,
                                     is_active,
-                                    has_docs,
+                                    query: query.clone(),
+                                    status: component_status(&component_name),
                                 }
                             }
                         }
@@ -118,24 +145,55 @@ pub(crate) fn DocNode(
 }
 
 #[component]
-fn TreeNodeHeader(expanded: Signal<bool>, name: String, component_count: usize) -> Element {
+fn TreeNodeHeader(
+    expanded: bool,
+    on_expand: EventHandler<bool>,
+    name: String,
+    query: String,
+    component_count: usize,
+    full_path: String,
+    mut selected: Signal<Option<Selection>>,
+) -> Element {
+    // Not provided when the node is rendered as a story preview
+    let overview = try_use_context::<StorybookConfig>().is_some_and(|c| c.category_overview);
+    let overview_path = full_path.clone();
+    let is_selected = selected() == Some(Selection::Category(full_path));
+
     rsx! {
-        div { class: "tree-header", onclick: move |_| expanded.toggle(),
-            span { class: if expanded() { "arrow expanded" } else { "arrow" },
+        div {
+            class: if is_selected { "tree-header selected" } else { "tree-header" },
+            onclick: move |_| {
+                if overview {
+                    on_expand.call(true);
+                    selected.set(Some(Selection::Category(overview_path.clone())));
+                } else {
+                    on_expand.call(!expanded);
+                }
+            },
+            span {
+                class: if expanded { "arrow expanded" } else { "arrow" },
+                onclick: move |e| {
+                    if overview {
+                        e.stop_propagation();
+                        on_expand.call(!expanded);
+                    }
+                },
                 ChevronRight { size: 14, stroke_width: 2 }
             }
             FolderIcon { expanded }
-            span { class: "node-name", "{name}" }
+            span { class: "node-name",
+                Highlight { text: name, query }
+            }
             span { class: "category-count", "{component_count}" }
         }
     }
 }
 
 #[component]
-fn FolderIcon(expanded: Signal<bool>) -> Element {
+fn FolderIcon(expanded: bool) -> Element {
     rsx! {
         span { class: "node-icon",
-            if expanded() {
+            if expanded {
                 FolderOpen { size: 16, stroke_width: 2 }
             } else {
                 Folder { size: 16, stroke_width: 2 }
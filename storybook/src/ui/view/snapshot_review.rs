@@ -0,0 +1,151 @@
+use crate::ui::models::{DiffSegment, Selection};
+use crate::ui::services::story_ids::find_story;
+use crate::ui::viewmodels::snapshot_vm::{SnapshotReview, pending_diff};
+use dioxus::prelude::*;
+use lucide_dioxus::{Check, CheckCheck, Download, X};
+
+/// Review of the snapshots without a baseline or differing from it: the
+/// baseline and the new snapshot side by side with their HTML diff.
+/// Approving makes the snapshot the new baseline, rejecting keeps the old
+/// one.
+#[component]
+pub(crate) fn SnapshotReviewPage(mut selected: Signal<Option<Selection>>) -> Element {
+    let review = use_context::<SnapshotReview>();
+    let pending = (review.pending)();
+    let mut current = use_signal(|| 0usize);
+    let index = current().min(pending.len().saturating_sub(1));
+
+    rsx! {
+        div { class: "story-page snapshot-review",
+            div { class: "story-page-header",
+                span { class: "story-page-component-name", "Snapshots" }
+                span { class: "story-page-separator", "/" }
+                span { class: "story-page-story-name", "{pending.len()} to review" }
+                div { class: "gallery-actions",
+                    button {
+                        class: "snapshot-btn",
+                        disabled: pending.is_empty(),
+                        onclick: move |_| review.approve_all(),
+                        CheckCheck { size: 14, stroke_width: 2 }
+                        span { "Approve all" }
+                    }
+                    button {
+                        class: "snapshot-btn",
+                        title: "Download the approved baselines as JSON",
+                        onclick: move |_| review.export(),
+                        Download { size: 14, stroke_width: 2 }
+                        span { "Export baselines" }
+                    }
+                }
+            }
+            if pending.is_empty() {
+                div { class: "empty-state",
+                    h2 { "Nothing to review" }
+                    p {
+                        "Every snapshot matches its baseline. Take snapshots from a component gallery, a category overview or a playlist."
+                    }
+                }
+            } else {
+                div { class: "snapshot-review-body",
+                    ul { class: "snapshot-review-list",
+                        for (i , item) in pending.iter().enumerate() {
+                            li {
+                                key: "{item.snapshot.id}",
+                                class: if i == index { "snapshot-review-item selected" } else { "snapshot-review-item" },
+                                onclick: move |_| current.set(i),
+                                span { "{item.snapshot.component} / {item.snapshot.story}" }
+                                span { class: "snapshot-status snapshot-status-{item.status().label()}",
+                                    "{item.status().label()}"
+                                }
+                            }
+                        }
+                    }
+                    {
+                        let item = pending[index].clone();
+                        let id = item.snapshot.id.clone();
+                        let approve_id = id.clone();
+                        let reject_id = id.clone();
+                        let target = find_story(&id)
+                            .map(|(component_name, story_index)| Selection::Story(component_name, story_index));
+                        rsx! {
+                            div { class: "snapshot-review-detail",
+                                div { class: "playlist-actions",
+                                    span { class: "snapshot-review-title",
+                                        if let Some(target) = target {
+                                            a {
+                                                href: "#",
+                                                onclick: move |e| {
+                                                    e.prevent_default();
+                                                    selected.set(Some(target.clone()));
+                                                },
+                                                "{id}"
+                                            }
+                                        } else {
+                                            "{id}"
+                                        }
+                                    }
+                                    button {
+                                        class: "snapshot-btn",
+                                        onclick: move |_| review.approve(&approve_id),
+                                        Check { size: 14, stroke_width: 2 }
+                                        span { "Approve" }
+                                    }
+                                    button {
+                                        class: "snapshot-btn",
+                                        onclick: move |_| review.reject(&reject_id),
+                                        X { size: 14, stroke_width: 2 }
+                                        span { "Reject" }
+                                    }
+                                }
+                                div { class: "snapshot-review-sides",
+                                    div { class: "snapshot-review-side",
+                                        div { class: "compare-label", "Baseline" }
+                                        if let Some(baseline) = item.baseline.clone() {
+                                            iframe { "sandbox": "", srcdoc: "{baseline}" }
+                                        } else {
+                                            div { class: "snapshot-review-missing", "No baseline yet" }
+                                        }
+                                    }
+                                    div { class: "snapshot-review-side",
+                                        div { class: "compare-label", "New snapshot" }
+                                        iframe { "sandbox": "", srcdoc: "{item.snapshot.html}" }
+                                    }
+                                }
+                                details { class: "snapshot-review-diff",
+                                    summary { "HTML diff" }
+                                    HtmlDiff { segments: pending_diff(&item) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A semantic HTML diff: the normalized markup of both sides, with removed
+/// and added parts highlighted.
+#[component]
+pub(crate) fn HtmlDiff(segments: Vec<DiffSegment>) -> Element {
+    rsx! {
+        pre { class: "html-diff",
+            if segments.iter().all(|segment| matches!(segment, DiffSegment::Same(_))) {
+                div { class: "html-diff-empty", "No markup differences" }
+            }
+            for segment in segments {
+                match segment {
+                    DiffSegment::Same(text) => rsx! {
+                        span { "{text}" }
+                    },
+                    DiffSegment::Removed(text) => rsx! {
+                        del { "{text}" }
+                    },
+                    DiffSegment::Added(text) => rsx! {
+                        ins { "{text}" }
+                    },
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,22 @@
+use crate::ui::view::playlist::AddToPlaylist;
 use crate::ui::view::story::header::StoryHeader;
-use crate::{RenderFn, StoryInfo};
+use crate::{ComponentStatus, RenderFn, StoryInfo};
 use dioxus::prelude::*;
 use schemars::Schema;
 
+mod a11y_audit;
+mod class_list;
+mod gallery;
 mod header;
+mod interactions;
+mod layout_audit;
+mod live_frame;
+mod performance;
 mod preview;
+mod report;
+mod slide;
+mod snapshot;
+mod thumbnail;
 mod toolbar;
 pub use toolbar::StoryZoomControls;
 
@@ -14,6 +26,10 @@ mod stories;
 mod card;
 use crate::ui::view::story::preview::StoryPreview;
 pub use card::StoryCard;
+pub(crate) use gallery::{CategoryGallery, ComponentGallery};
+pub(crate) use slide::PresentationSlide;
+pub(crate) use snapshot::SnapshotButton;
+pub(crate) use thumbnail::{StoryThumbnail, ThumbnailStage};
 
 pub mod props_editor;
 
@@ -28,10 +44,14 @@ pub(crate) fn StoryPage(
     story_title: String,
     render_fn: RenderFn,
     prop_schema: Schema,
+    status: Option<ComponentStatus>,
 ) -> Element {
     rsx! {
         div { class: "story-page",
-            StoryHeader { component_name: component_name.clone(), story_title }
+            div { class: "story-page-bar",
+                StoryHeader { component_name: component_name.clone(), story_title, status }
+                AddToPlaylist { entry: story.id.clone() }
+            }
 
             StoryPreview {
                 key: "{component_name}-{story_index}",
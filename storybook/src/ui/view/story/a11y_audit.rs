@@ -0,0 +1,76 @@
+use super::report::ReportButtons;
+use crate::ui::viewmodels::a11y_audit_vm::{run_a11y_audit, use_a11y_audit};
+use crate::ui::viewmodels::report_vm::a11y_suite;
+use dioxus::prelude::*;
+use lucide_dioxus::{CircleCheck, RefreshCw, TriangleAlert};
+
+/// Panel listing accessibility violations found in the story preview.
+///
+/// Audits the preview iframe `iframe_id` for missing alt text, unlabeled
+/// form controls, empty buttons and low-contrast inline colors, most severe
+/// violations first, again after each edit of `props_json`. The result can be
+/// downloaded as a JUnit or JSON report named `report_name`, with one test
+/// case per rule.
+#[component]
+pub(crate) fn A11yAuditPanel(
+    iframe_id: String,
+    story_id: String,
+    report_name: String,
+    props_json: Signal<String>,
+) -> Element {
+    let state = use_a11y_audit(&iframe_id, props_json);
+    let report = (state.report)();
+
+    rsx! {
+        div { class: "layout-audit",
+            div { class: "layout-audit-toolbar",
+                span { class: "layout-audit-summary",
+                    match &report {
+                        Some(report) => rsx! { "{report.violations.len()} violation(s)" },
+                        None => rsx! { "Not audited yet" },
+                    }
+                }
+                if let Some(report) = &report {
+                    ReportButtons {
+                        suite: a11y_suite(report_name.clone(), &story_id, report),
+                        kind: "a11y",
+                    }
+                }
+                button {
+                    class: "props-panel-btn",
+                    title: "Re-run accessibility audit",
+                    disabled: (state.running)(),
+                    onclick: move |_| run_a11y_audit(iframe_id.clone(), state),
+                    RefreshCw { size: 16, stroke_width: 2 }
+                }
+            }
+
+            if let Some(error) = (state.error)() {
+                div { class: "layout-audit-error", "{error}" }
+            }
+
+            if let Some(report) = report {
+                if report.violations.is_empty() {
+                    div { class: "layout-audit-ok",
+                        CircleCheck { size: 16, stroke_width: 2 }
+                        "No accessibility violations detected."
+                    }
+                } else {
+                    ul { class: "layout-audit-issues",
+                        for (index , violation) in report.sorted_violations().into_iter().enumerate() {
+                            li {
+                                key: "{index}",
+                                class: "layout-audit-issue a11y-{violation.rule.severity().class()}",
+                                TriangleAlert { size: 14, stroke_width: 2 }
+                                span { class: "a11y-severity", "{violation.rule.severity().label()}" }
+                                span { class: "layout-audit-kind", "{violation.rule.label()}" }
+                                code { class: "layout-audit-selector", "{violation.selector}" }
+                                span { class: "layout-audit-detail", "{violation.detail}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,12 @@
-use super::props_editor::{PropsEditor, PropsEditorHeader};
-use crate::ui::services::decorators::apply_decorators;
+use super::live_frame::{PreviewFrame, StoryLoadError, StoryLoading};
+use super::props_editor::{PropsEditor, PropsEditorHeader, PropsErrorBanner};
 use crate::ui::view::story::toolbar::StoryZoomControls;
 use crate::ui::viewmodels::story_card_vm::use_story_card;
 use crate::{RenderFn, StoryInfo};
 use dioxus::prelude::*;
 use schemars::Schema;
 
-/// A single story card that renders one story with its own HTML capture and iframe.
+/// A single story card that renders one story live in its own iframe.
 /// Used for embedded story display in documentation pages.
 #[component]
 pub fn StoryCard(
@@ -27,28 +27,38 @@ pub fn StoryCard(
                 p { class: "story-card-description", "{desc}" }
             }
 
-            div {
-                id: "{state.container_id}",
-                position: "absolute",
-                visibility: "hidden",
-                pointer_events: "none",
-                {apply_decorators((render_fn.0)(&(state.props_json)()), &story.decorators)}
-            }
+            StoryZoomControls { zoom_level: state.zoom_level, preview_id: state.iframe_id.clone() }
 
-            StoryZoomControls { zoom_level: state.zoom_level }
+            if let Some(error) = state.load_error.clone() {
+                StoryLoadError { error }
+            }
 
             div { class: "story-preview-area",
-                iframe {
-                    class: "preview-iframe",
-                    srcdoc: "{state.srcdoc}",
+                if state.loading {
+                    StoryLoading {}
+                } else {
+                    PreviewFrame {
+                        id: state.iframe_id.clone(),
+                        document: state.srcdoc.clone(),
+                        isolation: state.isolation,
+                        render_fn,
+                        decorators: state.decorators.clone(),
+                        contexts: state.contexts.clone(),
+                        props_json: state.props_json,
+                    }
                 }
             }
 
             div { class: "props-editor-section",
                 PropsEditorHeader { expanded: state.props_expanded }
-                if (state.props_expanded)() {
+                if (state.props_expanded)() && !state.loading {
+                    PropsErrorBanner {
+                        component_name: component_name.clone(),
+                        props_json: state.props_json,
+                    }
                     PropsEditor {
                         props_json: state.props_json,
+                        original_json: state.original_props_json.clone(),
                         schema: prop_schema.clone(),
                     }
                 }
@@ -0,0 +1,58 @@
+use crate::ui::services::class_list::{extract_classes, variant_prefix};
+use crate::ui::services::live_mount::build_capture_script;
+use dioxus::prelude::*;
+use lucide_dioxus::RefreshCw;
+
+/// Panel listing the utility classes present in the story rendered in the
+/// preview `iframe_id`.
+///
+/// Shown for Tailwind projects (see
+/// [`StorybookConfig::with_tailwind`](crate::StorybookConfig::with_tailwind)).
+/// Each class is shown once with its number of occurrences; variant prefixes
+/// such as `md:` or `hover:` are highlighted. The list is captured when the
+/// panel opens and can be refreshed after interacting with the story.
+#[component]
+pub(crate) fn ClassListPanel(iframe_id: String) -> Element {
+    let mut html = use_signal(String::new);
+    let capture = move || {
+        let script = build_capture_script(&iframe_id);
+        spawn(async move {
+            if let Ok(captured) = document::eval(&script).join::<String>().await {
+                html.set(captured);
+            }
+        });
+    };
+    use_hook(capture.clone());
+    let classes = extract_classes(&html());
+
+    rsx! {
+        div { class: "class-list",
+            div { class: "layout-audit-toolbar",
+                span { class: "layout-audit-summary", "{classes.len()} distinct classes" }
+                button {
+                    class: "props-panel-btn",
+                    title: "Refresh classes",
+                    onclick: move |_| capture(),
+                    RefreshCw { size: 16, stroke_width: 2 }
+                }
+            }
+            if classes.is_empty() {
+                div { class: "props-empty", "The story renders no classes." }
+            } else {
+                ul { class: "class-list-items",
+                    for (class , count) in classes.iter() {
+                        li { key: "{class}", class: "class-list-item",
+                            code {
+                                span { class: "class-list-variant", "{variant_prefix(class)}" }
+                                "{&class[variant_prefix(class).len()..]}"
+                            }
+                            if *count > 1 {
+                                span { class: "class-list-count", "×{count}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
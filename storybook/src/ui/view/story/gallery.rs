@@ -0,0 +1,213 @@
+use super::live_frame::PreviewFrame;
+use super::snapshot::SnapshotButton;
+use super::thumbnail::StoryThumbnail;
+use crate::ui::models::Selection;
+use crate::ui::view::shared::{Overridable, StatusBadge};
+use crate::ui::viewmodels::gallery_vm::{resolve_category_gallery, resolve_gallery};
+use crate::ui::viewmodels::sidebar_vm::group_stories;
+use crate::ui::viewmodels::snapshot_vm::{
+    SnapshotStory, category_snapshot_stories, component_snapshot_stories,
+};
+use crate::ui::viewmodels::story_card_vm::use_story_card;
+use crate::ui::viewmodels::thumbnail_vm::use_thumbnail;
+use crate::{RenderFn, Slot, StoryInfo, StorybookConfig};
+use dioxus::prelude::*;
+
+/// Overview page of a component: every story rendered as a small preview
+/// in a responsive grid, the grouped stories under collapsible sections.
+/// Clicking a tile opens the story.
+#[component]
+pub(crate) fn ComponentGallery(
+    component_name: String,
+    selected: Signal<Option<Selection>>,
+) -> Element {
+    let data = match resolve_gallery(&component_name) {
+        Ok(data) => data,
+        Err(e) => {
+            return rsx! {
+                Overridable {
+                    slot: Slot::Error,
+                    message: e.to_string(),
+                    div { class: "error", "{e}" }
+                }
+            };
+        }
+    };
+    let is_empty = data.stories.is_empty();
+    let groups = group_stories(data.stories, |(_, story)| story.group.clone());
+
+    rsx! {
+        div { class: "story-page gallery-page",
+            div { class: "story-page-header",
+                span { class: "story-page-component-name", "{data.component_name}" }
+                span { class: "story-page-separator", "/" }
+                span { class: "story-page-story-name", "All stories" }
+                if let Some(status) = data.status {
+                    StatusBadge { status }
+                }
+                div { class: "gallery-actions",
+                    SnapshotButton {
+                        title: data.component_name.clone(),
+                        stories: component_snapshot_stories(&data.component_name),
+                        selected,
+                    }
+                }
+            }
+            if is_empty {
+                div { class: "empty-state",
+                    p { "This component has no story to show" }
+                }
+            }
+            div { class: "gallery-groups",
+                for (index , group) in groups.into_iter().enumerate() {
+                    if let Some(name) = group.name {
+                        details { key: "{index}-{name}", class: "gallery-group", open: true,
+                            summary { class: "gallery-group-title",
+                                "{name}"
+                                span { class: "gallery-group-count", "{group.stories.len()}" }
+                            }
+                            StoryGrid {
+                                component_name: data.component_name.clone(),
+                                stories: group.stories,
+                                render_fn: data.render_fn,
+                                selected,
+                            }
+                        }
+                    } else {
+                        StoryGrid {
+                            key: "{index}",
+                            component_name: data.component_name.clone(),
+                            stories: group.stories,
+                            render_fn: data.render_fn,
+                            selected,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Tiles of `stories`, each opening its story.
+#[component]
+fn StoryGrid(
+    component_name: String,
+    stories: Vec<(usize, StoryInfo)>,
+    render_fn: RenderFn,
+    selected: Signal<Option<Selection>>,
+) -> Element {
+    rsx! {
+        div { class: "gallery-grid",
+            for (story_index , story) in stories {
+                GalleryTile {
+                    key: "{story_index}",
+                    label: story.title.clone(),
+                    target: Selection::Story(component_name.clone(), story_index),
+                    component_name: component_name.clone(),
+                    story_index,
+                    story,
+                    render_fn,
+                    selected,
+                }
+            }
+        }
+    }
+}
+
+/// Overview page of a category: the first story of every component in the
+/// category and its subfolders, as a responsive grid of small previews.
+/// Clicking a tile opens the component's story gallery.
+#[component]
+pub(crate) fn CategoryGallery(path: String, selected: Signal<Option<Selection>>) -> Element {
+    let config = use_context::<StorybookConfig>();
+    let entries = resolve_category_gallery(&config, &path);
+    let path_label = path.replace('/', " / ");
+
+    rsx! {
+        div { class: "story-page gallery-page",
+            div { class: "story-page-header",
+                span { class: "story-page-component-name", "{path_label}" }
+                span { class: "story-page-separator", "/" }
+                span { class: "story-page-story-name", "Overview" }
+                div { class: "gallery-actions",
+                    SnapshotButton {
+                        title: path.clone(),
+                        stories: category_snapshot_stories(&config, &path),
+                        selected,
+                    }
+                }
+            }
+            if entries.is_empty() {
+                div { class: "empty-state",
+                    p { "This category has no component to show" }
+                }
+            }
+            div { class: "gallery-grid",
+                for entry in entries {
+                    GalleryTile {
+                        key: "{entry.component_name}",
+                        label: entry.component_name.clone(),
+                        target: Selection::Component(entry.component_name.clone()),
+                        component_name: entry.component_name,
+                        story_index: entry.story_index,
+                        story: entry.story,
+                        render_fn: entry.render_fn,
+                        selected,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A gallery tile: `label` above a non-interactive preview of the story,
+/// replaced by the story's thumbnail once captured. Clicking it selects
+/// `target`.
+#[component]
+fn GalleryTile(
+    label: String,
+    target: Selection,
+    component_name: String,
+    story_index: usize,
+    story: StoryInfo,
+    render_fn: RenderFn,
+    mut selected: Signal<Option<Selection>>,
+) -> Element {
+    let state = use_story_card(&component_name, story_index, &story);
+    let description = story.description.clone().unwrap_or_default();
+    let thumbnail = use_thumbnail(
+        Some(SnapshotStory {
+            component_name: component_name.clone(),
+            story_index,
+            story: story.clone(),
+            render_fn,
+        }),
+        true,
+    );
+
+    rsx! {
+        div {
+            class: "gallery-tile",
+            title: "{description}",
+            onclick: move |_| selected.set(Some(target.clone())),
+            div { class: "gallery-tile-title", "{label}" }
+            div { class: "gallery-tile-preview",
+                if let Some(html) = thumbnail {
+                    StoryThumbnail { html }
+                } else {
+                    PreviewFrame {
+                        id: state.iframe_id.clone(),
+                        document: state.srcdoc.clone(),
+                        isolation: state.isolation,
+                        render_fn,
+                        decorators: state.decorators.clone(),
+                        contexts: state.contexts.clone(),
+                        props_json: state.props_json,
+                    }
+                }
+                // Catches the clicks the preview would otherwise swallow
+                div { class: "gallery-tile-overlay" }
+            }
+        }
+    }
+}
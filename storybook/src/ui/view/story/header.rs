@@ -1,3 +1,5 @@
+use crate::ComponentStatus;
+use crate::ui::view::shared::StatusBadge;
 use dioxus::prelude::*;
 
 #[cfg(feature = "self-stories")]
@@ -9,26 +11,37 @@ use storybook_macro::storybook;
 /// Breadcrumb header displayed at the top of a story page.
 ///
 /// Renders the component name and story title separated by a `/` divider,
-/// giving the user context about which story they are currently viewing.
+/// giving the user context about which story they are currently viewing,
+/// followed by the component's status badge, if any.
 ///
 /// # Props
 ///
-/// | Prop | Type | Description |
-/// |------|------|-------------|
-/// | `component_name` | `String` | Name of the component (left side). |
-/// | `story_title` | `String` | Title of the active story (right side). |
+/// | Prop | Type | Default | Description |
+/// |------|------|---------|-------------|
+/// | `component_name` | `String` | — | Name of the component (left side). |
+/// | `story_title` | `String` | — | Title of the active story (right side). |
+/// | `status` | `Option<ComponentStatus>` | `None` | Maturity badge of the component. |
 ///
 /// @[story:Molecules/StoryHeader/Default]
 ///
 /// @[story:Molecules/StoryHeader/Long Names]
+///
+/// @[story:Molecules/StoryHeader/Deprecated]
 #[cfg_attr(feature = "self-stories", storybook(tag = "Molecules"))]
 #[component]
-pub fn StoryHeader(component_name: String, story_title: String) -> Element {
+pub fn StoryHeader(
+    component_name: String,
+    story_title: String,
+    #[props(default)] status: Option<ComponentStatus>,
+) -> Element {
     rsx! {
         div { class: "story-page-header",
             span { class: "story-page-component-name", "{component_name}" }
             span { class: "story-page-separator", "/" }
             span { class: "story-page-story-name", "{story_title}" }
+            if let Some(status) = status {
+                StatusBadge { status }
+            }
         }
     }
 }
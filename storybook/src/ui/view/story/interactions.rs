@@ -0,0 +1,83 @@
+use super::report::ReportButtons;
+use crate::PlayFn;
+use crate::ui::models::{PlayStatus, PlayStepStatus};
+use crate::ui::viewmodels::play_vm::{PlayState, run_play};
+use crate::ui::viewmodels::report_vm::play_suite;
+use dioxus::prelude::*;
+use lucide_dioxus::{CircleCheck, CircleMinus, CircleX, RefreshCw};
+
+/// Panel listing the steps of a story's play function with their outcome.
+///
+/// The play function runs automatically when the story renders; the replay
+/// button runs it again against the current props. Finished runs can be
+/// downloaded as a JUnit or JSON report named `report_name`, with one test
+/// case per step.
+#[component]
+pub(crate) fn InteractionsPanel(
+    iframe_id: String,
+    play_fn: PlayFn,
+    state: PlayState,
+    story_id: String,
+    report_name: String,
+) -> Element {
+    let status = (state.status)();
+    let results = (state.results)();
+    let passed = results
+        .iter()
+        .filter(|r| r.status == PlayStepStatus::Passed)
+        .count();
+
+    rsx! {
+        div { class: "play-panel",
+            div { class: "layout-audit-toolbar",
+                span { class: "play-summary play-status-{status.class()}",
+                    match status {
+                        PlayStatus::Idle => rsx! { "Not run yet" },
+                        PlayStatus::Running => rsx! { "Running…" },
+                        PlayStatus::Passed => rsx! { "Passed — {passed}/{results.len()} steps" },
+                        PlayStatus::Failed => rsx! { "Failed — {passed}/{results.len()} steps passed" },
+                    }
+                }
+                if matches!(status, PlayStatus::Passed | PlayStatus::Failed) {
+                    ReportButtons {
+                        suite: play_suite(report_name.clone(), &story_id, &results),
+                        kind: "play",
+                    }
+                }
+                button {
+                    class: "props-panel-btn",
+                    title: "Replay interactions",
+                    disabled: status == PlayStatus::Running,
+                    onclick: move |_| run_play(iframe_id.clone(), play_fn.clone(), state),
+                    RefreshCw { size: 16, stroke_width: 2 }
+                }
+            }
+
+            if results.is_empty() && status != PlayStatus::Running {
+                div { class: "props-empty", "The play function ran no steps." }
+            }
+
+            ol { class: "play-steps",
+                for (index , result) in results.iter().enumerate() {
+                    li { key: "{index}", class: "play-step",
+                        match &result.status {
+                            PlayStepStatus::Passed => rsx! {
+                                span { class: "play-step-icon play-status-passed", CircleCheck { size: 14, stroke_width: 2 } }
+                            },
+                            PlayStepStatus::Failed(_) => rsx! {
+                                span { class: "play-step-icon play-status-failed", CircleX { size: 14, stroke_width: 2 } }
+                            },
+                            PlayStepStatus::Skipped => rsx! {
+                                span { class: "play-step-icon play-status-idle", CircleMinus { size: 14, stroke_width: 2 } }
+                            },
+                        }
+                        code { class: "play-step-label", "{result.step.label()}" }
+                        if let PlayStepStatus::Failed(message) = &result.status {
+                            span { class: "play-step-error", "{message}" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use crate::ui::viewmodels::layout_audit_vm::{run_layout_audit, use_layout_audit};
+use dioxus::prelude::*;
+use lucide_dioxus::{CircleCheck, RefreshCw, TriangleAlert};
+
+/// Panel listing layout problems found in the story preview.
+///
+/// Audits the preview iframe `iframe_id` at the currently selected viewport
+/// for horizontal overflow and elements escaping the viewport, again after
+/// each edit of `props_json`, and lists the offending selectors. It also
+/// warns when the story renders differently with a CSS reset, and offers to
+/// inject the reset into previews.
+#[component]
+pub(crate) fn LayoutAuditPanel(iframe_id: String, props_json: Signal<String>) -> Element {
+    let state = use_layout_audit(&iframe_id, props_json);
+    let report = (state.audit.report)();
+    let mut css_reset = state.css_reset;
+
+    rsx! {
+        div { class: "layout-audit",
+            div { class: "layout-audit-toolbar",
+                span { class: "layout-audit-summary",
+                    match &report {
+                        Some(report) => rsx! { "Viewport: {report.viewport_width}px" },
+                        None => rsx! { "Not audited yet" },
+                    }
+                }
+                button {
+                    class: "props-panel-btn",
+                    title: "Re-run layout audit",
+                    disabled: (state.audit.running)(),
+                    onclick: move |_| run_layout_audit(iframe_id.clone(), state),
+                    RefreshCw { size: 16, stroke_width: 2 }
+                }
+            }
+
+            if let Some(error) = (state.audit.error)() {
+                div { class: "layout-audit-error", "{error}" }
+            }
+
+            if let Some(cascade) = (state.cascade)() {
+                if css_reset() {
+                    div { class: "cascade-check",
+                        span { "A CSS reset is injected into previews." }
+                        button {
+                            class: "cascade-reset-btn",
+                            onclick: move |_| css_reset.set(false),
+                            "Remove reset"
+                        }
+                    }
+                } else if !cascade.changed.is_empty() {
+                    div { class: "cascade-check cascade-warning",
+                        TriangleAlert { size: 14, stroke_width: 2 }
+                        span {
+                            "{cascade.changed.len()} element(s) render differently with a CSS reset. "
+                            "The component may rely on global styles missing from the preview."
+                        }
+                        button {
+                            class: "cascade-reset-btn",
+                            onclick: move |_| css_reset.set(true),
+                            "Inject CSS reset"
+                        }
+                    }
+                    ul { class: "layout-audit-issues",
+                        for (index , diff) in cascade.changed.iter().enumerate() {
+                            li { key: "{index}", class: "layout-audit-issue",
+                                code { class: "layout-audit-selector", "{diff.selector}" }
+                                span { class: "layout-audit-detail", "{diff.detail}" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(report) = report {
+                if report.issues.is_empty() {
+                    div { class: "layout-audit-ok",
+                        CircleCheck { size: 16, stroke_width: 2 }
+                        "No overflow detected at this viewport."
+                    }
+                } else {
+                    ul { class: "layout-audit-issues",
+                        for (index , issue) in report.issues.iter().enumerate() {
+                            li { key: "{index}", class: "layout-audit-issue",
+                                TriangleAlert { size: 14, stroke_width: 2 }
+                                span { class: "layout-audit-kind", "{issue.kind.label()}" }
+                                code { class: "layout-audit-selector", "{issue.selector}" }
+                                span { class: "layout-audit-detail", "{issue.detail}" }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
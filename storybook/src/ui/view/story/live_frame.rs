@@ -0,0 +1,171 @@
+use crate::ui::services::decorators::{apply_decorators, catch_story_errors, provide_contexts};
+use crate::ui::services::iframe::preview_direction;
+use crate::ui::services::performance::use_render_timing;
+use crate::ui::viewmodels::UiSettings;
+#[cfg(target_family = "wasm")]
+use crate::ui::viewmodels::live_frame_vm::{use_live_frame, use_shadow_frame};
+use crate::{ContextFn, Decorator, PreviewIsolation, RenderFn, StorybookConfig};
+use dioxus::prelude::*;
+use lucide_dioxus::TriangleAlert;
+
+/// Story preview isolated with `isolation`, the story's own or the
+/// storybook's default (see [`StorybookConfig::isolation_for`]).
+///
+/// `document` is the output of `build_srcdoc` for iframes, or of
+/// `build_shadow_html` for shadow roots; inline previews ignore it.
+#[component]
+pub(crate) fn PreviewFrame(
+    id: String,
+    document: String,
+    isolation: PreviewIsolation,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Element {
+    #[cfg(not(target_family = "wasm"))]
+    let _ = document;
+
+    match isolation {
+        #[cfg(target_family = "wasm")]
+        PreviewIsolation::Iframe => rsx! {
+            LiveFrame { id, srcdoc: document, render_fn, decorators, contexts, props_json }
+        },
+        #[cfg(target_family = "wasm")]
+        PreviewIsolation::ShadowDom => rsx! {
+            ShadowFrame { id, html: document, render_fn, decorators, contexts, props_json }
+        },
+        _ => rsx! {
+            InlineFrame { id, render_fn, decorators, contexts, props_json }
+        },
+    }
+}
+
+/// Placeholder shown instead of the preview while the story's props
+/// loader is running (see [`Story::with_loader`](crate::Story::with_loader)).
+#[component]
+pub(crate) fn StoryLoading() -> Element {
+    rsx! {
+        div { class: "story-loading",
+            span { class: "story-loading-spinner" }
+            "Loading props…"
+        }
+    }
+}
+
+/// Banner shown above the preview when the story's props loader failed
+/// (see [`Story::with_try_loader`](crate::Story::with_try_loader)); the
+/// preview renders the story's own props.
+#[component]
+pub(crate) fn StoryLoadError(error: String) -> Element {
+    rsx! {
+        div { class: "props-error-banner", role: "alert",
+            TriangleAlert { size: 14, stroke_width: 2 }
+            div {
+                strong { "Loading props failed" }
+                span { class: "props-error-message", "{error}" }
+                span { class: "props-error-hint", "The story renders its own props instead." }
+            }
+        }
+    }
+}
+
+/// Story rendered directly in the storybook's tree (see
+/// [`PreviewIsolation::Inline`]).
+///
+/// The component stylesheets are added to the storybook page; props edits
+/// re-render the story in place.
+#[component]
+pub(crate) fn InlineFrame(
+    id: String,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Element {
+    use_hook(|| provide_contexts(&contexts));
+    use_render_timing(&id);
+    let config = use_context::<StorybookConfig>();
+    let direction = preview_direction(
+        try_use_context::<UiSettings>().is_some_and(|settings| (settings.rtl_enabled)()),
+    );
+    let stylesheets = config
+        .tailwind_css
+        .iter()
+        .chain(config.component_css.iter())
+        .copied()
+        .collect::<Vec<_>>();
+
+    rsx! {
+        for href in stylesheets {
+            document::Stylesheet { href }
+        }
+        div { id: "{id}", class: "preview-inline", dir: direction,
+            {
+                catch_story_errors(
+                    apply_decorators(
+                        (render_fn.0)(&config.substitute_props(&props_json())),
+                        &decorators,
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// Preview iframe with the story mounted live into its document.
+///
+/// The srcdoc only provides the document shell (stylesheets, overlays and
+/// an empty mount point); the story is rendered into it as a nested Dioxus
+/// app once the iframe loads, so it stays interactive. Changing the srcdoc
+/// reloads the iframe and remounts the story.
+#[cfg(target_family = "wasm")]
+#[component]
+pub(crate) fn LiveFrame(
+    id: String,
+    srcdoc: String,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Element {
+    let mount = use_live_frame(id.clone(), render_fn, decorators, contexts, props_json);
+
+    rsx! {
+        iframe {
+            id: "{id}",
+            class: "preview-iframe",
+            srcdoc: "{srcdoc}",
+            onload: move |_| mount.call(()),
+        }
+    }
+}
+
+/// Shadow host with the story mounted live into its shadow root.
+///
+/// Lighter than an iframe: the story shares the page's window, but its
+/// styles are scoped to the shadow root. Changing `html` refills the shadow
+/// root and remounts the story.
+#[cfg(target_family = "wasm")]
+#[component]
+pub(crate) fn ShadowFrame(
+    id: String,
+    html: String,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Element {
+    use_shadow_frame(
+        id.clone(),
+        html,
+        render_fn,
+        decorators,
+        contexts,
+        props_json,
+    );
+
+    rsx! {
+        div { id: "{id}", class: "preview-shadow-host" }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::ui::models::SLOW_RENDER_MS;
+use crate::ui::viewmodels::performance_vm::{
+    measure_performance, reset_performance, use_performance,
+};
+use dioxus::prelude::*;
+use lucide_dioxus::{RefreshCw, RotateCcw};
+
+/// Panel charting how long the story takes to render.
+///
+/// Lists the number of renders of the story in the preview `iframe_id`
+/// (every render of its render root, be it for a props edit or a globals
+/// change), the duration of the latest, average
+/// and slowest renders, and how long capturing the preview's HTML takes.
+/// The latest render durations are charted; renders slower than a frame are
+/// highlighted.
+#[component]
+pub(crate) fn PerformancePanel(iframe_id: String, props_json: Signal<String>) -> Element {
+    let state = use_performance(&iframe_id, props_json);
+    let report = (state.report)();
+    let reset_id = iframe_id.clone();
+
+    let format_ms = |ms: Option<f64>| match ms {
+        Some(ms) => format!("{ms:.1} ms"),
+        None => "–".to_string(),
+    };
+
+    rsx! {
+        div { class: "performance-panel",
+            div { class: "layout-audit-toolbar",
+                span { class: "layout-audit-summary",
+                    match &report {
+                        Some(report) => rsx! { "{report.render.renders} render(s)" },
+                        None => rsx! { "Not measured yet" },
+                    }
+                }
+                button {
+                    class: "props-panel-btn",
+                    title: "Reset render timings",
+                    onclick: move |_| reset_performance(reset_id.clone(), state),
+                    RotateCcw { size: 16, stroke_width: 2 }
+                }
+                button {
+                    class: "props-panel-btn",
+                    title: "Measure again",
+                    disabled: (state.measuring)(),
+                    onclick: move |_| measure_performance(iframe_id.clone(), state),
+                    RefreshCw { size: 16, stroke_width: 2 }
+                }
+            }
+
+            if let Some(report) = report {
+                dl { class: "performance-metrics",
+                    dt { "Last render" }
+                    dd { "{format_ms(report.render.last_ms())}" }
+                    dt { "Average render" }
+                    dd { "{format_ms(report.render.average_ms())}" }
+                    dt { "Slowest render" }
+                    dd { "{format_ms(report.render.max_ms())}" }
+                    dt { "HTML capture" }
+                    dd { "{format_ms(report.capture_ms)} ({report.html_bytes} bytes)" }
+                }
+
+                if report.render.recent_ms.is_empty() {
+                    p { class: "performance-hint",
+                        "No render timed in this preview yet. Edit a prop to time a re-render."
+                    }
+                } else {
+                    div {
+                        class: "performance-chart",
+                        title: "Latest render durations, oldest first",
+                        for (index , ms) in report.render.recent_ms.iter().copied().enumerate() {
+                            div {
+                                key: "{index}",
+                                class: if ms > SLOW_RENDER_MS { "performance-bar slow" } else { "performance-bar" },
+                                height: "{ms / report.render.max_ms().unwrap_or(ms).max(1.0) * 100.0}%",
+                                title: "{ms:.1} ms",
+                            }
+                        }
+                    }
+                    p { class: "performance-hint",
+                        "Renders are timed from the story render to the DOM update. Renders over {SLOW_RENDER_MS} ms take more than a frame."
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,28 @@
-use super::props_editor::PropsEditor;
-use crate::ui::services::decorators::apply_decorators;
-use crate::ui::viewmodels::story_preview_vm::{DockPosition, use_story_preview};
-use crate::{RenderFn, StoryInfo};
+use super::a11y_audit::A11yAuditPanel;
+use super::class_list::ClassListPanel;
+use super::interactions::InteractionsPanel;
+use super::layout_audit::LayoutAuditPanel;
+use super::live_frame::{PreviewFrame, StoryLoadError, StoryLoading};
+use super::performance::PerformancePanel;
+use super::props_editor::{PropsEditor, PropsErrorBanner, PropsJsonEditor};
+use crate::ui::models::{Device, ViewportSize};
+use crate::ui::services::iframe::preview_theme;
+use crate::ui::view::snapshot_review::HtmlDiff;
+use crate::ui::viewmodels::addon_vm::find_addon;
+use crate::ui::viewmodels::story_preview_vm::{
+    CompareSide, CompareState, DockPosition, PanelTab, diff_compared_markup, sweep_preview_id,
+    use_story_preview,
+};
+use crate::{
+    AddonContext, ContextFn, Decorator, PreviewIsolation, RenderFn, StoryInfo, StorybookConfig,
+};
 use dioxus::prelude::*;
-use lucide_dioxus::{PanelBottom, PanelRight, X};
+use lucide_dioxus::{BatteryFull, PanelBottom, PanelRight, Signal as SignalIcon, Wifi, X};
 use schemars::Schema;
 
-/// Full-screen story view with dockable props editor and viewport/zoom from UiSettings.
+/// Full-screen story view with a dockable side panel (props editor, layout
+/// and accessibility audits, play function interactions) and viewport/zoom from UiSettings.
+/// Can also compare the story with another one of the component.
 /// Used by StoryPage for the main story display.
 #[component]
 pub fn StoryPreview(
@@ -20,9 +36,12 @@ pub fn StoryPreview(
     let state = use_story_preview(&component_name, story_index, &story);
     let mut props_visible = state.props_visible;
     let mut props_dock_position = state.props_dock_position;
+    let mut active_tab = state.active_tab;
 
     let visible = props_visible();
     let dock = props_dock_position();
+    let (edited_props_json, edited_original_json) = state.edited_props();
+    let report_name = format!("{component_name} / {}", story.title);
 
     let container_class = match (visible, dock) {
         (true, DockPosition::Bottom) => "fullscreen-story-view dock-bottom",
@@ -37,23 +56,73 @@ pub fn StoryPreview(
 
     rsx! {
         div { class: "{container_class}",
-            // Hidden render container for HTML capture
-            div {
-                id: "{state.container_id}",
-                position: "absolute",
-                visibility: "hidden",
-                pointer_events: "none",
-                {apply_decorators((render_fn.0)(&(state.props_json)()), &story.decorators)}
-            }
-
             div { class: "fullscreen-preview-area",
-                div {
-                    class: "fullscreen-iframe-container",
-                    max_width: "{state.viewport_width}",
-                    margin: "auto",
-                    iframe {
-                        class: "preview-iframe",
-                        srcdoc: "{state.srcdoc}",
+                if let Some(error) = state.load_error.clone() {
+                    StoryLoadError { error }
+                }
+                if state.loading {
+                    StoryLoading {}
+                } else if let Some(compare) = state.compare.clone() {
+                    CompareView {
+                        story_title: story.title.clone(),
+                        srcdoc: state.srcdoc.clone(),
+                        width: state.viewport_width,
+                        iframe_id: state.iframe_id.clone(),
+                        isolation: state.isolation,
+                        render_fn,
+                        decorators: state.decorators.clone(),
+                        contexts: state.contexts.clone(),
+                        props_json: state.props_json,
+                        compare,
+                    }
+                } else if let Some((light_srcdoc, dark_srcdoc)) = state.theme_sweep_srcdocs.clone() {
+                    ThemeSweep {
+                        light_srcdoc,
+                        dark_srcdoc,
+                        width: state.viewport_width,
+                        iframe_id: state.iframe_id.clone(),
+                        isolation: state.isolation,
+                        render_fn,
+                        decorators: state.decorators.clone(),
+                        contexts: state.contexts.clone(),
+                        props_json: state.props_json,
+                    }
+                } else if state.responsive_sweep {
+                    ResponsiveSweep {
+                        srcdoc: state.srcdoc.clone(),
+                        iframe_id: state.iframe_id.clone(),
+                        isolation: state.isolation,
+                        render_fn,
+                        decorators: state.decorators.clone(),
+                        contexts: state.contexts.clone(),
+                        props_json: state.props_json,
+                    }
+                } else if let Some(device) = state.device_frame {
+                    DeviceFrame { device, width: state.viewport_width,
+                        PreviewFrame {
+                            id: state.iframe_id.clone(),
+                            document: state.srcdoc.clone(),
+                            isolation: state.isolation,
+                            render_fn,
+                            decorators: state.decorators.clone(),
+                            contexts: state.contexts.clone(),
+                            props_json: state.props_json,
+                        }
+                    }
+                } else {
+                    div {
+                        class: "fullscreen-iframe-container",
+                        max_width: "{state.viewport_width}",
+                        margin: "auto",
+                        PreviewFrame {
+                            id: state.iframe_id.clone(),
+                            document: state.srcdoc.clone(),
+                            isolation: state.isolation,
+                            render_fn,
+                            decorators: state.decorators.clone(),
+                            contexts: state.contexts.clone(),
+                            props_json: state.props_json,
+                        }
                     }
                 }
             }
@@ -62,7 +131,22 @@ pub fn StoryPreview(
                 div { class: "{panel_class}",
                     // Panel header with dock controls and close button
                     div { class: "props-panel-header",
-                        span { class: "props-panel-title", "Props Editor" }
+                        div { class: "props-panel-tabs",
+                            for tab in state.tabs() {
+                                button {
+                                    key: "{tab.label()}",
+                                    class: if active_tab() == tab { "props-panel-tab active" } else { "props-panel-tab" },
+                                    onclick: move |_| active_tab.set(tab),
+                                    if let Some(icon) = state.addon(tab).and_then(|addon| addon.icon()) {
+                                        span { class: "props-panel-tab-icon", {icon} }
+                                    }
+                                    "{tab.label()}"
+                                    if tab == PanelTab::Interactions {
+                                        span { class: "play-status-dot play-status-{(state.play.status)().class()}" }
+                                    }
+                                }
+                            }
+                        }
                         div { class: "props-panel-controls",
                             button {
                                 class: if dock == DockPosition::Bottom { "props-panel-btn active" } else { "props-panel-btn" },
@@ -85,9 +169,77 @@ pub fn StoryPreview(
                         }
                     }
                     div { class: "fullscreen-props-scroll",
-                        PropsEditor {
-                            props_json: state.props_json,
-                            schema: prop_schema.clone(),
+                        if state.loading {
+                            StoryLoading {}
+                        } else {
+                            if matches!(active_tab(), PanelTab::Props | PanelTab::Json) {
+                                PropsErrorBanner {
+                                    component_name: component_name.clone(),
+                                    props_json: edited_props_json,
+                                }
+                            }
+                            match active_tab() {
+                                PanelTab::Props => rsx! {
+                                    PropsEditor {
+                                        props_json: edited_props_json,
+                                        original_json: edited_original_json.clone(),
+                                        schema: prop_schema.clone(),
+                                    }
+                                },
+                                PanelTab::Json => rsx! {
+                                    PropsJsonEditor {
+                                        props_json: edited_props_json,
+                                        schema: prop_schema.clone(),
+                                    }
+                                },
+                                PanelTab::Layout => rsx! {
+                                    LayoutAuditPanel {
+                                        iframe_id: state.inspected_id.clone(),
+                                        props_json: edited_props_json,
+                                    }
+                                },
+                                PanelTab::Accessibility => rsx! {
+                                    A11yAuditPanel {
+                                        iframe_id: state.inspected_id.clone(),
+                                        story_id: story.id.clone(),
+                                        report_name: report_name.clone(),
+                                        props_json: edited_props_json,
+                                    }
+                                },
+                                PanelTab::Classes => rsx! {
+                                    ClassListPanel { iframe_id: state.inspected_id.clone() }
+                                },
+                                PanelTab::Interactions => match state.play_fn.clone() {
+                                    Some(play_fn) => rsx! {
+                                        InteractionsPanel {
+                                            iframe_id: state.iframe_id.clone(),
+                                            play_fn,
+                                            state: state.play,
+                                            story_id: story.id.clone(),
+                                            report_name: report_name.clone(),
+                                        }
+                                    },
+                                    None => rsx! {},
+                                },
+                                PanelTab::Performance => rsx! {
+                                    PerformancePanel {
+                                        iframe_id: state.inspected_id.clone(),
+                                        props_json: edited_props_json,
+                                    }
+                                },
+                                PanelTab::Addon(name) => rsx! {
+                                    AddonPanel {
+                                        name,
+                                        context: AddonContext {
+                                            component_name: component_name.clone(),
+                                            story_index,
+                                            story_id: story.id.clone(),
+                                            iframe_id: state.iframe_id.clone(),
+                                            props_json: edited_props_json(),
+                                        },
+                                    }
+                                },
+                            }
                         }
                     }
                 }
@@ -95,3 +247,223 @@ pub fn StoryPreview(
         }
     }
 }
+
+/// Renders an addon's panel in a scope of its own, so its hooks don't mix
+/// with the preview's.
+#[component]
+fn AddonPanel(name: &'static str, context: AddonContext) -> Element {
+    let config = use_context::<StorybookConfig>();
+    match find_addon(&config, name) {
+        Some(addon) => addon.render(context),
+        None => rsx! {},
+    }
+}
+
+/// Phone / tablet bezel drawn around the preview iframe.
+///
+/// The screen keeps the exact viewport `width`; the bezel, status bar and
+/// home indicator are drawn around it.
+#[component]
+fn DeviceFrame(device: Device, width: &'static str, children: Element) -> Element {
+    rsx! {
+        div { class: "device-frame device-frame-{device.value()}",
+            div { class: "device-status-bar",
+                span { class: "device-status-time", "9:41" }
+                if device == Device::Phone {
+                    span { class: "device-notch" }
+                }
+                span { class: "device-status-icons",
+                    SignalIcon { size: 12, stroke_width: 2 }
+                    Wifi { size: 12, stroke_width: 2 }
+                    BatteryFull { size: 14, stroke_width: 2 }
+                }
+            }
+            div { class: "device-screen", width: "{width}", {children} }
+            div { class: "device-home-indicator" }
+        }
+    }
+}
+
+/// The same story rendered at every viewport preset, stacked vertically
+/// with width labels. Each preview mounts its own live copy of the story.
+#[component]
+fn ResponsiveSweep(
+    srcdoc: String,
+    iframe_id: String,
+    isolation: PreviewIsolation,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Element {
+    rsx! {
+        div { class: "responsive-sweep",
+            for size in ViewportSize::all().iter().copied() {
+                div { key: "{size.value()}", class: "responsive-sweep-item",
+                    div { class: "responsive-sweep-label", "{size.label()}" }
+                    div {
+                        class: "fullscreen-iframe-container responsive-sweep-frame",
+                        max_width: "{size.to_width()}",
+                        PreviewFrame {
+                            id: sweep_preview_id(&iframe_id, size.value()),
+                            document: srcdoc.clone(),
+                            isolation,
+                            render_fn,
+                            decorators: decorators.clone(),
+                            contexts: contexts.clone(),
+                            props_json,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The same story rendered side by side with the light and the dark preview
+/// theme. Both previews share the same props, so edits stay in sync.
+#[component]
+fn ThemeSweep(
+    light_srcdoc: String,
+    dark_srcdoc: String,
+    width: &'static str,
+    iframe_id: String,
+    isolation: PreviewIsolation,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Element {
+    rsx! {
+        div { class: "theme-sweep",
+            for (label , dark , srcdoc) in [("Light", false, light_srcdoc), ("Dark", true, dark_srcdoc)] {
+                div { key: "{label}", class: "theme-sweep-item",
+                    div { class: "responsive-sweep-label", "{label}" }
+                    div {
+                        class: "fullscreen-iframe-container",
+                        max_width: "{width}",
+                        PreviewFrame {
+                            id: sweep_preview_id(&iframe_id, preview_theme(dark)),
+                            document: srcdoc,
+                            isolation,
+                            render_fn,
+                            decorators: decorators.clone(),
+                            contexts: contexts.clone(),
+                            props_json,
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The selected story next to a second story of the same component, in
+/// viewports of the same width. Clicking a side's label makes the side
+/// panel edit its props; the markup diff below shows how the two renders
+/// differ.
+#[component]
+fn CompareView(
+    story_title: String,
+    srcdoc: String,
+    width: &'static str,
+    iframe_id: String,
+    isolation: PreviewIsolation,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+    compare: CompareState,
+) -> Element {
+    let mut editing = compare.editing;
+    let mut compare_index = compare.story_index;
+    let label_class = |side: CompareSide| {
+        if editing() == side {
+            "compare-label active"
+        } else {
+            "compare-label"
+        }
+    };
+
+    let markup_diff = compare.markup_diff;
+    let diff_primary_id = iframe_id.clone();
+    let diff_compare = compare.clone();
+
+    rsx! {
+        div { class: "compare-layout",
+            div { class: "theme-sweep compare-view",
+                div { class: "theme-sweep-item",
+                    div {
+                        class: label_class(CompareSide::Primary),
+                        title: "Edit these props",
+                        onclick: move |_| editing.set(CompareSide::Primary),
+                        span { class: "responsive-sweep-label", "{story_title}" }
+                    }
+                    div {
+                        class: "fullscreen-iframe-container",
+                        max_width: "{width}",
+                        PreviewFrame {
+                            id: iframe_id,
+                            document: srcdoc.clone(),
+                            isolation,
+                            render_fn,
+                            decorators,
+                            contexts,
+                            props_json,
+                        }
+                    }
+                }
+                div { class: "theme-sweep-item",
+                    div {
+                        class: label_class(CompareSide::Secondary),
+                        title: "Edit these props",
+                        onclick: move |_| editing.set(CompareSide::Secondary),
+                        select {
+                            class: "compare-select",
+                            value: "{compare_index()}",
+                            onclick: move |e: Event<MouseData>| e.stop_propagation(),
+                            onchange: move |e: Event<FormData>| {
+                                if let Ok(index) = e.value().parse() {
+                                    compare_index.set(index);
+                                    editing.set(CompareSide::Secondary);
+                                }
+                            },
+                            for (index , title) in compare.stories.clone() {
+                                option {
+                                    value: "{index}",
+                                    selected: compare_index() == index,
+                                    "{title}"
+                                }
+                            }
+                        }
+                    }
+                    div {
+                        class: "fullscreen-iframe-container",
+                        max_width: "{width}",
+                        PreviewFrame {
+                            key: "{compare_index()}",
+                            id: compare.iframe_id.clone(),
+                            document: srcdoc,
+                            isolation,
+                            render_fn,
+                            decorators: compare.decorators.clone(),
+                            contexts: compare.contexts.clone(),
+                            props_json: compare.props_json,
+                        }
+                    }
+                }
+            }
+            div { class: "compare-diff",
+                button {
+                    class: "snapshot-btn",
+                    title: "Diff the rendered markup of both sides, ignoring attribute order, whitespace and generated ids",
+                    onclick: move |_| diff_compared_markup(diff_primary_id.clone(), &diff_compare),
+                    if markup_diff().is_some() { "Refresh markup diff" } else { "Diff markup" }
+                }
+                if let Some(segments) = markup_diff() {
+                    HtmlDiff { segments }
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use crate::ui::view::shared::{Checkbox, Td, TextInput, Tr};
-use crate::{SchemaFieldInfo, extract_fields_from_schema, parse_input_value, update_prop_value};
+use crate::{
+    Error, SchemaFieldInfo, StorybookConfig, extract_fields_from_schema, find_component,
+    parse_input_value, update_prop_value, validate_props_json,
+};
 use dioxus::prelude::*;
-use lucide_dioxus::{ChevronDown, ChevronRight};
+use lucide_dioxus::{ChevronDown, ChevronRight, RotateCcw, TriangleAlert, Undo2};
 use schemars::Schema;
 
 #[cfg(feature = "self-stories")]
@@ -43,9 +46,52 @@ pub fn PropsEditorHeader(expanded: Signal<bool>) -> Element {
     }
 }
 
+/// Warning shown above the props editors while the edited props don't
+/// deserialize into the component's props, naming the offending prop. The
+/// story renders its default props until they are fixed.
 #[component]
-pub(crate) fn PropsEditor(props_json: Signal<String>, schema: Schema) -> Element {
+pub(crate) fn PropsErrorBanner(component_name: String, props_json: Signal<String>) -> Element {
+    let config = use_context::<StorybookConfig>();
+    let error = find_component(&component_name)
+        .ok()
+        .and_then(|registration| {
+            registration
+                .check_props(&config.substitute_props(&props_json()))
+                .err()
+        });
+    let Some(Error::PropsDeserialize { field, message, .. }) = error else {
+        return rsx! {};
+    };
+
+    rsx! {
+        div { class: "props-error-banner", role: "alert",
+            TriangleAlert { size: 14, stroke_width: 2 }
+            div {
+                if let Some(field) = field {
+                    strong { "Invalid value for `{field}`" }
+                } else {
+                    strong { "Invalid props" }
+                }
+                span { class: "props-error-message", "{message}" }
+                span { class: "props-error-hint", "The story renders its default props until this is fixed." }
+            }
+        }
+    }
+}
+
+/// Table editing the story props field by field.
+///
+/// Each row shows the value defined by the story (`original_json`); fields
+/// edited away from it are highlighted and can be reset individually, and
+/// fields with a `#[props(default)]` can be reset to the component default.
+#[component]
+pub(crate) fn PropsEditor(
+    props_json: Signal<String>,
+    original_json: String,
+    schema: Schema,
+) -> Element {
     let fields = extract_fields_from_schema(&schema);
+    let original = serde_json::from_str::<serde_json::Value>(&original_json).unwrap_or_default();
 
     rsx! {
         div { class: "props-editor",
@@ -62,6 +108,7 @@ pub(crate) fn PropsEditor(props_json: Signal<String>, schema: Schema) -> Element
                             th { "Name" }
                             th { "Type" }
                             th { "Description" }
+                            th { "Default" }
                             th { "Value" }
                         }
                     }
@@ -70,6 +117,7 @@ pub(crate) fn PropsEditor(props_json: Signal<String>, schema: Schema) -> Element
                             PropFieldRow {
                                 key: "{field.name}",
                                 field: field.clone(),
+                                original_value: original.get(&field.name).cloned(),
                                 props_json,
                             }
                         }
@@ -80,9 +128,61 @@ pub(crate) fn PropsEditor(props_json: Signal<String>, schema: Schema) -> Element
     }
 }
 
+/// Raw JSON editor for the story props.
+///
+/// Valid documents are applied to the story as they are typed; otherwise the
+/// problems found against the props schema are listed and the story keeps
+/// its last valid props. Edits made with the field editors show up here.
+#[component]
+pub(crate) fn PropsJsonEditor(mut props_json: Signal<String>, schema: Schema) -> Element {
+    let fields = extract_fields_from_schema(&schema);
+    let mut draft = use_signal(|| props_json.peek().clone());
+    let mut errors = use_signal(Vec::<String>::new);
+
+    // Follow the props when they are edited elsewhere
+    use_effect(move || {
+        let json = props_json();
+        if *draft.peek() != json {
+            draft.set(json);
+            errors.set(Vec::new());
+        }
+    });
+
+    rsx! {
+        div { class: "props-json",
+            textarea {
+                class: if errors().is_empty() { "props-json-input" } else { "props-json-input invalid" },
+                spellcheck: false,
+                value: "{draft}",
+                oninput: move |e| {
+                    let text = e.value();
+                    let problems = validate_props_json(&text, &fields);
+                    draft.set(text.clone());
+                    if problems.is_empty() {
+                        props_json.set(text);
+                    }
+                    errors.set(problems);
+                },
+            }
+            if !errors().is_empty() {
+                ul { class: "props-json-errors",
+                    for error in errors() {
+                        li { "{error}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[component]
-fn PropFieldRow(field: SchemaFieldInfo, mut props_json: Signal<String>) -> Element {
+fn PropFieldRow(
+    field: SchemaFieldInfo,
+    original_value: Option<serde_json::Value>,
+    mut props_json: Signal<String>,
+) -> Element {
     let field_name = field.name.clone();
+    let display_name = field.display_name().to_string();
     let description = field.description.clone();
     let type_name = field.type_name.clone();
 
@@ -92,7 +192,7 @@ fn PropFieldRow(field: SchemaFieldInfo, mut props_json: Signal<String>) -> Eleme
     if is_non_editable {
         return rsx! {
             tr { class: "prop-row non-editable",
-                td { class: "prop-cell prop-name", "{field_name}" }
+                td { class: "prop-cell prop-name", title: "{field_name}", "{display_name}" }
                 td { class: "prop-cell prop-type", "non-editable" }
                 td { class: "prop-cell prop-description",
                     if let Some(desc) = &description {
@@ -101,23 +201,31 @@ fn PropFieldRow(field: SchemaFieldInfo, mut props_json: Signal<String>) -> Eleme
                         "—"
                     }
                 }
+                td { class: "prop-cell prop-default", "—" }
                 td { class: "prop-cell prop-value", "—" }
             }
         };
     }
 
     // Get the current value for this field by parsing the JSON
-    let current_value = serde_json::from_str::<serde_json::Value>(&props_json())
+    let current = serde_json::from_str::<serde_json::Value>(&props_json())
         .ok()
-        .and_then(|v| v.get(&field_name).cloned())
-        .map(|v| {
-            if v.is_string() {
-                v.as_str().unwrap_or("").to_string()
-            } else {
-                v.to_string()
-            }
-        })
-        .unwrap_or_default();
+        .and_then(|v| v.get(&field_name).cloned());
+    let current_value = current.as_ref().map(display_value).unwrap_or_default();
+    let default_value = original_value
+        .as_ref()
+        .map(display_value)
+        .unwrap_or_else(|| "—".to_string());
+    let modified = original_value.is_some() && current != original_value;
+    let component_default = field
+        .default_value
+        .as_deref()
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok());
+    let default_title = match &field.default_expr {
+        Some(expr) => format!("Reset to the component default ({expr})"),
+        None => "Reset to the component default".to_string(),
+    };
+    let can_reset_default = component_default.is_some() && current != component_default;
 
     let field_name_for_handler = field_name.clone();
     let schema_type = field.schema_type.clone();
@@ -166,9 +274,12 @@ fn PropFieldRow(field: SchemaFieldInfo, mut props_json: Signal<String>) -> Eleme
         }
     };
 
+    let reset_field = field_name.clone();
+    let reset_default_field = field_name.clone();
+
     rsx! {
-        Tr {
-            Td { class: "prop-name", "{field_name}{required_marker}" }
+        Tr { class: if modified { "modified" } else { "" },
+            Td { class: "prop-name", title: "{field_name}", "{display_name}{required_marker}" }
             Td { class: "prop-type", "{type_name}" }
             Td { class: "prop-description",
                 if let Some(desc) = &description {
@@ -177,7 +288,45 @@ fn PropFieldRow(field: SchemaFieldInfo, mut props_json: Signal<String>) -> Eleme
                     "—"
                 }
             }
-            Td { class: "prop-cell prop-value", {value_cell} }
+            Td { class: "prop-default", "{default_value}" }
+            Td { class: "prop-cell prop-value",
+                div { class: "prop-value-cell",
+                    {value_cell}
+                    if modified {
+                        button {
+                            class: "prop-reset",
+                            title: "Reset to the story's value",
+                            onclick: move |_| {
+                                if let Some(original) = original_value.clone() {
+                                    update_prop_value(&mut props_json, &reset_field, original);
+                                }
+                            },
+                            RotateCcw { size: 12, stroke_width: 2 }
+                        }
+                    }
+                    if can_reset_default {
+                        button {
+                            class: "prop-reset",
+                            title: "{default_title}",
+                            onclick: move |_| {
+                                if let Some(default) = component_default.clone() {
+                                    update_prop_value(&mut props_json, &reset_default_field, default);
+                                }
+                            },
+                            Undo2 { size: 12, stroke_width: 2 }
+                        }
+                    }
+                }
+            }
         }
     }
 }
+
+/// Render a JSON value for display: strings without quotes, other values as
+/// JSON.
+fn display_value(value: &serde_json::Value) -> String {
+    match value.as_str() {
+        Some(s) => s.to_string(),
+        None => value.to_string(),
+    }
+}
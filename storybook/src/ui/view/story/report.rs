@@ -0,0 +1,27 @@
+use crate::ui::models::TestSuite;
+use crate::ui::viewmodels::report_vm::{ReportFormat, download_report};
+use dioxus::prelude::*;
+use lucide_dioxus::FileDown;
+
+/// Buttons downloading `suite` as a JUnit XML or JSON `kind` report (e.g.
+/// `play`), for CI systems to annotate pull requests with the failing
+/// stories.
+#[component]
+pub(crate) fn ReportButtons(suite: TestSuite, kind: &'static str) -> Element {
+    rsx! {
+        for format in [ReportFormat::JUnit, ReportFormat::Json] {
+            {
+                let suite = suite.clone();
+                rsx! {
+                    button {
+                        class: "report-btn",
+                        title: "Download a {format.label()} report",
+                        onclick: move |_| download_report(&suite, kind, format),
+                        FileDown { size: 12, stroke_width: 2 }
+                        span { "{format.label()}" }
+                    }
+                }
+            }
+        }
+    }
+}
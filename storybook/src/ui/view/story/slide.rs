@@ -0,0 +1,30 @@
+use super::live_frame::PreviewFrame;
+use crate::ui::viewmodels::story_card_vm::use_story_card;
+use crate::{RenderFn, StoryInfo};
+use dioxus::prelude::*;
+
+/// A story shown alone by the presentation mode: its live preview filling
+/// the slide, without toolbar nor props editor.
+#[component]
+pub(crate) fn PresentationSlide(
+    component_name: String,
+    story_index: usize,
+    story: StoryInfo,
+    render_fn: RenderFn,
+) -> Element {
+    let state = use_story_card(&component_name, story_index, &story);
+
+    rsx! {
+        div { class: "presentation-story",
+            PreviewFrame {
+                id: state.iframe_id.clone(),
+                document: state.srcdoc.clone(),
+                isolation: state.isolation,
+                render_fn,
+                decorators: state.decorators.clone(),
+                contexts: state.contexts.clone(),
+                props_json: state.props_json,
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::live_frame::PreviewFrame;
+use super::report::ReportButtons;
+use crate::ui::models::Selection;
+use crate::ui::viewmodels::snapshot_vm::{SnapshotStory, use_snapshot_frame, use_snapshot_run};
+use dioxus::prelude::*;
+use lucide_dioxus::Camera;
+
+/// Button downloading a snapshot bundle of `stories`: the rendered HTML of
+/// each story and a report, as one HTML file titled `title`. The snapshots
+/// are compared with their baselines, and a link opens the review of the
+/// new and changed ones. The comparison can be downloaded as a JUnit or JSON
+/// report.
+///
+/// While running, the stories are mounted in off-screen previews batch by
+/// batch to be captured (see
+/// [`with_snapshot_concurrency`](crate::StorybookConfig::with_snapshot_concurrency)),
+/// and a progress bar follows the captured stories.
+#[component]
+pub(crate) fn SnapshotButton(
+    title: String,
+    stories: Vec<SnapshotStory>,
+    mut selected: Signal<Option<Selection>>,
+) -> Element {
+    let run = use_snapshot_run();
+    let running = (run.running)();
+    let (captured, total) = (run.progress)();
+
+    rsx! {
+        button {
+            class: "snapshot-btn",
+            title: "Download the rendered HTML of these stories with a report",
+            disabled: running || stories.is_empty(),
+            onclick: move |_| run.start(title.clone(), &stories),
+            Camera { size: 14, stroke_width: 2 }
+            span { if running { "Capturing {captured}/{total} stories…" } else { "Snapshot" } }
+        }
+        if running {
+            progress {
+                class: "snapshot-progress",
+                max: "{total}",
+                value: "{captured}",
+            }
+        }
+        if let Some(message) = (run.message)() {
+            span { class: "snapshot-message", "{message}" }
+            if let Some(suite) = (run.report)() {
+                ReportButtons { suite, kind: "snapshots" }
+            }
+            if !(run.review.pending)().is_empty() {
+                button {
+                    class: "snapshot-btn",
+                    onclick: move |_| selected.set(Some(Selection::SnapshotReview)),
+                    "Review"
+                }
+            }
+        }
+        if running {
+            div { class: "snapshot-stage", aria_hidden: "true",
+                for story in (run.batch)() {
+                    SnapshotFrame { key: "{story.preview_id()}", story }
+                }
+            }
+        }
+    }
+}
+
+/// Off-screen preview a story is captured from.
+#[component]
+fn SnapshotFrame(story: SnapshotStory) -> Element {
+    let state = use_snapshot_frame(&story);
+
+    rsx! {
+        div { class: "snapshot-frame",
+            PreviewFrame {
+                id: state.preview_id,
+                document: state.srcdoc,
+                isolation: state.isolation,
+                render_fn: story.render_fn,
+                decorators: state.decorators,
+                contexts: state.contexts,
+                props_json: state.props_json,
+            }
+        }
+    }
+}
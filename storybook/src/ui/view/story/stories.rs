@@ -1,7 +1,7 @@
 use crate::ui::view::story::header::StoryHeaderProps;
 use crate::ui::view::story::props_editor::PropsEditorHeaderProps;
 use crate::ui::view::story::toolbar::StoryZoomControlsProps;
-use crate::{Stories, Story};
+use crate::{ComponentStatus, Stories, Story};
 use dioxus::prelude::Signal;
 
 impl Stories for StoryHeaderProps {
@@ -12,6 +12,7 @@ impl Stories for StoryHeaderProps {
                 Self {
                     component_name: "ExampleButton".to_string(),
                     story_title: "Default".to_string(),
+                    status: None,
                 },
             ),
             Story::new(
@@ -19,6 +20,15 @@ impl Stories for StoryHeaderProps {
                 Self {
                     component_name: "SuperLongComponentNameForTesting".to_string(),
                     story_title: "With Very Long Story Title Description".to_string(),
+                    status: None,
+                },
+            ),
+            Story::new(
+                "Deprecated",
+                Self {
+                    component_name: "LegacyButton".to_string(),
+                    story_title: "Default".to_string(),
+                    status: Some(ComponentStatus::Deprecated),
                 },
             ),
         ]
@@ -32,18 +42,21 @@ impl Stories for StoryZoomControlsProps {
                 "Default (100%)",
                 Self {
                     zoom_level: Signal::new(100),
+                    preview_id: "fullscreen-preview".to_string(),
                 },
             ),
             Story::new(
                 "Zoomed In (150%)",
                 Self {
                     zoom_level: Signal::new(150),
+                    preview_id: "fullscreen-preview".to_string(),
                 },
             ),
             Story::new(
                 "Zoomed Out (50%)",
                 Self {
                     zoom_level: Signal::new(50),
+                    preview_id: "fullscreen-preview".to_string(),
                 },
             ),
         ]
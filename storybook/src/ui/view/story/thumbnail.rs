@@ -0,0 +1,56 @@
+use super::live_frame::PreviewFrame;
+use crate::ui::viewmodels::snapshot_vm::{SnapshotStory, use_snapshot_frame};
+use crate::ui::viewmodels::thumbnail_vm::{ThumbnailStore, thumbnail_preview_id};
+use dioxus::prelude::*;
+
+/// A story thumbnail: its captured HTML in a scaled-down, inert iframe.
+#[component]
+pub(crate) fn StoryThumbnail(html: String) -> Element {
+    rsx! {
+        div { class: "story-thumbnail",
+            iframe {
+                class: "story-thumbnail-frame",
+                srcdoc: "{html}",
+                "sandbox": "",
+                tabindex: "-1",
+                aria_hidden: "true",
+            }
+        }
+    }
+}
+
+/// Off-screen previews of the stories whose thumbnail is being captured.
+#[component]
+pub(crate) fn ThumbnailStage() -> Element {
+    let store = use_context::<ThumbnailStore>();
+
+    rsx! {
+        if !store.batch.read().is_empty() {
+            div { class: "snapshot-stage", aria_hidden: "true",
+                for story in (store.batch)() {
+                    ThumbnailFrame { key: "{thumbnail_preview_id(&story)}", story }
+                }
+            }
+        }
+    }
+}
+
+/// Off-screen preview a thumbnail is captured from.
+#[component]
+fn ThumbnailFrame(story: SnapshotStory) -> Element {
+    let state = use_snapshot_frame(&story);
+
+    rsx! {
+        div { class: "snapshot-frame",
+            PreviewFrame {
+                id: thumbnail_preview_id(&story),
+                document: state.srcdoc,
+                isolation: state.isolation,
+                render_fn: story.render_fn,
+                decorators: state.decorators,
+                contexts: state.contexts,
+                props_json: state.props_json,
+            }
+        }
+    }
+}
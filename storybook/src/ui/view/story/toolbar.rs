@@ -1,4 +1,4 @@
-use crate::ui::view::shared::{ResetZoomButton, ZoomInButton, ZoomOutButton};
+use crate::ui::view::shared::{FitWidthButton, ResetZoomButton, ZoomInButton, ZoomOutButton};
 use dioxus::prelude::*;
 
 #[cfg(feature = "self-stories")]
@@ -9,15 +9,18 @@ use storybook_macro::storybook;
 
 /// Zoom controls toolbar for the story preview.
 ///
-/// Groups a `ZoomOutButton`, a percentage label, a `ZoomInButton`, and a
-/// `ResetZoomButton` into a horizontal toolbar. The zoom range is 25 %–200 %
-/// in 25 % increments.
+/// Groups a `ZoomOutButton`, a percentage label, a `ZoomInButton`, a
+/// `FitWidthButton` and a `ResetZoomButton` into a horizontal toolbar. The
+/// range and step come from the [`ZoomConfig`](crate::ZoomConfig) in
+/// `UiSettings` (25 %–200 % in 25 % steps by default); fitting to width can
+/// land anywhere in between.
 ///
 /// # Props
 ///
 /// | Prop | Type | Description |
 /// |------|------|-------------|
 /// | `zoom_level` | `Signal<i32>` | Current zoom percentage shared with the preview. |
+/// | `preview_id` | `String` | DOM id of the preview the fit button measures. |
 ///
 /// @[story:Molecules/StoryZoomControls/Default (100%)]
 ///
@@ -26,12 +29,13 @@ use storybook_macro::storybook;
 /// @[story:Molecules/StoryZoomControls/Zoomed Out (50%)]
 #[cfg_attr(feature = "self-stories", storybook(tag = "Molecules"))]
 #[component]
-pub fn StoryZoomControls(zoom_level: Signal<i32>) -> Element {
+pub fn StoryZoomControls(zoom_level: Signal<i32>, preview_id: String) -> Element {
     rsx! {
         div { class: "story-toolbar",
             ZoomOutButton { zoom_level }
             span { class: "zoom-level", "{zoom_level()}%" }
             ZoomInButton { zoom_level }
+            FitWidthButton { zoom_level, preview_id }
             ResetZoomButton { zoom_level }
         }
     }
@@ -1,21 +1,36 @@
 use crate::ui::models::Selection;
+use crate::ui::services::iframe::make_iframe_id;
 use crate::ui::view::shared::{
-    FullscreenButton, GridButton, OutlineButton, ThemeToggleButton, ViewPortSelector,
+    ActivityButton, CompareButton, DeviceFrameButton, DirectionButton, FullscreenButton,
+    GridButton, MeasureButton, OutlineButton, PresentButton, ProfileSwitcher,
+    ResponsiveSweepButton, SnapshotReviewButton, ThemeSweepButton, ThemeToggleButton,
+    ViewPortSelector,
 };
 use crate::ui::view::story::StoryZoomControls;
 use crate::ui::viewmodels::UiSettings;
+use crate::ui::viewmodels::addon_vm::{find_addon, story_addon_context, storybook_addons};
+use crate::ui::viewmodels::globals_vm::select_global;
+use crate::ui::viewmodels::profile_vm::ProfileContext;
+use crate::ui::viewmodels::snapshot_vm::SnapshotReview;
 #[cfg(feature = "self-stories")]
 use crate::{self as storybook, Stories, Story};
+use crate::{AddonContext, GlobalSetting, Globals, StorybookConfig};
 use dioxus::prelude::*;
 #[cfg(feature = "self-stories")]
 use storybook_macro::storybook;
 
 /// Top navigation bar with global and story-specific controls.
 ///
-/// The left section always shows the theme toggle, grid overlay, and
-/// outline buttons. When a story is selected, it additionally renders
-/// the zoom controls toolbar and the viewport size selector, separated
-/// by dividers. The right section contains the fullscreen toggle.
+/// The left section starts with the logo of the
+/// [`StorybookTheme`](crate::StorybookTheme), if any, then always shows the
+/// theme toggle, grid overlay, outline and measure buttons. When a story is
+/// selected, it additionally renders the zoom controls toolbar, the
+/// viewport size selector, the device frame toggle, the responsive and
+/// light/dark sweep toggles and the comparison toggle, separated by
+/// dividers. The right section contains the custom header of
+/// [`StorybookConfig::with_header`], if any, the profile switcher, when
+/// several profiles are registered, the session activity button, when
+/// enabled, the presentation button and the fullscreen toggle.
 ///
 /// All UI settings are read from the `UiSettings` context rather than
 /// being passed as props, because `UiSettings` contains `Signal` fields
@@ -33,30 +48,154 @@ use storybook_macro::storybook;
 #[component]
 pub(crate) fn TopBar(selected: Signal<Option<Selection>>) -> Element {
     let ui_settings = use_context::<UiSettings>();
-    let is_story_selected = matches!(selected(), Some(Selection::Story(_, _)));
+    let profiles = use_context::<ProfileContext>();
+    let config = use_context::<StorybookConfig>();
+    let session_activity = config.session_activity;
+    let logo_alt = config.title.clone().unwrap_or_else(|| "Logo".to_string());
+    let pending_snapshots = try_use_context::<SnapshotReview>()
+        .map(|review| (review.pending)().len())
+        .unwrap_or_default();
+    let preview_id = match selected() {
+        Some(Selection::Story(component_name, story_index)) => Some(make_iframe_id(
+            "fullscreen-preview",
+            &component_name,
+            story_index,
+        )),
+        _ => None,
+    };
+    let globals = try_use_context::<Signal<Globals>>();
+    let addons = storybook_addons(&config);
+    let addon_context = match selected() {
+        Some(Selection::Story(component_name, story_index)) if !addons.is_empty() => {
+            story_addon_context(&component_name, story_index)
+        }
+        _ => None,
+    };
 
     rsx! {
         div { class: "top-bar",
             div { class: "top-bar-left",
+                if let Some(logo) = config.theme.logo {
+                    img { class: "top-bar-logo", src: logo, alt: "{logo_alt}" }
+                    div { class: "top-bar-divider" }
+                }
                 ThemeToggleButton { dark_preview_background: ui_settings.dark_preview_background }
                 GridButton { grid_enabled: ui_settings.grid_enabled }
                 OutlineButton { outline_enabled: ui_settings.outline_enabled }
+                MeasureButton { measure_enabled: ui_settings.measure_enabled }
+                DirectionButton { rtl_enabled: ui_settings.rtl_enabled }
+                if let Some(globals) = globals.filter(|_| !config.globals.is_empty()) {
+                    div { class: "top-bar-divider" }
+                    for setting in config.globals.iter() {
+                        GlobalSelector { key: "{setting.name}", setting: setting.clone(), globals }
+                    }
+                }
+                if !config.toolbar_items.is_empty() {
+                    div { class: "top-bar-divider" }
+                    div { class: "top-bar-items",
+                        for index in 0..config.toolbar_items.len() {
+                            ToolbarItemSlot { key: "{index}", index }
+                        }
+                    }
+                }
 
-                if is_story_selected {
+                if let Some(preview_id) = preview_id {
                     div { class: "top-bar-divider" }
-                    StoryZoomControls { zoom_level: ui_settings.zoom_level }
+                    StoryZoomControls { zoom_level: ui_settings.zoom_level, preview_id }
                     div { class: "top-bar-divider" }
                     ViewPortSelector { viewport_width: ui_settings.viewport_width }
+                    DeviceFrameButton { device_frame_enabled: ui_settings.device_frame_enabled }
+                    ResponsiveSweepButton { responsive_sweep: ui_settings.responsive_sweep }
+                    ThemeSweepButton { theme_sweep: ui_settings.theme_sweep }
+                    CompareButton { compare: ui_settings.compare }
+                }
+                if let Some(context) = addon_context {
+                    div { class: "top-bar-addons",
+                        for addon in addons {
+                            AddonToolbarButton {
+                                key: "{addon.name()}",
+                                name: addon.name(),
+                                context: context.clone(),
+                            }
+                        }
+                    }
                 }
             }
 
             div { class: "top-bar-right",
+                if config.header.is_some() {
+                    div { class: "top-bar-header",
+                        HeaderSlot {}
+                    }
+                    div { class: "top-bar-divider" }
+                }
+                if profiles.profiles.len() > 1 {
+                    ProfileSwitcher { profiles: profiles.profiles.clone(), active: profiles.active.clone() }
+                }
+                if pending_snapshots > 0 {
+                    SnapshotReviewButton { pending: pending_snapshots, selected }
+                }
+                if session_activity {
+                    ActivityButton { selected }
+                }
+                PresentButton { presenting: ui_settings.presenting }
                 FullscreenButton { fullscreen_on: ui_settings.fullscreen }
             }
         }
     }
 }
 
+/// Renders the custom header in a scope of its own, so its hooks don't mix
+/// with the top bar's.
+#[component]
+fn HeaderSlot() -> Element {
+    match use_context::<StorybookConfig>().header {
+        Some(header) => header(),
+        None => rsx! {},
+    }
+}
+
+/// Dropdown picking the value of a global setting.
+#[component]
+fn GlobalSelector(setting: GlobalSetting, globals: Signal<Globals>) -> Element {
+    let current = globals().get(&setting.name).map(str::to_string);
+    let name = setting.name.clone();
+
+    rsx! {
+        select {
+            class: "top-bar-viewport-select top-bar-global-select",
+            title: "{setting.name}",
+            onchange: move |e: Event<FormData>| select_global(globals, &name, &e.value()),
+            for value in setting.values.iter() {
+                option {
+                    key: "{value}",
+                    value: "{value}",
+                    selected: current.as_deref() == Some(value.as_str()),
+                    "{setting.name}: {value}"
+                }
+            }
+        }
+    }
+}
+
+/// Renders a custom toolbar item in a scope of its own.
+#[component]
+fn ToolbarItemSlot(index: usize) -> Element {
+    match use_context::<StorybookConfig>().toolbar_items.get(index) {
+        Some(item) => item(),
+        None => rsx! {},
+    }
+}
+
+/// Renders an addon's toolbar button in a scope of its own.
+#[component]
+fn AddonToolbarButton(name: &'static str, context: AddonContext) -> Element {
+    let config = use_context::<StorybookConfig>();
+    find_addon(&config, name)
+        .and_then(|addon| addon.toolbar_button(context))
+        .unwrap_or_else(|| rsx! {})
+}
+
 #[cfg(feature = "self-stories")]
 impl Stories for TopBarProps {
     fn stories() -> Vec<Story<Self>> {
@@ -0,0 +1,29 @@
+use crate::ui::models::{A11yAuditReport, A11ySeverity};
+use crate::ui::services::a11y_audit::build_a11y_audit_script;
+use crate::ui::viewmodels::audit_vm::{AuditState, run_audit, use_audit};
+use crate::watch;
+use dioxus::prelude::*;
+
+/// Prepared state for the accessibility audit panel.
+pub type A11yAuditState = AuditState<A11yAuditReport>;
+
+/// Custom hook that audits the preview iframe `iframe_id` for accessibility
+/// violations.
+///
+/// The audit runs when the panel mounts and again after each edit of
+/// `props_json` or change of story; call [`run_a11y_audit`] to re-run it.
+pub fn use_a11y_audit(iframe_id: &str, props_json: Signal<String>) -> A11yAuditState {
+    use_audit(iframe_id, props_json, run_a11y_audit)
+}
+
+/// Run the accessibility audit against the preview iframe and store the outcome in `state`.
+pub fn run_a11y_audit(iframe_id: String, state: A11yAuditState) {
+    run_audit(build_a11y_audit_script(&iframe_id), state, |report| {
+        let critical = report
+            .violations
+            .iter()
+            .filter(|v| v.rule.severity() == A11ySeverity::Critical)
+            .count();
+        watch::a11y_audited(report.violations.len(), critical);
+    });
+}
@@ -0,0 +1,82 @@
+use crate::ui::models::{StoryActivity, TagFilter};
+use crate::ui::viewmodels::sidebar_vm::get_story_titles;
+use crate::{StorybookConfig, get_components};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+/// Story views of the session, provided as context.
+///
+/// Only kept in memory: nothing is sent anywhere, and the counts reset when
+/// the page reloads.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SessionActivity {
+    /// View count per `(component_name, story_index)`.
+    pub views: Signal<BTreeMap<(String, usize), u32>>,
+}
+
+impl Default for SessionActivity {
+    fn default() -> Self {
+        SessionActivity {
+            views: Signal::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl SessionActivity {
+    /// Count a view of the story.
+    pub fn record(mut self, component_name: &str, story_index: usize) {
+        *self
+            .views
+            .write()
+            .entry((component_name.to_string(), story_index))
+            .or_default() += 1;
+    }
+
+    /// Forget every view.
+    pub fn reset(mut self) {
+        self.views.write().clear();
+    }
+}
+
+/// Resolved data for the session activity page.
+pub struct ActivityReport {
+    /// Every story listed in the sidebar, never opened ones first.
+    pub stories: Vec<StoryActivity>,
+    /// Number of stories opened at least once.
+    pub viewed: usize,
+}
+
+/// List the activity of every story listed in the sidebar with `config`.
+pub fn resolve_activity(
+    config: &StorybookConfig,
+    views: &BTreeMap<(String, usize), u32>,
+) -> ActivityReport {
+    let mut stories = get_components()
+        .filter(|reg| !config.visible_tags_of(reg).is_empty())
+        .flat_map(|reg| {
+            get_story_titles(reg.name, &TagFilter::All).into_iter().map(
+                |(story_index, story_title)| StoryActivity {
+                    component_name: reg.name.to_string(),
+                    story_index,
+                    story_title,
+                    views: views
+                        .get(&(reg.name.to_string(), story_index))
+                        .copied()
+                        .unwrap_or_default(),
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    stories.sort_by(|a, b| {
+        (a.views > 0, &a.component_name, a.story_index).cmp(&(
+            b.views > 0,
+            &b.component_name,
+            b.story_index,
+        ))
+    });
+
+    ActivityReport {
+        viewed: stories.iter().filter(|s| s.views > 0).count(),
+        stories,
+    }
+}
@@ -0,0 +1,36 @@
+use crate::ui::services::iframe::make_iframe_id;
+use crate::{Addon, AddonContext, StorybookConfig, find_component, get_addons};
+
+/// Addons of the storybook: those added with
+/// [`StorybookConfig::with_addon`], then the registered ones. Only the first
+/// addon with a given name is kept.
+pub fn storybook_addons(config: &StorybookConfig) -> Vec<&'static dyn Addon> {
+    let mut addons: Vec<&'static dyn Addon> = Vec::new();
+    for addon in config.addons.iter().copied().chain(get_addons()) {
+        if !addons.iter().any(|known| known.name() == addon.name()) {
+            addons.push(addon);
+        }
+    }
+    addons
+}
+
+/// The addon named `name`, see [`storybook_addons`].
+pub fn find_addon(config: &StorybookConfig, name: &str) -> Option<&'static dyn Addon> {
+    storybook_addons(config)
+        .into_iter()
+        .find(|addon| addon.name() == name)
+}
+
+/// Context of the addons shown for the story at `story_index` of
+/// `component_name`, rendered with its own props.
+pub fn story_addon_context(component_name: &str, story_index: usize) -> Option<AddonContext> {
+    let component = find_component(component_name).ok()?;
+    let story = (component.get_stories)().into_iter().nth(story_index)?;
+    Some(AddonContext {
+        component_name: component_name.to_string(),
+        story_index,
+        story_id: story.id,
+        iframe_id: make_iframe_id("fullscreen-preview", component_name, story_index),
+        props_json: story.props_json,
+    })
+}
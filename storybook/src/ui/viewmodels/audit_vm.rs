@@ -0,0 +1,67 @@
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+
+/// State shared by the audit panels: the latest report of an audit script
+/// run against a preview iframe.
+pub struct AuditState<R: 'static> {
+    /// The latest report, or `None` before the first audit completes.
+    pub report: Signal<Option<R>>,
+    /// Error message when the preview iframe could not be inspected.
+    pub error: Signal<Option<String>>,
+    pub running: Signal<bool>,
+}
+
+impl<R> Clone for AuditState<R> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R> Copy for AuditState<R> {}
+
+/// Custom hook creating the state of an audit panel. The audit runs through
+/// `run` when the panel mounts, and again when `props_json` is edited or the
+/// audited preview `iframe_id` changes (e.g. to the other story of a
+/// comparison).
+pub fn use_audit<R>(
+    iframe_id: &str,
+    props_json: Signal<String>,
+    mut run: impl FnMut(String, AuditState<R>) + 'static,
+) -> AuditState<R> {
+    let state = AuditState {
+        report: use_signal(|| None),
+        error: use_signal(|| None),
+        running: use_signal(|| false),
+    };
+    let iframe_id = iframe_id.to_string();
+    use_effect(use_reactive((&iframe_id,), move |(iframe_id,)| {
+        props_json();
+        run(iframe_id, state);
+    }));
+    state
+}
+
+/// Evaluate the audit `script` in a task and store its report, or why the
+/// preview could not be inspected, in `state`. `on_report` is called with
+/// each new report before it is stored.
+pub fn run_audit<R: DeserializeOwned>(
+    script: String,
+    mut state: AuditState<R>,
+    on_report: impl FnOnce(&R) + 'static,
+) {
+    state.running.set(true);
+    spawn(async move {
+        match document::eval(&script).join::<Option<R>>().await {
+            Ok(Some(report)) => {
+                on_report(&report);
+                state.report.set(Some(report));
+                state.error.set(None);
+            }
+            Ok(None) => state
+                .error
+                .set(Some("The story preview is not loaded yet.".to_string())),
+            Err(e) => state.error.set(Some(e.to_string())),
+        }
+        state.running.set(false);
+    });
+}
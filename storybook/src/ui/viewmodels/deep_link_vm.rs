@@ -0,0 +1,70 @@
+use crate::ui::models::Selection;
+use crate::ui::services::location::query_param;
+use crate::ui::services::story_ids::{
+    build_page_link_script, build_story_set_check_script, collect_story_ids, find_story,
+};
+use crate::{StorybookConfig, get_components, watch};
+use dioxus::prelude::*;
+
+/// The story requested by the `?story=<id>` URL parameter, resolved through
+/// the config's story migrations, or the doc page requested by `?doc=<path>`.
+pub fn initial_selection(config: &StorybookConfig) -> Option<Selection> {
+    if let Some(path) = query_param("doc") {
+        return Some(Selection::DocPage(path));
+    }
+    let id = query_param("story")?;
+    match find_story(config.migrate_story_id(&id)) {
+        Some((component_name, index)) => Some(Selection::Story(component_name, index)),
+        None => Some(Selection::NotFound(id)),
+    }
+}
+
+/// Custom hook keeping the `?story=` and `?doc=` URL parameters in sync with
+/// the selected story or doc page, and reporting to the watch-mode summary, once at startup, the ids
+/// of the previous build that disappeared without a migration.
+pub fn use_deep_link(selected: Signal<Option<Selection>>) {
+    let config = use_context::<StorybookConfig>();
+
+    // The `#heading` fragment of the page URL targets the initial page
+    let mut initial = true;
+    use_effect(move || {
+        let selection = selected();
+        // Keep a broken link in the URL while its not-found page is shown
+        if matches!(selection, Some(Selection::NotFound(_))) {
+            initial = false;
+            return;
+        }
+        let id = match &selection {
+            Some(Selection::Story(component_name, index)) => get_components()
+                .find(|c| c.name == component_name)
+                .and_then(|c| (c.get_stories)().into_iter().nth(*index))
+                .map(|story| story.id),
+            _ => None,
+        };
+        let doc = match &selection {
+            Some(Selection::DocPage(path)) if !path.is_empty() => Some(path.as_str()),
+            _ => None,
+        };
+        document::eval(&build_page_link_script(id.as_deref(), doc, initial));
+        initial = false;
+    });
+
+    use_hook(move || {
+        let ids = collect_story_ids();
+        spawn(async move {
+            let Ok(removed) = document::eval(&build_story_set_check_script(&ids))
+                .join::<Vec<String>>()
+                .await
+            else {
+                return;
+            };
+            let unmigrated = removed
+                .into_iter()
+                .filter(|id| !ids.contains(config.migrate_story_id(id)))
+                .collect::<Vec<_>>();
+            if !unmigrated.is_empty() {
+                watch::story_ids_removed(&unmigrated);
+            }
+        });
+    });
+}
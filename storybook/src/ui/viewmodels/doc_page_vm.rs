@@ -1,8 +1,26 @@
+use crate::ui::models::{DocFreshness, DocPart, DocStaleness, Selection, TocEntry};
+use crate::ui::services::dates::{DAY_SECONDS, format_date};
+use crate::ui::services::doc_anchors::{
+    build_heading_anchors_script, build_listen_doc_links_script, build_toc_sync_script,
+};
+use crate::ui::services::doc_parser::parse_doc_content;
+use crate::ui::services::doc_toc::extract_toc;
+use crate::ui::services::glossary::link_glossary_terms;
+use crate::ui::viewmodels::embedded_story_vm::{EmbeddedStoryData, resolve_embedded_canvas};
+use crate::{
+    DocDirectiveFn, DocRegistration, Error, StorybookConfig, category_matches, doc_component,
+    find_component, find_component_doc, get_components, get_glossary_terms,
+};
 use dioxus::prelude::*;
+use schemars::Schema;
+use std::collections::HashSet;
 
 const HLJS_VERSION: &str = "11.11.1";
 const HLJS_THEME: &str = "github";
 
+/// Headings a doc page needs for a table of contents to be shown.
+const MIN_TOC_ENTRIES: usize = 3;
+
 /// The highlight.js script URL (loaded once per doc page).
 pub const HLJS_SCRIPT_URL: &str = concat!(
     "https://cdnjs.cloudflare.com/ajax/libs/highlight.js/",
@@ -10,6 +28,55 @@ pub const HLJS_SCRIPT_URL: &str = concat!(
     "/highlight.min.js"
 );
 
+/// Delay between two checks of a doc page's markdown source (`hot-docs` only).
+#[cfg(feature = "hot-docs")]
+const HOT_DOCS_POLL_MS: u32 = 1000;
+
+/// Custom hook returning the HTML to display for a doc page.
+///
+/// Without the `hot-docs` feature this is the HTML baked in at compile time.
+#[cfg(not(feature = "hot-docs"))]
+pub fn use_doc_html(content_html: String, _source: Option<Asset>) -> String {
+    content_html
+}
+
+/// Custom hook returning the HTML to display for a doc page.
+///
+/// Starts from the HTML baked in at compile time, then polls the bundled
+/// markdown `source` and re-renders it whenever its content changes, so doc
+/// edits show up without rebuilding the binary.
+#[cfg(feature = "hot-docs")]
+pub fn use_doc_html(content_html: String, source: Option<Asset>) -> String {
+    let mut html = use_signal(|| content_html);
+
+    use_future(move || async move {
+        let Some(source) = source else {
+            return;
+        };
+        let mut last_markdown: Option<String> = None;
+        loop {
+            let fetched = document::eval(&format!(
+                r#"
+                await new Promise(function(resolve) {{ setTimeout(resolve, {HOT_DOCS_POLL_MS}); }});
+                var res = await fetch("{source}?t=" + Date.now(), {{ cache: "no-store" }});
+                return res.ok ? await res.text() : null;
+                "#
+            ))
+            .join::<Option<String>>()
+            .await;
+
+            if let Ok(Some(markdown)) = fetched
+                && last_markdown.as_ref() != Some(&markdown)
+            {
+                html.set(storybook_markdown::markdown_to_html(&markdown, true));
+                last_markdown = Some(markdown);
+            }
+        }
+    });
+
+    html()
+}
+
 /// Custom hook that manages the highlight.js theme stylesheet.
 ///
 /// Injects the highlight.js light theme stylesheet and highlights all
@@ -45,3 +112,149 @@ pub fn use_hljs_theme() {
         ));
     });
 }
+
+/// Split the HTML of a doc page, once passed through the config's
+/// [doc transforms](StorybookConfig::with_doc_transform), into its parts
+/// (see [`parse_doc_content`]), with the first occurrence of each glossary
+/// term linked to its definition.
+pub fn resolve_doc_parts(config: &StorybookConfig, content_html: &str) -> Vec<DocPart> {
+    let content_html = config
+        .doc_transforms
+        .iter()
+        .fold(content_html.to_string(), |html, transform| transform(html));
+    let terms: Vec<_> = get_glossary_terms().collect();
+    let mut linked = HashSet::new();
+    parse_doc_content(&content_html)
+        .into_iter()
+        .map(|part| match part {
+            DocPart::Html(html) if !terms.is_empty() => {
+                DocPart::Html(link_glossary_terms(&html, &terms, &mut linked))
+            }
+            part => part,
+        })
+        .collect()
+}
+
+/// The handler of the custom doc directive `kind`, see
+/// [`StorybookConfig::with_doc_directive`].
+pub fn find_doc_directive(config: &StorybookConfig, kind: &str) -> Option<DocDirectiveFn> {
+    config
+        .doc_directives
+        .iter()
+        .find(|(known, _)| known == kind)
+        .map(|(_, render)| *render)
+}
+
+/// Custom hook enabling the heading anchors of a doc page: clicking one
+/// copies a link to the section, and the page scrolls to the heading named
+/// by the URL's `#fragment` once rendered.
+pub fn use_heading_anchors() {
+    use_effect(move || {
+        document::eval(&build_heading_anchors_script());
+    });
+}
+
+/// The table of contents of the doc page `content_html`: its `h2` and `h3`
+/// headings, or nothing when the page is too short to need one.
+pub fn resolve_doc_toc(content_html: &str) -> Vec<TocEntry> {
+    let entries = extract_toc(content_html);
+    if entries.len() < MIN_TOC_ENTRIES {
+        return Vec::new();
+    }
+    entries
+}
+
+/// Custom hook highlighting the table of contents entry of the section
+/// being read as the page scrolls.
+pub fn use_toc_scroll_sync() {
+    use_effect(move || {
+        document::eval(&build_toc_sync_script());
+    });
+}
+
+/// Custom hook following the `@[link:…]` and `@[doc:…]` links of doc pages
+/// in place: clicking one selects the component or doc page rather than
+/// reloading the storybook.
+pub fn use_doc_links(mut selected: Signal<Option<Selection>>) {
+    use_hook(move || {
+        spawn(async move {
+            let mut clicks = document::eval(&build_listen_doc_links_script());
+            while let Ok((kind, target)) = clicks.recv::<(String, String)>().await {
+                selected.set(Some(match kind.as_str() {
+                    "component" => Selection::Component(target),
+                    _ => Selection::DocPage(target),
+                }));
+            }
+        });
+    });
+}
+
+/// Resolved data for a component's auto-generated documentation page.
+pub struct ComponentDocsData {
+    pub component_name: String,
+    /// Sidebar paths the component is listed under.
+    pub tags: Vec<String>,
+    /// HTML description extracted from the component's doc comments (may be empty).
+    pub description_html: String,
+    pub prop_schema: Schema,
+    pub stories: Vec<EmbeddedStoryData>,
+    /// The `storydoc!` page attached to the component, shown as its "Guide"
+    /// tab.
+    pub guide: Option<&'static DocRegistration>,
+}
+
+/// Resolve everything needed to render the autodocs page of `component_name`:
+/// its doc-comment description, props schema, and all of its stories.
+pub fn resolve_component_docs(component_name: &str) -> Result<ComponentDocsData, Error> {
+    let registration = find_component(component_name)?;
+
+    Ok(ComponentDocsData {
+        component_name: component_name.to_string(),
+        tags: registration.tags.iter().map(|t| t.to_string()).collect(),
+        description_html: registration.description.to_string(),
+        prop_schema: registration.prop_schema()?,
+        stories: resolve_embedded_canvas(component_name)?,
+        guide: find_component_doc(registration),
+    })
+}
+
+/// Resolve the "Last updated" line of the doc page `doc`, flagging it as
+/// stale when the config sets a threshold and a component listed under the
+/// page's folder (or the component the page is attached to) changed more
+/// than that many days after the page.
+///
+/// `None` when the date of the markdown file couldn't be recorded.
+pub fn resolve_doc_freshness(
+    doc: &DocRegistration,
+    config: &StorybookConfig,
+) -> Option<DocFreshness> {
+    let last_updated = doc.last_updated?;
+    let stale = config.doc_staleness_days.and_then(|days| {
+        let owner = doc_component(doc.path);
+        let pattern = if doc.path.is_empty() {
+            "**".to_string()
+        } else {
+            format!("{}/**", doc.path)
+        };
+        get_components()
+            .filter(|component| match owner {
+                Some(owner) => component.name == owner.name,
+                None => component
+                    .tags
+                    .iter()
+                    .any(|tag| category_matches(&pattern, tag)),
+            })
+            .filter_map(|component| Some((component.name, component.source_updated?)))
+            .max_by_key(|(_, updated)| *updated)
+            .map(|(name, updated)| (name, updated.saturating_sub(last_updated) / DAY_SECONDS))
+            .filter(|(_, days_behind)| *days_behind > u64::from(days))
+            .map(|(name, days_behind)| DocStaleness {
+                component_name: name.to_string(),
+                days_behind,
+            })
+    });
+    Some(DocFreshness {
+        last_updated: format_date(last_updated),
+        stale,
+    })
+}
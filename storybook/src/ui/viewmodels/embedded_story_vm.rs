@@ -1,4 +1,4 @@
-use crate::{RenderFn, StoryInfo, find_component};
+use crate::{Error, RenderFn, StoryInfo, find_component};
 use schemars::Schema;
 
 /// Resolved data for an EmbeddedStory view.
@@ -13,37 +13,30 @@ pub struct EmbeddedStoryData {
     pub prop_schema: Schema,
 }
 
-/// Error cases when resolving an embedded story.
-pub enum EmbeddedStoryError {
-    InvalidPath(String),
-    ComponentNotFound(String),
-    StoryNotFound {
-        component_name: String,
-        story_name: String,
-    },
-}
-
 /// Parse a story path and resolve the component + story.
 ///
 /// The `story_path` format is `"Category/Component/StoryName"` (at least two
 /// segments). The component name is the second-to-last segment.
 ///
-/// Returns the fully resolved [`EmbeddedStoryData`] or an
-/// [`EmbeddedStoryError`] describing what went wrong.
+/// `props_override`, a JSON object, replaces the matching fields of the
+/// story's props for this embed only.
+///
+/// Returns the fully resolved [`EmbeddedStoryData`] or an [`Error`]
+/// describing what went wrong.
 pub fn resolve_embedded_story(
     story_path: &str,
     story_name: &str,
-) -> Result<EmbeddedStoryData, EmbeddedStoryError> {
+    props_override: Option<&str>,
+) -> Result<EmbeddedStoryData, Error> {
     let path_parts: Vec<&str> = story_path.split('/').collect();
 
     if path_parts.len() < 2 {
-        return Err(EmbeddedStoryError::InvalidPath(story_path.to_string()));
+        return Err(Error::InvalidStoryPath(story_path.to_string()));
     }
 
     let component_name = path_parts[path_parts.len() - 2];
 
-    let registration = find_component(component_name)
-        .ok_or_else(|| EmbeddedStoryError::ComponentNotFound(component_name.to_string()))?;
+    let registration = find_component(component_name)?;
 
     let stories = (registration.get_stories)();
     let story_with_index = stories
@@ -51,20 +44,71 @@ pub fn resolve_embedded_story(
         .enumerate()
         .find(|(_, s)| s.title == story_name);
 
-    let (story_index, story) =
-        story_with_index.ok_or_else(|| EmbeddedStoryError::StoryNotFound {
-            component_name: component_name.to_string(),
-            story_name: story_name.to_string(),
+    let (story_index, story) = story_with_index.ok_or_else(|| Error::StoryNotFound {
+        component_name: component_name.to_string(),
+        story: story_name.to_string(),
+    })?;
+
+    let mut story = story.clone();
+    if let Some(props_override) = props_override {
+        story.props_json = merge_props(&story.props_json, props_override).map_err(|message| {
+            Error::PropsDeserialize {
+                component_name: component_name.to_string(),
+                field: None,
+                message,
+            }
         })?;
+    }
 
     let render_fn = registration.render_with_props;
-    let prop_schema = (registration.get_prop_schema)();
+    let prop_schema = registration.prop_schema()?;
 
     Ok(EmbeddedStoryData {
         component_name: component_name.to_string(),
         story_index,
-        story: story.clone(),
+        story,
         render_fn,
         prop_schema,
     })
 }
+
+/// Overwrite the fields of `props_json` set in `props_override`, both JSON
+/// objects.
+fn merge_props(props_json: &str, props_override: &str) -> Result<String, String> {
+    let mut props =
+        serde_json::from_str::<serde_json::Value>(props_json).map_err(|e| e.to_string())?;
+    let overrides = serde_json::from_str::<serde_json::Value>(props_override)
+        .map_err(|e| format!("invalid props override: {e}"))?;
+    let (Some(props_obj), serde_json::Value::Object(overrides)) =
+        (props.as_object_mut(), overrides)
+    else {
+        return Err("the props override must be a JSON object".to_string());
+    };
+    props_obj.extend(overrides);
+    serde_json::to_string_pretty(&props).map_err(|e| e.to_string())
+}
+
+/// Resolve the props schema of a component for a `@[props:…]` embed.
+pub fn resolve_embedded_props(component_name: &str) -> Result<Schema, Error> {
+    find_component(component_name)?.prop_schema()
+}
+
+/// Resolve every story of a component for a `@[canvas:…]` embed.
+pub fn resolve_embedded_canvas(component_name: &str) -> Result<Vec<EmbeddedStoryData>, Error> {
+    let registration = find_component(component_name)?;
+
+    let render_fn = registration.render_with_props;
+    let prop_schema = registration.prop_schema()?;
+
+    Ok((registration.get_stories)()
+        .into_iter()
+        .enumerate()
+        .map(|(story_index, story)| EmbeddedStoryData {
+            component_name: component_name.to_string(),
+            story_index,
+            story,
+            render_fn,
+            prop_schema: prop_schema.clone(),
+        })
+        .collect())
+}
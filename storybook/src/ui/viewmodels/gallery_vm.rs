@@ -0,0 +1,71 @@
+use crate::ui::models::TagFilter;
+use crate::ui::viewmodels::sidebar_vm::get_story_titles;
+use crate::{
+    ComponentStatus, Error, RenderFn, StoryInfo, StorybookConfig, category_matches, find_component,
+    get_components,
+};
+
+/// Resolved data for a component's story gallery.
+pub struct GalleryData {
+    pub component_name: String,
+    pub render_fn: RenderFn,
+    /// `(story_index, story)` of every story listed in the sidebar.
+    pub stories: Vec<(usize, StoryInfo)>,
+    pub status: Option<ComponentStatus>,
+}
+
+/// Resolve the stories shown by the gallery of `component_name`: the ones
+/// neither skipped nor filtered out by `STORYBOOK_ONLY`.
+pub fn resolve_gallery(component_name: &str) -> Result<GalleryData, Error> {
+    let registration = find_component(component_name)?;
+    let stories = (registration.get_stories)();
+
+    Ok(GalleryData {
+        component_name: component_name.to_string(),
+        render_fn: registration.render_with_props,
+        stories: get_story_titles(component_name, &TagFilter::All)
+            .into_iter()
+            .filter_map(|(index, _)| Some((index, stories.get(index)?.clone())))
+            .collect(),
+        status: registration.status,
+    })
+}
+
+/// A component of a category overview, shown with its first story.
+pub struct CategoryGalleryEntry {
+    pub component_name: String,
+    pub render_fn: RenderFn,
+    pub story_index: usize,
+    pub story: StoryInfo,
+}
+
+/// Resolve the components listed under the category `path` or one of its
+/// subfolders with `config`, in sidebar order, each with the first story
+/// listed in the sidebar.
+pub fn resolve_category_gallery(config: &StorybookConfig, path: &str) -> Vec<CategoryGalleryEntry> {
+    let pattern = format!("{path}/**");
+    let mut registrations = get_components()
+        .filter(|reg| {
+            config
+                .visible_tags_of(reg)
+                .iter()
+                .any(|tag| category_matches(&pattern, tag))
+        })
+        .collect::<Vec<_>>();
+    registrations.sort_by(|a, b| a.weight.cmp(&b.weight).then_with(|| a.name.cmp(b.name)));
+
+    registrations
+        .into_iter()
+        .filter_map(|reg| {
+            let (story_index, _) = get_story_titles(reg.name, &TagFilter::All)
+                .into_iter()
+                .next()?;
+            Some(CategoryGalleryEntry {
+                component_name: reg.name.to_string(),
+                render_fn: reg.render_with_props,
+                story_index,
+                story: (reg.get_stories)().get(story_index)?.clone(),
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,16 @@
+use crate::{Globals, StorybookConfig};
+use dioxus::prelude::*;
+
+/// Custom hook providing the current [`Globals`] as context, starting from
+/// the default value of every setting declared in the config.
+///
+/// Read by [`globals()`](crate::globals) in inline previews, and forwarded
+/// to the stories mounted in iframes and shadow roots.
+pub fn use_globals_provider(config: &StorybookConfig) -> Signal<Globals> {
+    use_context_provider(|| Signal::new(Globals::defaults(&config.globals)))
+}
+
+/// Set the global `name` to `value`.
+pub fn select_global(mut globals: Signal<Globals>, name: &str, value: &str) {
+    globals.write().set(name, value);
+}
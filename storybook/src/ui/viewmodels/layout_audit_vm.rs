@@ -0,0 +1,65 @@
+use crate::StorybookConfig;
+use crate::ui::models::{CascadeReport, LayoutAuditReport};
+use crate::ui::services::iframe::reset_href;
+use crate::ui::services::layout_audit::{build_cascade_check_script, build_layout_audit_script};
+use crate::ui::viewmodels::audit_vm::{AuditState, run_audit, use_audit};
+use crate::ui::viewmodels::ui_settings::UiSettings;
+use dioxus::prelude::*;
+
+/// Prepared state for the layout audit panel.
+#[derive(Clone, Copy)]
+pub struct LayoutAuditState {
+    /// The overflow audit.
+    pub audit: AuditState<LayoutAuditReport>,
+    /// Elements whose rendering depends on a CSS reset, or `None` before the
+    /// first audit completes.
+    pub cascade: Signal<Option<CascadeReport>>,
+    /// Whether the CSS reset is injected into previews (shared UI setting).
+    pub css_reset: Signal<bool>,
+    /// URL of the CSS reset the cascade check compares with.
+    pub reset_href: Signal<String>,
+}
+
+/// Custom hook that audits the preview iframe `iframe_id` for layout overflow
+/// and for differences caused by a CSS reset.
+///
+/// The audit runs when the panel mounts and again after each edit of
+/// `props_json` or change of story; call [`run_layout_audit`] to re-run it
+/// (e.g. after changing the viewport).
+pub fn use_layout_audit(iframe_id: &str, props_json: Signal<String>) -> LayoutAuditState {
+    let cascade = use_signal(|| None);
+    let css_reset = use_context::<UiSettings>().css_reset;
+    let config = use_context::<StorybookConfig>();
+    let reset_href = use_signal(|| reset_href(&config));
+    let audit = use_audit(iframe_id, props_json, move |iframe_id, audit| {
+        run_layout_audit(
+            iframe_id,
+            LayoutAuditState {
+                audit,
+                cascade,
+                css_reset,
+                reset_href,
+            },
+        )
+    });
+    LayoutAuditState {
+        audit,
+        cascade,
+        css_reset,
+        reset_href,
+    }
+}
+
+/// Run the layout audit against the preview iframe and store the outcome in `state`.
+pub fn run_layout_audit(iframe_id: String, mut state: LayoutAuditState) {
+    run_audit(build_layout_audit_script(&iframe_id), state.audit, |_| {});
+    let script = build_cascade_check_script(&iframe_id, &state.reset_href.peek());
+    spawn(async move {
+        if let Ok(cascade) = document::eval(&script)
+            .join::<Option<CascadeReport>>()
+            .await
+        {
+            state.cascade.set(cascade);
+        }
+    });
+}
@@ -0,0 +1,78 @@
+use crate::ui::services::live_mount::{LiveMount, build_shadow_setup_script, mount_live_story};
+use crate::{ContextFn, Decorator, Globals, RenderFn, StorybookConfig};
+use dioxus::prelude::*;
+
+/// Custom hook mounting a story live into the preview `iframe_id`.
+///
+/// Returns the callback to run whenever the iframe (re)loads its srcdoc or
+/// the shadow root is refilled: it unmounts the previous virtual DOM, if any,
+/// and mounts the story into the fresh document. Edits of `props_json` are
+/// forwarded to the mounted story without reloading the preview, with their
+/// placeholders substituted (see [`StorybookConfig::with_prop_placeholders`]),
+/// and so are changes of the [`Globals`].
+pub fn use_live_frame(
+    iframe_id: String,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) -> Callback<()> {
+    let mut mounted = use_signal(|| Option::<LiveMount>::None);
+    let config = use_context::<StorybookConfig>();
+
+    let props_config = config.clone();
+    use_effect(move || {
+        let json = props_config.substitute_props(&props_json());
+        if let Some(mount) = mounted.peek().as_ref() {
+            let _ = mount.updates.unbounded_send(json);
+        }
+    });
+
+    let globals = try_use_context::<Signal<Globals>>();
+    use_effect(move || {
+        let values = globals.map(|globals| globals()).unwrap_or_default();
+        if let Some(mount) = mounted.peek().as_ref() {
+            let _ = mount.globals_updates.unbounded_send(values);
+        }
+    });
+
+    use_callback(move |()| {
+        if let Some(previous) = mounted.take() {
+            previous.task.cancel();
+        }
+        mounted.set(mount_live_story(
+            &iframe_id,
+            render_fn,
+            decorators.clone(),
+            contexts.clone(),
+            config.substitute_props(&props_json.peek()),
+            globals
+                .map(|globals| globals.peek().clone())
+                .unwrap_or_default(),
+        ));
+    })
+}
+
+/// Custom hook mounting a story live into the shadow root of the host
+/// element `host_id` (see [`PreviewIsolation::ShadowDom`](crate::PreviewIsolation::ShadowDom)).
+///
+/// Whenever `html` changes, the shadow root is refilled with it and the
+/// story is remounted.
+pub fn use_shadow_frame(
+    host_id: String,
+    html: String,
+    render_fn: RenderFn,
+    decorators: Vec<Decorator>,
+    contexts: Vec<ContextFn>,
+    props_json: Signal<String>,
+) {
+    let mount = use_live_frame(host_id.clone(), render_fn, decorators, contexts, props_json);
+
+    use_effect(use_reactive((&html,), move |(html,)| {
+        let host_id = host_id.clone();
+        spawn(async move {
+            let _ = document::eval(&build_shadow_setup_script(&host_id, &html)).await;
+            mount.call(());
+        });
+    }));
+}
@@ -1,11 +1,33 @@
+pub mod a11y_audit_vm;
+pub mod activity_vm;
+pub mod addon_vm;
+pub mod audit_vm;
+pub mod deep_link_vm;
 pub mod doc_page_vm;
 pub mod embedded_story_vm;
+pub mod gallery_vm;
+pub mod globals_vm;
+pub mod layout_audit_vm;
+#[cfg(target_family = "wasm")]
+pub mod live_frame_vm;
+pub mod not_found_vm;
+pub mod onboarding_vm;
+pub mod performance_vm;
+pub mod play_vm;
+pub mod playlist_vm;
+pub mod presentation_vm;
+pub mod profile_vm;
+pub mod report_vm;
 pub mod sidebar_vm;
+pub mod snapshot_vm;
 pub mod store;
 pub mod story_card_vm;
 pub mod story_page_vm;
 pub mod story_preview_vm;
+pub mod story_props_vm;
+pub mod thumbnail_vm;
 pub mod ui_settings;
+pub mod zoom_vm;
 
 pub(crate) use store::ComponentStore;
 pub use ui_settings::UiSettings;
@@ -0,0 +1,76 @@
+use crate::ui::models::Selection;
+use crate::ui::services::fuzzy::{fuzzy_distance, normalize};
+use crate::{StorybookConfig, get_components, get_docs};
+
+/// Most suggestions shown on the not-found page.
+const SUGGESTION_LIMIT: usize = 5;
+
+/// A page the missing target may have meant.
+#[derive(Clone, PartialEq)]
+pub struct Suggestion {
+    /// `"Component / Story"`, the component name or the doc path.
+    pub label: String,
+    /// `"Story"`, `"Component"` or `"Docs"`.
+    pub kind: &'static str,
+    pub selection: Selection,
+}
+
+/// The visible stories, components and doc pages closest to `target`, a
+/// story id, component name or doc path that no longer exists, best match
+/// first.
+pub fn suggest(config: &StorybookConfig, target: &str) -> Vec<Suggestion> {
+    let query = normalize(target.trim_start_matches("__component__/"));
+    let mut candidates: Vec<(usize, Suggestion)> = Vec::new();
+    let mut consider = |keys: &[&str], suggestion: Suggestion| {
+        if let Some(distance) = keys
+            .iter()
+            .filter_map(|key| fuzzy_distance(&query, &normalize(key)))
+            .min()
+        {
+            candidates.push((distance, suggestion));
+        }
+    };
+
+    for component in get_components().filter(|c| !config.visible_tags_of(c).is_empty()) {
+        consider(
+            &[component.name],
+            Suggestion {
+                label: component.name.to_string(),
+                kind: "Component",
+                selection: Selection::Component(component.name.to_string()),
+            },
+        );
+        for (index, story) in (component.get_stories)().into_iter().enumerate() {
+            if story.skipped {
+                continue;
+            }
+            let label = format!("{} / {}", component.name, story.title);
+            consider(
+                &[&story.id, &label, &story.title],
+                Suggestion {
+                    label: label.clone(),
+                    kind: "Story",
+                    selection: Selection::Story(component.name.to_string(), index),
+                },
+            );
+        }
+    }
+    for doc in get_docs() {
+        let title = doc.path.rsplit('/').next().unwrap_or(doc.path);
+        consider(
+            &[doc.path, title],
+            Suggestion {
+                label: doc.path.to_string(),
+                kind: "Docs",
+                selection: Selection::DocPage(doc.path.to_string()),
+            },
+        );
+    }
+
+    candidates.sort_by(|(a, sa), (b, sb)| a.cmp(b).then_with(|| sa.label.cmp(&sb.label)));
+    candidates
+        .into_iter()
+        .map(|(_, suggestion)| suggestion)
+        .take(SUGGESTION_LIMIT)
+        .collect()
+}
@@ -0,0 +1,59 @@
+use crate::ui::models::TagFilter;
+use crate::ui::viewmodels::sidebar_vm::has_matching_stories;
+use crate::{STORYBOOK_ONLY, StorybookConfig, get_components, get_docs};
+
+/// Why the sidebar is empty, shown by the onboarding page.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OnboardingData {
+    /// Number of components registered with `#[storybook]`, hidden ones
+    /// included.
+    pub registered: usize,
+    /// The include, exclude and profile tag patterns of the config, which
+    /// may hide every registered component.
+    pub tag_patterns: Vec<String>,
+    /// The `STORYBOOK_ONLY` filter the storybook was built with, if any.
+    pub focus: Option<&'static str>,
+    /// Path of the "Welcome" documentation page, if one is registered.
+    pub welcome_doc: Option<String>,
+}
+
+impl OnboardingData {
+    /// Returns `true` when components are registered but none of them is
+    /// listed, because of the tag patterns or `STORYBOOK_ONLY`.
+    pub fn all_hidden(&self) -> bool {
+        self.registered > 0
+    }
+}
+
+/// Returns `true` when the sidebar lists no component with `config`, in
+/// which case the onboarding page replaces the empty state.
+pub fn needs_onboarding(config: &StorybookConfig) -> bool {
+    !get_components().any(|reg| {
+        !config.visible_tags_of(reg).is_empty() && has_matching_stories(reg.name, &TagFilter::All)
+    })
+}
+
+/// Gather what the onboarding page needs to explain an empty sidebar.
+pub fn resolve_onboarding(config: &StorybookConfig) -> OnboardingData {
+    let tag_patterns = config
+        .include_tags
+        .iter()
+        .chain(&config.visible_tags)
+        .cloned()
+        .chain(config.exclude_tags.iter().map(|tag| format!("!{tag}")))
+        .collect();
+
+    OnboardingData {
+        registered: get_components().count(),
+        tag_patterns,
+        focus: STORYBOOK_ONLY.filter(|only| !only.trim().is_empty()),
+        welcome_doc: get_docs()
+            .map(|doc| doc.path)
+            .find(|path| {
+                path.rsplit('/')
+                    .next()
+                    .is_some_and(|name| name.trim().eq_ignore_ascii_case("welcome"))
+            })
+            .map(str::to_string),
+    }
+}
@@ -0,0 +1,57 @@
+use crate::ui::models::PerformanceReport;
+use crate::ui::services::live_mount::build_capture_script;
+use crate::ui::services::performance::{now_ms, render_stats, reset_render_stats};
+use dioxus::prelude::*;
+
+/// Prepared state for the performance panel.
+#[derive(Clone, Copy)]
+pub struct PerformanceState {
+    /// The latest measures, or `None` before the first measure completes.
+    pub report: Signal<Option<PerformanceReport>>,
+    pub measuring: Signal<bool>,
+}
+
+/// Custom hook measuring the story rendered in the preview `iframe_id`.
+///
+/// Measures when the panel mounts and again after each edit of
+/// `props_json`, once the story re-rendered; call [`measure_performance`]
+/// to measure again.
+pub fn use_performance(iframe_id: &str, props_json: Signal<String>) -> PerformanceState {
+    let state = PerformanceState {
+        report: use_signal(|| None),
+        measuring: use_signal(|| false),
+    };
+
+    let iframe_id = iframe_id.to_string();
+    use_effect(move || {
+        props_json();
+        measure_performance(iframe_id.clone(), state);
+    });
+
+    state
+}
+
+/// Capture the preview's HTML, timing it, and store it with the render
+/// timings of the preview in `state`.
+pub fn measure_performance(iframe_id: String, mut state: PerformanceState) {
+    state.measuring.set(true);
+    spawn(async move {
+        let started = now_ms();
+        let html = document::eval(&build_capture_script(&iframe_id))
+            .join::<String>()
+            .await;
+        let capture_ms = html.is_ok().then(|| now_ms() - started);
+        state.report.set(Some(PerformanceReport {
+            render: render_stats(&iframe_id),
+            capture_ms,
+            html_bytes: html.map(|html| html.len()).unwrap_or_default(),
+        }));
+        state.measuring.set(false);
+    });
+}
+
+/// Forget the render timings of the preview and measure again.
+pub fn reset_performance(iframe_id: String, state: PerformanceState) {
+    reset_render_stats(&iframe_id);
+    measure_performance(iframe_id, state);
+}
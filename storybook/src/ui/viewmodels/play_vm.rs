@@ -0,0 +1,77 @@
+use crate::ui::models::{PlayStatus, PlayStepOutcome, PlayStepResult, PlayStepStatus};
+use crate::ui::services::play::build_play_step_script;
+use crate::{PlayContext, PlayFn, PlayStep};
+use dioxus::prelude::*;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Prepared state for a story's play function.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PlayState {
+    pub status: Signal<PlayStatus>,
+    pub results: Signal<Vec<PlayStepResult>>,
+}
+
+/// Custom hook that runs the story's play function once after it renders.
+///
+/// Steps are executed against the story mounted live in the preview iframe
+/// `iframe_id`; the first step waits for the story to be mounted.
+pub fn use_play(iframe_id: &str, play: Option<PlayFn>) -> PlayState {
+    let state = PlayState {
+        status: use_signal(|| PlayStatus::Idle),
+        results: use_signal(Vec::new),
+    };
+
+    let iframe_id = iframe_id.to_string();
+    use_effect(move || {
+        if let Some(play) = play.clone() {
+            run_play(iframe_id.clone(), play, state);
+        }
+    });
+
+    state
+}
+
+/// Run `play` against the story, storing each step's outcome in `state` as
+/// it completes.
+pub fn run_play(iframe_id: String, play: PlayFn, mut state: PlayState) {
+    state.status.set(PlayStatus::Running);
+    state.results.set(Vec::new());
+    spawn(async move {
+        let failed = Rc::new(Cell::new(false));
+        let ctx = PlayContext::new({
+            let failed = failed.clone();
+            move |step| run_step(iframe_id.clone(), step, failed.clone(), state)
+        });
+        play.run(ctx).await;
+
+        state.status.set(if failed.get() {
+            PlayStatus::Failed
+        } else {
+            PlayStatus::Passed
+        });
+    });
+}
+
+/// Execute `step` unless an earlier one `failed`, and store its outcome.
+async fn run_step(iframe_id: String, step: PlayStep, failed: Rc<Cell<bool>>, mut state: PlayState) {
+    let status = if failed.get() {
+        PlayStepStatus::Skipped
+    } else {
+        let outcome = document::eval(&build_play_step_script(&iframe_id, &step))
+            .join::<PlayStepOutcome>()
+            .await;
+        match outcome {
+            Ok(PlayStepOutcome { ok: true, .. }) => PlayStepStatus::Passed,
+            Ok(PlayStepOutcome { message, .. }) => {
+                failed.set(true);
+                PlayStepStatus::Failed(message.unwrap_or_else(|| "step failed".into()))
+            }
+            Err(e) => {
+                failed.set(true);
+                PlayStepStatus::Failed(e.to_string())
+            }
+        }
+    };
+    state.results.write().push(PlayStepResult { step, status });
+}
@@ -0,0 +1,183 @@
+use crate::StorybookConfig;
+use crate::ui::models::{Playlist, Selection};
+use crate::ui::services::playlists::{
+    build_load_playlists_script, build_save_playlists_script, build_share_link_script,
+    shared_playlist,
+};
+use dioxus::prelude::*;
+
+/// The playlists and the active one, provided as context.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PlaylistStore {
+    /// Configured playlists first, then the user's.
+    pub playlists: Signal<Vec<Playlist>>,
+    /// Name of the active playlist, driving the presentation and batch
+    /// operations.
+    pub active: Signal<Option<String>>,
+}
+
+impl PlaylistStore {
+    /// The playlist named `name`.
+    pub fn get(&self, name: &str) -> Option<Playlist> {
+        (self.playlists)().into_iter().find(|p| p.name == name)
+    }
+
+    /// The entries of the active playlist, if any.
+    pub fn active_entries(&self) -> Option<Vec<String>> {
+        let active = (self.active)()?;
+        self.get(&active).map(|p| p.entries)
+    }
+
+    /// The names of the playlists users can edit.
+    pub fn editable_names(&self) -> Vec<String> {
+        (self.playlists)()
+            .into_iter()
+            .filter(|p| !p.builtin)
+            .map(|p| p.name)
+            .collect()
+    }
+
+    /// Create an empty playlist named `name`, or a numbered variant if the
+    /// name is taken. Returns the name used.
+    pub fn create(mut self, name: &str) -> String {
+        let name = name.trim();
+        let name = if name.is_empty() { "Playlist" } else { name };
+        let mut unique = name.to_string();
+        let mut n = 2;
+        while self.get(&unique).is_some() {
+            unique = format!("{name} {n}");
+            n += 1;
+        }
+        self.playlists.write().push(Playlist {
+            name: unique.clone(),
+            entries: Vec::new(),
+            builtin: false,
+        });
+        unique
+    }
+
+    /// Delete the user playlist `name`.
+    pub fn delete(mut self, name: &str) {
+        self.playlists
+            .write()
+            .retain(|p| p.builtin || p.name != name);
+        if (self.active)().as_deref() == Some(name) {
+            self.active.set(None);
+        }
+    }
+
+    /// Append `entry` to the user playlist `name`, unless already listed.
+    pub fn add(self, name: &str, entry: &str) {
+        self.edit(name, |entries| {
+            if !entries.iter().any(|e| e == entry) {
+                entries.push(entry.to_string());
+            }
+        });
+    }
+
+    /// Remove the entry at `index` from the user playlist `name`.
+    pub fn remove(self, name: &str, index: usize) {
+        self.edit(name, |entries| {
+            if index < entries.len() {
+                entries.remove(index);
+            }
+        });
+    }
+
+    /// Swap the entry at `index` with the previous one.
+    pub fn move_up(self, name: &str, index: usize) {
+        self.edit(name, |entries| {
+            if index > 0 && index < entries.len() {
+                entries.swap(index - 1, index);
+            }
+        });
+    }
+
+    fn edit(mut self, name: &str, f: impl FnOnce(&mut Vec<String>)) {
+        if let Some(playlist) = self
+            .playlists
+            .write()
+            .iter_mut()
+            .find(|p| !p.builtin && p.name == name)
+        {
+            f(&mut playlist.entries);
+        }
+    }
+
+    /// Copy a link opening the playlist `name` to the clipboard.
+    pub fn share(&self, name: &str) {
+        if let Some(playlist) = self.get(name) {
+            document::eval(&build_share_link_script(&playlist));
+        }
+    }
+}
+
+/// Custom hook providing the [`PlaylistStore`] context.
+///
+/// Starts with the configured playlists, then adds the ones saved in the
+/// browser and saves every later change. A playlist shared through the URL
+/// is added (when no playlist has its name), made active and selected.
+pub fn use_playlist_store(selected: Signal<Option<Selection>>) -> PlaylistStore {
+    let config = use_context::<StorybookConfig>();
+    let store = use_context_provider(|| PlaylistStore {
+        playlists: Signal::new(
+            config
+                .playlists
+                .iter()
+                .map(|(name, entries)| Playlist {
+                    name: name.clone(),
+                    entries: entries.clone(),
+                    builtin: true,
+                })
+                .collect(),
+        ),
+        active: Signal::new(None),
+    });
+    let mut loaded = use_signal(|| false);
+
+    use_hook(move || {
+        let mut store = store;
+        let mut selected = selected;
+        spawn(async move {
+            let saved = document::eval(&build_load_playlists_script())
+                .join::<Vec<Playlist>>()
+                .await
+                .unwrap_or_default();
+            let mut playlists = store.playlists.write();
+            for playlist in saved {
+                if !playlists.iter().any(|p| p.name == playlist.name) {
+                    playlists.push(playlist);
+                }
+            }
+            if let Some(shared) = shared_playlist() {
+                let name = shared.name.clone();
+                if !playlists.iter().any(|p| p.name == name) {
+                    playlists.push(Playlist {
+                        builtin: false,
+                        ..shared
+                    });
+                }
+                store.active.set(Some(name.clone()));
+                selected.set(Some(Selection::Playlist(name)));
+            }
+            drop(playlists);
+            loaded.set(true);
+        });
+    });
+
+    // Save the user playlists once the saved ones are loaded, so they are
+    // not overwritten with an empty list
+    use_effect(move || {
+        let playlists = (store.playlists)();
+        if !loaded() {
+            return;
+        }
+        let user = playlists
+            .into_iter()
+            .filter(|p| !p.builtin)
+            .collect::<Vec<_>>();
+        document::eval(&build_save_playlists_script(&user));
+    });
+
+    store
+}
@@ -0,0 +1,136 @@
+use crate::ui::models::{ComponentInfo, Selection, TagFilter};
+use crate::ui::services::category_builder::build_category_tree;
+use crate::ui::services::story_ids::find_story;
+use crate::ui::viewmodels::sidebar_vm::get_story_titles;
+use crate::{StorybookConfig, find_doc, get_components, watch};
+use dioxus::prelude::*;
+use std::collections::HashSet;
+
+/// Seconds per slide when autoplaying without
+/// [`StorybookConfig::with_presentation_interval`].
+const DEFAULT_SLIDE_SECONDS: u32 = 10;
+
+/// State of a running presentation.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PresentationState {
+    /// Index of the current slide.
+    pub index: Signal<usize>,
+    pub autoplay: Signal<bool>,
+    /// Number of slides.
+    pub len: usize,
+}
+
+impl PresentationState {
+    /// Move to the next slide, wrapping around to the first one.
+    pub fn next(mut self) {
+        if self.len > 0 {
+            let index = (self.index)();
+            self.index.set((index + 1) % self.len);
+        }
+    }
+
+    /// Move to the previous slide, wrapping around to the last one.
+    pub fn previous(mut self) {
+        if self.len > 0 {
+            let index = (self.index)();
+            self.index.set((index + self.len - 1) % self.len);
+        }
+    }
+}
+
+/// Resolve the slides of the presentation: the story ids and doc paths of
+/// the active `playlist`, or else the configured ones, or else every story
+/// listed in the sidebar, in sidebar order.
+///
+/// Entries matching nothing are reported to the watch-mode summary and
+/// skipped.
+pub fn resolve_slides(config: &StorybookConfig, playlist: Option<&[String]>) -> Vec<Selection> {
+    let entries = playlist.unwrap_or(&config.presentation);
+    if playlist.is_none() && entries.is_empty() {
+        let components = get_components()
+            .filter_map(|reg| {
+                let categories = config.visible_tags_of(reg);
+                (!categories.is_empty()).then(|| ComponentInfo {
+                    name: reg.name.to_string(),
+                    categories: categories.iter().map(|tag| tag.to_string()).collect(),
+                    weight: reg.weight,
+                })
+            })
+            .collect::<Vec<_>>();
+        // Walk the sidebar's tree, showing the components listed under
+        // several categories once
+        let tree = build_category_tree(&components, config);
+        let mut seen = HashSet::new();
+        return tree
+            .ordered_components()
+            .into_iter()
+            .filter(|name| seen.insert(*name))
+            .flat_map(|name| {
+                get_story_titles(name, &TagFilter::All)
+                    .into_iter()
+                    .map(|(index, _)| Selection::Story(name.to_string(), index))
+            })
+            .collect();
+    }
+
+    let mut unknown = Vec::new();
+    let slides = entries
+        .iter()
+        .filter_map(|entry| {
+            let slide = find_story(config.migrate_story_id(entry))
+                .map(|(component_name, index)| Selection::Story(component_name, index))
+                .or_else(|| {
+                    find_doc(entry)
+                        .ok()
+                        .map(|_| Selection::DocPage(entry.clone()))
+                });
+            if slide.is_none() {
+                unknown.push(entry.clone());
+            }
+            slide
+        })
+        .collect();
+    if !unknown.is_empty() {
+        watch::unknown_slides(&unknown);
+    }
+    slides
+}
+
+/// Custom hook driving a presentation of `len` slides: the current slide
+/// index, and the autoplay timer advancing it.
+pub fn use_presentation(len: usize) -> PresentationState {
+    let config = use_context::<StorybookConfig>();
+    let index = use_signal(|| 0usize);
+    let autoplay = use_signal(|| false);
+    let state = PresentationState {
+        index,
+        autoplay,
+        len,
+    };
+
+    let interval_ms = u64::from(
+        config
+            .presentation_interval
+            .unwrap_or(DEFAULT_SLIDE_SECONDS),
+    ) * 1000;
+    // Restarted whenever the slide changes or autoplay is toggled, so each
+    // slide gets the full interval.
+    use_effect(move || {
+        let _ = index();
+        if !autoplay() {
+            return;
+        }
+        let slide = *index.peek();
+        spawn(async move {
+            let _ = document::eval(&format!(
+                "await new Promise(function(resolve) {{ setTimeout(resolve, {interval_ms}); }});"
+            ))
+            .await;
+            if autoplay() && *index.peek() == slide {
+                state.next();
+            }
+        });
+    });
+
+    state
+}
@@ -0,0 +1,38 @@
+use crate::ui::models::ProfileInfo;
+use crate::ui::services::profile;
+use crate::{active_profile, get_profiles};
+
+/// Registered profiles and the active one, provided as context.
+#[derive(Clone, PartialEq, Default)]
+pub struct ProfileContext {
+    /// Every registered profile; empty when launched with [`launch`](crate::launch).
+    pub profiles: Vec<ProfileInfo>,
+    /// Slug of the active profile.
+    pub active: Option<String>,
+}
+
+impl ProfileContext {
+    /// Returns `true` when several profiles exist and none is selected yet.
+    pub fn needs_picker(&self) -> bool {
+        !self.profiles.is_empty() && self.active.is_none()
+    }
+}
+
+/// Build the [`ProfileContext`] from the registered profiles and the URL.
+pub fn resolve_profiles() -> ProfileContext {
+    ProfileContext {
+        profiles: get_profiles()
+            .iter()
+            .map(|p| ProfileInfo {
+                name: p.name.clone(),
+                slug: p.slug(),
+            })
+            .collect(),
+        active: active_profile().map(|p| p.slug()),
+    }
+}
+
+/// Switch to the profile `slug`, reloading the storybook.
+pub fn select_profile(slug: &str) {
+    profile::switch_profile(slug);
+}
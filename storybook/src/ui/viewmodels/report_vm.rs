@@ -0,0 +1,125 @@
+use crate::ui::models::{
+    A11yAuditReport, A11yRule, BaselineStatus, PlayStepResult, PlayStepStatus, TestCase,
+    TestOutcome, TestSuite,
+};
+use crate::ui::services::report::{build_json_report, build_junit_xml, report_file_name};
+use crate::ui::services::snapshot::{SnapshotCapture, build_download_script};
+use dioxus::prelude::*;
+
+/// Format of a downloaded report.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    JUnit,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            ReportFormat::JUnit => "JUnit",
+            ReportFormat::Json => "JSON",
+        }
+    }
+}
+
+/// Download `suite` as a `kind` report (e.g. `play`) in `format`.
+pub fn download_report(suite: &TestSuite, kind: &str, format: ReportFormat) {
+    let (content, mime, extension) = match format {
+        ReportFormat::JUnit => (build_junit_xml(suite), "application/xml", "junit.xml"),
+        ReportFormat::Json => (build_json_report(suite), "application/json", "json"),
+    };
+    document::eval(&build_download_script(
+        &report_file_name(suite, kind, extension),
+        mime,
+        &content,
+    ));
+}
+
+/// One test case per play step of the story `story_id`.
+pub fn play_suite(name: String, story_id: &str, results: &[PlayStepResult]) -> TestSuite {
+    TestSuite {
+        name,
+        cases: results
+            .iter()
+            .map(|result| TestCase {
+                classname: story_id.to_string(),
+                name: result.step.label(),
+                time_ms: None,
+                outcome: match &result.status {
+                    PlayStepStatus::Passed => TestOutcome::Passed,
+                    PlayStepStatus::Failed(message) => TestOutcome::Failed(message.clone()),
+                    PlayStepStatus::Skipped => {
+                        TestOutcome::Skipped("an earlier step failed".to_string())
+                    }
+                },
+            })
+            .collect(),
+    }
+}
+
+/// One test case per accessibility rule checked on the story `story_id`,
+/// failing with its violations.
+pub fn a11y_suite(name: String, story_id: &str, report: &A11yAuditReport) -> TestSuite {
+    TestSuite {
+        name,
+        cases: A11yRule::ALL
+            .into_iter()
+            .map(|rule| {
+                let violations: Vec<String> = report
+                    .violations
+                    .iter()
+                    .filter(|violation| violation.rule == rule)
+                    .map(|violation| format!("{} ({})", violation.selector, violation.detail))
+                    .collect();
+                TestCase {
+                    classname: story_id.to_string(),
+                    name: rule.label().to_string(),
+                    time_ms: None,
+                    outcome: if violations.is_empty() {
+                        TestOutcome::Passed
+                    } else {
+                        TestOutcome::Failed(format!(
+                            "{} {}: {}",
+                            rule.severity().label(),
+                            rule.label().to_lowercase(),
+                            violations.join(", ")
+                        ))
+                    },
+                }
+            })
+            .collect(),
+    }
+}
+
+/// One test case per snapshot of a batch titled `title`, failing when the
+/// story rendered nothing or differs from its approved baseline, skipped
+/// when it has no baseline yet.
+pub fn snapshot_suite(
+    title: &str,
+    capture: &SnapshotCapture,
+    statuses: &[BaselineStatus],
+) -> TestSuite {
+    TestSuite {
+        name: format!("{title} snapshots"),
+        cases: capture
+            .snapshots
+            .iter()
+            .zip(statuses)
+            .map(|(snapshot, status)| TestCase {
+                classname: snapshot.id.clone(),
+                name: format!("{} / {}", snapshot.component, snapshot.story),
+                time_ms: Some(snapshot.duration_ms),
+                outcome: match status {
+                    _ if !snapshot.rendered => {
+                        TestOutcome::Failed("the story rendered nothing".to_string())
+                    }
+                    BaselineStatus::Changed => {
+                        TestOutcome::Failed("differs from the approved baseline".to_string())
+                    }
+                    BaselineStatus::New => TestOutcome::Skipped("no approved baseline".to_string()),
+                    BaselineStatus::Unchanged => TestOutcome::Passed,
+                },
+            })
+            .collect(),
+    }
+}
@@ -1,21 +1,331 @@
-use crate::find_component;
+use crate::ui::models::{Bookmarks, Selection, StoryGroup, TagFilter};
+use crate::ui::services::bookmarks::{build_load_bookmarks_script, build_save_bookmarks_script};
+use crate::ui::services::story_ids::find_story;
+use crate::{ComponentStatus, StorybookConfig, find_component, get_components, is_focused};
+use dioxus::prelude::*;
+use std::collections::{BTreeSet, HashSet};
 
 /// Look up the story titles for a given component name.
 ///
-/// Returns the list of story titles (e.g. `["Default", "Loading"]`) by
-/// calling `find_component()` and extracting the title from each story.
-/// Returns an empty `Vec` if the component is not found.
-pub fn get_story_titles(component_name: &str) -> Vec<String> {
+/// Returns `(story_index, title)` pairs (e.g. `[(0, "Default"), (1, "Loading")]`)
+/// for the stories passing `tag_filter` that are neither skipped nor
+/// filtered out by `STORYBOOK_ONLY`, by calling `find_component()` and
+/// extracting the title from each story. The index is the story's position
+/// among all of the component's stories, so it stays valid for
+/// [`Selection::Story`](crate::ui::models::Selection::Story) when stories are
+/// filtered out. Returns an empty `Vec` if the component is not found.
+pub fn get_story_titles(component_name: &str, tag_filter: &TagFilter) -> Vec<(usize, String)> {
     find_component(component_name)
-        .map(|reg| (reg.get_stories)().into_iter().map(|s| s.title).collect())
+        .map(|reg| {
+            (reg.get_stories)()
+                .into_iter()
+                .enumerate()
+                .filter(|(_, s)| {
+                    !s.skipped
+                        && is_focused(component_name, &s.title)
+                        && tag_filter.matches(&s.tags)
+                })
+                .map(|(index, s)| (index, s.title))
+                .collect()
+        })
         .unwrap_or_default()
 }
 
-/// Check whether a component has non-empty doc comments (description).
+/// Look up the stories listed for a component, like [`get_story_titles`],
+/// grouped by their [`in_group`](crate::Story::in_group) section.
+pub fn get_story_groups(component_name: &str, tag_filter: &TagFilter) -> Vec<StoryGroup> {
+    let stories = find_component(component_name)
+        .map(|reg| (reg.get_stories)())
+        .unwrap_or_default();
+    group_stories(
+        get_story_titles(component_name, tag_filter),
+        |(index, _)| stories.get(*index).and_then(|story| story.group.clone()),
+    )
+}
+
+/// Group `items` by the section `group_of` returns: the ungrouped items
+/// first, then each group in the order it first appears.
+pub fn group_stories<T>(
+    items: Vec<T>,
+    group_of: impl Fn(&T) -> Option<String>,
+) -> Vec<StoryGroup<T>> {
+    let mut groups: Vec<StoryGroup<T>> = vec![StoryGroup {
+        name: None,
+        stories: Vec::new(),
+    }];
+    for item in items {
+        let name = group_of(&item);
+        match groups.iter_mut().find(|group| group.name == name) {
+            Some(group) => group.stories.push(item),
+            None => groups.push(StoryGroup {
+                name,
+                stories: vec![item],
+            }),
+        }
+    }
+    groups.retain(|group| !group.stories.is_empty());
+    groups
+}
+
+/// Returns `true` if at least one story of the component is listed with `tag_filter`.
+pub fn has_matching_stories(component_name: &str, tag_filter: &TagFilter) -> bool {
+    !get_story_titles(component_name, tag_filter).is_empty()
+}
+
+/// Collect every tag used by a story visible with `config`, sorted alphabetically.
+pub fn get_all_tags(config: &StorybookConfig) -> Vec<String> {
+    get_components()
+        .filter(|reg| !config.visible_tags_of(reg).is_empty())
+        .flat_map(|reg| (reg.get_stories)())
+        .flat_map(|story| story.tags)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Expansion state of the sidebar's tree nodes, keyed by their full path and
+/// provided as context, so it survives the tree being rebuilt (e.g. while
+/// searching). Nodes are expanded unless collapsed.
 ///
-/// Returns `true` if the component is found and has a non-empty description.
-pub fn has_component_docs(component_name: &str) -> bool {
-    find_component(component_name)
-        .map(|reg| !reg.description.is_empty())
-        .unwrap_or(false)
+/// While a search is active every branch left by the search starts
+/// expanded, and collapsing one only lasts until the query changes; the
+/// state from before the search comes back once it is cleared.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SidebarExpansion {
+    collapsed: Signal<HashSet<String>>,
+    /// Nodes collapsed since the search query last changed.
+    search_collapsed: Signal<HashSet<String>>,
+    searching: Signal<bool>,
+}
+
+impl Default for SidebarExpansion {
+    fn default() -> Self {
+        Self {
+            collapsed: Signal::new(HashSet::new()),
+            search_collapsed: Signal::new(HashSet::new()),
+            searching: Signal::new(false),
+        }
+    }
+}
+
+impl SidebarExpansion {
+    /// The collapsed nodes of the current mode (searching or not).
+    fn collapsed(&self) -> Signal<HashSet<String>> {
+        if (self.searching)() {
+            self.search_collapsed
+        } else {
+            self.collapsed
+        }
+    }
+
+    pub fn is_expanded(&self, path: &str) -> bool {
+        !self.collapsed().read().contains(path)
+    }
+
+    pub fn set_expanded(self, path: &str, expanded: bool) {
+        let mut collapsed = self.collapsed();
+        if expanded {
+            collapsed.write().remove(path);
+        } else {
+            collapsed.write().insert(path.to_string());
+        }
+    }
+
+    pub fn expand_all(self) {
+        self.collapsed().write().clear();
+    }
+
+    /// Collapse the nodes at `paths`.
+    pub fn collapse_all(self, paths: impl IntoIterator<Item = String>) {
+        self.collapsed().write().extend(paths);
+    }
+
+    /// Called whenever the search `query` changes: expands every branch
+    /// again while searching.
+    pub fn search(mut self, query: &str) {
+        self.searching.set(!query.is_empty());
+        self.search_collapsed.write().clear();
+    }
+}
+
+/// The status badge of the component `component_name`, if it sets one.
+pub fn component_status(component_name: &str) -> Option<ComponentStatus> {
+    find_component(component_name).ok()?.status
+}
+
+/// Whether `text` contains `query`, ignoring case.
+pub fn matches_query(text: &str, query: &str) -> bool {
+    !query.is_empty() && text.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// Whether one of the stories of `groups` matches the search `query`.
+pub fn has_story_match(groups: &[StoryGroup], query: &str) -> bool {
+    groups
+        .iter()
+        .flat_map(|group| &group.stories)
+        .any(|(_, title)| matches_query(title, query))
+}
+
+/// Split `text` around the case-insensitive matches of the search `query`,
+/// as `(part, is_match)` pairs.
+pub fn highlight_parts(text: &str, query: &str) -> Vec<(String, bool)> {
+    let query: Vec<char> = query.chars().collect();
+    let chars: Vec<char> = text.chars().collect();
+    let same = |a: &char, b: &char| a.to_lowercase().eq(b.to_lowercase());
+    let mut parts: Vec<(String, bool)> = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        let matched = !query.is_empty()
+            && chars.len() - index >= query.len()
+            && chars[index..index + query.len()]
+                .iter()
+                .zip(&query)
+                .all(|(a, b)| same(a, b));
+        let len = if matched { query.len() } else { 1 };
+        let part: String = chars[index..index + len].iter().collect();
+        match parts.last_mut() {
+            Some((last, false)) if !matched => last.push_str(&part),
+            _ => parts.push((part, matched)),
+        }
+        index += len;
+    }
+    parts
+}
+
+/// Custom hook providing the [`SidebarExpansion`] context.
+pub fn use_sidebar_expansion() -> SidebarExpansion {
+    use_context_provider(SidebarExpansion::default)
+}
+
+/// Custom hook reading the [`SidebarExpansion`] context, or a state of its
+/// own when rendered outside the sidebar (e.g. as a story preview).
+pub fn use_tree_expansion() -> SidebarExpansion {
+    let own = use_hook(SidebarExpansion::default);
+    try_use_context::<SidebarExpansion>().unwrap_or(own)
+}
+
+/// How many recently viewed stories the sidebar lists.
+pub const RECENT_LIMIT: usize = 8;
+
+/// The favorite components and stories and the recently viewed stories,
+/// provided as context.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SidebarBookmarks {
+    pub bookmarks: Signal<Bookmarks>,
+}
+
+impl SidebarBookmarks {
+    pub fn is_favorite_component(&self, name: &str) -> bool {
+        self.bookmarks.read().components.iter().any(|c| c == name)
+    }
+
+    pub fn is_favorite_story(&self, id: &str) -> bool {
+        self.bookmarks.read().stories.iter().any(|s| s == id)
+    }
+
+    /// Add the component `name` to the favorites, or remove it.
+    pub fn toggle_component(mut self, name: &str) {
+        toggle_entry(&mut self.bookmarks.write().components, name);
+    }
+
+    /// Add the story with id `id` to the favorites, or remove it.
+    pub fn toggle_story(mut self, id: &str) {
+        toggle_entry(&mut self.bookmarks.write().stories, id);
+    }
+
+    /// Move the story with id `id` to the top of the recent stories.
+    pub fn visit(mut self, id: &str) {
+        if self.bookmarks.peek().recent.first().map(String::as_str) == Some(id) {
+            return;
+        }
+        let mut bookmarks = self.bookmarks.write();
+        bookmarks.recent.retain(|r| r != id);
+        bookmarks.recent.insert(0, id.to_string());
+        bookmarks.recent.truncate(RECENT_LIMIT);
+    }
+}
+
+fn toggle_entry(entries: &mut Vec<String>, entry: &str) {
+    if let Some(index) = entries.iter().position(|e| e == entry) {
+        entries.remove(index);
+    } else {
+        entries.push(entry.to_string());
+    }
+}
+
+/// The id of the story at `story_index` of the component `component_name`.
+pub fn story_id_at(component_name: &str, story_index: usize) -> Option<String> {
+    let component = find_component(component_name).ok()?;
+    (component.get_stories)()
+        .into_iter()
+        .nth(story_index)
+        .map(|story| story.id)
+}
+
+/// The component name, story index and `"Component / Story"` label of the
+/// bookmarked story `id`, unless it no longer exists.
+pub fn resolve_bookmarked_story(id: &str) -> Option<(String, usize, String)> {
+    let (component_name, story_index) = find_story(id)?;
+    let component = find_component(&component_name).ok()?;
+    let story = (component.get_stories)().into_iter().nth(story_index)?;
+    let label = format!("{component_name} / {}", story.title);
+    Some((component_name, story_index, label))
+}
+
+/// Custom hook providing the [`SidebarBookmarks`] context.
+///
+/// Loads the bookmarks saved in the browser, records every selected story
+/// as recently viewed and saves every later change.
+pub fn use_sidebar_bookmarks(selected: Signal<Option<Selection>>) -> SidebarBookmarks {
+    let bookmarks = use_context_provider(|| SidebarBookmarks {
+        bookmarks: Signal::new(Bookmarks::default()),
+    });
+    let mut loaded = use_signal(|| false);
+
+    use_hook(move || {
+        let mut bookmarks = bookmarks;
+        spawn(async move {
+            let saved = document::eval(&build_load_bookmarks_script())
+                .join::<Bookmarks>()
+                .await
+                .unwrap_or_default();
+            let mut current = bookmarks.bookmarks.write();
+            // Keep the stories viewed while loading on top
+            let mut recent = std::mem::take(&mut current.recent);
+            let older = saved
+                .recent
+                .into_iter()
+                .filter(|id| !recent.contains(id))
+                .collect::<Vec<_>>();
+            recent.extend(older);
+            recent.truncate(RECENT_LIMIT);
+            *current = Bookmarks { recent, ..saved };
+            drop(current);
+            loaded.set(true);
+        });
+    });
+
+    use_effect(move || {
+        let id = match selected() {
+            Some(Selection::Story(component_name, story_index)) => {
+                story_id_at(&component_name, story_index)
+            }
+            _ => None,
+        };
+        if let Some(id) = id {
+            bookmarks.visit(&id);
+        }
+    });
+
+    // Save once the saved bookmarks are loaded, so they are not overwritten
+    // with empty ones
+    use_effect(move || {
+        let current = (bookmarks.bookmarks)();
+        if !loaded() {
+            return;
+        }
+        document::eval(&build_save_bookmarks_script(&current));
+    });
+
+    bookmarks
 }
@@ -0,0 +1,387 @@
+use crate::ui::models::{
+    BaselineStatus, CapturedSnapshot, DiffSegment, PendingSnapshot, TestSuite,
+};
+use crate::ui::services::html_diff::{diff_html, html_equivalent};
+use crate::ui::services::iframe::{
+    SrcdocParts, build_css_links, build_head_html, build_layout_css, build_preview_document,
+    build_reset_link, category_config_for, make_iframe_id, preview_background, preview_direction,
+    preview_theme, story_decorators,
+};
+use crate::ui::services::live_mount::LIVE_ROOT_HTML;
+use crate::ui::services::snapshot::{
+    SnapshotCapture, SnapshotTarget, build_capture_snapshots_script, build_download_script,
+    build_load_baselines_script, build_save_baselines_script, build_snapshot_bundle,
+    bundle_file_name, format_duration,
+};
+use crate::ui::services::story_ids::find_story;
+use crate::ui::viewmodels::UiSettings;
+use crate::ui::viewmodels::gallery_vm::{resolve_category_gallery, resolve_gallery};
+use crate::ui::viewmodels::report_vm::snapshot_suite;
+use crate::{
+    ContextFn, Decorator, PreviewIsolation, RenderFn, StoryInfo, StorybookConfig, find_component,
+    is_focused,
+};
+use dioxus::prelude::*;
+use std::collections::BTreeMap;
+
+/// A story included in a batch snapshot.
+#[derive(Clone, PartialEq)]
+pub struct SnapshotStory {
+    pub component_name: String,
+    pub story_index: usize,
+    pub story: StoryInfo,
+    pub render_fn: RenderFn,
+}
+
+impl SnapshotStory {
+    /// DOM id of the off-screen preview the story is captured from.
+    pub fn preview_id(&self) -> String {
+        make_iframe_id("snapshot", &self.component_name, self.story_index)
+    }
+}
+
+/// Every story of the gallery of `component_name` that passes the
+/// `STORYBOOK_ONLY` filter.
+pub fn component_snapshot_stories(component_name: &str) -> Vec<SnapshotStory> {
+    resolve_gallery(component_name)
+        .map(|data| {
+            data.stories
+                .into_iter()
+                .filter(|(_, story)| is_focused(&data.component_name, &story.title))
+                .map(|(story_index, story)| SnapshotStory {
+                    component_name: data.component_name.clone(),
+                    story_index,
+                    story,
+                    render_fn: data.render_fn,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Every story of the components under the category `path`, in sidebar
+/// order.
+pub fn category_snapshot_stories(config: &StorybookConfig, path: &str) -> Vec<SnapshotStory> {
+    resolve_category_gallery(config, path)
+        .into_iter()
+        .flat_map(|entry| component_snapshot_stories(&entry.component_name))
+        .collect()
+}
+
+/// The stories among the playlist `entries`, in playlist order. Doc pages,
+/// unknown ids and stories outside the `STORYBOOK_ONLY` filter are left out.
+pub fn playlist_snapshot_stories(
+    config: &StorybookConfig,
+    entries: &[String],
+) -> Vec<SnapshotStory> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let (component_name, story_index) = find_story(config.migrate_story_id(entry))?;
+            let registration = find_component(&component_name).ok()?;
+            let story = (registration.get_stories)().get(story_index)?.clone();
+            if !is_focused(&component_name, &story.title) {
+                return None;
+            }
+            Some(SnapshotStory {
+                component_name,
+                story_index,
+                story,
+                render_fn: registration.render_with_props,
+            })
+        })
+        .collect()
+}
+
+/// Prepared state for an off-screen snapshot preview.
+pub struct SnapshotFrameState {
+    pub preview_id: String,
+    /// Category and story decorators, outermost first.
+    pub decorators: Vec<Decorator>,
+    /// Contexts provided to the story.
+    pub contexts: Vec<ContextFn>,
+    /// The preview document, without the grid, outline, measure or zoom
+    /// overlays of the interactive previews.
+    pub srcdoc: String,
+    /// How the preview is isolated, see [`StoryInfo::preview_isolation`].
+    pub isolation: PreviewIsolation,
+    pub props_json: Signal<String>,
+}
+
+/// Custom hook preparing the off-screen preview `story` is captured from.
+pub fn use_snapshot_frame(story: &SnapshotStory) -> SnapshotFrameState {
+    let props_json = use_signal(|| story.story.props_json.clone());
+    let config = use_context::<StorybookConfig>();
+    let ui_settings = use_context::<UiSettings>();
+    let dark_bg = (ui_settings.dark_preview_background)();
+
+    let category = category_config_for(&config, &story.component_name);
+    let parts = SrcdocParts {
+        css_links: &build_css_links(&config, &category, dark_bg),
+        reset_link: &build_reset_link(&config, config.css_reset.is_some()),
+        head_html: &build_head_html(&config),
+        outline_css: "",
+        layout_css: build_layout_css(story.story.layout),
+        grid_css: "",
+        zoom_css: "",
+        body_html: LIVE_ROOT_HTML,
+        scripts: "",
+        background_color: preview_background(
+            dark_bg,
+            story
+                .story
+                .background
+                .as_deref()
+                .or(category.background.as_deref()),
+        ),
+        theme: preview_theme(dark_bg),
+        direction: preview_direction((ui_settings.rtl_enabled)()),
+    };
+    let isolation = config.isolation_for(&story.story);
+    let srcdoc = build_preview_document(&config, isolation, &parts);
+
+    SnapshotFrameState {
+        preview_id: story.preview_id(),
+        decorators: story_decorators(&category, &story.story.decorators),
+        contexts: story.story.contexts.clone(),
+        srcdoc,
+        isolation,
+        props_json,
+    }
+}
+
+/// The approved baselines and the snapshots awaiting review, provided as
+/// context.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SnapshotReview {
+    /// Approved HTML of each story, by story id.
+    pub baselines: Signal<BTreeMap<String, String>>,
+    /// Snapshots without a baseline or differing from it.
+    pub pending: Signal<Vec<PendingSnapshot>>,
+}
+
+impl SnapshotReview {
+    /// Compare `snapshots` with the baselines and queue the new and changed
+    /// ones for review, replacing the pending snapshots of the same stories.
+    /// Returns the status of each snapshot.
+    pub fn compare(mut self, snapshots: &[CapturedSnapshot]) -> Vec<BaselineStatus> {
+        let baselines = self.baselines.read();
+        let mut pending = self.pending.write();
+        snapshots
+            .iter()
+            .map(|snapshot| {
+                pending.retain(|p| p.snapshot.id != snapshot.id);
+                let baseline = baselines.get(&snapshot.id);
+                if baseline.is_some_and(|baseline| html_equivalent(baseline, &snapshot.html)) {
+                    return BaselineStatus::Unchanged;
+                }
+                let queued = PendingSnapshot {
+                    snapshot: snapshot.clone(),
+                    baseline: baseline.cloned(),
+                };
+                let status = queued.status();
+                pending.push(queued);
+                status
+            })
+            .collect()
+    }
+
+    /// Make the pending snapshot of the story `id` its new baseline.
+    pub fn approve(mut self, id: &str) {
+        let Some(index) = self.pending.peek().iter().position(|p| p.snapshot.id == id) else {
+            return;
+        };
+        let approved = self.pending.write().remove(index);
+        self.baselines
+            .write()
+            .insert(approved.snapshot.id, approved.snapshot.html);
+    }
+
+    /// Drop the pending snapshot of the story `id`, keeping its baseline.
+    pub fn reject(mut self, id: &str) {
+        self.pending.write().retain(|p| p.snapshot.id != id);
+    }
+
+    /// Approve every pending snapshot.
+    pub fn approve_all(mut self) {
+        let approved = std::mem::take(&mut *self.pending.write());
+        let mut baselines = self.baselines.write();
+        for pending in approved {
+            baselines.insert(pending.snapshot.id, pending.snapshot.html);
+        }
+    }
+
+    /// Download the baselines as JSON, e.g. to keep them in the repository.
+    pub fn export(&self) {
+        let json = serde_json::to_string_pretty(&*self.baselines.peek()).unwrap_or_default();
+        document::eval(&build_download_script(
+            "storybook-baselines.json",
+            "application/json",
+            &json,
+        ));
+    }
+}
+
+/// The HTML diff of a pending snapshot against its baseline (everything
+/// added for a new story).
+pub fn pending_diff(pending: &PendingSnapshot) -> Vec<DiffSegment> {
+    diff_html(
+        pending.baseline.as_deref().unwrap_or_default(),
+        &pending.snapshot.html,
+    )
+}
+
+/// Custom hook providing the [`SnapshotReview`] context.
+///
+/// Loads the baselines saved in the browser and saves every later change.
+pub fn use_snapshot_review() -> SnapshotReview {
+    let review = use_context_provider(|| SnapshotReview {
+        baselines: Signal::new(BTreeMap::new()),
+        pending: Signal::new(Vec::new()),
+    });
+    let mut loaded = use_signal(|| false);
+
+    use_hook(move || {
+        let mut baselines = review.baselines;
+        spawn(async move {
+            let saved = document::eval(&build_load_baselines_script())
+                .join::<BTreeMap<String, String>>()
+                .await
+                .unwrap_or_default();
+            baselines.write().extend(saved);
+            loaded.set(true);
+        });
+    });
+
+    // Save once the saved baselines are loaded, so they are not overwritten
+    // with an empty map
+    use_effect(move || {
+        let baselines = (review.baselines)();
+        if loaded() {
+            document::eval(&build_save_baselines_script(&baselines));
+        }
+    });
+
+    review
+}
+
+/// Stories rendered at once without
+/// [`StorybookConfig::with_snapshot_concurrency`].
+const DEFAULT_SNAPSHOT_CONCURRENCY: usize = 8;
+
+/// State of a batch snapshot.
+#[derive(Clone, Copy, PartialEq)]
+pub struct SnapshotRun {
+    /// `true` while the stories are mounted off-screen and captured.
+    pub running: Signal<bool>,
+    /// The stories of the batch being captured, mounted off-screen by the
+    /// view.
+    pub batch: Signal<Vec<SnapshotStory>>,
+    /// `(captured, total)` stories of the running snapshot.
+    pub progress: Signal<(usize, usize)>,
+    /// Result of the last run, shown next to the button.
+    pub message: Signal<Option<String>>,
+    /// Test report of the last run, one case per snapshot.
+    pub report: Signal<Option<TestSuite>>,
+    /// The review the new and changed snapshots are queued for.
+    pub review: SnapshotReview,
+    /// Stories mounted and captured at once.
+    concurrency: usize,
+}
+
+impl SnapshotRun {
+    /// Capture `stories` batch by batch, each batch being mounted
+    /// off-screen by the view, then compare them with their baselines and
+    /// download them as a bundle titled `title`.
+    pub fn start(mut self, title: String, stories: &[SnapshotStory]) {
+        let queue = stories.to_vec();
+        self.running.set(true);
+        self.progress.set((0, queue.len()));
+        self.message.set(None);
+        self.report.set(None);
+        spawn(async move {
+            let mut capture: Option<SnapshotCapture> = None;
+            for chunk in queue.chunks(self.concurrency) {
+                let targets = chunk
+                    .iter()
+                    .map(|story| SnapshotTarget {
+                        preview_id: story.preview_id(),
+                        story_id: story.story.id.clone(),
+                        component: story.component_name.clone(),
+                        story: story.story.title.clone(),
+                        masks: story.story.masks.clone(),
+                    })
+                    .collect::<Vec<_>>();
+                self.batch.set(chunk.to_vec());
+                let batch = document::eval(&build_capture_snapshots_script(&targets))
+                    .join::<SnapshotCapture>()
+                    .await;
+                let batch = match batch {
+                    Ok(batch) => batch,
+                    Err(e) => {
+                        self.batch.set(Vec::new());
+                        self.running.set(false);
+                        self.message.set(Some(format!("Snapshot failed: {e}")));
+                        return;
+                    }
+                };
+                match &mut capture {
+                    Some(capture) => {
+                        capture.snapshots.extend(batch.snapshots);
+                        capture.duration_ms += batch.duration_ms;
+                    }
+                    None => capture = Some(batch),
+                }
+                self.progress
+                    .with_mut(|(captured, _)| *captured += chunk.len());
+            }
+            self.batch.set(Vec::new());
+            self.running.set(false);
+            let Some(capture) = capture else {
+                return;
+            };
+            let statuses = self.review.compare(&capture.snapshots);
+            self.report
+                .set(Some(snapshot_suite(&title, &capture, &statuses)));
+            document::eval(&build_download_script(
+                &bundle_file_name(&title),
+                "text/html",
+                &build_snapshot_bundle(&title, &capture, &statuses),
+            ));
+
+            let count = |status| statuses.iter().filter(|s| **s == status).count();
+            let empty = capture.snapshots.iter().filter(|s| !s.rendered).count();
+            let mut message = format!(
+                "Downloaded {} snapshots in {}",
+                capture.snapshots.len(),
+                format_duration(capture.duration_ms)
+            );
+            if empty > 0 {
+                message.push_str(&format!(", {empty} rendered nothing"));
+            }
+            match (count(BaselineStatus::Changed), count(BaselineStatus::New)) {
+                (0, 0) => message.push_str(", all matching their baselines"),
+                (changed, new) => {
+                    message.push_str(&format!(", {changed} changed and {new} new to review"))
+                }
+            }
+            self.message.set(Some(message));
+        });
+    }
+}
+
+/// Custom hook holding the state of a batch snapshot.
+pub fn use_snapshot_run() -> SnapshotRun {
+    let config = use_context::<StorybookConfig>();
+    SnapshotRun {
+        running: use_signal(|| false),
+        batch: use_signal(Vec::new),
+        progress: use_signal(|| (0, 0)),
+        message: use_signal(|| None),
+        report: use_signal(|| None),
+        review: use_context::<SnapshotReview>(),
+        concurrency: config
+            .snapshot_concurrency
+            .unwrap_or(DEFAULT_SNAPSHOT_CONCURRENCY),
+    }
+}
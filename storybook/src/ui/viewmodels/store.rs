@@ -8,13 +8,22 @@ pub(crate) struct ComponentStore {
 }
 
 impl ComponentStore {
-    /// Filter components by search query (matches name or category, case-insensitive).
+    /// Filter components by search query (matches name, any category or any
+    /// story title, case-insensitive).
     pub(crate) fn search(&self, query: &str) -> Vec<ComponentInfo> {
         let query = query.to_lowercase();
         self.components
             .values()
             .filter(|c| {
-                c.name.to_lowercase().contains(&query) || c.category.to_lowercase().contains(&query)
+                c.name.to_lowercase().contains(&query)
+                    || c.categories
+                        .iter()
+                        .any(|category| category.to_lowercase().contains(&query))
+                    || crate::find_component(&c.name).is_ok_and(|reg| {
+                        (reg.get_stories)()
+                            .iter()
+                            .any(|story| story.title.to_lowercase().contains(&query))
+                    })
             })
             .cloned()
             .collect()
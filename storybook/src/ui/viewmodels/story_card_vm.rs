@@ -1,43 +1,55 @@
 use crate::ui::services::iframe::{
-    build_css_links, build_grid_css, build_outline_css, build_srcdoc, build_zoom_css,
-    capture_inner_html, make_container_id,
+    SrcdocParts, build_css_links, build_grid_css, build_head_html, build_layout_css,
+    build_measure_script, build_outline_css, build_preview_document, build_reset_link,
+    build_zoom_css, category_config_for, make_iframe_id, preview_background, preview_direction,
+    preview_theme, story_decorators,
 };
+use crate::ui::services::live_mount::LIVE_ROOT_HTML;
+use crate::ui::viewmodels::story_props_vm::use_story_props;
 use crate::ui::viewmodels::ui_settings::UiSettings;
-use crate::{StoryInfo, StorybookConfig};
+use crate::{ContextFn, Decorator, PreviewIsolation, StoryInfo, StorybookConfig};
 use dioxus::prelude::*;
 
 /// Prepared state for a StoryCard view.
 pub struct StoryCardState {
-    pub container_id: String,
+    /// DOM id of the preview iframe the story is mounted into.
+    pub iframe_id: String,
+    /// Category and story decorators, outermost first.
+    pub decorators: Vec<Decorator>,
+    /// Contexts provided to the story.
+    pub contexts: Vec<ContextFn>,
+    /// The preview document: the iframe srcdoc, or the shadow root content
+    /// with [`PreviewIsolation::ShadowDom`](crate::PreviewIsolation::ShadowDom),
+    /// empty when rendering inline.
     pub srcdoc: String,
+    /// How the preview is isolated, see [`StoryInfo::preview_isolation`].
+    pub isolation: PreviewIsolation,
     pub zoom_level: Signal<i32>,
     pub props_json: Signal<String>,
+    /// The props as defined by the story, to show defaults and modified fields.
+    pub original_props_json: String,
+    /// Whether the story's props loader is still running.
+    pub loading: bool,
+    /// Why the story's props loader failed, its own props being shown instead.
+    pub load_error: Option<String>,
     pub props_expanded: Signal<bool>,
 }
 
 /// Custom hook that encapsulates all StoryCard business logic.
 ///
-/// Handles HTML capture, srcdoc building, context reading, and state management.
+/// Handles srcdoc building, context reading, and state management.
 /// Returns a `StoryCardState` with all data the view needs to render.
 pub fn use_story_card(
     component_name: &str,
     story_index: usize,
     story: &StoryInfo,
 ) -> StoryCardState {
-    let mut iframe_html = use_signal(String::new);
-    let props_json = use_signal(|| story.props_json.clone());
+    let story_props = use_story_props(story);
     let props_expanded = use_signal(|| false);
-    let zoom_level = use_signal(|| 100i32);
+    let zoom = use_context::<UiSettings>().zoom;
+    let zoom_level = use_signal(|| zoom.clamp(100));
 
-    let container_id = make_container_id("preview-render", component_name, story_index);
-    let container_id_for_effect = container_id.clone();
-
-    use_effect(move || {
-        let _props_json_value = props_json();
-        if let Some(html) = capture_inner_html(&container_id_for_effect) {
-            iframe_html.set(html);
-        }
-    });
+    let iframe_id = make_iframe_id("preview-render", component_name, story_index);
 
     let config = use_context::<StorybookConfig>();
     let ui_settings = use_context::<UiSettings>();
@@ -47,25 +59,48 @@ pub fn use_story_card(
 
     let current_zoom = (zoom_level)();
 
-    let css_links = build_css_links(&config);
+    let category = category_config_for(&config, component_name);
+    let css_links = build_css_links(&config, &category, dark_bg);
+    let reset_link = build_reset_link(&config, (ui_settings.css_reset)());
+    let head_html = build_head_html(&config);
     let outline_css = build_outline_css(outline_enabled);
+    let measure_script = build_measure_script((ui_settings.measure_enabled)());
     let grid_css = build_grid_css(grid_enabled);
     let zoom_css = build_zoom_css(current_zoom);
-    let background_color = if dark_bg { "#1e1e1e" } else { "#ffffff" };
-    let srcdoc = build_srcdoc(
-        &css_links,
+    let parts = SrcdocParts {
+        css_links: &css_links,
+        reset_link: &reset_link,
+        head_html: &head_html,
         outline_css,
+        layout_css: build_layout_css(story.layout),
         grid_css,
-        &zoom_css,
-        &iframe_html(),
-        background_color,
-    );
+        zoom_css: &zoom_css,
+        body_html: LIVE_ROOT_HTML,
+        scripts: measure_script,
+        background_color: preview_background(
+            dark_bg,
+            story
+                .background
+                .as_deref()
+                .or(category.background.as_deref()),
+        ),
+        theme: preview_theme(dark_bg),
+        direction: preview_direction((ui_settings.rtl_enabled)()),
+    };
+    let isolation = config.isolation_for(story);
+    let srcdoc = build_preview_document(&config, isolation, &parts);
 
     StoryCardState {
-        container_id,
+        iframe_id,
+        decorators: story_decorators(&category, &story.decorators),
+        contexts: story.contexts.clone(),
         srcdoc,
+        isolation,
         zoom_level,
-        props_json,
+        props_json: story_props.props_json,
+        original_props_json: (story_props.original_props_json)(),
+        loading: (story_props.loading)(),
+        load_error: (story_props.load_error)(),
         props_expanded,
     }
 }
@@ -1,4 +1,4 @@
-use crate::{RenderFn, StoryInfo, find_component};
+use crate::{ComponentStatus, Error, RenderFn, StoryInfo, find_component};
 use schemars::Schema;
 
 /// Resolved data for a StoryPage view.
@@ -10,38 +10,29 @@ pub struct StoryPageData {
     pub story_title: String,
     pub render_fn: RenderFn,
     pub prop_schema: Schema,
-}
-
-/// Error cases when resolving a story page.
-pub enum StoryPageError {
-    ComponentNotFound(String),
-    StoryNotFound {
-        component_name: String,
-        story_index: usize,
-    },
+    pub status: Option<ComponentStatus>,
 }
 
 /// Look up a component by name and resolve the story at `story_index`.
 ///
-/// Returns the fully resolved [`StoryPageData`] or a [`StoryPageError`]
-/// describing what went wrong.
+/// Returns the fully resolved [`StoryPageData`] or an [`Error`] describing
+/// what went wrong.
 pub fn resolve_story_page(
     component_name: &str,
     story_index: usize,
-) -> Result<StoryPageData, StoryPageError> {
-    let registration = find_component(component_name)
-        .ok_or_else(|| StoryPageError::ComponentNotFound(component_name.to_string()))?;
+) -> Result<StoryPageData, Error> {
+    let registration = find_component(component_name)?;
 
     let stories = (registration.get_stories)();
     let render_fn = registration.render_with_props;
-    let prop_schema = (registration.get_prop_schema)();
+    let prop_schema = registration.prop_schema()?;
 
     let story = stories
         .get(story_index)
         .cloned()
-        .ok_or_else(|| StoryPageError::StoryNotFound {
+        .ok_or_else(|| Error::StoryNotFound {
             component_name: component_name.to_string(),
-            story_index,
+            story: format!("#{story_index}"),
         })?;
 
     let story_title = story.title.clone();
@@ -51,5 +42,6 @@ pub fn resolve_story_page(
         story_title,
         render_fn,
         prop_schema,
+        status: registration.status,
     })
 }
@@ -1,9 +1,24 @@
+use crate::ui::models::{Device, DiffSegment};
+use crate::ui::services::html_diff::diff_html;
 use crate::ui::services::iframe::{
-    build_css_links, build_grid_css, build_outline_css, build_srcdoc, build_zoom_css,
-    capture_inner_html, make_container_id,
+    SrcdocParts, build_css_links, build_grid_css, build_head_html, build_layout_css,
+    build_measure_script, build_outline_css, build_preview_document, build_reset_link,
+    build_zoom_css, category_config_for, make_iframe_id, preview_background, preview_direction,
+    preview_theme, story_decorators,
 };
+use crate::ui::services::live_mount::LIVE_ROOT_HTML;
+use crate::ui::services::snapshot::{
+    SnapshotCapture, SnapshotTarget, build_capture_snapshots_script,
+};
+use crate::ui::services::zoom::{build_apply_zoom_script, build_forward_zoom_script};
+use crate::ui::viewmodels::addon_vm::storybook_addons;
+use crate::ui::viewmodels::play_vm::{PlayState, use_play};
+use crate::ui::viewmodels::story_props_vm::use_story_props;
 use crate::ui::viewmodels::ui_settings::UiSettings;
-use crate::{StoryInfo, StorybookConfig};
+use crate::{
+    Addon, ContextFn, Decorator, PlayFn, PreviewIsolation, StoryInfo, StorybookConfig,
+    ViewportSize, find_component,
+};
 use dioxus::prelude::*;
 
 /// Docking position for the props editor panel.
@@ -15,68 +30,389 @@ pub enum DockPosition {
     Right,
 }
 
+/// Tabs of the story preview's side panel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PanelTab {
+    /// Live props editor.
+    Props,
+    /// Raw props JSON editor.
+    Json,
+    /// Overflow / viewport-escape audit of the preview.
+    Layout,
+    /// Accessibility audit of the preview.
+    Accessibility,
+    /// Utility classes used by the story (Tailwind mode only).
+    Classes,
+    /// Step-by-step results of the story's play function.
+    Interactions,
+    /// Render timings of the story.
+    Performance,
+    /// Panel of the [`Addon`] with this name.
+    Addon(&'static str),
+}
+
+impl PanelTab {
+    /// Returns the tab label.
+    pub fn label(self) -> &'static str {
+        match self {
+            PanelTab::Props => "Props",
+            PanelTab::Json => "JSON",
+            PanelTab::Layout => "Layout",
+            PanelTab::Accessibility => "Accessibility",
+            PanelTab::Classes => "Classes",
+            PanelTab::Interactions => "Interactions",
+            PanelTab::Performance => "Performance",
+            PanelTab::Addon(name) => name,
+        }
+    }
+
+    /// All built-in tabs in display order.
+    pub fn all() -> &'static [PanelTab] {
+        &[
+            PanelTab::Props,
+            PanelTab::Json,
+            PanelTab::Layout,
+            PanelTab::Accessibility,
+            PanelTab::Classes,
+            PanelTab::Interactions,
+            PanelTab::Performance,
+        ]
+    }
+}
+
+/// Side of the comparison view whose props the side panel edits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CompareSide {
+    /// The selected story, on the left.
+    Primary,
+    /// The story it is compared with, on the right.
+    Secondary,
+}
+
+/// State of the second story shown by the comparison view.
+#[derive(Clone, PartialEq)]
+pub struct CompareState {
+    /// `(story_index, title)` of every story of the component.
+    pub stories: Vec<(usize, String)>,
+    /// Index of the compared story; the selected story itself compares two
+    /// props sets.
+    pub story_index: Signal<usize>,
+    /// DOM id of the compared story's preview.
+    pub iframe_id: String,
+    /// Category and story decorators of the compared story.
+    pub decorators: Vec<Decorator>,
+    /// Contexts provided to the compared story.
+    pub contexts: Vec<ContextFn>,
+    pub props_json: Signal<String>,
+    /// The props as defined by the compared story.
+    pub original_props_json: String,
+    /// Which side the props panel edits.
+    pub editing: Signal<CompareSide>,
+    /// Semantic diff of the two previews' markup, once requested.
+    pub markup_diff: Signal<Option<Vec<DiffSegment>>>,
+}
+
 /// Prepared state for a StoryPreview view.
 pub struct StoryPreviewState {
-    pub container_id: String,
+    /// Category and story decorators, outermost first.
+    pub decorators: Vec<Decorator>,
+    /// Contexts provided to the story.
+    pub contexts: Vec<ContextFn>,
+    /// DOM id of the preview iframe the story is mounted into, used by tools
+    /// inspecting or driving its document.
+    pub iframe_id: String,
+    /// DOM id of the preview inspected by the side panel's audits: the
+    /// preview itself, in the comparison view the side whose props are
+    /// edited, or in the sweep views the copy at the selected viewport width
+    /// or preview theme.
+    pub inspected_id: String,
+    /// The preview document: the iframe srcdoc, or the shadow root content
+    /// with [`PreviewIsolation::ShadowDom`], empty when rendering inline.
     pub srcdoc: String,
     pub viewport_width: &'static str,
+    /// Device whose frame is drawn around the preview, or `None` when the
+    /// frame is disabled or not applicable.
+    pub device_frame: Option<Device>,
+    /// Render the story once per viewport preset instead of a single preview.
+    pub responsive_sweep: bool,
+    /// `(light, dark)` srcdocs when the light/dark split view is enabled.
+    pub theme_sweep_srcdocs: Option<(String, String)>,
+    /// The second story when the comparison view is enabled.
+    pub compare: Option<CompareState>,
     pub props_json: Signal<String>,
+    /// The props as defined by the story, to show defaults and modified fields.
+    pub original_props_json: String,
+    /// Whether the story's props loader is still running.
+    pub loading: bool,
+    /// Why the story's props loader failed, its own props being shown instead.
+    pub load_error: Option<String>,
     pub props_visible: Signal<bool>,
     pub props_dock_position: Signal<DockPosition>,
+    pub active_tab: Signal<PanelTab>,
+    /// The story's play function, if any.
+    pub play_fn: Option<PlayFn>,
+    pub play: PlayState,
+    /// Whether the Tailwind integration is enabled.
+    pub tailwind: bool,
+    /// How the preview is isolated, see [`PreviewIsolation`].
+    pub isolation: PreviewIsolation,
+    /// Addons of the storybook, shown after the built-in tabs.
+    pub addons: Vec<&'static dyn Addon>,
+}
+
+impl StoryPreviewState {
+    /// The props edited by the side panel, with the story's original props:
+    /// the compared story's when it is being edited.
+    pub fn edited_props(&self) -> (Signal<String>, String) {
+        match &self.compare {
+            Some(compare) if (compare.editing)() == CompareSide::Secondary => {
+                (compare.props_json, compare.original_props_json.clone())
+            }
+            _ => (self.props_json, self.original_props_json.clone()),
+        }
+    }
+
+    /// Tabs shown in the side panel; the interactions tab only appears for
+    /// stories with a play function, the classes tab in Tailwind mode, and
+    /// the audits need iframe isolation. Addon tabs come last.
+    pub fn tabs(&self) -> impl Iterator<Item = PanelTab> + '_ {
+        PanelTab::all()
+            .iter()
+            .copied()
+            .filter(|tab| match tab {
+                PanelTab::Interactions => self.play_fn.is_some(),
+                PanelTab::Classes => self.tailwind,
+                PanelTab::Layout | PanelTab::Accessibility => {
+                    self.isolation == PreviewIsolation::Iframe
+                }
+                _ => true,
+            })
+            .chain(
+                self.addons
+                    .iter()
+                    .map(|addon| PanelTab::Addon(addon.name())),
+            )
+    }
+
+    /// The addon shown by `tab`, if it is an addon tab.
+    pub fn addon(&self, tab: PanelTab) -> Option<&'static dyn Addon> {
+        match tab {
+            PanelTab::Addon(name) => self.addons.iter().copied().find(|a| a.name() == name),
+            _ => None,
+        }
+    }
 }
 
 /// Custom hook that encapsulates all StoryPreview business logic.
 ///
-/// Handles HTML capture, srcdoc building, context reading, and state management.
+/// Handles srcdoc building, context reading, and state management; the story
+/// itself is mounted live into the preview by the `PreviewFrame` view.
 /// Returns a `StoryPreviewState` with all data the view needs to render.
 pub fn use_story_preview(
     component_name: &str,
     story_index: usize,
     story: &StoryInfo,
 ) -> StoryPreviewState {
-    let mut iframe_html = use_signal(String::new);
-    let props_json = use_signal(|| story.props_json.clone());
+    let story_props = use_story_props(story);
     let props_visible = use_signal(|| true);
     let props_dock_position = use_signal(|| DockPosition::Bottom);
+    let active_tab = use_signal(|| PanelTab::Props);
 
-    let container_id = make_container_id("fullscreen-render", component_name, story_index);
-    let container_id_for_effect = container_id.clone();
+    let iframe_id = make_iframe_id("fullscreen-preview", component_name, story_index);
 
+    // The comparison view starts with the selected story and its own copy of
+    // the props; picking another story resets them to that story's props.
+    let stories = use_hook(|| {
+        find_component(component_name)
+            .map(|reg| (reg.get_stories)())
+            .unwrap_or_default()
+    });
+    let compare_index = use_signal(|| story_index);
+    let mut compare_props_json = use_signal(|| story.props_json.clone());
+    let compare_editing = use_signal(|| CompareSide::Primary);
+    let mut markup_diff = use_signal(|| None);
+    let compare_stories = stories.clone();
     use_effect(move || {
-        let _props_json_value = props_json();
-        if let Some(html) = capture_inner_html(&container_id_for_effect) {
-            iframe_html.set(html);
+        if let Some(compared) = compare_stories.get(compare_index()) {
+            compare_props_json.set(compared.props_json.clone());
+            markup_diff.set(None);
         }
     });
+    let play = use_play(&iframe_id, story.play.clone());
 
     let config = use_context::<StorybookConfig>();
     let ui_settings = use_context::<UiSettings>();
+    use_story_viewport(ui_settings.viewport_width, story.viewport);
     let outline_enabled = (ui_settings.outline_enabled)();
     let grid_enabled = (ui_settings.grid_enabled)();
-    let zoom_level = (ui_settings.zoom_level)();
+    // Zoom changes are applied live by the effect below rather than by
+    // reloading the preview, so gesture zooming stays smooth
+    let zoom_level = *ui_settings.zoom_level.peek();
     let viewport_size = (ui_settings.viewport_width)();
     let dark_bg = (ui_settings.dark_preview_background)();
+    let responsive_sweep = (ui_settings.responsive_sweep)();
+    let device_frame = if (ui_settings.device_frame_enabled)() {
+        viewport_size.device_frame()
+    } else {
+        None
+    };
 
-    let css_links = build_css_links(&config);
+    let category = category_config_for(&config, component_name);
+    let isolation = config.isolation_for(story);
+    let background = story
+        .background
+        .as_deref()
+        .or(category.background.as_deref());
+    let reset_link = build_reset_link(&config, (ui_settings.css_reset)());
+    let head_html = build_head_html(&config);
     let outline_css = build_outline_css(outline_enabled);
+    let scripts = format!(
+        "{}{}",
+        build_measure_script((ui_settings.measure_enabled)()),
+        build_forward_zoom_script()
+    );
     let grid_css = build_grid_css(grid_enabled);
     let zoom_css = build_zoom_css(zoom_level);
-    let background_color = if dark_bg { "#1e1e1e" } else { "#ffffff" };
-    let srcdoc = build_srcdoc(
-        &css_links,
-        outline_css,
-        grid_css,
-        &zoom_css,
-        &iframe_html(),
-        background_color,
-    );
+    let srcdoc_for = |dark: bool| {
+        let parts = SrcdocParts {
+            css_links: &build_css_links(&config, &category, dark),
+            reset_link: &reset_link,
+            head_html: &head_html,
+            outline_css,
+            layout_css: build_layout_css(story.layout),
+            grid_css,
+            zoom_css: &zoom_css,
+            body_html: LIVE_ROOT_HTML,
+            scripts: &scripts,
+            background_color: preview_background(dark, background),
+            theme: preview_theme(dark),
+            direction: preview_direction((ui_settings.rtl_enabled)()),
+        };
+        build_preview_document(&config, isolation, &parts)
+    };
+    let srcdoc = srcdoc_for(dark_bg);
+    let theme_sweep_srcdocs = if (ui_settings.theme_sweep)() {
+        Some((srcdoc_for(false), srcdoc_for(true)))
+    } else {
+        None
+    };
+
+    let zoom_id = iframe_id.clone();
+    use_effect(move || {
+        let zoom_level = (ui_settings.zoom_level)();
+        document::eval(&build_apply_zoom_script(&zoom_id, zoom_level));
+    });
+
+    let compare = (ui_settings.compare)().then(|| {
+        let compared = stories.get(compare_index()).unwrap_or(story);
+        CompareState {
+            stories: stories
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| !s.skipped)
+                .map(|(index, s)| (index, s.title.clone()))
+                .collect(),
+            story_index: compare_index,
+            iframe_id: format!("{iframe_id}-compare"),
+            decorators: story_decorators(&category, &compared.decorators),
+            contexts: compared.contexts.clone(),
+            props_json: compare_props_json,
+            original_props_json: compared.props_json.clone(),
+            editing: compare_editing,
+            markup_diff,
+        }
+    });
+
+    // Same precedence as the view: comparison, then theme sweep, then
+    // responsive sweep
+    let inspected_id = if let Some(compare) = &compare {
+        match (compare.editing)() {
+            CompareSide::Primary => iframe_id.clone(),
+            CompareSide::Secondary => compare.iframe_id.clone(),
+        }
+    } else if theme_sweep_srcdocs.is_some() {
+        sweep_preview_id(&iframe_id, preview_theme(dark_bg))
+    } else if responsive_sweep {
+        sweep_preview_id(&iframe_id, viewport_size.value())
+    } else {
+        iframe_id.clone()
+    };
 
     StoryPreviewState {
-        container_id,
+        decorators: story_decorators(&category, &story.decorators),
+        contexts: story.contexts.clone(),
+        iframe_id,
+        inspected_id,
         srcdoc,
         viewport_width: viewport_size.to_width(),
-        props_json,
+        device_frame,
+        responsive_sweep,
+        theme_sweep_srcdocs,
+        compare,
+        props_json: story_props.props_json,
+        original_props_json: (story_props.original_props_json)(),
+        loading: (story_props.loading)(),
+        load_error: (story_props.load_error)(),
         props_visible,
         props_dock_position,
+        active_tab,
+        play_fn: story.play.clone(),
+        play,
+        tailwind: config.tailwind_css.is_some(),
+        isolation,
+        addons: storybook_addons(&config),
     }
 }
+
+/// DOM id of the copy `variant` (a viewport preset or a preview theme) of
+/// the preview `iframe_id` in the sweep views.
+pub fn sweep_preview_id(iframe_id: &str, variant: &str) -> String {
+    format!("{iframe_id}-{variant}")
+}
+
+/// Capture the markup of both sides of the comparison view and store its
+/// semantic diff in `compare.markup_diff`.
+pub fn diff_compared_markup(primary_id: String, compare: &CompareState) {
+    let mut markup_diff = compare.markup_diff;
+    let targets: Vec<SnapshotTarget> = [primary_id, compare.iframe_id.clone()]
+        .into_iter()
+        .map(|preview_id| SnapshotTarget {
+            story_id: preview_id.clone(),
+            preview_id,
+            component: String::new(),
+            story: String::new(),
+            masks: Vec::new(),
+        })
+        .collect();
+    spawn(async move {
+        let Ok(capture) = document::eval(&build_capture_snapshots_script(&targets))
+            .join::<SnapshotCapture>()
+            .await
+        else {
+            return;
+        };
+        if let [primary, compared] = capture.snapshots.as_slice() {
+            markup_diff.set(Some(diff_html(&primary.html, &compared.html)));
+        }
+    });
+}
+
+/// Pre-selects the story's preferred `viewport` while it is open. The top bar
+/// can still change it; if it didn't, the previous viewport comes back when
+/// the story is left.
+fn use_story_viewport(mut viewport_width: Signal<ViewportSize>, viewport: Option<ViewportSize>) {
+    let previous = use_hook(move || {
+        let previous = *viewport_width.peek();
+        if let Some(viewport) = viewport {
+            viewport_width.set(viewport);
+        }
+        previous
+    });
+    use_drop(move || {
+        if let Some(viewport) = viewport
+            && *viewport_width.peek() == viewport
+        {
+            viewport_width.set(previous);
+        }
+    });
+}
@@ -0,0 +1,69 @@
+use crate::StoryInfo;
+use dioxus::core::Task;
+use dioxus::prelude::*;
+
+/// Props of a story, resolved by its loader when it has one.
+pub struct StoryProps {
+    /// The edited props.
+    pub props_json: Signal<String>,
+    /// The props as defined by the story, the loaded ones once resolved.
+    pub original_props_json: Signal<String>,
+    /// Whether the story's loader is still running.
+    pub loading: Signal<bool>,
+    /// Why the story's loader failed; the story's own props stand in for
+    /// the loaded ones.
+    pub load_error: Signal<Option<String>>,
+}
+
+/// Custom hook holding the edited props of a story.
+///
+/// Starts with the story's props; for a story with a
+/// [`loader`](crate::Story::with_loader), runs it and replaces both the
+/// edited and the original props with the loaded ones. When the story
+/// changes (e.g. its props or loader), the props are reset and the loader
+/// runs again.
+pub fn use_story_props(story: &StoryInfo) -> StoryProps {
+    let mut props_json = use_signal(|| story.props_json.clone());
+    let mut original_props_json = use_signal(|| story.props_json.clone());
+    let mut loading = use_signal(|| story.loader.is_some());
+    let mut load_error = use_signal(|| None);
+    let mut task = use_signal(|| None::<Task>);
+
+    use_effect(use_reactive(
+        (&story.props_json, &story.loader),
+        move |(story_props, loader)| {
+            if let Some(task) = task.take() {
+                task.cancel();
+            }
+            if *original_props_json.peek() != story_props {
+                original_props_json.set(story_props.clone());
+                props_json.set(story_props.clone());
+            }
+            if load_error.peek().is_some() {
+                load_error.set(None);
+            }
+            if *loading.peek() != loader.is_some() {
+                loading.set(loader.is_some());
+            }
+            if let Some(loader) = loader {
+                task.set(Some(spawn(async move {
+                    match loader.load().await {
+                        Ok(loaded) => {
+                            original_props_json.set(loaded.clone());
+                            props_json.set(loaded);
+                        }
+                        Err(err) => load_error.set(Some(err)),
+                    }
+                    loading.set(false);
+                })));
+            }
+        },
+    ));
+
+    StoryProps {
+        props_json,
+        original_props_json,
+        loading,
+        load_error,
+    }
+}
@@ -0,0 +1,217 @@
+use crate::find_component;
+use crate::ui::services::iframe::make_iframe_id;
+use crate::ui::services::snapshot::{
+    SnapshotCapture, SnapshotTarget, build_capture_snapshots_script,
+};
+use crate::ui::services::story_ids::collect_story_ids;
+use crate::ui::services::thumbnails::{
+    CachedThumbnail, build_load_thumbnails_script, build_remove_thumbnails_script,
+    build_save_thumbnail_script, build_touch_thumbnail_script, thumbnail_key,
+};
+use crate::ui::viewmodels::snapshot_vm::SnapshotStory;
+use dioxus::prelude::*;
+use std::collections::HashMap;
+
+/// Stories mounted and captured at once for their thumbnails.
+const THUMBNAIL_BATCH_SIZE: usize = 4;
+
+/// Story thumbnails: the captured HTML of the stories shown in the sidebar
+/// and the galleries, and the stories waiting for one. Provided as context.
+///
+/// Thumbnails are saved in `localStorage` with the [key](story_thumbnail_key)
+/// of the story they were captured from, so only the stories that changed
+/// since are captured again. The least recently shown ones are evicted when
+/// the storage is full.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ThumbnailStore {
+    /// Captured thumbnail of each story, by story id.
+    pub thumbnails: Signal<HashMap<String, CachedThumbnail>>,
+    /// The stories being captured, mounted off-screen by the view.
+    pub batch: Signal<Vec<SnapshotStory>>,
+    /// Stories waiting to be captured, in request order.
+    queue: Signal<Vec<SnapshotStory>>,
+    /// Whether the saved thumbnails are loaded; captures wait for them.
+    loaded: Signal<bool>,
+}
+
+impl ThumbnailStore {
+    /// The thumbnail of `story`, unless it was never captured or is stale.
+    pub fn get(&self, story: &SnapshotStory) -> Option<String> {
+        let key = story_thumbnail_key(story);
+        self.thumbnails
+            .read()
+            .get(&story.story.id)
+            .filter(|cached| cached.key == key)
+            .map(|cached| cached.html.clone())
+    }
+
+    /// Queue `story` for capture, unless it has an up-to-date thumbnail —
+    /// marked as used — or is already waiting for one.
+    pub fn request(mut self, story: SnapshotStory) {
+        let key = story_thumbnail_key(&story);
+        if self
+            .thumbnails
+            .peek()
+            .get(&story.story.id)
+            .is_some_and(|cached| cached.key == key)
+        {
+            document::eval(&build_touch_thumbnail_script(&story.story.id));
+            return;
+        }
+        let known = |queued: &[SnapshotStory]| queued.iter().any(|s| s.story.id == story.story.id);
+        if known(&self.queue.peek()) || known(&self.batch.peek()) {
+            return;
+        }
+        self.queue.write().push(story);
+    }
+
+    /// Capture the next stories of the queue, unless a batch is running or
+    /// the saved thumbnails aren't loaded yet.
+    fn capture_next(mut self) {
+        if !*self.loaded.peek() || !self.batch.peek().is_empty() || self.queue.peek().is_empty() {
+            return;
+        }
+        let chunk: Vec<SnapshotStory> = {
+            let mut queue = self.queue.write();
+            let count = queue.len().min(THUMBNAIL_BATCH_SIZE);
+            queue.drain(..count).collect()
+        };
+        let targets = chunk
+            .iter()
+            .map(|story| SnapshotTarget {
+                preview_id: thumbnail_preview_id(story),
+                story_id: story.story.id.clone(),
+                component: story.component_name.clone(),
+                story: story.story.title.clone(),
+                masks: story.story.masks.clone(),
+            })
+            .collect::<Vec<_>>();
+        let keys: HashMap<String, String> = chunk
+            .iter()
+            .map(|story| (story.story.id.clone(), story_thumbnail_key(story)))
+            .collect();
+        self.batch.set(chunk);
+        spawn(async move {
+            let capture = document::eval(&build_capture_snapshots_script(&targets))
+                .join::<SnapshotCapture>()
+                .await;
+            if let Ok(capture) = capture {
+                self.thumbnails.with_mut(|thumbnails| {
+                    for snapshot in capture.snapshots.into_iter().filter(|s| s.rendered) {
+                        if let Some(key) = keys.get(&snapshot.id) {
+                            let thumbnail = CachedThumbnail {
+                                key: key.clone(),
+                                html: snapshot.html,
+                            };
+                            document::eval(&build_save_thumbnail_script(&snapshot.id, &thumbnail));
+                            thumbnails.insert(snapshot.id, thumbnail);
+                        }
+                    }
+                });
+            }
+            self.batch.set(Vec::new());
+        });
+    }
+}
+
+/// The [`thumbnail_key`] of `story`: its props, title and layout, and the
+/// name and source date of its component.
+pub fn story_thumbnail_key(story: &SnapshotStory) -> String {
+    let source_updated = find_component(&story.component_name)
+        .ok()
+        .and_then(|registration| registration.source_updated)
+        .map(|updated| updated.to_string())
+        .unwrap_or_default();
+    thumbnail_key(&[
+        &story.component_name,
+        &source_updated,
+        &story.story.title,
+        &format!("{:?}", story.story.layout),
+        &story.story.props_json,
+    ])
+}
+
+/// DOM id of the off-screen preview the thumbnail of `story` is captured
+/// from.
+pub fn thumbnail_preview_id(story: &SnapshotStory) -> String {
+    make_iframe_id("thumbnail", &story.component_name, story.story_index)
+}
+
+/// The story at `story_index` of `component_name`, to request its
+/// thumbnail.
+pub fn thumbnail_story(component_name: &str, story_index: usize) -> Option<SnapshotStory> {
+    let registration = find_component(component_name).ok()?;
+    let story = (registration.get_stories)().into_iter().nth(story_index)?;
+    Some(SnapshotStory {
+        component_name: component_name.to_string(),
+        story_index,
+        story,
+        render_fn: registration.render_with_props,
+    })
+}
+
+/// Custom hook providing the [`ThumbnailStore`]: loading the saved
+/// thumbnails of the stories that still exist (removing the others), and
+/// capturing the requested ones batch by batch, each saved once captured.
+pub fn use_thumbnail_store() -> ThumbnailStore {
+    let store = use_context_provider(|| ThumbnailStore {
+        thumbnails: Signal::new(HashMap::new()),
+        batch: Signal::new(Vec::new()),
+        queue: Signal::new(Vec::new()),
+        loaded: Signal::new(false),
+    });
+
+    use_hook(move || {
+        let mut thumbnails = store.thumbnails;
+        let mut loaded = store.loaded;
+        spawn(async move {
+            let saved = document::eval(&build_load_thumbnails_script())
+                .join::<HashMap<String, CachedThumbnail>>()
+                .await
+                .unwrap_or_default();
+            let ids = collect_story_ids();
+            let (current, removed): (HashMap<_, _>, HashMap<_, _>) =
+                saved.into_iter().partition(|(id, _)| ids.contains(id));
+            if !removed.is_empty() {
+                let removed = removed.into_keys().collect::<Vec<_>>();
+                document::eval(&build_remove_thumbnails_script(&removed));
+            }
+            thumbnails.write().extend(current);
+            loaded.set(true);
+        });
+    });
+
+    use_effect(move || {
+        // Subscribe to the queue, the end of each batch and the loading
+        let _ = (
+            store.queue.read().len(),
+            store.batch.read().len(),
+            (store.loaded)(),
+        );
+        store.capture_next();
+    });
+
+    store
+}
+
+/// Custom hook returning the thumbnail of `story` — `None` until it has been
+/// captured, or outside the storybook — and requesting it when `requested`.
+pub fn use_thumbnail(story: Option<SnapshotStory>, requested: bool) -> Option<String> {
+    let store = try_use_context::<ThumbnailStore>();
+
+    use_effect(use_reactive(
+        (&requested, &story),
+        move |(requested, story)| {
+            if requested
+                && let Some(store) = store
+                && let Some(story) = story
+            {
+                store.request(story);
+            }
+        },
+    ));
+
+    store
+        .zip(story)
+        .and_then(|(store, story)| store.get(&story))
+}
@@ -1,3 +1,4 @@
+use crate::ZoomConfig;
 use crate::ui::models::ViewportSize;
 use dioxus::prelude::*;
 
@@ -11,9 +12,35 @@ pub struct UiSettings {
     pub dark_preview_background: Signal<bool>,
     pub grid_enabled: Signal<bool>,
     pub outline_enabled: Signal<bool>,
+    pub measure_enabled: Signal<bool>,
+    /// Render the previews right-to-left.
+    pub rtl_enabled: Signal<bool>,
     pub fullscreen: Signal<bool>,
     pub zoom_level: Signal<i32>,
+    /// Range and step shared by every zoom widget, from
+    /// [`StorybookConfig::zoom`](crate::StorybookConfig::zoom).
+    pub zoom: ZoomConfig,
     pub viewport_width: Signal<ViewportSize>,
+    pub device_frame_enabled: Signal<bool>,
+    pub responsive_sweep: Signal<bool>,
+    pub theme_sweep: Signal<bool>,
+    pub compare: Signal<bool>,
+    pub presenting: Signal<bool>,
+    /// Load the CSS reset into the previews, see
+    /// [`build_reset_link`](crate::ui::services::iframe::build_reset_link).
+    pub css_reset: Signal<bool>,
+}
+
+impl UiSettings {
+    /// Default settings, with the zoom range of `zoom` (starting at 100 %
+    /// when within it).
+    pub fn with_zoom(zoom: ZoomConfig) -> Self {
+        UiSettings {
+            zoom_level: Signal::new(zoom.clamp(100)),
+            zoom,
+            ..UiSettings::default()
+        }
+    }
 }
 
 impl Default for UiSettings {
@@ -22,9 +49,18 @@ impl Default for UiSettings {
             dark_preview_background: Signal::new(false),
             grid_enabled: Signal::new(false),
             outline_enabled: Signal::new(false),
+            measure_enabled: Signal::new(false),
+            rtl_enabled: Signal::new(false),
             fullscreen: Signal::new(false),
             zoom_level: Signal::new(100),
+            zoom: ZoomConfig::default(),
             viewport_width: Signal::new(ViewportSize::FullWidth),
+            device_frame_enabled: Signal::new(false),
+            responsive_sweep: Signal::new(false),
+            theme_sweep: Signal::new(false),
+            compare: Signal::new(false),
+            presenting: Signal::new(false),
+            css_reset: Signal::new(false),
         }
     }
 }
@@ -0,0 +1,32 @@
+use crate::ZoomConfig;
+use crate::ui::services::zoom::{build_fit_width_script, build_listen_zoom_script, zoom_by};
+use dioxus::prelude::*;
+
+/// Custom hook zooming the fullscreen preview with Ctrl+wheel and pinch
+/// gestures, in small steps proportional to the gesture, within the range
+/// of `zoom`.
+pub fn use_gesture_zoom(mut zoom_level: Signal<i32>, zoom: ZoomConfig) {
+    use_hook(move || {
+        spawn(async move {
+            let mut gestures = document::eval(&build_listen_zoom_script());
+            while let Ok(factor) = gestures.recv::<f64>().await {
+                let current = *zoom_level.peek();
+                zoom_level.set(zoom_by(&zoom, current, factor));
+            }
+        });
+    });
+}
+
+/// Set `zoom_level` so the widest element of the preview `preview_id` fits
+/// the preview width, within the range of `zoom`.
+pub fn fit_to_width(mut zoom_level: Signal<i32>, zoom: ZoomConfig, preview_id: String) {
+    let current = *zoom_level.peek();
+    spawn(async move {
+        let fitted = document::eval(&build_fit_width_script(&preview_id, current))
+            .join::<Option<i32>>()
+            .await;
+        if let Ok(Some(fitted)) = fitted {
+            zoom_level.set(zoom.clamp(fitted));
+        }
+    });
+}
@@ -0,0 +1,218 @@
+//! Watch mode: a live storybook summary in the terminal.
+//!
+//! The storybook emits [`tracing`] events under the [`TARGET`] target when the
+//! storybook starts (see [`RegistrySummary`](crate::RegistrySummary)), when a
+//! story is selected or fails to resolve, when a story fails to render or
+//! falls back to its default props, when the app panics, when a story is
+//! audited for accessibility, and when story ids change between builds.
+//! With the `watch` feature, [`WatchLayer`] prints these events as a compact
+//! summary on stdout, so problems surface in the terminal even when the
+//! browser tab isn't visible:
+//!
+//! ```rust,ignore
+//! fn main() {
+//!     storybook::watch::init();
+//!     storybook::launch(StorybookConfig::default());
+//! }
+//! ```
+//!
+//! ```text
+//! [storybook] ◆ 12 components, 48 stories, 3 docs
+//! [storybook] ▶ ExampleButton / Disabled
+//! [storybook] ⚠ a11y: 2 violations (1 critical)
+//! [storybook] ✗ panicked at src/button.rs:12:5: index out of bounds
+//! ```
+//!
+//! Without the feature, the events still reach whatever subscriber is
+//! installed (on the web, Dioxus' default logger prints them to the browser
+//! console). The startup banner is printed on stdout when there is none.
+
+use std::sync::{Mutex, Once};
+
+/// `tracing` target of the watch-mode events.
+pub const TARGET: &str = "storybook::watch";
+
+/// The last render error reported, so that a story re-rendering with the
+/// same error (e.g. on each keystroke in the props editor) reports it once.
+static LAST_RENDER_ERROR: Mutex<Option<String>> = Mutex::new(None);
+
+/// Components whose `auto_variants` were truncated, reported once each as
+/// their stories are listed again and again.
+static AUTO_VARIANTS_TRUNCATED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Report that a story was selected in the sidebar.
+pub(crate) fn story_selected(component_name: &str, story_title: &str) {
+    // Selecting a story again reports its errors again
+    if let Ok(mut last) = LAST_RENDER_ERROR.lock() {
+        *last = None;
+    }
+    tracing::info!(target: TARGET, "▶ {component_name} / {story_title}");
+}
+
+/// Report a selected story that could not be resolved, e.g. an unknown
+/// component or story index.
+pub(crate) fn story_unresolved(message: &str) {
+    tracing::error!(target: TARGET, "✗ {message}");
+}
+
+/// Report an error raised while rendering a story, unless it is the same
+/// as the last one reported.
+pub(crate) fn render_error(message: &str) {
+    if let Ok(mut last) = LAST_RENDER_ERROR.lock() {
+        if last.as_deref() == Some(message) {
+            return;
+        }
+        *last = Some(message.to_string());
+    }
+    tracing::error!(target: TARGET, "✗ render error: {message}");
+}
+
+/// Print the startup banner: the registry counts, then one line per problem.
+///
+/// Without a global subscriber (e.g. [`init`] wasn't called), the banner is
+/// printed on stdout rather than dropped, so misconfigured registrations are
+/// noticed even outside watch mode.
+pub(crate) fn registry_summary(summary: &crate::RegistrySummary) {
+    if !tracing::dispatcher::has_been_set() {
+        println!("[storybook] ◆ {summary}");
+        for warning in summary.warnings() {
+            println!("[storybook] ⚠ {warning}");
+        }
+        return;
+    }
+    tracing::info!(target: TARGET, "◆ {summary}");
+    for warning in summary.warnings() {
+        tracing::warn!(target: TARGET, "⚠ {warning}");
+    }
+}
+
+/// Report that edits to borrowed props are ignored from now on, the values
+/// kept for them having reached `budget` bytes.
+pub(crate) fn borrowed_props_budget_spent(budget: usize) {
+    tracing::warn!(
+        target: TARGET,
+        "⚠ edits to borrowed props are ignored: their values reached {} KiB (use owned props to edit them freely)",
+        budget / 1024
+    );
+}
+
+/// Report that `auto_variants` generated `kept` of the `combinations` of
+/// props of `component_name`, once per component.
+pub(crate) fn auto_variants_truncated(component_name: &str, combinations: usize, kept: usize) {
+    if let Ok(mut reported) = AUTO_VARIANTS_TRUNCATED.lock() {
+        if reported.iter().any(|name| name == component_name) {
+            return;
+        }
+        reported.push(component_name.to_string());
+    }
+    tracing::warn!(
+        target: TARGET,
+        "⚠ {component_name}: auto_variants generated {kept} of {combinations} stories (list fewer props to see the others)"
+    );
+}
+
+/// Report presentation slides matching no story id nor documentation page.
+pub(crate) fn unknown_slides(entries: &[String]) {
+    tracing::warn!(
+        target: TARGET,
+        "⚠ unknown presentation slides: {}",
+        entries.join(", ")
+    );
+}
+
+/// Report story ids of the previous build that no longer exist and have no
+/// migration.
+pub(crate) fn story_ids_removed(ids: &[String]) {
+    tracing::warn!(
+        target: TARGET,
+        "⚠ story ids removed since the last build: {} (add migrations with `with_story_migrations`)",
+        ids.join(", ")
+    );
+}
+
+/// Report the outcome of an accessibility audit.
+pub(crate) fn a11y_audited(violations: usize, critical: usize) {
+    if violations == 0 {
+        tracing::info!(target: TARGET, "✓ a11y: no violations");
+    } else {
+        tracing::warn!(target: TARGET, "⚠ a11y: {violations} violations ({critical} critical)");
+    }
+}
+
+/// Report a storybook [`Error`](crate::Error) as a watch-mode event.
+///
+/// Used by the generated render functions when story props fail to
+/// deserialize and the story falls back to its default props. An error
+/// identical to the last one reported (same component and message) is not
+/// reported again.
+pub fn report(error: &crate::Error) {
+    render_error(&error.to_string());
+}
+
+/// Report panics as watch-mode events, then run the previously installed hook.
+///
+/// Installed once per process, however many times the app mounts.
+pub(crate) fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            tracing::error!(target: TARGET, "✗ {info}");
+            previous(info);
+        }));
+    });
+}
+
+/// [`tracing_subscriber::Layer`] printing the storybook's watch-mode events
+/// on stdout.
+#[cfg(feature = "watch")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WatchLayer;
+
+#[cfg(feature = "watch")]
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for WatchLayer {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        if event.metadata().target() != TARGET {
+            return;
+        }
+        let mut message = MessageVisitor(String::new());
+        event.record(&mut message);
+        println!("[storybook] {}", message.0);
+    }
+}
+
+/// Extracts the formatted `message` field of an event.
+#[cfg(feature = "watch")]
+struct MessageVisitor(String);
+
+#[cfg(feature = "watch")]
+impl tracing::field::Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+/// Install a global subscriber printing watch-mode events with
+/// [`WatchLayer`], and other `INFO`-and-above logs with the default
+/// formatter.
+///
+/// Call it before [`launch`](crate::launch); Dioxus then keeps this
+/// subscriber instead of installing its own.
+#[cfg(feature = "watch")]
+pub fn init() {
+    use tracing_subscriber::prelude::*;
+
+    let logs = tracing_subscriber::fmt::layer().with_filter(tracing_subscriber::filter::filter_fn(
+        |meta| meta.target() != TARGET && *meta.level() <= tracing::Level::INFO,
+    ));
+    let _ = tracing_subscriber::registry()
+        .with(logs)
+        .with(WatchLayer)
+        .try_init();
+}
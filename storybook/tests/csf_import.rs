@@ -0,0 +1,80 @@
+use storybook::scaffold::csf::{self, CsfFile};
+use storybook::serde_json::{Map, Value, json};
+
+const BUTTON: &str = include_str!("fixtures/csf/button.stories.jsx");
+
+fn button() -> CsfFile {
+    csf::parse(BUTTON, "Button").expect("the fixture has a default export")
+}
+
+fn args(value: Value) -> Map<String, Value> {
+    value.as_object().cloned().unwrap_or_default()
+}
+
+#[test]
+fn reads_the_default_export() {
+    let file = button();
+    assert_eq!(file.title, "Forms/Inputs/Button");
+    assert_eq!(file.component.as_deref(), Some("Button"));
+    assert_eq!(file.component_name(), "Button");
+    assert_eq!(file.tag(), "Forms/Inputs");
+}
+
+#[test]
+fn falls_back_to_the_default_title() {
+    let file = csf::parse("export default { component: Card };", "Layout/Card").unwrap();
+    assert_eq!(file.title, "Layout/Card");
+    assert!(csf::parse("export const Primary = {};", "Card").is_none());
+}
+
+#[test]
+fn reads_named_story_exports() {
+    let file = button();
+    let names: Vec<(&str, &str)> = file
+        .stories
+        .iter()
+        .map(|story| (story.export_name.as_str(), story.name.as_str()))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("Primary", "Primary"),
+            ("WithIcon", "With an icon"),
+            ("Secondary", "Secondary button"),
+        ]
+    );
+    assert_eq!(
+        file.stories[0].args,
+        args(json!({ "primary": true, "label": "Primary", "count": 3 }))
+    );
+    assert_eq!(
+        file.stories[1].description.as_deref(),
+        Some("A button with a leading icon")
+    );
+    assert_eq!(
+        file.stories[2].args,
+        args(json!({ "label": "It's secondary", "primary": false }))
+    );
+}
+
+#[test]
+fn skips_args_json_cannot_represent() {
+    let file = button();
+    // `fn()`, JSX, arrow functions and variables are left out
+    assert_eq!(
+        file.args,
+        args(json!({ "size": "medium", "disabled": false }))
+    );
+    assert_eq!(
+        file.stories[1].args,
+        args(json!({ "tags": ["new", "beta"], "ratio": 1.5 }))
+    );
+}
+
+#[test]
+fn generates_the_stories_skeleton() {
+    assert_eq!(
+        button().to_rust("button.stories.jsx"),
+        include_str!("fixtures/csf/button.rs")
+    );
+}
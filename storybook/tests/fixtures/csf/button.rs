@@ -0,0 +1,71 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+/// TODO: port `Button` from `button.stories.jsx`.
+#[storybook(tag = "Forms/Inputs")]
+#[component]
+pub fn Button(
+    disabled: bool,
+    size: String,
+    count: i64,
+    label: String,
+    primary: bool,
+    ratio: f64,
+    tags: Vec<String>,
+) -> Element {
+    rsx! {
+        div {
+            div { "disabled: " {format!("{:?}", disabled)} }
+            div { "size: " {format!("{:?}", size)} }
+            div { "count: " {format!("{:?}", count)} }
+            div { "label: " {format!("{:?}", label)} }
+            div { "primary: " {format!("{:?}", primary)} }
+            div { "ratio: " {format!("{:?}", ratio)} }
+            div { "tags: " {format!("{:?}", tags)} }
+        }
+    }
+}
+
+impl Stories for ButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![
+            Story::new(
+                "Primary",
+                Self {
+                    disabled: false,
+                    size: "medium".to_string(),
+                    count: 3,
+                    label: "Primary".to_string(),
+                    primary: true,
+                    ratio: 0.0,
+                    tags: Vec::new(),
+                },
+            ),
+            Story::with_description(
+                "With an icon",
+                "A button with a leading icon",
+                Self {
+                    disabled: false,
+                    size: "medium".to_string(),
+                    count: 0,
+                    label: String::new(),
+                    primary: false,
+                    ratio: 1.5,
+                    tags: vec!["new".to_string(), "beta".to_string()],
+                },
+            ),
+            Story::new(
+                "Secondary button",
+                Self {
+                    disabled: false,
+                    size: "medium".to_string(),
+                    count: 0,
+                    label: "It's secondary".to_string(),
+                    primary: false,
+                    ratio: 0.0,
+                    tags: Vec::new(),
+                },
+            ),
+        ]
+    }
+}
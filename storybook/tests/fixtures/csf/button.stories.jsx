@@ -0,0 +1,40 @@
+import { fn } from '@storybook/test';
+import { Button } from './Button';
+import { Icon } from './Icon';
+
+const defaultLabel = 'Button';
+
+// Shared by every story
+export default {
+  title: 'Forms/Inputs/Button',
+  component: Button,
+  args: { onClick: fn(), size: 'medium', disabled: false },
+};
+
+export const Primary = {
+  args: {
+    primary: true,
+    label: 'Primary', // trailing comment
+    count: 3,
+  },
+};
+
+export const WithIcon = {
+  name: 'With an icon',
+  parameters: {
+    docs: { description: { story: 'A button with a leading icon' } },
+  },
+  args: {
+    label: defaultLabel,
+    icon: <Icon name="plus" />,
+    render: () => null,
+    tags: ['new', 'beta'],
+    ratio: 1.5,
+  },
+};
+
+/* CSF 2 */
+const Template = (args) => <Button {...args} />;
+export const Secondary = Template.bind({});
+Secondary.storyName = 'Secondary button';
+Secondary.args = { label: "It's secondary", primary: false };
@@ -0,0 +1,11 @@
+use storybook::storybook;
+
+#[storybook(tag = "Tests")]
+#[dioxus::prelude::component]
+fn Labeled<T: Clone + PartialEq + std::fmt::Display + 'static>(
+    value: T,
+) -> dioxus::prelude::Element {
+    dioxus::prelude::rsx! { span { "{value}" } }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dioxus::prelude::*;
+use storybook::storybook;
+
+#[storybook(tag = "Tests")]
+#[component]
+fn Badge(label: String) -> Element {
+    rsx! { span { "{label}" } }
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+#[storybook(tag = "Tests", wieght = 2)]
+#[component]
+fn Badge(label: String) -> Element {
+    rsx! { span { "{label}" } }
+}
+
+impl Stories for BadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                label: "New".to_string(),
+            },
+        )]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+#[derive(Clone, PartialEq)]
+struct Theme {
+    accent: String,
+}
+
+#[storybook(tag = "Tests")]
+#[component]
+fn Badge(label: String, theme: Theme) -> Element {
+    rsx! { span { color: "{theme.accent}", "{label}" } }
+}
+
+impl Stories for BadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                label: "New".to_string(),
+                theme: Theme {
+                    accent: "red".to_string(),
+                },
+            },
+        )]
+    }
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+use dioxus::prelude::*;
+use std::fmt::Display;
+use storybook::{Stories, Story, storybook};
+
+#[storybook(tag = "Lists", concrete = "List::<String>")]
+#[component]
+fn List<T: Display + Clone + PartialEq + 'static>(items: Vec<T>) -> Element {
+    rsx! {
+        ul {
+            for item in items {
+                li { "{item}" }
+            }
+        }
+    }
+}
+
+impl Stories for ListProps<String> {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Fruits",
+            Self {
+                items: vec!["Apple".to_string(), "Pear".to_string()],
+            },
+        )]
+    }
+}
+
+fn main() {
+    let list = storybook::inventory::iter::<storybook::ComponentRegistration>
+        .into_iter()
+        .find(|registration| registration.name == "List")
+        .unwrap();
+    let story = list.story("Fruits").unwrap();
+    assert!(story.props_json.contains("Pear"));
+    assert!(list.check_props(r#"{"items": ["Plum"]}"#).is_ok());
+    assert!(list.check_props(r#"{"items": [1]}"#).is_err());
+}
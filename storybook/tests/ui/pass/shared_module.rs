@@ -0,0 +1,48 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+#[storybook(tag = "Forms", module = "__stories")]
+#[component]
+fn Button(label: String) -> Element {
+    rsx! { button { "{label}" } }
+}
+
+impl Stories for ButtonProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                label: "Save".to_string(),
+            },
+        )]
+    }
+}
+
+#[storybook(tag = "Display", module = "__stories")]
+#[component]
+fn Badge(label: String) -> Element {
+    rsx! { span { "{label}" } }
+}
+
+impl Stories for BadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                label: "New".to_string(),
+            },
+        )]
+    }
+}
+
+fn main() {
+    let _: Option<__stories_button::ButtonStoryProps> = None;
+    let _: Option<__stories_badge::BadgeStoryProps> = None;
+    for name in ["Button", "Badge"] {
+        assert!(
+            storybook::inventory::iter::<storybook::ComponentRegistration>
+                .into_iter()
+                .any(|registration| registration.name == name)
+        );
+    }
+}
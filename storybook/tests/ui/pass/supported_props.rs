@@ -0,0 +1,31 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+#[storybook(tag = "Tests")]
+#[component]
+fn Badge(
+    label: String,
+    count: Option<u32>,
+    #[props(default = false)] highlighted: bool,
+    onclick: EventHandler<MouseEvent>,
+) -> Element {
+    rsx! {
+        span { onclick: move |event| onclick.call(event), "{label}" }
+    }
+}
+
+impl Stories for BadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                label: "New".to_string(),
+                count: Some(3),
+                highlighted: false,
+                onclick: EventHandler::new(|_| {}),
+            },
+        )]
+    }
+}
+
+fn main() {}
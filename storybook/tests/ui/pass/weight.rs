@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+use storybook::{Stories, Story, storybook};
+
+#[storybook(tag = "Tests", weight = -2147483648)]
+#[component]
+fn Badge(label: String) -> Element {
+    rsx! { span { "{label}" } }
+}
+
+impl Stories for BadgeProps {
+    fn stories() -> Vec<Story<Self>> {
+        vec![Story::new(
+            "Default",
+            Self {
+                label: "New".to_string(),
+            },
+        )]
+    }
+}
+
+fn main() {
+    let badge = storybook::inventory::iter::<storybook::ComponentRegistration>
+        .into_iter()
+        .find(|registration| registration.name == "Badge")
+        .unwrap();
+    assert_eq!(badge.weight, i32::MIN);
+}